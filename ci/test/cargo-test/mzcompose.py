@@ -32,6 +32,7 @@ from materialize.mzbuild import (
 from materialize.mzcompose.composition import Composition, WorkflowArgumentParser
 from materialize.mzcompose.service import Service as MzComposeService
 from materialize.mzcompose.services.azurite import Azurite
+from materialize.mzcompose.services.fake_gcs import FakeGcs
 from materialize.mzcompose.services.foundationdb import FoundationDB
 from materialize.mzcompose.services.kafka import Kafka
 from materialize.mzcompose.services.metadata_store import CockroachOrPostgresMetadata
@@ -80,6 +81,10 @@ SERVICES = [
         ports=["40111:10000"],
         allow_host_ports=True,
     ),
+    FakeGcs(
+        ports=["40112:4443"],
+        allow_host_ports=True,
+    ),
     MzComposeService(
         "clusterd", {"mzbuild": "clusterd"}
     ),  # Only to download the binary
@@ -130,6 +135,7 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
         "foundationdb",
         "minio",
         "azurite",
+        "fake-gcs",
     )
     # Heads up: this intentionally runs on the host rather than in a Docker
     # image. See database-issues#3739.
@@ -162,6 +168,7 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_S3_BUCKET="mz-test-persist-1d-lifecycle-delete",
         MZ_S3_UPLOADER_TEST_S3_BUCKET="mz-test-1d-lifecycle-delete",
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_CONTAINER="mz-test-azure",
+        MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET="mz-test-gcs",
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_POSTGRES_URL=metadata_backend_url,
         FDB_CLUSTER_FILE=fdb_cluster_file.name,
     )
@@ -0,0 +1,47 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+
+from materialize.mzcompose.service import Service
+
+
+def gcs_blob_uri(address: str = "fake-gcs") -> str:
+    return f"gs://persist/persist?endpoint=http://{address}:4443&anonymous=true"
+
+
+class FakeGcs(Service):
+    def __init__(
+        self,
+        name: str = "fake-gcs",
+        image: str = "fsouza/fake-gcs-server:1.52.2",
+        ports: list[int | str] = [4443],
+        allow_host_ports: bool = False,
+    ) -> None:
+        super().__init__(
+            name=name,
+            config={
+                "image": image,
+                "command": [
+                    "-scheme",
+                    "http",
+                    "-port",
+                    "4443",
+                    "-backend",
+                    "memory",
+                ],
+                "ports": ports,
+                "allow_host_ports": allow_host_ports,
+                "init": True,
+                "healthcheck": {
+                    "test": "wget -q -O /dev/null http://localhost:4443/storage/v1/b",
+                    "interval": "1s",
+                    "start_period": "30s",
+                },
+            },
+        )
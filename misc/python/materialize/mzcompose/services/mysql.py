@@ -90,3 +90,47 @@ class MySql(Service):
             name=name,
             config=config,
         )
+
+
+class MariaDb(Service):
+    DEFAULT_VERSION = "11.4"
+
+    def __init__(
+        self,
+        root_password: str = MySql.DEFAULT_ROOT_PASSWORD,
+        name: str = "mysql",
+        version: str = DEFAULT_VERSION,
+        port: int = 3306,
+    ) -> None:
+        config: ServiceConfig = {
+            "image": f"mariadb:{version}",
+            "init": True,
+            "ports": [port],
+            "environment": [
+                f"MARIADB_ROOT_PASSWORD={root_password}",
+            ],
+            "command": [
+                "--log-bin=mysql-bin",
+                "--binlog-format=row",
+                "--binlog-row-image=full",
+                "--binlog-row-metadata=full",
+                "--gtid-strict-mode=ON",
+                "--server-id=1",
+                "--max-connections=1000",
+            ],
+            "healthcheck": {
+                "test": [
+                    "CMD",
+                    "healthcheck.sh",
+                    "--connect",
+                    "--innodb_initialized",
+                ],
+                "interval": "1s",
+                "start_period": "180s",
+            },
+        }
+
+        super().__init__(
+            name=name,
+            config=config,
+        )
@@ -128,6 +128,54 @@ pub const ENABLE_PASSWORD_AUTH: Config<bool> = Config::new(
     "Enable password authentication.",
 );
 
+/// Whether to record logins, logouts, and authentication failures in the
+/// audit log.
+pub const ENABLE_AUTH_AUDIT_EVENTS: Config<bool> = Config::new(
+    "enable_auth_audit_events",
+    false,
+    "Record logins, logouts, and authentication failures in the audit log.",
+);
+
+/// The number of authentication failures recorded per role and client address
+/// in each `AUTH_AUDIT_FAILURE_WINDOW`. Further failures in the window are
+/// counted but not recorded individually; the count is attached to a single
+/// summary event written when the window closes, so a brute-force attempt
+/// can't flood the catalog.
+pub const AUTH_AUDIT_FAILURES_PER_WINDOW: Config<usize> = Config::new(
+    "auth_audit_failures_per_window",
+    10,
+    "The number of authentication failures recorded in the audit log per role and client \
+     address in each auth_audit_failure_window.",
+);
+
+/// The number of authentication failures recorded per client address, across
+/// all roles, in each `AUTH_AUDIT_FAILURE_WINDOW`. Unlike
+/// `AUTH_AUDIT_FAILURES_PER_WINDOW` this doesn't depend on the role name the
+/// client sent, so cycling through made-up role names can't get around it.
+pub const AUTH_AUDIT_FAILURES_PER_ADDRESS_WINDOW: Config<usize> = Config::new(
+    "auth_audit_failures_per_address_window",
+    100,
+    "The number of authentication failures recorded in the audit log per client address, across \
+     all roles, in each auth_audit_failure_window.",
+);
+
+/// The length of the window over which `AUTH_AUDIT_FAILURES_PER_WINDOW` and
+/// `AUTH_AUDIT_FAILURES_PER_ADDRESS_WINDOW` apply.
+pub const AUTH_AUDIT_FAILURE_WINDOW: Config<Duration> = Config::new(
+    "auth_audit_failure_window",
+    Duration::from_secs(60),
+    "The window over which auth_audit_failures_per_window and \
+     auth_audit_failures_per_address_window apply.",
+);
+
+/// Upper bound on the number of authentication audit events buffered between
+/// catalog writes. Events beyond it are dropped and counted in a warning.
+pub const AUTH_AUDIT_MAX_PENDING_EVENTS: Config<usize> = Config::new(
+    "auth_audit_max_pending_events",
+    1000,
+    "The maximum number of authentication audit events buffered between catalog writes.",
+);
+
 /// Upper bound on the number of transitive dependencies validated for a
 /// read-then-write statement (e.g. `DELETE ... WHERE ... IN (SELECT ...)`).
 /// Validation walks the read set's dependency graph, which is user controlled
@@ -187,6 +235,93 @@ pub const OIDC_GROUP_ROLE_SYNC_STRICT: Config<bool> = Config::new(
     "When true, reject login if OIDC group-to-role sync fails (fail-closed).",
 );
 
+/// The client certificate field that identifies the user during certificate
+/// authentication.
+pub const CERT_AUTH_IDENTITY_FIELD: Config<&'static str> = Config::new(
+    "cert_auth_identity_field",
+    "common_name",
+    "Client certificate field used as the identity for certificate authentication. One of \
+     `common_name`, `san_dns`, `san_email`, or `san_uri`.",
+);
+
+/// A regular expression that maps the certificate identity to a role name.
+/// The first capture group, if any, is the role name; otherwise the whole
+/// match is. The pattern must match the entire identity.
+pub const CERT_AUTH_ROLE_PATTERN: Config<&'static str> = Config::new(
+    "cert_auth_role_pattern",
+    "(.+)",
+    "Regular expression mapping the certificate identity to a role name. Must match the entire \
+     identity; the first capture group, if any, is the role name.",
+);
+
+/// The URL of the LDAP server used by LDAP authentication, e.g.
+/// `ldaps://ldap.example.com:636`.
+pub const LDAP_URL: Config<Option<&'static str>> = Config::new(
+    "ldap_url",
+    None,
+    "URL of the LDAP server used for LDAP authentication (ldap:// or ldaps://).",
+);
+
+/// Whether to upgrade `ldap://` connections to TLS with StartTLS.
+pub const LDAP_START_TLS: Config<bool> = Config::new(
+    "ldap_start_tls",
+    false,
+    "Whether to upgrade ldap:// connections to TLS with StartTLS.",
+);
+
+/// A template for the DN to bind as in simple-bind mode. `{username}` is
+/// replaced with the DN-escaped user name. Mutually exclusive with
+/// `LDAP_SEARCH_BASE_DN`.
+pub const LDAP_BIND_DN_TEMPLATE: Config<Option<&'static str>> = Config::new(
+    "ldap_bind_dn_template",
+    None,
+    "DN to bind as in simple-bind mode, with {username} replaced by the user name \
+     (e.g. uid={username},ou=people,dc=example,dc=com).",
+);
+
+/// The base DN under which to search for users in search+bind mode. Mutually
+/// exclusive with `LDAP_BIND_DN_TEMPLATE`.
+pub const LDAP_SEARCH_BASE_DN: Config<Option<&'static str>> = Config::new(
+    "ldap_search_base_dn",
+    None,
+    "Base DN under which to search for users in search+bind mode.",
+);
+
+/// The filter that selects the user's entry in search+bind mode. `{username}`
+/// is replaced with the filter-escaped user name.
+pub const LDAP_SEARCH_FILTER: Config<&'static str> = Config::new(
+    "ldap_search_filter",
+    "(uid={username})",
+    "Filter that selects the user's entry in search+bind mode, with {username} replaced by \
+     the user name (e.g. (sAMAccountName={username}) for Active Directory).",
+);
+
+/// The DN of the service account that performs the search in search+bind
+/// mode. Its password is provided to environmentd out of band. When unset,
+/// the search is performed anonymously.
+pub const LDAP_SEARCH_BIND_DN: Config<Option<&'static str>> = Config::new(
+    "ldap_search_bind_dn",
+    None,
+    "DN of the service account that searches for users in search+bind mode. When unset, \
+     the search is anonymous.",
+);
+
+/// The attribute of the user's entry that lists the groups it belongs to, for
+/// group-to-role sync. When empty, LDAP logins do not sync groups.
+pub const LDAP_GROUP_ATTRIBUTE: Config<&'static str> = Config::new(
+    "ldap_group_attribute",
+    "memberOf",
+    "Attribute of the user's LDAP entry that lists its groups, for group-to-role sync. \
+     Empty to disable group sync for LDAP logins.",
+);
+
+/// How long an LDAP login may take, including connecting to the server.
+pub const LDAP_TIMEOUT: Config<Duration> = Config::new(
+    "ldap_timeout",
+    Duration::from_secs(10),
+    "How long an LDAP login may take, including connecting to the LDAP server.",
+);
+
 pub const PERSIST_FAST_PATH_ORDER: Config<bool> = Config::new(
     "persist_fast_path_order",
     false,
@@ -464,6 +599,11 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&PLAN_INSIGHTS_NOTICE_FAST_PATH_CLUSTERS_OPTIMIZE_DURATION)
         .add(&ENABLE_EXPRESSION_CACHE)
         .add(&ENABLE_PASSWORD_AUTH)
+        .add(&ENABLE_AUTH_AUDIT_EVENTS)
+        .add(&AUTH_AUDIT_FAILURES_PER_WINDOW)
+        .add(&AUTH_AUDIT_FAILURES_PER_ADDRESS_WINDOW)
+        .add(&AUTH_AUDIT_FAILURE_WINDOW)
+        .add(&AUTH_AUDIT_MAX_PENDING_EVENTS)
         .add(&READ_THEN_WRITE_MAX_DEPENDENCIES)
         .add(&OIDC_ISSUER)
         .add(&OIDC_AUDIENCE)
@@ -471,6 +611,16 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&OIDC_GROUP_ROLE_SYNC_ENABLED)
         .add(&OIDC_GROUP_CLAIM)
         .add(&OIDC_GROUP_ROLE_SYNC_STRICT)
+        .add(&CERT_AUTH_IDENTITY_FIELD)
+        .add(&CERT_AUTH_ROLE_PATTERN)
+        .add(&LDAP_URL)
+        .add(&LDAP_START_TLS)
+        .add(&LDAP_BIND_DN_TEMPLATE)
+        .add(&LDAP_SEARCH_BASE_DN)
+        .add(&LDAP_SEARCH_FILTER)
+        .add(&LDAP_SEARCH_BIND_DN)
+        .add(&LDAP_GROUP_ATTRIBUTE)
+        .add(&LDAP_TIMEOUT)
         .add(&PERSIST_FAST_PATH_ORDER)
         .add(&ENABLE_S3_TABLES_REGION_CHECK)
         .add(&ENABLE_MCP_AGENT)
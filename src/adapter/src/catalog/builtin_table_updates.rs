@@ -645,13 +645,23 @@ impl CatalogState {
             ));
 
             if let mz_sql::func::Func::Aggregate(_) = func.inner {
+                // Ordered-set aggregates record their number of direct arguments.
+                let (agg_kind, agg_num_direct_args) = match mz_sql::func::ORDERED_SET_AGGREGATES
+                    .iter()
+                    .find(|(agg, _)| *agg == name)
+                {
+                    Some((_, direct_args)) => (
+                        "o",
+                        i16::try_from(*direct_args).expect("few direct arguments"),
+                    ),
+                    None => ("n", 0),
+                };
                 updates.push(BuiltinTableUpdate::row(
                     &*MZ_AGGREGATES,
                     Row::pack_slice(&[
                         Datum::UInt32(func_impl_details.oid),
-                        // TODO(database-issues#1064): Support ordered-set aggregate functions.
-                        Datum::String("n"),
-                        Datum::Int16(0),
+                        Datum::String(agg_kind),
+                        Datum::Int16(agg_num_direct_args),
                     ]),
                     diff,
                 ));
@@ -345,10 +345,10 @@ fn rewrite_sources_to_tables(
 ) -> Result<(), anyhow::Error> {
     use mz_sql::ast::{
         CreateSourceConnection, CreateSourceStatement, CreateSubsourceOptionName,
-        CreateSubsourceStatement, CreateTableFromSourceStatement, Ident,
-        KafkaSourceConfigOptionName, LoadGenerator, MySqlConfigOptionName, PgConfigOptionName,
-        RawItemName, TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName,
-        UnresolvedItemName, Value, WithOptionValue,
+        CreateSubsourceStatement, CreateTableFromSourceStatement, IcebergSourceConfigOptionName,
+        Ident, KafkaSourceConfigOptionName, LoadGenerator, MySqlConfigOptionName,
+        PgConfigOptionName, RawItemName, TableFromSourceColumns, TableFromSourceOption,
+        TableFromSourceOptionName, UnresolvedItemName, Value, WithOptionValue,
     };
 
     let mut updated_items = BTreeMap::new();
@@ -434,8 +434,9 @@ fn rewrite_sources_to_tables(
                     }
                 });
             }
-            CreateSourceConnection::Kafka { .. } | CreateSourceConnection::LoadGenerator { .. } => {
-            }
+            CreateSourceConnection::Kafka { .. }
+            | CreateSourceConnection::Iceberg { .. }
+            | CreateSourceConnection::LoadGenerator { .. } => {}
         }
 
         // Then, figure out the new statements for the progress and source.
@@ -584,6 +585,82 @@ fn rewrite_sources_to_tables(
                         new_source_stmt,
                     )
                 }
+                CreateSourceConnection::Iceberg {
+                    options,
+                    connection,
+                } => {
+                    let columns = if col_names.is_empty() {
+                        TableFromSourceColumns::NotSpecified
+                    } else {
+                        TableFromSourceColumns::Named(col_names)
+                    };
+
+                    // All source tables must have a `details` option, which is a serialized proto
+                    // describing any source-specific details for this table statement.
+                    let details = SourceExportStatementDetails::Iceberg {};
+                    let table_with_options = vec![TableFromSourceOption {
+                        name: TableFromSourceOptionName::Details,
+                        value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                            details.into_proto().encode_to_vec(),
+                        )))),
+                    }];
+                    // The external reference for an iceberg source is the namespace and name of
+                    // the table
+                    let string_option = |name: IcebergSourceConfigOptionName| {
+                        let option = options
+                            .iter()
+                            .find(|o| o.name == name)
+                            .expect("iceberg sources must have a namespace and table");
+                        match &option.value {
+                            Some(WithOptionValue::Value(Value::String(value))) => value.clone(),
+                            _ => unreachable!("namespace and table must be strings"),
+                        }
+                    };
+                    let namespace = string_option(IcebergSourceConfigOptionName::Namespace);
+                    let table = string_option(IcebergSourceConfigOptionName::Table);
+                    let external_reference = UnresolvedItemName::qualified(&[
+                        Ident::new(&namespace)?,
+                        Ident::new(&table)?,
+                    ]);
+
+                    let new_source_stmt =
+                        Statement::CreateTableFromSource(CreateTableFromSourceStatement {
+                            name: source_name,
+                            constraints: vec![],
+                            columns,
+                            if_not_exists,
+                            source: raw_progress_name,
+                            include_metadata,
+                            format,
+                            envelope,
+                            external_reference: Some(external_reference),
+                            with_options: table_with_options,
+                        });
+
+                    let new_progress_stmt = CreateSourceStatement {
+                        name: progress_name,
+                        in_cluster,
+                        col_names: vec![],
+                        connection: CreateSourceConnection::Iceberg {
+                            options,
+                            connection,
+                        },
+                        include_metadata: vec![],
+                        format: None,
+                        envelope: None,
+                        if_not_exists,
+                        key_constraint: None,
+                        with_options,
+                        external_references: None,
+                        progress_subsource: None,
+                    };
+                    (
+                        progress_item.name().item.clone(),
+                        new_progress_stmt,
+                        full_source_name.item,
+                        new_source_stmt,
+                    )
+                }
                 CreateSourceConnection::LoadGenerator {
                     generator:
                         generator @ (LoadGenerator::Clock
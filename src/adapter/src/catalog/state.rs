@@ -1412,12 +1412,14 @@ impl CatalogState {
                             mz_sql::plan::DataSourceDesc::Webhook {
                                 validate_using,
                                 body_format,
+                                body_decoding,
                                 headers,
                                 cluster_id,
                             } => TableDataSource::DataSource {
                                 desc: DataSourceDesc::Webhook {
                                     validate_using,
                                     body_format,
+                                    body_decoding,
                                     headers,
                                     cluster_id: cluster_id
                                         .expect("Webhook Tables must have a cluster_id set"),
@@ -1495,6 +1497,7 @@ impl CatalogState {
                     mz_sql::plan::DataSourceDesc::Webhook {
                         validate_using,
                         body_format,
+                        body_decoding,
                         headers,
                         cluster_id,
                     } => {
@@ -1505,6 +1508,7 @@ impl CatalogState {
                         DataSourceDesc::Webhook {
                             validate_using,
                             body_format,
+                            body_decoding,
                             headers,
                             cluster_id: in_cluster
                                 .expect("webhook sources must use an existing cluster"),
@@ -59,8 +59,8 @@ use uuid::Uuid;
 
 use crate::catalog::Catalog;
 use crate::command::{
-    CatalogDump, CatalogSnapshot, Command, CopyFromStdinWriter, ExecuteResponse, Response,
-    SASLChallengeResponse, SASLVerifyProofResponse, SuperuserAttribute,
+    AuthFailure, CatalogDump, CatalogSnapshot, Command, CopyFromStdinWriter, ExecuteResponse,
+    Response, SASLChallengeResponse, SASLVerifyProofResponse, SuperuserAttribute,
 };
 use crate::config::{ScopedParameters, ScopedParametersScope, SystemParameterFrontend};
 use crate::coord::{Coordinator, ExecuteContextGuard};
@@ -255,6 +255,7 @@ impl Client {
         let client_ip = session.client_ip();
         let application_name = session.application_name().into();
         let notice_tx = session.retain_notice_transmitter();
+        let audit_login = session.audit_login();
 
         let (tx, rx) = oneshot::channel();
 
@@ -286,6 +287,7 @@ impl Client {
             client_ip: client_ip.copied(),
             application_name,
             notice_tx,
+            audit_login,
         });
 
         // When startup fails, no need to call terminate (handle_startup does this). Delay creating
@@ -628,6 +630,14 @@ Issue a SQL query to get started. Need help?
         self.send(Command::InstallScopedSystemParameterFrontend { frontend });
     }
 
+    /// Records a rejected authentication attempt in the audit log, if
+    /// authentication auditing is enabled. Doesn't wait for the event to be
+    /// written.
+    pub fn record_auth_failure(&self, failure: AuthFailure) {
+        // Failures can race with shutdown; losing one then is harmless.
+        let _ = self.try_send(Command::RecordAuthFailure { failure });
+    }
+
     #[instrument(level = "debug")]
     pub(crate) fn try_send(&self, cmd: Command) -> bool {
         self.inner_cmd_tx
@@ -1373,6 +1383,7 @@ impl SessionClient {
                 | Command::ExplainTimestamp { .. }
                 | Command::FrontendStatementLogging(..)
                 | Command::InjectAuditEvents { .. }
+                | Command::RecordAuthFailure { .. }
                 | Command::RegisterConnectionCancelWatch { .. }
                 | Command::CreateInternalSubscribe { .. }
                 | Command::AttemptWrite { .. }
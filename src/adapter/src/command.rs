@@ -18,6 +18,7 @@ use derivative::Derivative;
 use enum_kinds::EnumKind;
 use futures::Stream;
 use mz_adapter_types::connection::{ConnectionId, ConnectionIdType};
+use mz_auth::AuthenticatorKind;
 use mz_auth::password::Password;
 use mz_cluster_client::ReplicaId;
 use mz_compute_types::ComputeInstanceId;
@@ -100,6 +101,7 @@ pub enum Command {
         uuid: Uuid,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
+        audit_login: bool,
     },
 
     AuthenticatePassword {
@@ -204,6 +206,13 @@ pub enum Command {
         tx: oneshot::Sender<Result<(), AdapterError>>,
     },
 
+    /// Records a rejected authentication attempt in the audit log. Sent
+    /// without waiting for a response, so a flood of failures can't tie up
+    /// the frontends.
+    RecordAuthFailure {
+        failure: AuthFailure,
+    },
+
     Terminate {
         conn_id: ConnectionId,
         tx: Option<oneshot::Sender<Result<(), AdapterError>>>,
@@ -494,6 +503,7 @@ impl Command {
             | Command::ExplainTimestamp { .. }
             | Command::FrontendStatementLogging(..)
             | Command::InjectAuditEvents { .. }
+            | Command::RecordAuthFailure { .. }
             | Command::RegisterConnectionCancelWatch { .. }
             | Command::CreateInternalSubscribe { .. }
             | Command::AttemptWrite { .. }
@@ -539,6 +549,7 @@ impl Command {
             | Command::ExplainTimestamp { .. }
             | Command::FrontendStatementLogging(..)
             | Command::InjectAuditEvents { .. }
+            | Command::RecordAuthFailure { .. }
             | Command::RegisterConnectionCancelWatch { .. }
             | Command::CreateInternalSubscribe { .. }
             | Command::AttemptWrite { .. }
@@ -594,6 +605,20 @@ pub struct StartupResponse {
     pub read_only: bool,
 }
 
+/// A rejected authentication attempt, reported by a frontend with
+/// [`Client::record_auth_failure`](crate::Client::record_auth_failure).
+#[derive(Debug, Clone)]
+pub struct AuthFailure {
+    /// The role the client attempted to authenticate as.
+    pub role_name: String,
+    /// The authenticator that rejected the attempt.
+    pub authenticator_kind: AuthenticatorKind,
+    pub client_ip: Option<IpAddr>,
+    pub application_name: Option<String>,
+    /// Why the attempt was rejected.
+    pub reason: String,
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct SASLChallengeResponse {
@@ -1047,8 +1072,9 @@ impl ExecuteResponse {
             DropObjects => &[DroppedObject],
             DropOwned => &[DroppedOwned],
             PlanKind::EmptyQuery => &[ExecuteResponseKind::EmptyQuery],
-            ExplainPlan | ExplainPushdown | ExplainTimestamp | Select | ShowAllVariables
-            | ShowCreate | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema => &[
+            ExplainPlan | ExplainPushdown | ExplainAnalyzeSelect | ExplainTimestamp | Select
+            | ShowAllVariables | ShowCreate | ShowColumns | ShowVariable | InspectShard
+            | ExplainSinkSchema => &[
                 ExecuteResponseKind::CopyTo,
                 SendingRowsStreaming,
                 SendingRowsImmediate,
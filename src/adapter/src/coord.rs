@@ -157,8 +157,8 @@ use mz_sql::plan::{
 };
 use mz_sql::session::user::User;
 use mz_sql::session::vars::{MAX_CREDIT_CONSUMPTION_RATE, SystemVars, Var};
-use mz_sql_parser::ast::ExplainStage;
 use mz_sql_parser::ast::display::AstDisplay;
+use mz_sql_parser::ast::{ExplainAnalyzeProperty, ExplainStage};
 use mz_storage_client::client::TableData;
 use mz_storage_client::controller::{CollectionDescription, DataSource, ExportDescription};
 use mz_storage_types::connections::Connection as StorageConnection;
@@ -194,6 +194,7 @@ use crate::coord::appends::{
     BuiltinTableAppendCompletion, BuiltinTableAppendNotify, DeferredOp, GroupCommitPermit,
     PendingWriteTxn,
 };
+use crate::coord::auth_audit::AuthAuditLog;
 use crate::coord::caught_up::CaughtUpCheckContext;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::introspection::IntrospectionSubscribe;
@@ -217,6 +218,7 @@ use crate::webhook::{WebhookAppenderInvalidator, WebhookConcurrencyLimiter};
 use crate::{AdapterNotice, ReadHolds, flags};
 
 pub(crate) mod appends;
+pub(crate) mod auth_audit;
 pub(crate) mod catalog_serving;
 pub(crate) mod cluster_controller;
 pub(crate) mod consistency;
@@ -448,6 +450,7 @@ pub enum Message {
         stage: ExplainTimestampStage,
     },
     DrainStatementLog,
+    DrainAuthAuditLog,
     PrivateLinkVpcEndpointEvents(Vec<VpcEndpointEvent>),
 
     /// One pull/apply call from the cluster controller task, answered on the main
@@ -505,6 +508,7 @@ impl Message {
                 Command::FrontendStatementLogging(..) => "frontend-statement-logging",
                 Command::StartCopyFromStdin { .. } => "start-copy-from-stdin",
                 Command::InjectAuditEvents { .. } => "inject-audit-events",
+                Command::RecordAuthFailure { .. } => "record-auth-failure",
                 Command::RegisterConnectionCancelWatch { .. } => "register-connection-cancel-watch",
                 Command::CreateInternalSubscribe { .. } => "create-internal-subscribe",
                 Command::AttemptWrite { .. } => "attempt-write",
@@ -559,6 +563,7 @@ impl Message {
             Message::SecretStageReady { .. } => "secret_stage_ready",
             Message::ClusterStageReady { .. } => "cluster_stage_ready",
             Message::DrainStatementLog => "drain_statement_log",
+            Message::DrainAuthAuditLog => "drain_auth_audit_log",
             Message::AlterConnectionValidationReady(..) => "alter_connection_validation_ready",
             Message::PrivateLinkVpcEndpointEvents(_) => "private_link_vpc_endpoint_events",
             Message::ClusterControllerRequest(_) => "cluster_controller_request",
@@ -622,6 +627,8 @@ pub enum PeekStage {
     /// Final stage for an explain.
     ExplainPlan(PeekStageExplainPlan),
     ExplainPushdown(PeekStageExplainPushdown),
+    /// Reports on the query of an `EXPLAIN ANALYZE` after it has run.
+    ExplainAnalyze(PeekStageExplainAnalyze),
     /// Preflight checks for a copy to operation.
     CopyToPreflight(PeekStageCopyTo),
     /// Final stage for a copy to which involves shipping the dataflow.
@@ -726,6 +733,8 @@ pub struct PeekStageFinish {
     insights_ctx: Option<Box<PlanInsightsContext>>,
     global_lir_plan: optimize::peek::GlobalLirPlan,
     optimization_finished_at: EpochMillis,
+    /// Set iff this is one of the peeks of an `EXPLAIN ANALYZE`.
+    explain_analyze_ctx: Option<ExplainAnalyzeContext>,
 }
 
 #[derive(Debug)]
@@ -754,6 +763,30 @@ pub struct PeekStageExplainPushdown {
     imports: BTreeMap<GlobalId, MapFilterProject>,
 }
 
+#[derive(Debug)]
+pub struct PeekStageExplainAnalyze {
+    validity: PlanValidity,
+    properties: ExplainAnalyzeProperty,
+    desc: RelationDesc,
+    cluster_id: ComputeInstanceId,
+    target: ExplainAnalyzeTarget,
+    /// The number of rows the query returned.
+    row_count: u64,
+    /// How long the query took to run, from issuing the peek to receiving its
+    /// last row.
+    duration: Duration,
+}
+
+/// What computed the query of an `EXPLAIN ANALYZE`.
+#[derive(Debug)]
+pub enum ExplainAnalyzeTarget {
+    /// A transient dataflow building the objects `ids`, which `hold` keeps
+    /// installed so that its introspection data can be read.
+    Dataflow { ids: Vec<GlobalId>, hold: ReadHold },
+    /// A fast-path peek, which has no dataflow to report on.
+    FastPath { description: String },
+}
+
 #[derive(Debug)]
 pub enum CreateIndexStage {
     Optimize(CreateIndexOptimize),
@@ -964,6 +997,8 @@ pub enum ExplainContext {
     PlanInsightsNotice(OptimizerTrace),
     /// `EXPLAIN FILTER PUSHDOWN`
     Pushdown,
+    /// `EXPLAIN ANALYZE ... FOR <query>`
+    Analyze(ExplainAnalyzeContext),
 }
 
 impl ExplainContext {
@@ -985,6 +1020,7 @@ impl ExplainContext {
             ExplainContext::Plan(..) => false,
             ExplainContext::PlanInsightsNotice(..) => true,
             ExplainContext::Pushdown => false,
+            ExplainContext::Analyze(..) => true,
         }
     }
 
@@ -999,6 +1035,21 @@ impl ExplainContext {
     }
 }
 
+/// `EXPLAIN ANALYZE ... FOR <query>` is sequenced as two peeks: the first runs
+/// the query, and the second reads the introspection data of the dataflow that
+/// computed it.
+#[derive(Debug)]
+pub enum ExplainAnalyzeContext {
+    /// Run the query to completion, keeping its dataflow installed afterwards.
+    Run {
+        properties: ExplainAnalyzeProperty,
+        desc: RelationDesc,
+    },
+    /// Read the introspection data. `dataflow_hold` keeps the dataflow being
+    /// reported on installed until the rows are in.
+    Report { dataflow_hold: ReadHold },
+}
+
 #[derive(Debug)]
 pub struct ExplainPlanContext {
     /// EXPLAIN BROKEN is internal syntax for showing EXPLAIN output despite an internal error in
@@ -1359,6 +1410,9 @@ pub struct ConnMeta {
     /// WARNING: This role reference is not updated when the role is dropped.
     /// Consumers should not assume that this role exist.
     authenticated_role: RoleId,
+
+    /// Whether the connection's login and logout are recorded in the audit log.
+    audit_login: bool,
 }
 
 impl ConnMeta {
@@ -2172,6 +2226,9 @@ pub struct Coordinator {
     /// Data used by the statement logging feature.
     statement_logging: StatementLogging,
 
+    /// Authentication audit events waiting to be written to the catalog.
+    auth_audit: AuthAuditLog,
+
     /// Limit for how many concurrent webhook requests we allow.
     webhook_concurrency_limit: WebhookConcurrencyLimiter,
 
@@ -4058,6 +4115,7 @@ impl Coordinator {
             self.schedule_arrangement_sizes_collection().await;
             self.spawn_privatelink_vpc_endpoints_watch_task();
             self.spawn_statement_logging_task();
+            self.spawn_auth_audit_task();
             self.spawn_catalog_info_metrics_task();
             self.spawn_cluster_controller_task();
             flags::tracing_config(self.catalog.system_config()).apply(&self.tracing_handle);
@@ -5293,6 +5351,7 @@ pub fn serve(
                     optimizer_metrics,
                     tracing_handle,
                     statement_logging: StatementLogging::new(coord_now.clone()),
+                    auth_audit: AuthAuditLog::default(),
                     webhook_concurrency_limit,
                     timestamp_oracle_config,
                     caught_up_check_interval: clusters_caught_up_check_interval,
@@ -53,7 +53,10 @@ use mz_ore::now::NowFn;
 use mz_ore::task;
 use mz_repr::{CatalogItemId, GlobalId, Timestamp};
 use mz_sql::names::ResolvedIds;
-use mz_sql::plan::{ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement, Plan};
+use mz_sql::plan::{
+    ExplainAnalyzeSelectPlan, ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement,
+    Plan,
+};
 use mz_sql::session::metadata::SessionMetadata;
 use mz_storage_client::client::TableData;
 use mz_storage_client::controller::{TableRegistration, TableWriteHandle};
@@ -1594,6 +1597,9 @@ pub(crate) fn waiting_on_startup_appends(
             explainee: Explainee::Statement(ExplaineeStatement::Select { plan, .. }),
             ..
         }) => plan.source.depends_on(),
+        Plan::ExplainAnalyzeSelect(ExplainAnalyzeSelectPlan { select, .. }) => {
+            select.source.depends_on()
+        }
         Plan::ExplainTimestamp(ExplainTimestampPlan { raw_plan, .. }) => raw_plan.depends_on(),
         Plan::CreateConnection(_)
         | Plan::CreateDatabase(_)
@@ -0,0 +1,473 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Audit events for logins, logouts, and authentication failures.
+//!
+//! Unlike DDL, these events don't ride along with a catalog transaction of
+//! their own, so they are buffered on the coordinator and written in one
+//! catalog transaction per [`Coordinator::AUTH_AUDIT_WRITE_INTERVAL`].
+//!
+//! Authentication failures are attacker controlled, so they are rate limited
+//! per role and client address: the first `auth_audit_failures_per_window`
+//! failures in each `auth_audit_failure_window` are recorded individually,
+//! and the rest are summarized by a single event, carrying their count, when
+//! the window closes. The role name is also attacker controlled, so a second
+//! window per client address caps the failures recorded individually across
+//! all roles at `auth_audit_failures_per_address_window`, and is summarized
+//! the same way. The buffer as a whole is bounded by
+//! `auth_audit_max_pending_events`.
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::time::Duration;
+
+use mz_adapter_types::dyncfgs::{
+    AUTH_AUDIT_FAILURE_WINDOW, AUTH_AUDIT_FAILURES_PER_ADDRESS_WINDOW,
+    AUTH_AUDIT_FAILURES_PER_WINDOW, AUTH_AUDIT_MAX_PENDING_EVENTS, ENABLE_AUTH_AUDIT_EVENTS,
+};
+use mz_audit_log::{AuthFailureV1, EventDetails, EventType, ObjectType, SessionV1};
+use mz_ore::now::EpochMillis;
+use mz_ore::task::spawn;
+use tokio::time::MissedTickBehavior;
+use tracing::warn;
+
+use crate::catalog::{InjectedAuditEvent, Op};
+use crate::command::AuthFailure;
+use crate::coord::{ConnMeta, Coordinator, Message};
+use crate::error::AdapterError;
+
+/// Authentication audit state in the Coordinator.
+#[derive(Debug, Default)]
+pub(crate) struct AuthAuditLog {
+    /// Events waiting for the next catalog write.
+    pending: Vec<InjectedAuditEvent>,
+    /// Open rate-limiting windows, by role name and client address.
+    failure_windows: BTreeMap<(String, Option<IpAddr>), FailureWindow>,
+    /// Open rate-limiting windows, by client address alone.
+    address_windows: BTreeMap<Option<IpAddr>, FailureWindow>,
+    /// Events dropped because `pending` was full, since the last drain.
+    dropped: u64,
+}
+
+/// Authentication failures for one role and client address, or for one client
+/// address, in the current rate-limiting window.
+#[derive(Debug)]
+struct FailureWindow {
+    started_at: EpochMillis,
+    /// Failures recorded individually.
+    recorded: usize,
+    /// Failures not recorded individually.
+    suppressed: u64,
+    /// The most recent suppressed failure, which stands in for all of them
+    /// when the window closes.
+    last_suppressed: Option<AuthFailureV1>,
+}
+
+/// The limits that apply to the buffer, read from dyncfgs.
+#[derive(Debug, Clone, Copy)]
+struct AuthAuditLimits {
+    failures_per_window: usize,
+    failures_per_address_window: usize,
+    window: Duration,
+    max_pending: usize,
+}
+
+impl AuthAuditLog {
+    fn push(&mut self, event: InjectedAuditEvent, limits: AuthAuditLimits) {
+        if self.pending.len() < limits.max_pending {
+            self.pending.push(event);
+        } else {
+            self.dropped += 1;
+        }
+    }
+
+    fn record_failure(&mut self, failure: AuthFailure, now: EpochMillis, limits: AuthAuditLimits) {
+        let address = failure.client_ip;
+        let key = (failure.role_name.clone(), address);
+        let details = AuthFailureV1 {
+            role_name: failure.role_name,
+            authenticator_kind: failure.authenticator_kind.as_str().to_string(),
+            client_ip: failure.client_ip.map(|ip| ip.to_string()),
+            application_name: failure.application_name,
+            reason: failure.reason,
+            suppressed_failures: 0,
+        };
+
+        // Windows that expired since the last drain close now, so this
+        // failure starts fresh ones.
+        if self
+            .address_windows
+            .get(&address)
+            .is_some_and(|window| window_expired(window, now, limits))
+        {
+            let window = self
+                .address_windows
+                .remove(&address)
+                .expect("known to exist");
+            self.close_window(window, limits);
+        }
+        if self
+            .failure_windows
+            .get(&key)
+            .is_some_and(|window| window_expired(window, now, limits))
+        {
+            let window = self.failure_windows.remove(&key).expect("known to exist");
+            self.close_window(window, limits);
+        }
+
+        // Once the client address is over its limit, failures are counted
+        // against the address alone, so made-up role names don't open new
+        // windows either.
+        let address_window = self
+            .address_windows
+            .entry(address)
+            .or_insert_with(|| FailureWindow::new(now));
+        if address_window.recorded >= limits.failures_per_address_window {
+            address_window.suppress(details);
+            return;
+        }
+
+        let window = self
+            .failure_windows
+            .entry(key)
+            .or_insert_with(|| FailureWindow::new(now));
+        if window.recorded < limits.failures_per_window {
+            window.recorded += 1;
+            address_window.recorded += 1;
+            self.push(auth_failure_event(details), limits);
+        } else {
+            window.suppress(details);
+        }
+    }
+
+    /// Closes expired rate-limiting windows and returns the buffered events.
+    fn drain(&mut self, now: EpochMillis, limits: AuthAuditLimits) -> Vec<InjectedAuditEvent> {
+        let expired: Vec<_> = self
+            .failure_windows
+            .iter()
+            .filter(|(_, window)| window_expired(window, now, limits))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in expired {
+            let window = self.failure_windows.remove(&key).expect("known to exist");
+            self.close_window(window, limits);
+        }
+        let expired: Vec<_> = self
+            .address_windows
+            .iter()
+            .filter(|(_, window)| window_expired(window, now, limits))
+            .map(|(address, _)| *address)
+            .collect();
+        for address in expired {
+            let window = self
+                .address_windows
+                .remove(&address)
+                .expect("known to exist");
+            self.close_window(window, limits);
+        }
+
+        if self.dropped > 0 {
+            warn!(
+                dropped = self.dropped,
+                "dropped authentication audit events: too many pending events",
+            );
+            self.dropped = 0;
+        }
+        std::mem::take(&mut self.pending)
+    }
+
+    fn close_window(&mut self, window: FailureWindow, limits: AuthAuditLimits) {
+        if let Some(mut details) = window.last_suppressed {
+            details.suppressed_failures = window.suppressed;
+            self.push(auth_failure_event(details), limits);
+        }
+    }
+}
+
+impl FailureWindow {
+    fn new(started_at: EpochMillis) -> Self {
+        FailureWindow {
+            started_at,
+            recorded: 0,
+            suppressed: 0,
+            last_suppressed: None,
+        }
+    }
+
+    fn suppress(&mut self, details: AuthFailureV1) {
+        self.suppressed += 1;
+        self.last_suppressed = Some(details);
+    }
+}
+
+fn window_expired(window: &FailureWindow, now: EpochMillis, limits: AuthAuditLimits) -> bool {
+    let window_ms = u64::try_from(limits.window.as_millis()).unwrap_or(u64::MAX);
+    now.saturating_sub(window.started_at) >= window_ms
+}
+
+fn auth_failure_event(details: AuthFailureV1) -> InjectedAuditEvent {
+    InjectedAuditEvent {
+        event_type: EventType::AuthFailure,
+        object_type: ObjectType::Role,
+        details: EventDetails::AuthFailureV1(details),
+        user: None,
+    }
+}
+
+fn session_event(event_type: EventType, conn: &ConnMeta) -> InjectedAuditEvent {
+    let user = conn.user();
+    InjectedAuditEvent {
+        event_type,
+        object_type: ObjectType::Role,
+        details: EventDetails::SessionV1(SessionV1 {
+            session_id: conn.uuid().to_string(),
+            authenticator_kind: user
+                .authenticator_kind
+                .unwrap_or_default()
+                .as_str()
+                .to_string(),
+            client_ip: conn.client_ip().map(|ip| ip.to_string()),
+            application_name: conn.application_name().to_string(),
+        }),
+        user: Some(user.name.clone()),
+    }
+}
+
+impl Coordinator {
+    /// How often buffered authentication audit events are written to the
+    /// catalog.
+    const AUTH_AUDIT_WRITE_INTERVAL: Duration = Duration::from_secs(1);
+
+    pub(crate) fn spawn_auth_audit_task(&self) {
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        spawn(|| "auth_audit", async move {
+            let mut interval = tokio::time::interval(Coordinator::AUTH_AUDIT_WRITE_INTERVAL);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                interval.tick().await;
+                let _ = internal_cmd_tx.send(Message::DrainAuthAuditLog);
+            }
+        });
+    }
+
+    fn auth_audit_limits(&self) -> Option<AuthAuditLimits> {
+        let dyncfgs = self.catalog().system_config().dyncfgs();
+        if !ENABLE_AUTH_AUDIT_EVENTS.get(dyncfgs) {
+            return None;
+        }
+        Some(AuthAuditLimits {
+            failures_per_window: AUTH_AUDIT_FAILURES_PER_WINDOW.get(dyncfgs),
+            failures_per_address_window: AUTH_AUDIT_FAILURES_PER_ADDRESS_WINDOW.get(dyncfgs),
+            window: AUTH_AUDIT_FAILURE_WINDOW.get(dyncfgs),
+            max_pending: AUTH_AUDIT_MAX_PENDING_EVENTS.get(dyncfgs),
+        })
+    }
+
+    /// Records that the session `conn` logged in.
+    pub(crate) fn audit_login(&mut self, conn: &ConnMeta) {
+        if !conn.audit_login {
+            return;
+        }
+        if let Some(limits) = self.auth_audit_limits() {
+            self.auth_audit
+                .push(session_event(EventType::Login, conn), limits);
+        }
+    }
+
+    /// Records that the session `conn` logged out.
+    pub(crate) fn audit_logout(&mut self, conn: &ConnMeta) {
+        if !conn.audit_login {
+            return;
+        }
+        if let Some(limits) = self.auth_audit_limits() {
+            self.auth_audit
+                .push(session_event(EventType::Logout, conn), limits);
+        }
+    }
+
+    /// Records a rejected authentication attempt, subject to rate limiting.
+    pub(crate) fn audit_auth_failure(&mut self, failure: AuthFailure) {
+        if let Some(limits) = self.auth_audit_limits() {
+            let now = self.now();
+            self.auth_audit.record_failure(failure, now, limits);
+        }
+    }
+
+    /// Writes buffered authentication audit events to the catalog.
+    #[mz_ore::instrument(level = "debug")]
+    pub(crate) async fn drain_auth_audit_log(&mut self) {
+        // A read-only coordinator can't write to the catalog, so it keeps its
+        // events buffered, bounded by `auth_audit_max_pending_events`, and
+        // writes them once it is promoted.
+        if self.controller.read_only() {
+            return;
+        }
+        let limits = match self.auth_audit_limits() {
+            Some(limits) => limits,
+            None => {
+                // Disabled since the events were buffered. Drop them, along
+                // with any open windows, so re-enabling starts from scratch.
+                self.auth_audit = AuthAuditLog::default();
+                return;
+            }
+        };
+        let now = self.now();
+        let events = self.auth_audit.drain(now, limits);
+        if events.is_empty() {
+            return;
+        }
+        let count = events.len();
+        match self
+            .catalog_transact(None, vec![Op::InjectAuditEvents { events }])
+            .await
+        {
+            Ok(()) => {}
+            Err(AdapterError::ReadOnly) => {}
+            Err(err) => {
+                warn!(count, "failed to write authentication audit events: {err}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_auth::AuthenticatorKind;
+
+    use super::*;
+
+    const LIMITS: AuthAuditLimits = AuthAuditLimits {
+        failures_per_window: 2,
+        failures_per_address_window: 100,
+        window: Duration::from_secs(10),
+        max_pending: 100,
+    };
+
+    fn failure(role_name: &str, reason: &str) -> AuthFailure {
+        AuthFailure {
+            role_name: role_name.to_string(),
+            authenticator_kind: AuthenticatorKind::Password,
+            client_ip: Some([10, 0, 0, 1].into()),
+            application_name: Some("psql".to_string()),
+            reason: reason.to_string(),
+        }
+    }
+
+    fn details(events: &[InjectedAuditEvent]) -> Vec<(&str, &str, u64)> {
+        events
+            .iter()
+            .map(|event| match &event.details {
+                EventDetails::AuthFailureV1(v) => (
+                    v.role_name.as_str(),
+                    v.reason.as_str(),
+                    v.suppressed_failures,
+                ),
+                details => panic!("unexpected details: {details:?}"),
+            })
+            .collect()
+    }
+
+    #[mz_ore::test]
+    fn failures_are_rate_limited_per_role() {
+        let mut log = AuthAuditLog::default();
+        for i in 0..5 {
+            log.record_failure(failure("alice", &format!("attempt {i}")), 1_000, LIMITS);
+        }
+        log.record_failure(failure("bob", "attempt 0"), 1_000, LIMITS);
+
+        // The window is still open, so only the first failures are written.
+        let events = log.drain(5_000, LIMITS);
+        assert_eq!(
+            details(&events),
+            [
+                ("alice", "attempt 0", 0),
+                ("alice", "attempt 1", 0),
+                ("bob", "attempt 0", 0),
+            ]
+        );
+
+        // Closing the window summarizes the rest with the latest failure.
+        let events = log.drain(11_000, LIMITS);
+        assert_eq!(details(&events), [("alice", "attempt 4", 3)]);
+        assert!(log.failure_windows.is_empty());
+
+        // A new window records failures individually again.
+        log.record_failure(failure("alice", "attempt 5"), 12_000, LIMITS);
+        let events = log.drain(12_000, LIMITS);
+        assert_eq!(details(&events), [("alice", "attempt 5", 0)]);
+    }
+
+    #[mz_ore::test]
+    fn expired_window_closes_on_next_failure() {
+        let mut log = AuthAuditLog::default();
+        for i in 0..3 {
+            log.record_failure(failure("alice", &format!("attempt {i}")), 1_000, LIMITS);
+        }
+        log.record_failure(failure("alice", "attempt 3"), 20_000, LIMITS);
+        let events = log.drain(20_000, LIMITS);
+        assert_eq!(
+            details(&events),
+            [
+                ("alice", "attempt 0", 0),
+                ("alice", "attempt 1", 0),
+                ("alice", "attempt 2", 1),
+                ("alice", "attempt 3", 0),
+            ]
+        );
+    }
+
+    #[mz_ore::test]
+    fn failures_are_rate_limited_per_address() {
+        let limits = AuthAuditLimits {
+            failures_per_address_window: 3,
+            ..LIMITS
+        };
+        let mut log = AuthAuditLog::default();
+        for i in 0..10 {
+            log.record_failure(failure(&format!("user{i}"), "attempt"), 1_000, limits);
+        }
+        // Other addresses have their own window.
+        let mut other = failure("user0", "attempt");
+        other.client_ip = Some([10, 0, 0, 2].into());
+        log.record_failure(other, 1_000, limits);
+
+        let events = log.drain(5_000, limits);
+        assert_eq!(
+            details(&events),
+            [
+                ("user0", "attempt", 0),
+                ("user1", "attempt", 0),
+                ("user2", "attempt", 0),
+                ("user0", "attempt", 0),
+            ]
+        );
+        // Suppressed failures don't open per-role windows.
+        assert_eq!(log.failure_windows.len(), 4);
+
+        let events = log.drain(11_000, limits);
+        assert_eq!(details(&events), [("user9", "attempt", 7)]);
+        assert!(log.address_windows.is_empty());
+    }
+
+    #[mz_ore::test]
+    fn pending_events_are_bounded() {
+        let limits = AuthAuditLimits {
+            failures_per_window: 100,
+            max_pending: 3,
+            ..LIMITS
+        };
+        let mut log = AuthAuditLog::default();
+        for i in 0..5 {
+            log.record_failure(failure("alice", &format!("attempt {i}")), 1_000, limits);
+        }
+        assert_eq!(log.dropped, 2);
+        assert_eq!(log.drain(1_000, limits).len(), 3);
+        assert_eq!(log.dropped, 0);
+    }
+}
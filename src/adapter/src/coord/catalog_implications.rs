@@ -1304,6 +1304,7 @@ impl Coordinator {
                     DataSourceDesc::Webhook {
                         validate_using: _,
                         body_format: _,
+                        body_decoding: _,
                         headers: _,
                         cluster_id: _,
                     } => {
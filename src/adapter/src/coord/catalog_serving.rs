@@ -22,8 +22,8 @@ use mz_expr::CollectionPlan;
 use mz_repr::GlobalId;
 use mz_sql::catalog::SessionCatalog;
 use mz_sql::plan::{
-    ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement, Plan, SubscribeFrom,
-    SubscribePlan,
+    ExplainAnalyzeSelectPlan, ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement,
+    Plan, SubscribeFrom, SubscribePlan,
 };
 use smallvec::SmallVec;
 
@@ -72,6 +72,10 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
             explainee: Explainee::Statement(ExplaineeStatement::Subscribe { plan, .. }),
             ..
         }) => inspect_subscribe(plan),
+        Plan::ExplainAnalyzeSelect(ExplainAnalyzeSelectPlan { select, .. }) => (
+            select.source.depends_on(),
+            select.source.could_run_expensive_function(),
+        ),
         Plan::ExplainTimestamp(ExplainTimestampPlan { raw_plan, .. }) => (
             raw_plan.depends_on(),
             raw_plan.could_run_expensive_function(),
@@ -247,6 +251,9 @@ pub fn check_cluster_restrictions(
             SubscribeFrom::Query { ref expr, .. } => Box::new(expr.depends_on().into_iter()),
         },
         Plan::Select(plan) => Box::new(plan.source.depends_on().into_iter()),
+        Plan::ExplainAnalyzeSelect(ExplainAnalyzeSelectPlan { select, .. }) => {
+            Box::new(select.source.depends_on().into_iter())
+        }
         // COPY ... TO <url> runs its select as a dataflow on the active
         // cluster. COPY ... TO STDOUT is planned as `Plan::Select` and is
         // covered above.
@@ -36,8 +36,8 @@ use mz_repr::role_id::RoleId;
 use mz_repr::{Diff, GlobalId, SqlScalarType, Timestamp};
 use mz_sql::ast::{
     AlterConnectionAction, AlterConnectionStatement, AlterSourceAction, AstInfo, ConstantVisitor,
-    CopyRelation, CopyStatement, CreateSourceOptionName, Raw, Statement, StatementKind,
-    SubscribeStatement,
+    CopyRelation, CopyStatement, CreateSourceOptionName, CreateWebhookSourceStatement, Format, Raw,
+    Statement, StatementKind, SubscribeStatement,
 };
 use mz_sql::catalog::RoleAttributesRaw;
 use mz_sql::names::{Aug, PartialItemName, ResolvedIds};
@@ -57,8 +57,8 @@ use mz_sql::session::vars::{
 };
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
-    CreateMaterializedViewStatement, ExplainPlanStatement, Explainee, InsertStatement,
-    WithOptionValue,
+    CreateMaterializedViewStatement, ExplainAnalyzeObjectStatement, ExplainPlanStatement,
+    Explainee, InsertStatement, WithOptionValue,
 };
 use mz_storage_types::sources::Timeline;
 use opentelemetry::trace::TraceContextExt;
@@ -83,7 +83,8 @@ use crate::session::{Session, TransactionOps, TransactionStatus};
 use crate::statement_logging::{StatementEndedExecutionReason, WatchSetCreation};
 use crate::util::ClientTransmitter;
 use crate::webhook::{
-    AppendWebhookResponse, AppendWebhookValidator, WebhookAppender, WebhookAppenderInvalidator,
+    AppendWebhookDecoder, AppendWebhookResponse, AppendWebhookValidator, WebhookAppender,
+    WebhookAppenderInvalidator,
 };
 use crate::{AppendWebhookError, ExecuteContext, catalog, metrics};
 
@@ -129,6 +130,7 @@ impl Coordinator {
                     client_ip,
                     application_name,
                     notice_tx,
+                    audit_login,
                 } => {
                     // Note: A ClientTransmitter is not applicable here. Its purpose is to
                     // rescue the Session from an undeliverable Response, and startup
@@ -144,6 +146,7 @@ impl Coordinator {
                         client_ip,
                         application_name,
                         notice_tx,
+                        audit_login,
                     )
                     .await;
                 }
@@ -305,6 +308,10 @@ impl Coordinator {
                     let _ = tx.send(result);
                 }
 
+                Command::RecordAuthFailure { failure } => {
+                    self.audit_auth_failure(failure);
+                }
+
                 Command::Terminate { conn_id, tx } => {
                     self.handle_terminate(conn_id).await;
                     // Note: We purposefully do not use a ClientTransmitter here because we're already
@@ -886,6 +893,7 @@ impl Coordinator {
         client_ip: Option<IpAddr>,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
+        audit_login: bool,
     ) {
         // Early return if successful, otherwise cleanup any possible state.
         match self
@@ -898,6 +906,8 @@ impl Coordinator {
                     .active_sessions
                     .with_label_values(&[session_type])
                     .inc();
+                // Internal users are the system itself, not clients logging in.
+                let audit_login = audit_login && !user.is_internal();
                 let conn = ConnMeta {
                     secret_key,
                     notice_tx,
@@ -911,10 +921,12 @@ impl Coordinator {
                     conn_id: conn_id.clone(),
                     authenticated_role: role_id,
                     deferred_lock: None,
+                    audit_login,
                 };
                 let update = self.catalog().state().pack_session_update(&conn, Diff::ONE);
                 let update = self.catalog().state().resolve_builtin_table_update(update);
                 self.begin_session_for_statement_logging(&conn);
+                self.audit_login(&conn);
                 self.active_conns.insert(conn_id.clone(), conn);
 
                 // Note: Do NOT await the notify here, we pass this back to
@@ -1390,6 +1402,18 @@ impl Coordinator {
             | TransactionStatus::InTransactionImplicit(_)
             | TransactionStatus::InTransaction(_) => {
                 match &*stmt {
+                    // `EXPLAIN ANALYZE ... FOR <query>` runs the query and then reads its
+                    // dataflow's introspection data at a later timestamp, which cannot share a
+                    // transaction's timestamp, so it must be run singly.
+                    Statement::ExplainAnalyzeObject(ExplainAnalyzeObjectStatement {
+                        explainee: Explainee::Select(..),
+                        ..
+                    }) => {
+                        return ctx.retire(Err(AdapterError::OperationProhibitsTransaction(
+                            stmt.to_string(),
+                        )));
+                    }
+
                     // Statements that are safe in a transaction. We still need to verify that we
                     // don't interleave reads and writes since we can't perform those serializably.
                     Statement::Close(_)
@@ -1846,7 +1870,15 @@ impl Coordinator {
                 | Statement::CreateSink(_)
                 | Statement::CreateTableFromSource(_)
         ) {
-            return false;
+            // Webhook sources only need purification when their body is decoded with a schema
+            // that might live in a schema registry.
+            return matches!(
+                stmt,
+                Statement::CreateWebhookSource(CreateWebhookSourceStatement {
+                    body_format: Format::Avro(_) | Format::Protobuf(_),
+                    ..
+                })
+            );
         }
 
         // However `ALTER SOURCE RETAIN HISTORY` should be excluded from off-thread purification.
@@ -2124,6 +2156,7 @@ impl Coordinator {
         self.cancel_pending_watchsets(&conn_id);
         self.cancel_pending_copy(&conn_id);
         self.end_session_for_statement_logging(conn.uuid());
+        self.audit_logout(&conn);
 
         // Queue the builtin table update, but do not wait for it to complete. We explicitly do
         // this to prevent blocking the Coordinator in the case that a lot of connections are
@@ -2195,6 +2228,7 @@ impl Coordinator {
             let DataSourceDesc::Webhook {
                 validate_using,
                 body_format,
+                body_decoding,
                 headers,
                 ..
             } = data_source
@@ -2205,24 +2239,44 @@ impl Coordinator {
             let body_format = body_format.clone();
             let header_tys = headers.clone();
 
-            // Assert we have one column for the body, and how ever many are required for
-            // the headers.
-            let num_columns = headers.num_columns() + 1;
-            mz_ore::soft_assert_or_log!(
-                desc.arity() <= num_columns,
-                "expected at most {} columns, but got {}",
-                num_columns,
-                desc.arity()
-            );
+            let decoder = match body_decoding {
+                // Any columns that aren't for the headers hold the decoded body.
+                Some(body_decoding) => {
+                    let num_body_columns = desc.arity().saturating_sub(headers.num_columns());
+                    let body_tys = desc
+                        .iter()
+                        .take(num_body_columns)
+                        .map(|(name, ty)| (name.clone(), ty.clone()))
+                        .collect();
+                    Some(AppendWebhookDecoder::new(
+                        body_decoding.clone(),
+                        body_tys,
+                        coord.catalog().state(),
+                        coord.controller.storage.config().clone(),
+                    ))
+                }
+                None => {
+                    // Assert we have one column for the body, and how ever many are required for
+                    // the headers.
+                    let num_columns = headers.num_columns() + 1;
+                    mz_ore::soft_assert_or_log!(
+                        desc.arity() <= num_columns,
+                        "expected at most {} columns, but got {}",
+                        num_columns,
+                        desc.arity()
+                    );
 
-            // Double check that the body column of the webhook source matches the type
-            // we're about to deserialize as.
-            let body_column = desc
-                .get_by_name(&"body".into())
-                .map(|(_idx, ty)| ty.clone())
-                .ok_or_else(|| name.clone())?;
-            assert!(!body_column.nullable, "webhook body column is nullable!?");
-            assert_eq!(body_column.scalar_type, SqlScalarType::from(body_format));
+                    // Double check that the body column of the webhook source matches the type
+                    // we're about to deserialize as.
+                    let body_column = desc
+                        .get_by_name(&"body".into())
+                        .map(|(_idx, ty)| ty.clone())
+                        .ok_or_else(|| name.clone())?;
+                    assert!(!body_column.nullable, "webhook body column is nullable!?");
+                    assert_eq!(body_column.scalar_type, SqlScalarType::from(body_format));
+                    None
+                }
+            };
 
             // Create a validator that can be called to validate a webhook request.
             let validator = validate_using.as_ref().map(|v| {
@@ -2250,6 +2304,7 @@ impl Coordinator {
             Ok(AppendWebhookResponse {
                 tx,
                 body_format,
+                decoder,
                 header_tys,
                 validator,
             })
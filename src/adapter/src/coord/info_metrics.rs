@@ -407,6 +407,7 @@ mod tests {
             data_source: DataSourceDesc::Webhook {
                 validate_using: None,
                 body_format: WebhookBodyFormat::Json { array: false },
+                body_decoding: None,
                 headers: WebhookHeaders::default(),
                 cluster_id,
             },
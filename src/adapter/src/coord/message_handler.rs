@@ -237,6 +237,9 @@ impl Coordinator {
             Message::DrainStatementLog => {
                 self.drain_statement_log();
             }
+            Message::DrainAuthAuditLog => {
+                self.drain_auth_audit_log().await;
+            }
             Message::PrivateLinkVpcEndpointEvents(events) => {
                 if !self.controller.read_only() {
                     self.controller.storage.append_introspection_updates(
@@ -872,7 +875,8 @@ impl Coordinator {
                 .map(|(plan, resolved_ids)| (plan, resolved_ids, ResolvedIds::empty())),
             o @ (PurifiedStatement::PurifiedAlterSource { .. }
             | PurifiedStatement::PurifiedCreateSink(..)
-            | PurifiedStatement::PurifiedCreateTableFromSource { .. }) => {
+            | PurifiedStatement::PurifiedCreateTableFromSource { .. }
+            | PurifiedStatement::PurifiedCreateWebhookSource(..)) => {
                 // Unify these into a `Statement`.
                 let stmt = match o {
                     PurifiedStatement::PurifiedAlterSource { alter_source_stmt } => {
@@ -882,6 +886,9 @@ impl Coordinator {
                         Statement::CreateTableFromSource(stmt)
                     }
                     PurifiedStatement::PurifiedCreateSink(stmt) => Statement::CreateSink(stmt),
+                    PurifiedStatement::PurifiedCreateWebhookSource(stmt) => {
+                        Statement::CreateWebhookSource(stmt)
+                    }
                     PurifiedStatement::PurifiedCreateSource { .. }
                     | PurifiedStatement::PurifiedAlterSourceAddSubsources { .. }
                     | PurifiedStatement::PurifiedAlterSourceRefreshReferences { .. } => {
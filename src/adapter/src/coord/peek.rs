@@ -48,6 +48,7 @@ use mz_repr::{
     Diff, GlobalId, IntoRowIterator, RelationDesc, Row, RowIterator, SqlRelationType,
     preserves_order,
 };
+use mz_storage_types::read_holds::ReadHold;
 use mz_storage_types::sources::SourceData;
 use serde::{Deserialize, Serialize};
 use timely::progress::Antichain;
@@ -435,6 +436,10 @@ pub struct PlannedPeek {
     pub intermediate_result_type: SqlRelationType,
     pub source_arity: usize,
     pub source_ids: BTreeSet<GlobalId>,
+    /// If set, a slow-path peek sends an additional read hold on its transient
+    /// index here, which keeps the dataflow installed after the peek has been
+    /// served. Used by `EXPLAIN ANALYZE` to inspect the dataflow afterwards.
+    pub retain_dataflow: Option<oneshot::Sender<ReadHold>>,
 }
 
 /// Possible ways in which the coordinator could produce the result for a goal view.
@@ -721,6 +726,7 @@ impl crate::coord::Coordinator {
             intermediate_result_type,
             source_arity,
             source_ids,
+            retain_dataflow,
         } = plan;
 
         // If the dataflow optimizes to a constant expression, we can immediately return the result.
@@ -906,6 +912,11 @@ impl crate::coord::Coordinator {
                             return Err(e);
                         }
                     };
+                    if let Some(retain_dataflow) = retain_dataflow {
+                        // The receiver going away only means that nobody is interested in
+                        // the dataflow anymore.
+                        let _ = retain_dataflow.send(read_hold.clone());
+                    }
 
                     // Create an identity MFP operator.
                     let mut map_filter_project = mz_expr::MapFilterProject::new(source_arity);
@@ -1381,6 +1392,7 @@ impl crate::coord::Coordinator {
             intermediate_result_type,
             source_arity,
             source_ids,
+            retain_dataflow: None,
         };
 
         // TODO(peek-seq): After the old peek sequencing is completely removed, we should merge the
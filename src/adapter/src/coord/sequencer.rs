@@ -444,6 +444,9 @@ impl Coordinator {
                     self.sequence_explain_pushdown(ctx, plan, target_cluster)
                         .await;
                 }
+                Plan::ExplainAnalyzeSelect(plan) => {
+                    self.explain_analyze_peek(ctx, plan, target_cluster).await;
+                }
                 Plan::ExplainSinkSchema(plan) => {
                     let result = self.sequence_explain_schema(plan);
                     ctx.retire(result);
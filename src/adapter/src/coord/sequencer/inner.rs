@@ -1078,12 +1078,14 @@ impl Coordinator {
                 plan::DataSourceDesc::Webhook {
                     validate_using,
                     body_format,
+                    body_decoding,
                     headers,
                     cluster_id,
                 } => TableDataSource::DataSource {
                     desc: DataSourceDesc::Webhook {
                         validate_using,
                         body_format,
+                        body_decoding,
                         headers,
                         cluster_id: cluster_id.expect("Webhook Tables must have cluster_id set"),
                     },
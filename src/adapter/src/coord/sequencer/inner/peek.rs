@@ -9,7 +9,10 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
+use std::time::Instant;
 
+use anyhow::anyhow;
+use futures::StreamExt;
 use mz_adapter_types::dyncfgs::PLAN_INSIGHTS_NOTICE_FAST_PATH_CLUSTERS_OPTIMIZE_DURATION;
 use mz_compute_types::sinks::ComputeSinkConnection;
 use mz_controller_types::ClusterId;
@@ -17,12 +20,12 @@ use mz_expr::{CollectionPlan, ResultSpec};
 use mz_ore::cast::CastFrom;
 use mz_ore::instrument;
 use mz_repr::optimize::{OptimizerFeatures, OverrideFrom};
-use mz_repr::{Datum, GlobalId, Timestamp};
+use mz_repr::{Datum, GlobalId, Row, RowIterator, Timestamp};
 use mz_sql::ast::{ExplainStage, Statement};
 use mz_sql::catalog::CatalogCluster;
 // Import `plan` module, but only import select elements to avoid merge conflicts on use statements.
 use mz_sql::plan::QueryWhen;
-use mz_sql::plan::{self};
+use mz_sql::plan::{self, Params};
 use mz_sql::session::metadata::SessionMetadata;
 use mz_transform::EmptyStatisticsOracle;
 use tokio::sync::oneshot;
@@ -32,14 +35,15 @@ use tracing::{Instrument, Span};
 use crate::active_compute_sink::{ActiveComputeSink, ActiveCopyTo};
 use crate::command::ExecuteResponse;
 use crate::coord::id_bundle::CollectionIdBundle;
-use crate::coord::peek::{self, PeekDataflowPlan, PeekPlan, PlannedPeek};
+use crate::coord::peek::{self, PeekDataflowPlan, PeekPlan, PeekResponseUnary, PlannedPeek};
 use crate::coord::sequencer::inner::{return_if_err, spawn_linearized_read_ts};
 use crate::coord::sequencer::{check_log_reads, emit_optimizer_notices, eval_copy_to_uri};
 use crate::coord::timeline::{TimelineContext, timedomain_for};
 use crate::coord::timestamp_selection::{TimestampContext, TimestampDetermination};
 use crate::coord::{
-    Coordinator, CopyToContext, ExecuteContext, ExplainContext, ExplainPlanContext, Message,
-    PeekStage, PeekStageCopyTo, PeekStageExplainPlan, PeekStageExplainPushdown, PeekStageFinish,
+    Coordinator, CopyToContext, ExecuteContext, ExplainAnalyzeContext, ExplainAnalyzeTarget,
+    ExplainContext, ExplainPlanContext, Message, PeekStage, PeekStageCopyTo,
+    PeekStageExplainAnalyze, PeekStageExplainPlan, PeekStageExplainPushdown, PeekStageFinish,
     PeekStageLinearizeTimestamp, PeekStageOptimize, PeekStageRealTimeRecency,
     PeekStageTimestampReadHold, PlanValidity, StageResult, Staged, TargetCluster,
 };
@@ -64,6 +68,7 @@ impl Staged for PeekStage {
             PeekStage::Finish(stage) => &mut stage.validity,
             PeekStage::ExplainPlan(stage) => &mut stage.validity,
             PeekStage::ExplainPushdown(stage) => &mut stage.validity,
+            PeekStage::ExplainAnalyze(stage) => &mut stage.validity,
             PeekStage::CopyToPreflight(stage) => &mut stage.validity,
             PeekStage::CopyToDataflow(stage) => &mut stage.validity,
         }
@@ -90,6 +95,9 @@ impl Staged for PeekStage {
             PeekStage::ExplainPushdown(stage) => {
                 coord.peek_explain_pushdown(ctx.session(), stage).await
             }
+            PeekStage::ExplainAnalyze(stage) => {
+                coord.peek_explain_analyze(ctx.session_mut(), stage)
+            }
             PeekStage::CopyToPreflight(stage) => coord.peek_copy_to_preflight(stage).await,
             PeekStage::CopyToDataflow(stage) => coord.peek_copy_to_dataflow(ctx, stage).await,
         }
@@ -236,6 +244,36 @@ impl Coordinator {
         self.sequence_staged(ctx, Span::current(), stage).await;
     }
 
+    /// Sequence an `EXPLAIN ANALYZE ... FOR <query>`.
+    ///
+    /// The query is run to completion as a regular peek, discarding its rows
+    /// but keeping its dataflow installed. A second peek then reads the
+    /// introspection data of that dataflow, which is what gets reported.
+    #[instrument]
+    pub(crate) async fn explain_analyze_peek(
+        &mut self,
+        ctx: ExecuteContext,
+        plan::ExplainAnalyzeSelectPlan {
+            select,
+            properties,
+            desc,
+        }: plan::ExplainAnalyzeSelectPlan,
+        target_cluster: TargetCluster,
+    ) {
+        let stage = return_if_err!(
+            self.peek_validate(
+                ctx.session(),
+                select,
+                target_cluster,
+                None,
+                ExplainContext::Analyze(ExplainAnalyzeContext::Run { properties, desc }),
+                Some(ctx.session().vars().max_query_result_size()),
+            ),
+            ctx
+        );
+        self.sequence_staged(ctx, Span::current(), stage).await;
+    }
+
     /// Do some simple validation. We must defer most of it until after any off-thread work.
     #[instrument]
     pub fn peek_validate(
@@ -587,6 +625,7 @@ impl Coordinator {
                                         global_lir_plan,
                                         optimization_finished_at,
                                         insights_ctx,
+                                        explain_analyze_ctx: None,
                                     })
                                 }
                                 ExplainContext::None => PeekStage::Finish(PeekStageFinish {
@@ -603,7 +642,26 @@ impl Coordinator {
                                     global_lir_plan,
                                     optimization_finished_at,
                                     insights_ctx,
+                                    explain_analyze_ctx: None,
                                 }),
+                                ExplainContext::Analyze(explain_analyze_ctx) => {
+                                    PeekStage::Finish(PeekStageFinish {
+                                        validity,
+                                        plan,
+                                        max_query_result_size,
+                                        id_bundle,
+                                        target_replica,
+                                        source_ids,
+                                        determination,
+                                        cluster_id: optimizer.cluster_id(),
+                                        finishing: optimizer.finishing().clone(),
+                                        plan_insights_optimizer_trace: None,
+                                        global_lir_plan,
+                                        optimization_finished_at,
+                                        insights_ctx,
+                                        explain_analyze_ctx: Some(explain_analyze_ctx),
+                                    })
+                                }
                                 ExplainContext::Pushdown => {
                                     let (plan, _, _) = global_lir_plan.unapply();
                                     let imports = match plan {
@@ -743,7 +801,7 @@ impl Coordinator {
         &mut self,
         ctx: &mut ExecuteContext,
         PeekStageFinish {
-            validity: _,
+            validity,
             plan,
             max_query_result_size,
             id_bundle,
@@ -756,6 +814,7 @@ impl Coordinator {
             global_lir_plan,
             optimization_finished_at,
             insights_ctx,
+            explain_analyze_ctx,
         }: PeekStageFinish,
     ) -> Result<StageResult<Box<PeekStage>>, AdapterError> {
         if let Some(id) = ctx.extra.contents() {
@@ -792,15 +851,37 @@ impl Coordinator {
             session.add_notice(AdapterNotice::PlanInsights(insights));
         }
 
-        let planned_peek = PlannedPeek {
+        let mut planned_peek = PlannedPeek {
             plan: peek_plan,
             determination: determination.clone(),
             conn_id: conn_id.clone(),
             intermediate_result_type: typ,
             source_arity,
             source_ids,
+            retain_dataflow: None,
         };
 
+        // When running the query of an `EXPLAIN ANALYZE`, keep its dataflow
+        // installed so that it can be reported on. Fast-path peeks have no
+        // dataflow, so we can only say which fast path was taken.
+        let mut retained_dataflow = None;
+        let mut fast_path_description = None;
+        if let Some(ExplainAnalyzeContext::Run { .. }) = &explain_analyze_ctx {
+            match &planned_peek.plan {
+                PeekPlan::SlowPath(PeekDataflowPlan { desc, .. }) => {
+                    // The LIR mapping is keyed by the objects the dataflow
+                    // builds, not by the transient index it exports.
+                    let ids = desc.objects_to_build.iter().map(|o| o.id).collect();
+                    let (tx, rx) = oneshot::channel();
+                    planned_peek.retain_dataflow = Some(tx);
+                    retained_dataflow = Some((ids, rx));
+                }
+                PeekPlan::FastPath(plan) => {
+                    fast_path_description = Some(self.describe_fast_path(session, plan));
+                }
+            }
+        }
+
         if let Some(transient_index_id) = match &planned_peek.plan {
             peek::PeekPlan::FastPath(_) => None,
             peek::PeekPlan::SlowPath(PeekDataflowPlan { id, .. }) => Some(id),
@@ -821,6 +902,7 @@ impl Coordinator {
         }
 
         let max_result_size = self.catalog().system_config().max_result_size();
+        let started_at = Instant::now();
 
         // Implement the peek, and capture the response.
         let resp = self
@@ -835,6 +917,66 @@ impl Coordinator {
             )
             .await?;
 
+        match explain_analyze_ctx {
+            None => (),
+            Some(ExplainAnalyzeContext::Run { properties, desc }) => {
+                let target = match retained_dataflow {
+                    Some((ids, mut rx)) => {
+                        // A slow-path peek sends the hold before it is issued.
+                        let hold = rx.try_recv().map_err(|_| {
+                            AdapterError::Internal("EXPLAIN ANALYZE dataflow not retained".into())
+                        })?;
+                        ExplainAnalyzeTarget::Dataflow { ids, hold }
+                    }
+                    None => ExplainAnalyzeTarget::FastPath {
+                        description: fast_path_description.expect("set for fast-path peeks"),
+                    },
+                };
+                let span = Span::current();
+                return Ok(StageResult::Handle(mz_ore::task::spawn(
+                    || "explain analyze run",
+                    async move {
+                        let mut row_count = 0;
+                        drain_peek_response(resp, |rows| {
+                            row_count += u64::cast_from(rows.count());
+                        })
+                        .await?;
+                        let stage = PeekStage::ExplainAnalyze(PeekStageExplainAnalyze {
+                            validity,
+                            properties,
+                            desc,
+                            cluster_id,
+                            target,
+                            row_count,
+                            duration: started_at.elapsed(),
+                        });
+                        Ok(Box::new(stage))
+                    }
+                    .instrument(span),
+                )));
+            }
+            Some(ExplainAnalyzeContext::Report { dataflow_hold }) => {
+                let span = Span::current();
+                return Ok(StageResult::HandleRetire(mz_ore::task::spawn(
+                    || "explain analyze report",
+                    async move {
+                        let mut report = Vec::new();
+                        drain_peek_response(resp, |rows| {
+                            while let Some(row) = rows.next() {
+                                report.push(row.to_owned());
+                            }
+                        })
+                        .await?;
+                        // The dataflow may only go away once its introspection
+                        // data has been read.
+                        drop(dataflow_hold);
+                        Ok(Self::send_immediate_rows(report))
+                    }
+                    .instrument(span),
+                )));
+            }
+        }
+
         if ctx.session().vars().emit_timestamp_notice() {
             let explanation = self.explain_timestamp(
                 ctx.session().conn_id(),
@@ -1003,6 +1145,90 @@ impl Coordinator {
         )))
     }
 
+    /// Reports on the query of an `EXPLAIN ANALYZE`, which has run to
+    /// completion, by peeking at the introspection data of its dataflow.
+    #[instrument]
+    fn peek_explain_analyze(
+        &mut self,
+        session: &mut Session,
+        PeekStageExplainAnalyze {
+            validity: _,
+            properties,
+            desc,
+            cluster_id,
+            target,
+            row_count,
+            duration,
+        }: PeekStageExplainAnalyze,
+    ) -> Result<StageResult<Box<PeekStage>>, AdapterError> {
+        session.add_notice(AdapterNotice::ExplainAnalyzeQueryRan {
+            rows: row_count,
+            duration,
+        });
+
+        let (ids, dataflow_hold) = match target {
+            ExplainAnalyzeTarget::Dataflow { ids, hold } => (ids, hold),
+            ExplainAnalyzeTarget::FastPath { description } => {
+                let mut datums = vec![Datum::String(&description)];
+                datums.resize(desc.arity(), Datum::Null);
+                let row = Row::pack_slice(&datums);
+                return Ok(StageResult::Response(Self::send_immediate_rows(row)));
+            }
+        };
+
+        // The introspection data lies outside the time domain of the query.
+        // `EXPLAIN ANALYZE` cannot run in a multi-statement transaction, so the
+        // read holds of its implicit transaction are not needed anymore.
+        self.txn_read_holds.remove(session.conn_id());
+
+        let sql = plan::explain_analyze_dataflow_query(properties, &ids);
+        let catalog = self.catalog().for_session(session);
+        let parsed = mz_sql::parse::parse(&sql)?.into_element();
+        let (stmt, resolved_ids) = mz_sql::names::resolve(&catalog, parsed.ast)?;
+        let (report_plan, _sql_impl_ids) =
+            plan::plan(None, &catalog, stmt, &Params::empty(), &resolved_ids)?;
+        let plan::Plan::Select(mut select) = report_plan else {
+            return Err(AdapterError::Internal(format!(
+                "unexpected EXPLAIN ANALYZE report plan: {report_plan:?}"
+            )));
+        };
+        // The introspection data must include everything the query did.
+        select.when = QueryWhen::AtLeastTimestamp(Timestamp::from(self.now()));
+
+        let stage = self.peek_validate(
+            session,
+            select,
+            TargetCluster::Transaction(cluster_id),
+            None,
+            ExplainContext::Analyze(ExplainAnalyzeContext::Report { dataflow_hold }),
+            Some(session.vars().max_query_result_size()),
+        )?;
+        Ok(StageResult::Immediate(Box::new(stage)))
+    }
+
+    /// Describes which fast path a peek takes, for `EXPLAIN ANALYZE`.
+    fn describe_fast_path(&self, session: &Session, plan: &peek::FastPathPlan) -> String {
+        let name = |id: &GlobalId| match self.catalog().try_get_entry_by_global_id(id) {
+            Some(entry) => self
+                .catalog()
+                .resolve_full_name(entry.name(), Some(session.conn_id()))
+                .to_string(),
+            None => id.to_string(),
+        };
+        match plan {
+            peek::FastPathPlan::Constant(..) => "Fast path: constant".into(),
+            peek::FastPathPlan::PeekExisting(_, index_id, None, _) => {
+                format!("Fast path: read from index {}", name(index_id))
+            }
+            peek::FastPathPlan::PeekExisting(_, index_id, Some(_), _) => {
+                format!("Fast path: lookup in index {}", name(index_id))
+            }
+            peek::FastPathPlan::PeekPersist(id, _, _) => {
+                format!("Fast path: read from {}", name(id))
+            }
+        }
+    }
+
     /// Determines the query timestamp and acquires read holds on dependent sources
     /// if necessary.
     #[instrument]
@@ -1137,3 +1363,33 @@ impl Coordinator {
         Ok(determination)
     }
 }
+
+/// Drains the rows of a peek response, passing each batch to `f`.
+async fn drain_peek_response(
+    resp: ExecuteResponse,
+    mut f: impl FnMut(&mut dyn RowIterator),
+) -> Result<(), AdapterError> {
+    match resp {
+        ExecuteResponse::SendingRowsImmediate { mut rows } => f(&mut *rows),
+        ExecuteResponse::SendingRowsStreaming { mut rows, .. } => {
+            while let Some(res) = rows.next().await {
+                match res {
+                    PeekResponseUnary::Rows(mut rows) => f(&mut *rows),
+                    PeekResponseUnary::Canceled => return Err(AdapterError::Canceled),
+                    PeekResponseUnary::Error(e) => {
+                        return Err(AdapterError::Unstructured(anyhow!(e)));
+                    }
+                    PeekResponseUnary::DependencyDropped(dep) => {
+                        return Err(dep.to_concurrent_dependency_drop());
+                    }
+                }
+            }
+        }
+        resp => {
+            return Err(AdapterError::Internal(format!(
+                "unexpected peek response: {resp:?}"
+            )));
+        }
+    }
+    Ok(())
+}
@@ -118,6 +118,19 @@ impl PeekClient {
         // This must happen BEFORE statement logging setup to avoid orphaned execution records.
         if let Some(ref stmt) = stmt {
             match &**stmt {
+                Statement::ExplainAnalyzeObject(explain_stmt)
+                    if matches!(
+                        explain_stmt.explainee,
+                        mz_sql_parser::ast::Explainee::Select(..)
+                    ) =>
+                {
+                    // EXPLAIN ANALYZE of a query runs the query and then a second peek over
+                    // its dataflow's introspection data, which only the coordinator does.
+                    debug!(
+                        "Bailing out from try_frontend_peek, because EXPLAIN ANALYZE is for a query"
+                    );
+                    return Ok(None);
+                }
                 Statement::Select(_)
                 | Statement::ExplainAnalyzeObject(_)
                 | Statement::ExplainAnalyzeCluster(_)
@@ -1048,6 +1061,13 @@ impl PeekClient {
                                             .expect("it's present for the ExplainPushdown case"),
                                     })
                                 }
+                                ExplainContext::Analyze(_) => {
+                                    // We bail out for EXPLAIN ANALYZE of queries at the AST
+                                    // level, before ever constructing this context.
+                                    Err(AdapterError::Internal(
+                                        "EXPLAIN ANALYZE in frontend peek sequencing".into(),
+                                    ))
+                                }
                             }
                         })
                     },
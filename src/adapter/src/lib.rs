@@ -64,7 +64,7 @@ pub use crate::peek_client::PeekClient;
 
 pub use crate::client::{Client, Handle, SessionClient};
 pub use crate::command::{
-    CopyFromStdinWriter, ExecuteResponse, ExecuteResponseKind, StartupResponse,
+    AuthFailure, CopyFromStdinWriter, ExecuteResponse, ExecuteResponseKind, StartupResponse,
 };
 pub use crate::coord::ExecuteContext;
 pub use crate::coord::ExecuteContextExtra;
@@ -81,5 +81,6 @@ pub use crate::error::{AdapterError, AuthenticationError};
 pub use crate::notice::AdapterNotice;
 pub use crate::util::{ResultExt, verify_datum_desc};
 pub use crate::webhook::{
-    AppendWebhookError, AppendWebhookResponse, AppendWebhookValidator, WebhookAppenderCache,
+    AppendWebhookDecoder, AppendWebhookError, AppendWebhookResponse, AppendWebhookValidator,
+    WebhookAppenderCache,
 };
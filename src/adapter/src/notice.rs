@@ -8,6 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::fmt;
+use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use itertools::Itertools;
@@ -160,6 +161,11 @@ pub enum AdapterNotice {
     OidcGroupSyncError {
         message: String,
     },
+    /// The query of an `EXPLAIN ANALYZE` ran to completion.
+    ExplainAnalyzeQueryRan {
+        rows: u64,
+        duration: Duration,
+    },
 }
 
 impl AdapterNotice {
@@ -232,6 +238,7 @@ impl AdapterNotice {
             AdapterNotice::OidcGroupSyncUnmatchedGroup { .. } => Severity::Notice,
             AdapterNotice::OidcGroupSyncReservedRole { .. } => Severity::Warning,
             AdapterNotice::OidcGroupSyncError { .. } => Severity::Warning,
+            AdapterNotice::ExplainAnalyzeQueryRan { .. } => Severity::Notice,
         }
     }
 
@@ -356,6 +363,7 @@ impl AdapterNotice {
             AdapterNotice::OidcGroupSyncUnmatchedGroup { .. } => SqlState::SUCCESSFUL_COMPLETION,
             AdapterNotice::OidcGroupSyncReservedRole { .. } => SqlState::WARNING,
             AdapterNotice::OidcGroupSyncError { .. } => SqlState::WARNING,
+            AdapterNotice::ExplainAnalyzeQueryRan { .. } => SqlState::SUCCESSFUL_COMPLETION,
         }
     }
 }
@@ -584,6 +592,9 @@ impl fmt::Display for AdapterNotice {
             AdapterNotice::OidcGroupSyncError { message } => {
                 write!(f, "OIDC group-to-role sync failed: {}", message)
             }
+            AdapterNotice::ExplainAnalyzeQueryRan { rows, duration } => {
+                write!(f, "query returned {rows} rows in {duration:?}")
+            }
         }
     }
 }
@@ -58,7 +58,7 @@ use crate::coord::appends::BuiltinTableAppendNotify;
 use crate::coord::in_memory_oracle::InMemoryTimestampOracle;
 use crate::coord::peek::PeekResponseUnary;
 use crate::coord::timestamp_selection::{TimestampContext, TimestampDetermination};
-use crate::coord::{Coordinator, ExplainContext};
+use crate::coord::{Coordinator, ExplainAnalyzeContext, ExplainContext};
 use crate::error::AdapterError;
 use crate::metrics::{Metrics, SessionMetrics};
 use crate::statement_logging::PreparedStatementLoggingInfo;
@@ -123,6 +123,8 @@ pub struct Session {
     /// TODO: We might want to track changes also to session variables.
     /// (`Catalog::transient_revision` similarly tracks changes on the catalog side.)
     state_revision: u64,
+    /// Whether the session's login and logout are recorded in the audit log.
+    audit_login: bool,
 }
 
 impl SessionMetadata for Session {
@@ -355,6 +357,7 @@ impl Session {
             qcell_owner: QCellOwner::new(),
             session_oracles: BTreeMap::new(),
             state_revision: 0,
+            audit_login: true,
         }
     }
 
@@ -363,6 +366,19 @@ impl Session {
         self.secret_key
     }
 
+    /// Stops the session's login and logout from being recorded in the audit
+    /// log, for sessions that serve a single request rather than a client
+    /// connection.
+    pub fn skip_login_audit(&mut self) {
+        self.audit_login = false;
+    }
+
+    /// Reports whether the session's login and logout are recorded in the
+    /// audit log.
+    pub fn audit_login(&self) -> bool {
+        self.audit_login
+    }
+
     fn new_pcx(&self, mut wall_time: DateTime<Utc>) -> PlanContext {
         if let Some(mock_time) = self.vars().unsafe_new_transaction_wall_time() {
             wall_time = *mock_time;
@@ -1711,7 +1727,9 @@ pub enum RequireLinearization {
 impl From<&ExplainContext> for RequireLinearization {
     fn from(ctx: &ExplainContext) -> Self {
         match ctx {
-            ExplainContext::None | ExplainContext::PlanInsightsNotice(_) => {
+            ExplainContext::None
+            | ExplainContext::PlanInsightsNotice(_)
+            | ExplainContext::Analyze(ExplainAnalyzeContext::Run { .. }) => {
                 RequireLinearization::Required
             }
             _ => RequireLinearization::NotRequired,
@@ -14,15 +14,27 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use anyhow::Context;
 use chrono::{DateTime, Utc};
 use derivative::Derivative;
-use mz_expr::Eval;
+use itertools::Itertools;
+use mz_expr::{Eval, MirScalarExpr};
+use mz_interchange::avro;
+use mz_interchange::protobuf::{self, DecodedDescriptors};
 use mz_ore::cast::CastFrom;
-use mz_repr::{Datum, Diff, Row, RowArena};
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_ore::str::StrExt;
+use mz_repr::{ColumnName, Datum, Diff, Row, RowArena, SqlColumnType};
 use mz_secrets::SecretsReader;
 use mz_secrets::cache::CachingSecretsReader;
-use mz_sql::plan::{WebhookBodyFormat, WebhookHeaders, WebhookValidation, WebhookValidationSecret};
+use mz_sql::plan::{
+    WebhookBodyDecoding, WebhookBodyFormat, WebhookHeaders, WebhookValidation,
+    WebhookValidationSecret,
+};
 use mz_storage_client::controller::MonotonicAppender;
 use mz_storage_client::statistics::WebhookStatistics;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::connections::inline::{ConnectionResolver, IntoInlineConnection};
 use mz_storage_types::controller::StorageError;
+use mz_storage_types::sources::encoding::{DataEncoding, ProtobufEncoding};
 use tokio::sync::Semaphore;
 
 use crate::optimize::dataflows::{ExprPrep, ExprPrepWebhookValidation};
@@ -37,6 +49,8 @@ pub enum AppendWebhookError {
     InvalidUtf8Body { msg: String },
     #[error("the provided request body is not valid JSON: {msg}")]
     InvalidJsonBody { msg: String },
+    #[error("failed to decode the request body: {msg}")]
+    DecodeError { msg: String },
     #[error("webhook source '{database}.{schema}.{name}' does not exist")]
     UnknownWebhook {
         database: String,
@@ -232,6 +246,193 @@ impl AppendWebhookValidator {
     }
 }
 
+/// Decodes the body of a webhook request into the typed columns of a webhook source.
+///
+/// To actually decode a request call [`AppendWebhookDecoder::decode`].
+#[derive(Clone, Debug)]
+pub struct AppendWebhookDecoder {
+    inner: AppendWebhookDecoderInner,
+}
+
+#[derive(Clone, Debug)]
+enum AppendWebhookDecoderInner {
+    /// Extracts columns from each JSON object in the body.
+    Json {
+        columns: Vec<MirScalarExpr>,
+        column_tys: Vec<(ColumnName, SqlColumnType)>,
+    },
+    /// Decodes the entire body with an encoding, e.g. Avro or Protobuf.
+    ///
+    /// The decoder is created on first use because connecting to a schema registry is async.
+    Encoding {
+        encoding: DataEncoding,
+        storage_configuration: StorageConfiguration,
+        num_columns: usize,
+        decoder: Arc<tokio::sync::Mutex<Option<BodyDecoder>>>,
+    },
+}
+
+#[derive(Debug)]
+enum BodyDecoder {
+    Avro(avro::Decoder),
+    Protobuf(protobuf::Decoder),
+}
+
+impl AppendWebhookDecoder {
+    /// Creates a decoder for a webhook source whose body columns are described by `column_tys`.
+    pub fn new<R: ConnectionResolver>(
+        decoding: WebhookBodyDecoding,
+        column_tys: Vec<(ColumnName, SqlColumnType)>,
+        connection_resolver: R,
+        storage_configuration: StorageConfiguration,
+    ) -> Self {
+        let inner = match decoding {
+            WebhookBodyDecoding::Json { columns } => AppendWebhookDecoderInner::Json {
+                columns,
+                column_tys,
+            },
+            WebhookBodyDecoding::Encoding(encoding) => AppendWebhookDecoderInner::Encoding {
+                encoding: encoding.into_inline_connection(connection_resolver),
+                storage_configuration,
+                num_columns: column_tys.len(),
+                decoder: Arc::new(tokio::sync::Mutex::new(None)),
+            },
+        };
+        AppendWebhookDecoder { inner }
+    }
+
+    /// Returns the number of columns each decoded body is split into.
+    pub fn num_columns(&self) -> usize {
+        match &self.inner {
+            AppendWebhookDecoderInner::Json { column_tys, .. } => column_tys.len(),
+            AppendWebhookDecoderInner::Encoding { num_columns, .. } => *num_columns,
+        }
+    }
+
+    /// Decodes `bodies`, each a [`Row`] containing only the raw "body" column of a request, into
+    /// [`Row`]s containing the body columns of the webhook source.
+    pub async fn decode(&self, bodies: Vec<Row>) -> Result<Vec<Row>, AppendWebhookError> {
+        match &self.inner {
+            AppendWebhookDecoderInner::Json {
+                columns,
+                column_tys,
+            } => {
+                let mut rows = Vec::with_capacity(bodies.len());
+                for body in bodies {
+                    let temp_storage = RowArena::new();
+                    let datums = body.unpack();
+                    let mut row = Row::default();
+                    let mut packer = row.packer();
+                    for (expr, (name, ty)) in columns.iter().zip_eq(column_tys) {
+                        let datum = expr.eval(&datums, &temp_storage).map_err(|e| {
+                            AppendWebhookError::DecodeError {
+                                msg: format!("column {}: {e}", name.as_str().quoted()),
+                            }
+                        })?;
+                        if datum.is_null() && !ty.nullable {
+                            return Err(AppendWebhookError::DecodeError {
+                                msg: format!("null value in column {}", name.as_str().quoted()),
+                            });
+                        }
+                        packer.push(datum);
+                    }
+                    rows.push(row);
+                }
+                Ok(rows)
+            }
+            AppendWebhookDecoderInner::Encoding {
+                encoding,
+                storage_configuration,
+                num_columns: _,
+                decoder,
+            } => {
+                // Note: this serializes decoding for a single webhook source, which is fine
+                // because appends to it are serialized as well.
+                let mut guard = decoder.lock().await;
+                if guard.is_none() {
+                    let new_decoder = match encoding {
+                        DataEncoding::Avro(avro_encoding) => {
+                            let writer_schemas = avro_encoding
+                                .writer_schemas(storage_configuration, InTask::Yes)
+                                .await
+                                .map_err(anyhow::Error::from)?;
+                            BodyDecoder::Avro(avro::Decoder::new(
+                                &avro_encoding.schema,
+                                &avro_encoding.reference_schemas,
+                                writer_schemas,
+                                "webhook".to_string(),
+                            )?)
+                        }
+                        DataEncoding::Protobuf(ProtobufEncoding {
+                            descriptors,
+                            message_name,
+                            confluent_wire_format,
+                        }) => {
+                            let descriptors =
+                                DecodedDescriptors::from_bytes(descriptors, message_name.clone())?;
+                            BodyDecoder::Protobuf(protobuf::Decoder::new(
+                                descriptors,
+                                *confluent_wire_format,
+                            )?)
+                        }
+                        encoding => {
+                            return Err(AppendWebhookError::InternalError(anyhow::anyhow!(
+                                "unsupported webhook body encoding {}",
+                                encoding.type_()
+                            )));
+                        }
+                    };
+                    *guard = Some(new_decoder);
+                }
+                let body_decoder = guard.as_mut().expect("initialized above");
+
+                let mut rows = Vec::with_capacity(bodies.len());
+                for body in bodies {
+                    let Datum::Bytes(mut bytes) = body.unpack_first() else {
+                        return Err(AppendWebhookError::InternalError(anyhow::anyhow!(
+                            "webhook body was not packed as bytes"
+                        )));
+                    };
+                    let row = match body_decoder {
+                        BodyDecoder::Avro(decoder) => {
+                            let row = decoder.decode(&mut bytes).await?.map_err(|e| {
+                                AppendWebhookError::DecodeError {
+                                    msg: format!(
+                                        "avro deserialization error: {}",
+                                        e.display_with_causes()
+                                    ),
+                                }
+                            })?;
+                            // Each request holds exactly one message.
+                            if !bytes.is_empty() {
+                                return Err(AppendWebhookError::DecodeError {
+                                    msg: format!(
+                                        "unexpected bytes remaining for decoded value: {bytes:?}"
+                                    ),
+                                });
+                            }
+                            row
+                        }
+                        BodyDecoder::Protobuf(decoder) => decoder
+                            .decode(bytes)
+                            .map_err(|e| AppendWebhookError::DecodeError {
+                                msg: format!(
+                                    "protobuf deserialization error: {}",
+                                    e.display_with_causes()
+                                ),
+                            })?
+                            .ok_or_else(|| AppendWebhookError::DecodeError {
+                                msg: "protobuf deserialization returned None".to_string(),
+                            })?,
+                    };
+                    rows.push(row);
+                }
+                Ok(rows)
+            }
+        }
+    }
+}
+
 #[derive(Derivative, Clone)]
 #[derivative(Debug)]
 pub struct AppendWebhookResponse {
@@ -239,6 +440,8 @@ pub struct AppendWebhookResponse {
     pub tx: WebhookAppender,
     /// Column type for the `body` column.
     pub body_format: WebhookBodyFormat,
+    /// Decodes the `body` column into the typed body columns of the source, if required.
+    pub decoder: Option<AppendWebhookDecoder>,
     /// Types of the columns for the headers of a request.
     pub header_tys: WebhookHeaders,
     /// Expression used to validate a webhook request.
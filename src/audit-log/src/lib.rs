@@ -117,6 +117,9 @@ pub enum EventType {
     Grant,
     Revoke,
     Comment,
+    Login,
+    Logout,
+    AuthFailure,
 }
 
 impl EventType {
@@ -128,6 +131,9 @@ impl EventType {
             EventType::Grant => "Granted",
             EventType::Revoke => "Revoked",
             EventType::Comment => "Comment",
+            EventType::Login => "Logged In",
+            EventType::Logout => "Logged Out",
+            EventType::AuthFailure => "Failed Authentication",
         }
     }
 }
@@ -256,6 +262,8 @@ pub enum EventDetails {
     ResetAllV1,
     RotateKeysV1(RotateKeysV1),
     CreateRoleV1(CreateRoleV1),
+    SessionV1(SessionV1),
+    AuthFailureV1(AuthFailureV1),
 }
 
 #[derive(
@@ -471,6 +479,61 @@ pub struct CreateRoleV1 {
     pub auto_provision_source: Option<String>,
 }
 
+/// A session that logged in or out, recorded with [`EventType::Login`] and
+/// [`EventType::Logout`]. The role the session authenticated as is the
+/// event's `user`.
+#[derive(
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    PartialOrd,
+    PartialEq,
+    Eq,
+    Ord,
+    Hash,
+    Arbitrary
+)]
+pub struct SessionV1 {
+    /// The session's UUID, as reported by `mz_sessions`.
+    pub session_id: String,
+    /// The authenticator that authenticated the session, e.g. `password`.
+    pub authenticator_kind: String,
+    pub client_ip: Option<String>,
+    pub application_name: String,
+}
+
+/// A rejected attempt to authenticate, recorded with
+/// [`EventType::AuthFailure`]. The event has no `user`, since nobody was
+/// authenticated.
+#[derive(
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    PartialOrd,
+    PartialEq,
+    Eq,
+    Ord,
+    Hash,
+    Arbitrary
+)]
+pub struct AuthFailureV1 {
+    /// The role the client attempted to authenticate as, or empty if the
+    /// rejected credentials didn't name one (e.g. an HTTP bearer token).
+    pub role_name: String,
+    /// The authenticator that rejected the attempt, e.g. `password`.
+    pub authenticator_kind: String,
+    pub client_ip: Option<String>,
+    pub application_name: Option<String>,
+    pub reason: String,
+    /// Zero for an individually recorded failure. Otherwise this event
+    /// summarizes that many failures for the same role and client address
+    /// that rate limiting kept out of the audit log, and its other fields
+    /// describe the most recent of them.
+    pub suppressed_failures: u64,
+}
+
 /// A transition in the lifecycle of a hydration burst (a `burst` record on a
 /// managed cluster), recorded so an operator can trace a controller-initiated
 /// burst from start to teardown.
@@ -1461,6 +1524,8 @@ impl EventDetails {
             EventDetails::ResetAllV1 => serde_json::Value::Null,
             EventDetails::RotateKeysV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::CreateRoleV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::SessionV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::AuthFailureV1(v) => serde_json::to_value(v).expect("must serialize"),
         }
     }
 }
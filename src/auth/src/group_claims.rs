@@ -10,7 +10,8 @@
 //! Shared JWT group-claim extraction used by both OIDC and Frontegg
 //! authenticators. Encapsulates the dot-separated claim-path resolution and
 //! the array-vs-string normalization so the two authenticators behave
-//! identically.
+//! identically. The LDAP authenticator reuses the final normalization step
+//! for group names read from directory entries.
 
 use std::collections::{BTreeMap, BTreeSet};
 
@@ -61,14 +62,22 @@ pub fn extract_groups(
         }
     };
 
-    let groups: Vec<String> = raw_groups
+    Some(normalize_groups(raw_groups))
+}
+
+/// Normalizes group names for group-to-role sync: drops empty names and
+/// returns the rest deduplicated and sorted. Case is preserved, as matching
+/// against catalog role names is case-sensitive.
+///
+/// Used by every source of group names (JWT claims, LDAP entries) so that
+/// they are synced identically.
+pub fn normalize_groups(groups: impl IntoIterator<Item = String>) -> Vec<String> {
+    groups
         .into_iter()
         .filter(|g| !g.is_empty())
         .collect::<BTreeSet<_>>()
         .into_iter()
-        .collect();
-
-    Some(groups)
+        .collect()
 }
 
 /// Walks a dot-separated claim path into nested JSON objects. Returns
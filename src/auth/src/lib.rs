@@ -24,11 +24,30 @@ pub enum AuthenticatorKind {
     Sasl,
     /// Authenticated via OIDC (JWT tokens).
     Oidc,
+    /// Authenticated via a TLS client certificate.
+    Certificate,
+    /// Authenticated via a bind against an LDAP directory.
+    Ldap,
     /// No authentication performed.
     #[default]
     None,
 }
 
+impl AuthenticatorKind {
+    /// Returns the name of the authenticator, as recorded in the audit log.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthenticatorKind::Frontegg => "frontegg",
+            AuthenticatorKind::Password => "password",
+            AuthenticatorKind::Sasl => "sasl",
+            AuthenticatorKind::Oidc => "oidc",
+            AuthenticatorKind::Certificate => "certificate",
+            AuthenticatorKind::Ldap => "ldap",
+            AuthenticatorKind::None => "none",
+        }
+    }
+}
+
 /// A sentinel type signifying successful authentication.
 ///
 /// This type is used to establish an authenticated Adapter client session,
@@ -0,0 +1,388 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! TLS client certificate authentication.
+//!
+//! The certificate itself is verified against the configured client CA during
+//! the TLS handshake. This module only maps the identity carried by an already
+//! verified certificate to a role, using the `cert_auth_identity_field` and
+//! `cert_auth_role_pattern` system variables.
+
+use mz_adapter::{AdapterError, AuthenticationError, Client as AdapterClient};
+use mz_adapter_types::dyncfgs::{CERT_AUTH_IDENTITY_FIELD, CERT_AUTH_ROLE_PATTERN};
+use mz_auth::Authenticated;
+use mz_pgwire_common::{ErrorResponse, Severity};
+use openssl::nid::Nid;
+use openssl::x509::X509Ref;
+use regex::Regex;
+use tokio_postgres::error::SqlState;
+use tracing::{debug, warn};
+
+/// Errors that can occur during certificate authentication.
+#[derive(Debug)]
+pub enum CertificateError {
+    /// The client did not present a certificate.
+    MissingCertificate,
+    /// The `cert_auth_identity_field` system variable is not a known field.
+    InvalidIdentityField(String),
+    /// The `cert_auth_role_pattern` system variable is not a valid regex.
+    InvalidRolePattern(String),
+    /// No identity in the certificate matched the role pattern.
+    NoMatchingIdentity { field: IdentityField },
+    /// The certificate maps to a role other than the one the client asked
+    /// to connect as.
+    WrongUser,
+    /// The mapped role does not exist.
+    RoleNotFound,
+    /// The role exists but does not have the LOGIN attribute.
+    NonLogin,
+    /// The adapter returned an unexpected error while checking the role.
+    LoginCheckError,
+}
+
+impl std::fmt::Display for CertificateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CertificateError::MissingCertificate => write!(f, "no client certificate presented"),
+            CertificateError::InvalidIdentityField(_) => {
+                write!(f, "invalid cert_auth_identity_field system variable")
+            }
+            CertificateError::InvalidRolePattern(_) => {
+                write!(f, "invalid cert_auth_role_pattern system variable")
+            }
+            CertificateError::NoMatchingIdentity { .. } => {
+                write!(f, "client certificate does not map to a role")
+            }
+            CertificateError::WrongUser => write!(f, "wrong user"),
+            CertificateError::RoleNotFound => write!(f, "role does not exist"),
+            CertificateError::NonLogin => write!(f, "role is not allowed to login"),
+            CertificateError::LoginCheckError => {
+                write!(f, "unexpected error checking if role can login")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CertificateError {}
+
+impl CertificateError {
+    pub fn code(&self) -> SqlState {
+        SqlState::INVALID_AUTHORIZATION_SPECIFICATION
+    }
+
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            CertificateError::InvalidIdentityField(field) => Some(format!(
+                "\"{field}\" is not one of common_name, san_dns, san_email, or san_uri."
+            )),
+            CertificateError::InvalidRolePattern(error) => Some(error.clone()),
+            CertificateError::NoMatchingIdentity { field } => Some(format!(
+                "No {} in the client certificate matched cert_auth_role_pattern.",
+                field.description()
+            )),
+            CertificateError::NonLogin => {
+                Some("The role does not have the LOGIN attribute.".into())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            CertificateError::WrongUser => {
+                Some("Connect as the role that the client certificate maps to.".into())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn into_response(self) -> ErrorResponse {
+        ErrorResponse {
+            severity: Severity::Fatal,
+            code: self.code(),
+            message: self.to_string(),
+            detail: self.detail(),
+            hint: self.hint(),
+            position: None,
+        }
+    }
+}
+
+/// The certificate field that carries the identity of the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentityField {
+    /// The common name (CN) of the subject.
+    CommonName,
+    /// A DNS name subject alternative name.
+    SanDns,
+    /// An email subject alternative name.
+    SanEmail,
+    /// A URI subject alternative name.
+    SanUri,
+}
+
+impl IdentityField {
+    fn parse(s: &str) -> Result<IdentityField, CertificateError> {
+        match s {
+            "common_name" => Ok(IdentityField::CommonName),
+            "san_dns" => Ok(IdentityField::SanDns),
+            "san_email" => Ok(IdentityField::SanEmail),
+            "san_uri" => Ok(IdentityField::SanUri),
+            _ => Err(CertificateError::InvalidIdentityField(s.to_string())),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            IdentityField::CommonName => "subject common name",
+            IdentityField::SanDns => "DNS subject alternative name",
+            IdentityField::SanEmail => "email subject alternative name",
+            IdentityField::SanUri => "URI subject alternative name",
+        }
+    }
+}
+
+/// The identities carried by a verified client certificate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientCertificate {
+    /// The common names of the subject.
+    pub common_names: Vec<String>,
+    /// The DNS name subject alternative names.
+    pub dns_names: Vec<String>,
+    /// The email subject alternative names.
+    pub emails: Vec<String>,
+    /// The URI subject alternative names.
+    pub uris: Vec<String>,
+}
+
+impl ClientCertificate {
+    /// Extracts the identities from `cert`.
+    ///
+    /// Entries that are not valid UTF-8 are ignored.
+    pub fn from_x509(cert: &X509Ref) -> ClientCertificate {
+        let common_names = cert
+            .subject_name()
+            .entries_by_nid(Nid::COMMONNAME)
+            .filter_map(|entry| entry.data().as_utf8().ok())
+            .map(|cn| cn.to_string())
+            .collect();
+        let mut client_cert = ClientCertificate {
+            common_names,
+            ..Default::default()
+        };
+        for name in cert.subject_alt_names().into_iter().flatten() {
+            if let Some(dns) = name.dnsname() {
+                client_cert.dns_names.push(dns.to_string());
+            } else if let Some(email) = name.email() {
+                client_cert.emails.push(email.to_string());
+            } else if let Some(uri) = name.uri() {
+                client_cert.uris.push(uri.to_string());
+            }
+        }
+        client_cert
+    }
+
+    fn identities(&self, field: IdentityField) -> &[String] {
+        match field {
+            IdentityField::CommonName => &self.common_names,
+            IdentityField::SanDns => &self.dns_names,
+            IdentityField::SanEmail => &self.emails,
+            IdentityField::SanUri => &self.uris,
+        }
+    }
+
+    /// Maps the certificate to a role name.
+    ///
+    /// Each identity in `field` is matched in order against `pattern`, which
+    /// must match the entire identity. The role name is the first capture
+    /// group of the first matching identity, or the whole identity if the
+    /// pattern has no capture groups.
+    pub fn role(&self, field: IdentityField, pattern: &str) -> Result<String, CertificateError> {
+        let regex = Regex::new(&format!("^(?:{pattern})$"))
+            .map_err(|e| CertificateError::InvalidRolePattern(e.to_string()))?;
+        self.identities(field)
+            .iter()
+            .find_map(|identity| {
+                let captures = regex.captures(identity)?;
+                let role = captures.get(1).or_else(|| captures.get(0))?.as_str();
+                (!role.is_empty()).then(|| role.to_string())
+            })
+            .ok_or(CertificateError::NoMatchingIdentity { field })
+    }
+}
+
+/// Authenticates users by the client certificate they presented during the
+/// TLS handshake.
+#[derive(Debug, Clone)]
+pub struct CertificateAuthenticator {
+    adapter_client: AdapterClient,
+}
+
+impl CertificateAuthenticator {
+    pub fn new(adapter_client: AdapterClient) -> Self {
+        Self { adapter_client }
+    }
+
+    /// Maps the verified client certificate to a role.
+    ///
+    /// If `expected_user` is provided, it must equal the mapped role. Returns
+    /// the role name on success.
+    pub async fn authenticate(
+        &self,
+        cert: Option<&ClientCertificate>,
+        expected_user: Option<&str>,
+    ) -> Result<(String, Authenticated), CertificateError> {
+        let cert = cert.ok_or(CertificateError::MissingCertificate)?;
+        let system_vars = self.adapter_client.get_system_vars().await;
+        let field = IdentityField::parse(&CERT_AUTH_IDENTITY_FIELD.get(system_vars.dyncfgs()))?;
+        let pattern = CERT_AUTH_ROLE_PATTERN.get(system_vars.dyncfgs());
+        let role = cert.role(field, &pattern).inspect_err(|_| {
+            debug!(
+                ?cert,
+                ?field,
+                pattern,
+                "client certificate did not map to a role"
+            );
+        })?;
+
+        if let Some(expected) = expected_user
+            && role != expected
+        {
+            return Err(CertificateError::WrongUser);
+        }
+
+        self.check_role_login(&role).await?;
+        Ok((role, Authenticated))
+    }
+
+    /// Checks that the role exists and has the LOGIN attribute. Unlike OIDC,
+    /// certificate authentication does not auto-provision roles.
+    async fn check_role_login(&self, role_name: &str) -> Result<(), CertificateError> {
+        match self.adapter_client.role_can_login(role_name).await {
+            Ok(()) => Ok(()),
+            Err(AdapterError::AuthenticationError(AuthenticationError::RoleNotFound)) => {
+                Err(CertificateError::RoleNotFound)
+            }
+            Err(AdapterError::AuthenticationError(AuthenticationError::NonLogin)) => {
+                Err(CertificateError::NonLogin)
+            }
+            Err(e) => {
+                warn!(?e, "unexpected error checking certificate role login");
+                Err(CertificateError::LoginCheckError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cert() -> ClientCertificate {
+        ClientCertificate {
+            common_names: vec!["svc-ingest".into()],
+            dns_names: vec!["ingest.internal".into(), "ingest.prod.internal".into()],
+            emails: vec!["ingest@example.com".into()],
+            uris: vec!["spiffe://example.com/ns/prod/sa/ingest".into()],
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_role_whole_identity() {
+        assert_eq!(
+            cert().role(IdentityField::CommonName, "(.+)").unwrap(),
+            "svc-ingest"
+        );
+        assert_eq!(
+            cert()
+                .role(IdentityField::SanEmail, ".+@example\\.com")
+                .unwrap(),
+            "ingest@example.com"
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_role_capture_group() {
+        assert_eq!(
+            cert().role(IdentityField::CommonName, "svc-(.+)").unwrap(),
+            "ingest"
+        );
+        assert_eq!(
+            cert()
+                .role(
+                    IdentityField::SanUri,
+                    "spiffe://example\\.com/ns/prod/sa/(.+)"
+                )
+                .unwrap(),
+            "ingest"
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_role_first_matching_identity() {
+        assert_eq!(
+            cert()
+                .role(IdentityField::SanDns, "(.+)\\.prod\\.internal")
+                .unwrap(),
+            "ingest"
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_role_anchored() {
+        // The pattern must match the whole identity, not a substring.
+        assert!(matches!(
+            cert().role(IdentityField::CommonName, "ingest"),
+            Err(CertificateError::NoMatchingIdentity {
+                field: IdentityField::CommonName
+            })
+        ));
+        // Alternations are anchored as a group.
+        assert_eq!(
+            cert()
+                .role(IdentityField::CommonName, "other|svc-ingest")
+                .unwrap(),
+            "svc-ingest"
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_role_no_identity() {
+        let cert = ClientCertificate {
+            common_names: vec!["svc-ingest".into()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            cert.role(IdentityField::SanDns, "(.+)"),
+            Err(CertificateError::NoMatchingIdentity {
+                field: IdentityField::SanDns
+            })
+        ));
+    }
+
+    #[mz_ore::test]
+    fn test_role_invalid_pattern() {
+        assert!(matches!(
+            cert().role(IdentityField::CommonName, "(.+"),
+            Err(CertificateError::InvalidRolePattern(_))
+        ));
+    }
+
+    #[mz_ore::test]
+    fn test_identity_field_parse() {
+        assert_eq!(
+            IdentityField::parse("san_uri").unwrap(),
+            IdentityField::SanUri
+        );
+        assert!(matches!(
+            IdentityField::parse("subject"),
+            Err(CertificateError::InvalidIdentityField(_))
+        ));
+    }
+}
@@ -0,0 +1,549 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! LDAP password authentication.
+//!
+//! Users are authenticated by binding to the directory with the password they
+//! supply, in one of two modes, selected by the `ldap_*` system variables:
+//!
+//! * **Simple bind**: the user's DN is derived from `ldap_bind_dn_template`.
+//! * **Search+bind**: the user's entry is found by searching under
+//!   `ldap_search_base_dn` with `ldap_search_filter`, optionally as the
+//!   `ldap_search_bind_dn` service account, and then bound to.
+//!
+//! The groups listed in the user's `ldap_group_attribute` are handed to the
+//! same group-to-role sync that OIDC and Frontegg logins use. Like password
+//! and certificate authentication, LDAP authentication does not auto-provision
+//! roles: the user's role must already exist.
+
+use std::time::Duration;
+
+use ldap3::{Ldap, LdapConnAsync, LdapConnSettings, Scope, SearchEntry, dn_escape, ldap_escape};
+use mz_adapter::{AdapterError, AuthenticationError, Client as AdapterClient};
+use mz_adapter_types::dyncfgs::{
+    LDAP_BIND_DN_TEMPLATE, LDAP_GROUP_ATTRIBUTE, LDAP_SEARCH_BASE_DN, LDAP_SEARCH_BIND_DN,
+    LDAP_SEARCH_FILTER, LDAP_START_TLS, LDAP_TIMEOUT, LDAP_URL,
+};
+use mz_auth::Authenticated;
+use mz_auth::group_claims::normalize_groups;
+use mz_auth::password::Password;
+use mz_ore::task;
+use mz_pgwire_common::{ErrorResponse, Severity};
+use tokio_postgres::error::SqlState;
+use tracing::{debug, warn};
+
+/// The placeholder for the user name in `ldap_bind_dn_template` and
+/// `ldap_search_filter`.
+const USERNAME_PLACEHOLDER: &str = "{username}";
+
+/// The LDAP result code for a bind with a wrong password or unknown DN.
+const LDAP_INVALID_CREDENTIALS: u32 = 49;
+
+/// Errors that can occur during LDAP authentication.
+#[derive(Debug)]
+pub enum LdapError {
+    /// The `ldap_url` system variable is not set.
+    NotConfigured,
+    /// The `ldap_*` system variables are inconsistent.
+    InvalidConfig(String),
+    /// The user is not in the directory, or the password is wrong.
+    InvalidCredentials,
+    /// The LDAP server could not be reached or returned an unexpected error.
+    Server(String),
+    /// The LDAP server did not respond within `ldap_timeout`.
+    Timeout,
+    /// The user's role does not exist.
+    RoleNotFound,
+    /// The role exists but does not have the LOGIN attribute.
+    NonLogin,
+    LoginCheckError,
+}
+
+impl std::fmt::Display for LdapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LdapError::NotConfigured => write!(f, "LDAP authentication is not configured"),
+            LdapError::InvalidConfig(_) => write!(f, "invalid LDAP authentication configuration"),
+            LdapError::InvalidCredentials => write!(f, "invalid password"),
+            LdapError::Server(_) => write!(f, "LDAP authentication failed"),
+            LdapError::Timeout => write!(f, "LDAP authentication timed out"),
+            LdapError::RoleNotFound => write!(f, "role does not exist"),
+            LdapError::NonLogin => write!(f, "role is not allowed to login"),
+            LdapError::LoginCheckError => {
+                write!(f, "unexpected error checking if role can login")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LdapError {}
+
+impl LdapError {
+    pub fn code(&self) -> SqlState {
+        match self {
+            LdapError::InvalidCredentials => SqlState::INVALID_PASSWORD,
+            _ => SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+        }
+    }
+
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            LdapError::InvalidConfig(detail) => Some(detail.clone()),
+            LdapError::NonLogin => Some("The role does not have the LOGIN attribute.".into()),
+            // Server errors are logged rather than reported, as they may
+            // describe the directory to an unauthenticated client.
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            LdapError::NotConfigured => Some("Set the ldap_url system variable.".into()),
+            _ => None,
+        }
+    }
+
+    pub fn into_response(self) -> ErrorResponse {
+        ErrorResponse {
+            severity: Severity::Fatal,
+            code: self.code(),
+            message: self.to_string(),
+            detail: self.detail(),
+            hint: self.hint(),
+            position: None,
+        }
+    }
+}
+
+impl From<ldap3::LdapError> for LdapError {
+    fn from(err: ldap3::LdapError) -> Self {
+        LdapError::Server(err.to_string())
+    }
+}
+
+/// How the user's DN is determined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BindMode {
+    /// Bind directly as the DN produced by the template.
+    Simple { dn_template: String },
+    /// Search for the user's entry, then bind as it.
+    Search {
+        base_dn: String,
+        filter: String,
+        bind_dn: Option<String>,
+    },
+}
+
+impl BindMode {
+    fn resolve(
+        dn_template: Option<String>,
+        search_base_dn: Option<String>,
+        search_filter: String,
+        search_bind_dn: Option<String>,
+    ) -> Result<BindMode, LdapError> {
+        match (dn_template, search_base_dn) {
+            (Some(_), Some(_)) => Err(LdapError::InvalidConfig(
+                "ldap_bind_dn_template and ldap_search_base_dn are mutually exclusive.".into(),
+            )),
+            (None, None) => Err(LdapError::InvalidConfig(
+                "One of ldap_bind_dn_template or ldap_search_base_dn must be set.".into(),
+            )),
+            (Some(dn_template), None) => {
+                if !dn_template.contains(USERNAME_PLACEHOLDER) {
+                    return Err(LdapError::InvalidConfig(format!(
+                        "ldap_bind_dn_template must contain {USERNAME_PLACEHOLDER}."
+                    )));
+                }
+                Ok(BindMode::Simple { dn_template })
+            }
+            (None, Some(base_dn)) => {
+                if !search_filter.contains(USERNAME_PLACEHOLDER) {
+                    return Err(LdapError::InvalidConfig(format!(
+                        "ldap_search_filter must contain {USERNAME_PLACEHOLDER}."
+                    )));
+                }
+                Ok(BindMode::Search {
+                    base_dn,
+                    filter: search_filter,
+                    bind_dn: search_bind_dn,
+                })
+            }
+        }
+    }
+}
+
+/// The LDAP configuration in effect for a single login.
+#[derive(Debug, Clone)]
+struct LdapConfig {
+    url: String,
+    start_tls: bool,
+    mode: BindMode,
+    group_attribute: Option<String>,
+    timeout: Duration,
+}
+
+impl LdapConfig {
+    fn from_dyncfgs(dyncfgs: &mz_dyncfg::ConfigSet) -> Result<LdapConfig, LdapError> {
+        let url = LDAP_URL.get(dyncfgs).ok_or(LdapError::NotConfigured)?;
+        let mode = BindMode::resolve(
+            LDAP_BIND_DN_TEMPLATE.get(dyncfgs),
+            LDAP_SEARCH_BASE_DN.get(dyncfgs),
+            LDAP_SEARCH_FILTER.get(dyncfgs),
+            LDAP_SEARCH_BIND_DN.get(dyncfgs),
+        )?;
+        let group_attribute = LDAP_GROUP_ATTRIBUTE.get(dyncfgs);
+        Ok(LdapConfig {
+            url,
+            start_tls: LDAP_START_TLS.get(dyncfgs),
+            mode,
+            group_attribute: (!group_attribute.is_empty()).then_some(group_attribute),
+            timeout: LDAP_TIMEOUT.get(dyncfgs),
+        })
+    }
+}
+
+/// Authenticates users by binding to an LDAP directory.
+#[derive(Debug, Clone)]
+pub struct LdapAuthenticator {
+    adapter_client: AdapterClient,
+    /// The password of the `ldap_search_bind_dn` service account.
+    search_bind_password: Option<Password>,
+}
+
+impl LdapAuthenticator {
+    pub fn new(adapter_client: AdapterClient, search_bind_password: Option<Password>) -> Self {
+        Self {
+            adapter_client,
+            search_bind_password,
+        }
+    }
+
+    /// Authenticates `user` with `password` against the directory.
+    ///
+    /// On success, returns the user's groups for group-to-role sync, or `None`
+    /// if group sync is disabled for LDAP logins. A user with no groups yields
+    /// `Some(vec![])`, which revokes any previously synced memberships.
+    pub async fn authenticate(
+        &self,
+        user: &str,
+        password: &str,
+    ) -> Result<(Option<Vec<String>>, Authenticated), LdapError> {
+        // An empty password makes a simple bind "unauthenticated", which many
+        // servers accept without checking anything.
+        if password.is_empty() {
+            return Err(LdapError::InvalidCredentials);
+        }
+
+        let system_vars = self.adapter_client.get_system_vars().await;
+        let config = LdapConfig::from_dyncfgs(system_vars.dyncfgs())?;
+        let groups =
+            match tokio::time::timeout(config.timeout, self.bind(&config, user, password)).await {
+                Ok(result) => result?,
+                Err(_) => {
+                    warn!(url = config.url, "LDAP authentication timed out");
+                    return Err(LdapError::Timeout);
+                }
+            };
+
+        self.check_role_login(user).await?;
+        Ok((groups, Authenticated))
+    }
+
+    /// Binds to the directory as `user`, returning the user's groups.
+    async fn bind(
+        &self,
+        config: &LdapConfig,
+        user: &str,
+        password: &str,
+    ) -> Result<Option<Vec<String>>, LdapError> {
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(config.timeout)
+            .set_starttls(config.start_tls);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &config.url)
+            .await
+            .map_err(|e| {
+                warn!(url = config.url, "failed to connect to LDAP server: {e}");
+                LdapError::Server(e.to_string())
+            })?;
+        task::spawn(|| "ldap_conn", async move {
+            if let Err(e) = conn.drive().await {
+                debug!("LDAP connection closed with error: {e}");
+            }
+        });
+
+        let result = self.bind_user(&mut ldap, config, user, password).await;
+        // The connection is single use; failing to close it cleanly does not
+        // affect the outcome of the login.
+        let _ = ldap.unbind().await;
+        result
+    }
+
+    async fn bind_user(
+        &self,
+        ldap: &mut Ldap,
+        config: &LdapConfig,
+        user: &str,
+        password: &str,
+    ) -> Result<Option<Vec<String>>, LdapError> {
+        let attrs = match &config.group_attribute {
+            Some(attr) => vec![attr.as_str()],
+            // "1.1" requests no attributes (RFC 4511, section 4.5.1.8).
+            None => vec!["1.1"],
+        };
+
+        let entry = match &config.mode {
+            BindMode::Simple { dn_template } => {
+                let dn = dn_template.replace(USERNAME_PLACEHOLDER, &dn_escape(user));
+                bind_as(ldap, &dn, password).await?;
+                if config.group_attribute.is_none() {
+                    return Ok(None);
+                }
+                // Read the user's own entry, as the user.
+                let (mut entries, _) = ldap
+                    .search(&dn, Scope::Base, "(objectClass=*)", attrs)
+                    .await?
+                    .success()?;
+                match entries.pop() {
+                    Some(entry) => SearchEntry::construct(entry),
+                    None => return Ok(Some(vec![])),
+                }
+            }
+            BindMode::Search {
+                base_dn,
+                filter,
+                bind_dn,
+            } => {
+                if let Some(bind_dn) = bind_dn {
+                    let Some(bind_password) = &self.search_bind_password else {
+                        return Err(LdapError::InvalidConfig(
+                            "ldap_search_bind_dn is set, but environmentd was started without \
+                             --ldap-search-bind-password."
+                                .into(),
+                        ));
+                    };
+                    let result = ldap.simple_bind(bind_dn, bind_password.as_str()).await?;
+                    if let Err(e) = result.success() {
+                        warn!(bind_dn, "LDAP search bind failed: {e}");
+                        return Err(LdapError::Server(e.to_string()));
+                    }
+                }
+                let filter = filter.replace(USERNAME_PLACEHOLDER, &ldap_escape(user));
+                let (mut entries, _) = ldap
+                    .search(base_dn, Scope::Subtree, &filter, attrs)
+                    .await?
+                    .success()?;
+                let entry = match entries.len() {
+                    0 => {
+                        debug!(filter, "LDAP search found no user");
+                        return Err(LdapError::InvalidCredentials);
+                    }
+                    1 => SearchEntry::construct(entries.remove(0)),
+                    n => {
+                        warn!(filter, "LDAP search found {n} users; refusing to guess");
+                        return Err(LdapError::InvalidCredentials);
+                    }
+                };
+                bind_as(ldap, &entry.dn, password).await?;
+                entry
+            }
+        };
+
+        let Some(group_attribute) = &config.group_attribute else {
+            return Ok(None);
+        };
+        // Attribute names are case insensitive, and servers do not always
+        // echo the requested spelling.
+        let values = entry
+            .attrs
+            .into_iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(group_attribute))
+            .map(|(_, values)| values)
+            .unwrap_or_default();
+        Ok(Some(normalize_groups(
+            values.iter().map(|value| group_name(value)),
+        )))
+    }
+
+    /// Checks that the role exists and has the LOGIN attribute. Unlike OIDC,
+    /// LDAP authentication does not auto-provision roles.
+    async fn check_role_login(&self, role_name: &str) -> Result<(), LdapError> {
+        match self.adapter_client.role_can_login(role_name).await {
+            Ok(()) => Ok(()),
+            Err(AdapterError::AuthenticationError(AuthenticationError::RoleNotFound)) => {
+                Err(LdapError::RoleNotFound)
+            }
+            Err(AdapterError::AuthenticationError(AuthenticationError::NonLogin)) => {
+                Err(LdapError::NonLogin)
+            }
+            Err(e) => {
+                warn!(?e, "unexpected error checking LDAP role login");
+                Err(LdapError::LoginCheckError)
+            }
+        }
+    }
+}
+
+/// Binds as `dn`, distinguishing wrong credentials from other failures.
+async fn bind_as(ldap: &mut Ldap, dn: &str, password: &str) -> Result<(), LdapError> {
+    match ldap.simple_bind(dn, password).await?.success() {
+        Ok(_) => Ok(()),
+        Err(ldap3::LdapError::LdapResult { result }) if result.rc == LDAP_INVALID_CREDENTIALS => {
+            Err(LdapError::InvalidCredentials)
+        }
+        Err(e) => {
+            warn!(dn, "LDAP bind failed: {e}");
+            Err(LdapError::Server(e.to_string()))
+        }
+    }
+}
+
+/// Extracts a group name from a value of the group attribute.
+///
+/// Attributes like `memberOf` list the DNs of groups, e.g.
+/// `cn=analytics,ou=groups,dc=example,dc=com`, and the group name is the
+/// value of the first RDN (`analytics`). Values that are not DNs are used as
+/// is.
+fn group_name(value: &str) -> String {
+    // Split off the first RDN at the first unescaped comma.
+    let mut rdn = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                rdn.push(c);
+                if let Some(escaped) = chars.next() {
+                    rdn.push(escaped);
+                }
+            }
+            ',' => break,
+            c => rdn.push(c),
+        }
+    }
+    let Some((_, rdn_value)) = rdn.split_once('=') else {
+        return value.trim().to_string();
+    };
+    // Undo the escaping of special characters (RFC 4514, section 2.4).
+    let mut name = String::new();
+    let mut chars = rdn_value.trim().chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => name.extend(chars.next()),
+            c => name.push(c),
+        }
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_group_name() {
+        assert_eq!(
+            group_name("cn=analytics,ou=groups,dc=example,dc=com"),
+            "analytics"
+        );
+        assert_eq!(
+            group_name("CN=Platform Eng,OU=Groups,DC=corp,DC=example,DC=com"),
+            "Platform Eng"
+        );
+        // Escaped commas are part of the name.
+        assert_eq!(group_name("cn=eng\\, data,ou=groups"), "eng, data");
+        // Values that are not DNs are used as is.
+        assert_eq!(group_name("analytics"), "analytics");
+    }
+
+    #[mz_ore::test]
+    fn test_group_names_normalized() {
+        let values = [
+            "cn=b,ou=groups,dc=example,dc=com",
+            "cn=a,ou=groups,dc=example,dc=com",
+            "cn=a,ou=other,dc=example,dc=com",
+        ];
+        assert_eq!(
+            normalize_groups(values.iter().map(|value| group_name(value))),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_bind_mode_resolve() {
+        let filter = || "(uid={username})".to_string();
+        assert_eq!(
+            BindMode::resolve(
+                Some("uid={username},ou=people,dc=example,dc=com".into()),
+                None,
+                filter(),
+                None,
+            )
+            .unwrap(),
+            BindMode::Simple {
+                dn_template: "uid={username},ou=people,dc=example,dc=com".into()
+            }
+        );
+        assert_eq!(
+            BindMode::resolve(
+                None,
+                Some("dc=example,dc=com".into()),
+                filter(),
+                Some("cn=search,dc=example,dc=com".into()),
+            )
+            .unwrap(),
+            BindMode::Search {
+                base_dn: "dc=example,dc=com".into(),
+                filter: filter(),
+                bind_dn: Some("cn=search,dc=example,dc=com".into()),
+            }
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_bind_mode_resolve_invalid() {
+        let filter = || "(uid={username})".to_string();
+        // Exactly one mode must be configured.
+        assert!(matches!(
+            BindMode::resolve(None, None, filter(), None),
+            Err(LdapError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            BindMode::resolve(
+                Some("uid={username}".into()),
+                Some("dc=example,dc=com".into()),
+                filter(),
+                None
+            ),
+            Err(LdapError::InvalidConfig(_))
+        ));
+        // The user name must appear in the DN or filter.
+        assert!(matches!(
+            BindMode::resolve(Some("uid=alice".into()), None, filter(), None),
+            Err(LdapError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            BindMode::resolve(
+                None,
+                Some("dc=example,dc=com".into()),
+                "(uid=alice)".into(),
+                None
+            ),
+            Err(LdapError::InvalidConfig(_))
+        ));
+    }
+
+    #[mz_ore::test]
+    fn test_username_escaping() {
+        // User names cannot inject DN components or filter clauses.
+        let dn =
+            "uid={username},dc=example".replace(USERNAME_PLACEHOLDER, &dn_escape("a,cn=admin"));
+        assert!(!dn.contains(",cn=admin"), "{dn}");
+        let filter = "(uid={username})".replace(USERNAME_PLACEHOLDER, &ldap_escape("*)(uid=*"));
+        assert!(!filter.contains('*') && !filter.contains(")("), "{filter}");
+    }
+}
@@ -166,6 +166,7 @@ async fn test_balancer() {
     let cert_config = Some(TlsCertConfig {
         cert: server_cert.clone(),
         key: server_key.clone(),
+        client_ca: None,
     });
 
     let body = r#"{"query": "select 12234"}"#;
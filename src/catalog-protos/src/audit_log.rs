@@ -16,7 +16,7 @@
 use mz_audit_log::{
     AlterAddColumnV1, AlterApplyReplacementV1, AlterClusterReconfigurationV1,
     AlterDefaultPrivilegeV1, AlterRetainHistoryV1, AlterSetClusterV1, AlterSourceSinkV1,
-    AlterSourceTimestampIntervalV1, BurstFinishCauseV1, ClusterHydrationBurstV1,
+    AlterSourceTimestampIntervalV1, AuthFailureV1, BurstFinishCauseV1, ClusterHydrationBurstV1,
     ClusterReplicaLoggingV1, CreateClusterReplicaV1, CreateClusterReplicaV2,
     CreateClusterReplicaV3, CreateClusterReplicaV4, CreateIndexV1, CreateMaterializedViewV1,
     CreateOrDropClusterReplicaReasonV1, CreateRoleV1, CreateSourceSinkV1, CreateSourceSinkV2,
@@ -26,8 +26,8 @@ use mz_audit_log::{
     ReconfigurationLifecycleV1, RefreshDecisionWithReasonV1, RefreshDecisionWithReasonV2,
     RenameClusterReplicaV1, RenameClusterV1, RenameItemV1, RenameSchemaV1, RevokeRoleV1,
     RevokeRoleV2, RotateKeysV1, SchedulingDecisionV1, SchedulingDecisionsWithReasonsV1,
-    SchedulingDecisionsWithReasonsV2, SchemaV1, SchemaV2, SetV1, ToNewIdV1, UpdateItemV1,
-    UpdateOwnerV1, UpdatePrivilegeV1, VersionedEvent,
+    SchedulingDecisionsWithReasonsV2, SchemaV1, SchemaV2, SessionV1, SetV1, ToNewIdV1,
+    UpdateItemV1, UpdateOwnerV1, UpdatePrivilegeV1, VersionedEvent,
 };
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
 
@@ -56,6 +56,9 @@ impl RustType<crate::objects::audit_log_event_v1::EventType> for EventType {
             EventType::Grant => crate::objects::audit_log_event_v1::EventType::Grant,
             EventType::Revoke => crate::objects::audit_log_event_v1::EventType::Revoke,
             EventType::Comment => crate::objects::audit_log_event_v1::EventType::Comment,
+            EventType::Login => crate::objects::audit_log_event_v1::EventType::Login,
+            EventType::Logout => crate::objects::audit_log_event_v1::EventType::Logout,
+            EventType::AuthFailure => crate::objects::audit_log_event_v1::EventType::AuthFailure,
         }
     }
 
@@ -69,6 +72,11 @@ impl RustType<crate::objects::audit_log_event_v1::EventType> for EventType {
             crate::objects::audit_log_event_v1::EventType::Grant => Ok(EventType::Grant),
             crate::objects::audit_log_event_v1::EventType::Revoke => Ok(EventType::Revoke),
             crate::objects::audit_log_event_v1::EventType::Comment => Ok(EventType::Comment),
+            crate::objects::audit_log_event_v1::EventType::Login => Ok(EventType::Login),
+            crate::objects::audit_log_event_v1::EventType::Logout => Ok(EventType::Logout),
+            crate::objects::audit_log_event_v1::EventType::AuthFailure => {
+                Ok(EventType::AuthFailure)
+            }
             crate::objects::audit_log_event_v1::EventType::Unknown => Err(
                 TryFromProtoError::unknown_enum_variant("EventType::Unknown"),
             ),
@@ -1511,6 +1519,54 @@ impl RustType<crate::objects::audit_log_event_v1::CreateRoleV1> for CreateRoleV1
     }
 }
 
+impl RustType<crate::objects::audit_log_event_v1::SessionV1> for SessionV1 {
+    fn into_proto(&self) -> crate::objects::audit_log_event_v1::SessionV1 {
+        crate::objects::audit_log_event_v1::SessionV1 {
+            session_id: self.session_id.clone(),
+            authenticator_kind: self.authenticator_kind.clone(),
+            client_ip: self.client_ip.clone(),
+            application_name: self.application_name.clone(),
+        }
+    }
+
+    fn from_proto(
+        proto: crate::objects::audit_log_event_v1::SessionV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(SessionV1 {
+            session_id: proto.session_id,
+            authenticator_kind: proto.authenticator_kind,
+            client_ip: proto.client_ip,
+            application_name: proto.application_name,
+        })
+    }
+}
+
+impl RustType<crate::objects::audit_log_event_v1::AuthFailureV1> for AuthFailureV1 {
+    fn into_proto(&self) -> crate::objects::audit_log_event_v1::AuthFailureV1 {
+        crate::objects::audit_log_event_v1::AuthFailureV1 {
+            role_name: self.role_name.clone(),
+            authenticator_kind: self.authenticator_kind.clone(),
+            client_ip: self.client_ip.clone(),
+            application_name: self.application_name.clone(),
+            reason: self.reason.clone(),
+            suppressed_failures: self.suppressed_failures,
+        }
+    }
+
+    fn from_proto(
+        proto: crate::objects::audit_log_event_v1::AuthFailureV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(AuthFailureV1 {
+            role_name: proto.role_name,
+            authenticator_kind: proto.authenticator_kind,
+            client_ip: proto.client_ip,
+            application_name: proto.application_name,
+            reason: proto.reason,
+            suppressed_failures: proto.suppressed_failures,
+        })
+    }
+}
+
 impl RustType<crate::objects::audit_log_event_v1::Details> for EventDetails {
     fn into_proto(&self) -> crate::objects::audit_log_event_v1::Details {
         use crate::objects::audit_log_event_v1::Details::*;
@@ -1589,6 +1645,8 @@ impl RustType<crate::objects::audit_log_event_v1::Details> for EventDetails {
             EventDetails::ResetAllV1 => ResetAllV1(Empty {}),
             EventDetails::RotateKeysV1(details) => RotateKeysV1(details.into_proto()),
             EventDetails::CreateRoleV1(details) => CreateRoleV1(details.into_proto()),
+            EventDetails::SessionV1(details) => SessionV1(details.into_proto()),
+            EventDetails::AuthFailureV1(details) => AuthFailureV1(details.into_proto()),
         }
     }
 
@@ -1679,6 +1737,8 @@ impl RustType<crate::objects::audit_log_event_v1::Details> for EventDetails {
             ClusterHydrationBurstV1(details) => {
                 Ok(EventDetails::ClusterHydrationBurstV1(details.into_rust()?))
             }
+            SessionV1(details) => Ok(EventDetails::SessionV1(details.into_rust()?)),
+            AuthFailureV1(details) => Ok(EventDetails::AuthFailureV1(details.into_rust()?)),
         }
     }
 }
@@ -30,6 +30,7 @@ pub mod objects_v89;
 pub mod objects_v90;
 pub mod objects_v91;
 pub mod objects_v92;
+pub mod objects_v93;
 pub mod serialization;
 
 /// The current version of the `Catalog`.
@@ -37,7 +38,7 @@ pub mod serialization;
 /// We will initialize new `Catalog`s with this version, and migrate existing `Catalog`s to this
 /// version. Whenever the `Catalog` changes, e.g. the types we serialize in the `Catalog`
 /// change, we need to bump this version.
-pub const CATALOG_VERSION: u64 = 92;
+pub const CATALOG_VERSION: u64 = 93;
 
 /// The minimum `Catalog` version number that we support migrating from.
 ///
@@ -1879,6 +1879,26 @@ pub mod audit_log_event_v1 {
         pub auto_provision_source: Option<String>,
     }
 
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "proptest"), derive(Arbitrary))]
+    pub struct SessionV1 {
+        pub session_id: String,
+        pub authenticator_kind: String,
+        pub client_ip: Option<String>,
+        pub application_name: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    #[cfg_attr(any(test, feature = "proptest"), derive(Arbitrary))]
+    pub struct AuthFailureV1 {
+        pub role_name: String,
+        pub authenticator_kind: String,
+        pub client_ip: Option<String>,
+        pub application_name: Option<String>,
+        pub reason: String,
+        pub suppressed_failures: u64,
+    }
+
     #[derive(
         Clone,
         Copy,
@@ -1901,6 +1921,9 @@ pub mod audit_log_event_v1 {
         Grant = 4,
         Revoke = 5,
         Comment = 6,
+        Login = 7,
+        Logout = 8,
+        AuthFailure = 9,
     }
 
     #[derive(
@@ -1986,6 +2009,8 @@ pub mod audit_log_event_v1 {
         AlterSourceTimestampIntervalV1(AlterSourceTimestampIntervalV1),
         AlterClusterReconfigurationV1(AlterClusterReconfigurationV1),
         ClusterHydrationBurstV1(ClusterHydrationBurstV1),
+        SessionV1(SessionV1),
+        AuthFailureV1(AuthFailureV1),
     }
 }
 
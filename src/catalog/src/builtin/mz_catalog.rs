@@ -2865,7 +2865,7 @@ pub static MZ_AUDIT_EVENTS: LazyLock<BuiltinMaterializedView> = LazyLock::new(||
             ),
             (
                 "event_type",
-                "The type of the event: `create`, `drop`, `alter`, `grant`, `revoke`, or `comment`.",
+                "The type of the event: `create`, `drop`, `alter`, `grant`, `revoke`, `comment`, `login`, `logout`, or `auth-failure`.",
             ),
             (
                 "object_type",
@@ -2928,6 +2928,9 @@ SELECT
         WHEN '4' THEN 'grant'
         WHEN '5' THEN 'revoke'
         WHEN '6' THEN 'comment'
+        WHEN '7' THEN 'login'
+        WHEN '8' THEN 'logout'
+        WHEN '9' THEN 'auth-failure'
     END                                                                     AS event_type,
     CASE (e->>'object_type')
         WHEN '1'  THEN 'cluster'
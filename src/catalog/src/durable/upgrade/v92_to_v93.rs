@@ -0,0 +1,22 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::MigrationAction;
+use crate::durable::upgrade::objects_v92 as v92;
+use crate::durable::upgrade::objects_v93 as v93;
+
+/// No-op migration. Every v92->v93 change is additive and confined to the
+/// append-only audit log: new `Login`, `Logout`, and `AuthFailure` event
+/// types and the `SessionV1` / `AuthFailureV1` event details. No existing
+/// record changes shape, so nothing is rewritten.
+pub fn upgrade(
+    _snapshot: Vec<v92::StateUpdateKind>,
+) -> Vec<MigrationAction<v92::StateUpdateKind, v93::StateUpdateKind>> {
+    Vec::new()
+}
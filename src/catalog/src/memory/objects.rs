@@ -55,7 +55,7 @@ use mz_sql::plan::{
     AutoScalingStrategy, ClusterSchedule, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig,
     ConnectionDetails, CreateClusterManagedPlan, CreateClusterPlan, CreateClusterVariant,
     CreateSourcePlan, HirRelationExpr, NetworkPolicyRule, OnTimeoutAction, PlanError,
-    WebhookBodyFormat, WebhookHeaders, WebhookValidation,
+    WebhookBodyDecoding, WebhookBodyFormat, WebhookHeaders, WebhookValidation,
 };
 use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
@@ -997,6 +997,8 @@ pub enum DataSourceDesc {
         validate_using: Option<WebhookValidation>,
         /// Describes how we deserialize the body of a webhook request.
         body_format: WebhookBodyFormat,
+        /// Describes how we decode the body of a webhook request into typed columns, if at all.
+        body_decoding: Option<WebhookBodyDecoding>,
         /// Describes whether or not to include headers and how to map them.
         headers: WebhookHeaders,
         /// The cluster which this source is associated with.
@@ -1177,6 +1179,7 @@ impl Source {
                 mz_sql::plan::DataSourceDesc::Webhook {
                     validate_using,
                     body_format,
+                    body_decoding,
                     headers,
                     cluster_id,
                 } => {
@@ -1187,6 +1190,7 @@ impl Source {
                     DataSourceDesc::Webhook {
                         validate_using,
                         body_format,
+                        body_decoding,
                         headers,
                         cluster_id: plan
                             .in_cluster
@@ -1264,7 +1268,7 @@ impl Source {
                         | LoadGenerator::Marketing
                         | LoadGenerator::Tpch { .. } => 0,
                     },
-                    GenericSourceConnection::Kafka(_) => 1,
+                    GenericSourceConnection::Kafka(_) | GenericSourceConnection::Iceberg(_) => 1,
                 }
             }
             //  DataSourceDesc::IngestionExport represents a subsource, which
@@ -1349,7 +1353,9 @@ impl Sink {
     pub fn combined_format(&self) -> Option<Cow<'_, str>> {
         match &self.connection {
             StorageSinkConnection::Kafka(connection) => Some(connection.format.get_format_name()),
-            StorageSinkConnection::Iceberg(_) => None,
+            StorageSinkConnection::Iceberg(_)
+            | StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::Http(_) => None,
         }
     }
 
@@ -1369,7 +1375,9 @@ impl Sink {
                 let value_format = connection.format.value_format.get_format_name();
                 Some((key_format, value_format))
             }
-            StorageSinkConnection::Iceberg(_) => None,
+            StorageSinkConnection::Iceberg(_)
+            | StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::Http(_) => None,
         }
     }
 
@@ -375,6 +375,7 @@ impl LogVariant {
                 .with_column("lir_id", SqlScalarType::UInt64.nullable(false))
                 .with_column("worker_id", SqlScalarType::UInt64.nullable(false))
                 .with_column("hydrated", SqlScalarType::Bool.nullable(false))
+                .with_column("time_ns", SqlScalarType::UInt64.nullable(true))
                 .with_key(vec![0, 1, 2])
                 .finish(),
 
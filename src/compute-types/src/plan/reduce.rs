@@ -750,12 +750,22 @@ pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::ArrayConcat { .. }
         | AggregateFunc::ListConcat { .. }
         | AggregateFunc::StringAgg { .. }
+        | AggregateFunc::PercentileCont { .. }
+        | AggregateFunc::PercentileDisc { .. }
+        | AggregateFunc::Mode { .. }
+        | AggregateFunc::ApproxCountDistinct
+        | AggregateFunc::HllSketch
+        | AggregateFunc::HllMerge
         | AggregateFunc::RowNumber { .. }
         | AggregateFunc::Rank { .. }
         | AggregateFunc::DenseRank { .. }
+        | AggregateFunc::PercentRank { .. }
+        | AggregateFunc::CumeDist { .. }
         | AggregateFunc::LagLead { .. }
+        | AggregateFunc::Ntile { .. }
         | AggregateFunc::FirstValue { .. }
         | AggregateFunc::LastValue { .. }
+        | AggregateFunc::NthValue { .. }
         | AggregateFunc::WindowAggregate { .. }
         | AggregateFunc::FusedValueWindowFunc { .. }
         | AggregateFunc::FusedWindowAggregate { .. } => ReductionType::Basic,
@@ -701,18 +701,19 @@ impl DemuxState {
     }
 
     /// Pack an operator hydration status update key-value for the given export ID, LIR ID, and
-    /// hydration status.
+    /// hydration time, which is `None` if the operator is not hydrated.
     fn pack_operator_hydration_status_update(
         &mut self,
         export_id: GlobalId,
         lir_id: LirId,
-        hydrated: bool,
+        time_ns: Option<u64>,
     ) -> (&RowRef, &RowRef) {
         self.operator_hydration_status_packer.pack_slice(&[
             make_string_datum(export_id, &mut self.scratch_string_a),
             Datum::UInt64(lir_id.into()),
             Datum::UInt64(u64::cast_from(self.worker_id)),
-            Datum::from(hydrated),
+            Datum::from(time_ns.is_some()),
+            Datum::from(time_ns),
         ])
     }
 
@@ -797,8 +798,10 @@ struct ExportState {
     /// duration at nanosecond precision. These instants exist to identify and bound hydration
     /// episodes, which a duration cannot do.
     hydration_timestamps: HydrationTimestamps,
-    /// Hydration status of operators feeding this export.
-    operator_hydration: BTreeMap<LirId, bool>,
+    /// Hydration time of operators feeding this export, measured from `created_at`.
+    ///
+    /// `None` for operators that are not hydrated.
+    operator_hydration: BTreeMap<LirId, Option<u64>>,
 }
 
 impl ExportState {
@@ -965,10 +968,10 @@ impl DemuxHandler<'_, '_, '_> {
             .give((datum, ts, Diff::MINUS_ONE));
 
         // Remove operator hydration logging for this export.
-        for (lir_id, hydrated) in export.operator_hydration {
+        for (lir_id, time_ns) in export.operator_hydration {
             let datum = self
                 .state
-                .pack_operator_hydration_status_update(export_id, lir_id, hydrated);
+                .pack_operator_hydration_status_update(export_id, lir_id, time_ns);
             self.output
                 .operator_hydration_status
                 .give((datum, ts, Diff::MINUS_ONE));
@@ -1148,12 +1151,21 @@ impl DemuxHandler<'_, '_, '_> {
         };
 
         let old_status = export.operator_hydration.get(&lir_id).copied();
-        export.operator_hydration.insert(lir_id, hydrated);
+        let time_ns = match (hydrated, old_status) {
+            (false, _) => None,
+            // Keep the time at which the operator first reported hydration.
+            (true, Some(Some(time_ns))) => Some(time_ns),
+            (true, _) => {
+                let duration = export.created_at.elapsed();
+                Some(u64::try_from(duration.as_nanos()).expect("must fit"))
+            }
+        };
+        export.operator_hydration.insert(lir_id, time_ns);
 
-        if let Some(hydrated) = old_status {
-            let retraction = self
-                .state
-                .pack_operator_hydration_status_update(export_id, lir_id, hydrated);
+        if let Some(old_time_ns) = old_status {
+            let retraction =
+                self.state
+                    .pack_operator_hydration_status_update(export_id, lir_id, old_time_ns);
             self.output
                 .operator_hydration_status
                 .give((retraction, ts, Diff::MINUS_ONE));
@@ -1161,7 +1173,7 @@ impl DemuxHandler<'_, '_, '_> {
 
         let insertion = self
             .state
-            .pack_operator_hydration_status_update(export_id, lir_id, hydrated);
+            .pack_operator_hydration_status_update(export_id, lir_id, time_ns);
         self.output
             .operator_hydration_status
             .give((insertion, ts, Diff::ONE));
@@ -34,7 +34,7 @@ use mz_compute_types::plan::reduce::{
 };
 use mz_compute_types::plan::scalar::LirScalarExpr;
 use mz_expr::{AggregateFunc, EvalError, SafeMfpPlan};
-use mz_ore::cast::CastLossy;
+use mz_ore::cast::{CastFrom, CastLossy};
 use mz_repr::adt::numeric::{self, Numeric, NumericAgg};
 use mz_repr::fixed_length::ExtendDatums;
 use mz_repr::{Datum, DatumVec, Diff, Row, RowArena, SharedRow};
@@ -65,6 +65,17 @@ use mz_row_spine::{
 /// Key container of trace `Tr`'s batch cursor.
 type BatchKeyContainer<Tr> = <BatchCursor<Tr> as Cursor>::KeyContainer;
 
+/// The number of bits of the keys of values (see [`ordered_set_key`]) that each level of the tree
+/// of an ordered-set aggregate distinguishes (see `build_ordered_set_aggregate`).
+const ORDERED_SET_DIGIT_BITS: u32 = 8;
+
+/// The number of levels of inner nodes of the tree of an ordered-set aggregate.
+const ORDERED_SET_LEVELS: u32 = u64::BITS / ORDERED_SET_DIGIT_BITS;
+
+/// The number of registers of a HyperLogLog sketch whose ranks are maintained together, in one
+/// row (see `build_hll_aggregate`).
+const HLL_CHUNK_REGISTERS: u16 = 64;
+
 impl<'scope, T: RenderTimestamp> Context<'scope, T> {
     /// Renders a `MirRelationExpr::Reduce` using various non-obvious techniques to
     /// minimize worst-case incremental update times and memory footprint.
@@ -499,6 +510,19 @@ impl<'scope, T: RenderTimestamp> Context<'scope, T> {
             distinct,
         } = aggr.clone();
 
+        // Ordered-set aggregates need their values in order, which the arrangement below doesn't
+        // provide. (The planner doesn't allow `distinct` or fusing with `unnest_list` for them.)
+        if func.is_ordered_set() {
+            return self
+                .build_ordered_set_aggregate(input, index, func, validating, key_arity, mfp_after);
+        }
+
+        // HyperLogLog aggregates maintain the ranks of their sketch's registers rather than their
+        // values, for which `distinct` makes no difference.
+        if func.is_hll() {
+            return self.build_hll_aggregate(input, index, func, validating, key_arity, mfp_after);
+        }
+
         // Extract the value we were asked to aggregate over.
         let mut partial = input.map(move |(key, row)| {
             let mut row_builder = SharedRow::get();
@@ -774,6 +798,650 @@ impl<'scope, T: RenderTimestamp> Context<'scope, T> {
         (oks, err_output)
     }
 
+    /// Build the dataflow to compute a single ordered-set aggregation, like `percentile_cont`.
+    ///
+    /// Ordered-set aggregates depend on the order of all values of a group, but arrangements
+    /// don't present values in their `Datum` order, so evaluating the aggregate on the arranged
+    /// group would sort all of its values on every change. Instead, we maintain a tree of counts
+    /// for each group, whose nodes are ranges of values. Each value has a 64-bit key that is
+    /// monotonic in its order (see [`ordered_set_key`]). A leaf holds the counts of the distinct
+    /// values with the same key, and each of the [`ORDERED_SET_LEVELS`] levels of inner nodes
+    /// above the leaves holds the counts of the nodes below it, of which there are up to
+    /// 2^[`ORDERED_SET_DIGIT_BITS`], as the multiplicities of an arrangement. A change to a value
+    /// thus changes one node of each level.
+    ///
+    /// `percentile_cont` and `percentile_disc` look up the values at the few positions that they
+    /// depend on (see [`AggregateFunc::ordered_set_positions`]) by descending the tree: a reduction
+    /// per level routes each position to the node below that contains it, using the prefix sums
+    /// of the counts of the nodes below, and the leaves route it to a value. `mode` instead
+    /// maintains the most frequent value of each node, from the most frequent values of the nodes
+    /// below, like the hierarchical reduction of `min` and `max`.
+    ///
+    /// Each change costs time proportional to the number of levels times the number of nodes
+    /// below a node, independent of the size of the group. The state is the count of each
+    /// distinct value and of each node with values below it, at most the number of distinct
+    /// values per level, but far fewer in the upper levels. Values whose keys are equal, like
+    /// strings with the same first eight bytes or values of types without a key, share a leaf,
+    /// so a group of many such values costs time linear in their number.
+    fn build_ordered_set_aggregate<'s>(
+        &self,
+        input: VecCollection<'s, T, (Row, Row), Diff>,
+        index: usize,
+        func: AggregateFunc,
+        validating: bool,
+        key_arity: usize,
+        mfp_after: Option<SafeMfpPlan<LirScalarExpr>>,
+    ) -> (
+        RowRowArrangement<'s, T>,
+        Option<VecCollection<'s, T, DataflowErrorSer, Diff>>,
+    ) {
+        // Extract the record we were asked to aggregate over, which holds the direct arguments
+        // followed by the value, along with the key of the value. Records without a value don't
+        // contribute to the aggregate.
+        let records = input.flat_map(move |(key, row)| {
+            let record = row.iter().nth(index).unwrap();
+            if record.is_null() {
+                return None;
+            }
+            let value = record.unwrap_list().iter().last().unwrap();
+            if value.is_null() {
+                return None;
+            }
+            let order_key = ordered_set_key(value);
+            Some((key, order_key, SharedRow::pack(std::iter::once(record))))
+        });
+
+        // The leaves, keyed by the key of their values prefixed to the group key.
+        let leaves = records.clone().map(|(key, order_key, record)| {
+            let value = record.unpack_first().unwrap_list().iter().last().unwrap();
+            let mut row_builder = SharedRow::get();
+            let node_key =
+                row_builder.pack_using(std::iter::once(Datum::from(order_key)).chain(&key));
+            let value = row_builder.pack_using([Datum::False, value]);
+            (node_key, value)
+        });
+
+        let (partial, mut err_output) = match func {
+            AggregateFunc::Mode { desc } => {
+                self.build_ordered_set_mode(leaves, desc, validating, key_arity)
+            }
+            _ => self.build_ordered_set_percentile(
+                records,
+                leaves,
+                func.clone(),
+                validating,
+                key_arity,
+            ),
+        };
+        let arranged_partial = partial
+            .mz_arrange::<
+                ColumnationChunker<_>,
+                RowRowBatcher<_, _>,
+                RowRowBuilder<_, _>,
+                RowRowSpine<_, _>,
+            >("Arranged ReduceOrderedSet");
+
+        // Allocations for the two closures.
+        let mut datums1 = DatumVec::new();
+        let mut datums2 = DatumVec::new();
+        let mfp_after1 = mfp_after.clone();
+        let mfp_after2 = mfp_after.filter(|mfp| mfp.could_error());
+        let func2 = func.clone();
+
+        let oks = arranged_partial
+            .clone()
+            .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                "ReduceOrderedSet",
+                move |key, source, target| {
+                    let temp_storage = RowArena::new();
+                    let vals = source.iter().map(|(val, _count)| *val);
+                    let Some(result) = eval_ordered_set_root(&func, vals, &temp_storage) else {
+                        return;
+                    };
+
+                    let mut datums_local = datums1.borrow();
+                    key.extend_datums(&temp_storage, &mut datums_local, None);
+                    let key_len = datums_local.len();
+                    datums_local.push(result);
+                    if let Some(row) =
+                        evaluate_mfp_after(&mfp_after1, &mut datums_local, &temp_storage, key_len)
+                    {
+                        target.push((row, Diff::ONE));
+                    }
+                },
+            );
+
+        if let Some(mfp) = mfp_after2 {
+            let mfp_errs = arranged_partial
+                .mz_reduce_abelian::<_, RowErrBuilder<_, _>, RowErrSpine<_, _>, _>(
+                    "ReduceOrderedSet Error Check",
+                    move |key, source, target| {
+                        let temp_storage = RowArena::new();
+                        let vals = source.iter().map(|(val, _count)| *val);
+                        let Some(result) = eval_ordered_set_root(&func2, vals, &temp_storage)
+                        else {
+                            return;
+                        };
+
+                        let mut datums_local = datums2.borrow();
+                        key.extend_datums(&temp_storage, &mut datums_local, None);
+                        datums_local.push(result);
+                        // We know that `mfp_after` can error if it exists, so try to evaluate it here.
+                        if let Err(e) = mfp.evaluate_inner(&mut datums_local, &temp_storage) {
+                            target.push((e.into(), Diff::ONE));
+                        }
+                    },
+                )
+                .as_collection(|_, v| v.clone());
+            err_output = Some(match err_output {
+                Some(errs) => errs.concat(mfp_errs),
+                None => mfp_errs,
+            });
+        }
+        (oks, err_output)
+    }
+
+    /// Build the levels of the tree of an ordered-set aggregation that look up the values at the
+    /// positions that `percentile_cont` or `percentile_disc` depend on (see
+    /// `build_ordered_set_aggregate`).
+    ///
+    /// `records` are the group keys, keys of the values and records of the aggregation, and
+    /// `leaves` are its values keyed by their leaves. Returns, keyed by the group key, a row
+    /// holding `false`, any record and the number of values of the group, and one row per
+    /// position holding `true`, the index of the position and the value at it.
+    fn build_ordered_set_percentile<'s>(
+        &self,
+        records: VecCollection<'s, T, (Row, u64, Row), Diff>,
+        leaves: VecCollection<'s, T, (Row, Row), Diff>,
+        func: AggregateFunc,
+        validating: bool,
+        key_arity: usize,
+    ) -> (
+        VecCollection<'s, T, (Row, Row), Diff>,
+        Option<VecCollection<'s, T, DataflowErrorSer, Diff>>,
+    ) {
+        // Count the values of each group by their direct arguments, which are the same in all
+        // records, with the value replaced by null.
+        let direct_args = records.clone().map(|(key, _order_key, record)| {
+            let record = record.unpack_first();
+            let mut fields = record.unwrap_list().iter().collect::<Vec<_>>();
+            *fields.last_mut().unwrap() = Datum::Null;
+            let mut row_builder = SharedRow::get();
+            row_builder.packer().push_list(fields);
+            (key, row_builder.clone())
+        });
+        let root = direct_args
+            .mz_arrange::<
+                ColumnationChunker<_>,
+                RowRowBatcher<_, _>,
+                RowRowBuilder<_, _>,
+                RowRowSpine<_, _>,
+            >("Arranged ReduceOrderedSet input")
+            .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                "ReduceOrderedSet Positions",
+                move |_key, source, target| {
+                    // Non-positive counts are reported by the error check of the leaves.
+                    let mut total = 0;
+                    let mut record = None;
+                    for (args, count) in source.iter() {
+                        if count.is_positive() {
+                            total += count.into_inner();
+                            record.get_or_insert(*args);
+                        }
+                    }
+                    let Some(mut record) = record else {
+                        return;
+                    };
+                    let record = record.next().unwrap();
+                    let positions = func.ordered_set_positions(record, total);
+
+                    let mut row_builder = SharedRow::get();
+                    let record = [Datum::False, record, Datum::Int64(total)];
+                    target.push((row_builder.pack_using(record), Diff::ONE));
+                    for (index, position) in positions.into_iter().enumerate() {
+                        let query = row_builder.pack_using([
+                            Datum::True,
+                            Datum::from(u64::cast_from(index)),
+                            Datum::Int64(position),
+                        ]);
+                        target.push((query, Diff::ONE));
+                    }
+                },
+            )
+            .as_collection(|key, val| (key.to_row(), val.to_row()));
+
+        let totals = root
+            .clone()
+            .flat_map(|(key, val)| (val.unpack_first() == Datum::False).then_some((key, val)));
+        // The positions to look up, keyed by the node that contains them, and holding `true`, the
+        // index of the position and the position among the values of the node.
+        let mut queries = root.flat_map(|(key, val)| {
+            let mut val = val.iter();
+            if val.next().unwrap() == Datum::False {
+                return None;
+            }
+            let mut row_builder = SharedRow::get();
+            let node_key = row_builder.pack_using(std::iter::once(Datum::from(0u64)).chain(&key));
+            let query = row_builder.pack_using(std::iter::once(Datum::True).chain(val));
+            Some((node_key, query))
+        });
+
+        // Descend the levels of inner nodes, whose keys are the prefixes of the keys of their
+        // values prefixed to the group key, and whose arrangements hold the counts of the nodes
+        // below them.
+        for depth in 0..ORDERED_SET_LEVELS {
+            let children = records.clone().map(move |(key, order_key, _record)| {
+                let prefix = ordered_set_prefix(order_key, depth);
+                let child = ordered_set_prefix(order_key, depth + 1);
+                let mut row_builder = SharedRow::get();
+                let node_key =
+                    row_builder.pack_using(std::iter::once(Datum::from(prefix)).chain(&key));
+                let child = row_builder.pack_using([Datum::False, Datum::from(child)]);
+                (node_key, child)
+            });
+            queries = children
+                .concat(queries)
+                .mz_arrange::<
+                    ColumnationChunker<_>,
+                    RowRowBatcher<_, _>,
+                    RowRowBuilder<_, _>,
+                    RowRowSpine<_, _>,
+                >("Arranged ReduceOrderedSet Level")
+                .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                    "ReduceOrderedSet Level",
+                    move |_key, source, target| {
+                        let mut children = Vec::new();
+                        let mut queries = Vec::new();
+                        for (val, count) in source.iter() {
+                            let mut val = *val;
+                            if val.next().unwrap() == Datum::True {
+                                let index = val.next().unwrap();
+                                queries.push((index, val.next().unwrap().unwrap_int64()));
+                            } else if count.is_positive() {
+                                let child = val.next().unwrap().unwrap_uint64();
+                                children.push((child, count.into_inner()));
+                            }
+                        }
+                        children.sort_unstable();
+
+                        let mut row_builder = SharedRow::get();
+                        for (index, mut position) in queries {
+                            for &(child, count) in &children {
+                                if position < count {
+                                    let query = row_builder.pack_using([
+                                        Datum::from(child),
+                                        index,
+                                        Datum::Int64(position),
+                                    ]);
+                                    target.push((query, Diff::ONE));
+                                    break;
+                                }
+                                position -= count;
+                            }
+                        }
+                    },
+                )
+                .as_collection(move |node_key, query| {
+                    let node_key = node_key.to_row();
+                    let query = query.to_row();
+                    let mut query_iter = query.iter();
+                    let child = query_iter.next().unwrap();
+                    let mut row_builder = SharedRow::get();
+                    let child_key = row_builder.pack_using(
+                        std::iter::once(child).chain(node_key.iter().skip(1).take(key_arity)),
+                    );
+                    let query =
+                        row_builder.pack_using(std::iter::once(Datum::True).chain(query_iter));
+                    (child_key, query)
+                });
+        }
+
+        // Find the values at the positions in the leaves.
+        let arranged_leaves = leaves
+            .concat(queries)
+            .mz_arrange::<
+                ColumnationChunker<_>,
+                RowRowBatcher<_, _>,
+                RowRowBuilder<_, _>,
+                RowRowSpine<_, _>,
+            >("Arranged ReduceOrderedSet Leaves");
+        let err_output = validating.then(|| self.build_ordered_set_error_check(&arranged_leaves));
+        let values = arranged_leaves
+            .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                "ReduceOrderedSet Leaves",
+                move |_key, source, target| {
+                    let mut values = Vec::new();
+                    let mut queries = Vec::new();
+                    for (val, count) in source.iter() {
+                        let mut val = *val;
+                        if val.next().unwrap() == Datum::True {
+                            let index = val.next().unwrap();
+                            queries.push((index, val.next().unwrap().unwrap_int64()));
+                        } else if count.is_positive() {
+                            values.push((val.next().unwrap(), count.into_inner()));
+                        }
+                    }
+                    let values = sorted_ordered_set_values(values);
+
+                    let mut row_builder = SharedRow::get();
+                    for (index, position) in queries {
+                        let mut seen = 0;
+                        for (value, count) in &values {
+                            seen += count;
+                            if position < seen {
+                                let value = row_builder.pack_using([Datum::True, index, *value]);
+                                target.push((value, Diff::ONE));
+                                break;
+                            }
+                        }
+                    }
+                },
+            )
+            .as_collection(move |node_key, value| {
+                let node_key = node_key.to_row();
+                let key = SharedRow::pack(node_key.iter().skip(1).take(key_arity));
+                (key, value.to_row())
+            });
+
+        (totals.concat(values), err_output)
+    }
+
+    /// Build the levels of the tree of a `mode` aggregation, which maintain the most frequent
+    /// value of each node (see `build_ordered_set_aggregate`).
+    ///
+    /// `leaves` are the values of the aggregation keyed by their leaves. Returns, keyed by the
+    /// group key, rows holding the number of occurrences of the most frequent value of each of
+    /// the top-level nodes of the group, and the value.
+    fn build_ordered_set_mode<'s>(
+        &self,
+        leaves: VecCollection<'s, T, (Row, Row), Diff>,
+        desc: bool,
+        validating: bool,
+        key_arity: usize,
+    ) -> (
+        VecCollection<'s, T, (Row, Row), Diff>,
+        Option<VecCollection<'s, T, DataflowErrorSer, Diff>>,
+    ) {
+        let arranged_leaves = leaves
+            .mz_arrange::<
+                ColumnationChunker<_>,
+                RowRowBatcher<_, _>,
+                RowRowBuilder<_, _>,
+                RowRowSpine<_, _>,
+            >("Arranged ReduceOrderedSet Leaves");
+        let err_output = validating.then(|| self.build_ordered_set_error_check(&arranged_leaves));
+        let mut stage = arranged_leaves
+            .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                "ReduceOrderedSet Leaves",
+                move |_key, source, target| {
+                    let values = source
+                        .iter()
+                        .filter(|(_val, count)| count.is_positive())
+                        .map(|&(mut val, count)| {
+                            let _tag = val.next();
+                            (val.next().unwrap(), count.into_inner())
+                        })
+                        .collect();
+                    let values = sorted_ordered_set_values(values);
+                    if let Some((value, count)) = ordered_set_mode(desc, values.into_iter()) {
+                        target.push((SharedRow::pack([Datum::Int64(count), value]), Diff::ONE));
+                    }
+                },
+            );
+
+        // Ascend the levels of inner nodes, whose keys are the prefixes of the keys of their
+        // values prefixed to the group key, up to the top-level nodes.
+        for _depth in 1..ORDERED_SET_LEVELS {
+            stage = stage
+                .as_collection(|node_key, mode| {
+                    let node_key = node_key.to_row();
+                    let mut node_key_iter = node_key.iter();
+                    let prefix = node_key_iter.next().unwrap().unwrap_uint64();
+                    let parent = prefix >> ORDERED_SET_DIGIT_BITS;
+                    let parent_key =
+                        SharedRow::pack(std::iter::once(Datum::from(parent)).chain(node_key_iter));
+                    (parent_key, mode.to_row())
+                })
+                .mz_arrange::<
+                    ColumnationChunker<_>,
+                    RowRowBatcher<_, _>,
+                    RowRowBuilder<_, _>,
+                    RowRowSpine<_, _>,
+                >("Arranged ReduceOrderedSet Level")
+                .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                    "ReduceOrderedSet Level",
+                    move |_key, source, target| {
+                        let modes = source.iter().map(|&(mut mode, _count)| {
+                            let count = mode.next().unwrap().unwrap_int64();
+                            (mode.next().unwrap(), count)
+                        });
+                        if let Some((value, count)) = ordered_set_mode(desc, modes) {
+                            target.push((SharedRow::pack([Datum::Int64(count), value]), Diff::ONE));
+                        }
+                    },
+                );
+        }
+
+        let partial = stage.as_collection(move |node_key, mode| {
+            let node_key = node_key.to_row();
+            let key = SharedRow::pack(node_key.iter().skip(1).take(key_arity));
+            (key, mode.to_row())
+        });
+        (partial, err_output)
+    }
+
+    /// Build the check for non-positive counts in the leaves of the tree of an ordered-set
+    /// aggregation.
+    fn build_ordered_set_error_check<'s>(
+        &self,
+        arranged_leaves: &RowRowArrangement<'s, T>,
+    ) -> VecCollection<'s, T, DataflowErrorSer, Diff> {
+        let error_logger = self.error_logger();
+        arranged_leaves
+            .clone()
+            .mz_reduce_abelian::<_, RowErrBuilder<_, _>, RowErrSpine<_, _>, _>(
+                "ReduceOrderedSet Leaves Error Check",
+                move |_key, source, target| {
+                    // Negative counts would be surprising, but until we are 100% certain we
+                    // won't see them, we should report when we do.
+                    for (value, count) in source.iter() {
+                        if count.is_positive() {
+                            continue;
+                        }
+                        let value = value.to_row();
+                        let message = "Non-positive accumulation in ReduceOrderedSet";
+                        error_logger.log(message, &format!("value={value:?}, count={count}"));
+                        target.push((EvalError::Internal(message.into()).into(), Diff::ONE));
+                        return;
+                    }
+                },
+            )
+            .as_collection(|_, v| v.clone())
+    }
+
+    /// Build the dataflow to compute a single HyperLogLog aggregation, like
+    /// `approx_count_distinct`.
+    ///
+    /// The state of the aggregation must not grow with the number of distinct values of a group,
+    /// so rather than arranging the values, we break each value (or sketch, for `hll_merge`) down
+    /// into the ranks of the registers of its sketch, and count the values of each rank of each
+    /// register. The registers of a group are packed into chunks of [`HLL_CHUNK_REGISTERS`]
+    /// registers. A first reduction maintains the maximum rank of each register of a chunk, from
+    /// the counts of its ranks, as a single row per chunk. A second reduction collects the chunks
+    /// of each group into its sketch, and evaluates the aggregate on it.
+    ///
+    /// The arranged input holds one entry per distinct rank of each register of a group. The
+    /// ranks of the values of a register are geometrically distributed, so a register that `n`
+    /// values select holds about `log2(n) + 1` distinct ranks, and at most 53. With 4096
+    /// registers, a group of `n` distinct values thus holds about `4096 * (log2(n / 4096) + 1)`
+    /// entries for large `n`, and at most `min(n, 4096 * 53)`; an `hll_merge` sketch contributes
+    /// up to one entry per register. Both reductions hold one row per chunk of a group, at most
+    /// 4096 / [`HLL_CHUNK_REGISTERS`], independent of the number of its values.
+    fn build_hll_aggregate<'s>(
+        &self,
+        input: VecCollection<'s, T, (Row, Row), Diff>,
+        index: usize,
+        func: AggregateFunc,
+        validating: bool,
+        key_arity: usize,
+        mfp_after: Option<SafeMfpPlan<LirScalarExpr>>,
+    ) -> (
+        RowRowArrangement<'s, T>,
+        Option<VecCollection<'s, T, DataflowErrorSer, Diff>>,
+    ) {
+        // Break the value we were asked to aggregate over down into the ranks of its registers,
+        // and prefix the key with the chunk of the register.
+        let func1 = func.clone();
+        let ranks = input.flat_map(move |(key, row)| {
+            let datum = row.iter().nth(index).unwrap();
+            func1
+                .hll_ranks(datum)
+                .map(|(register, rank)| {
+                    let chunk = register / HLL_CHUNK_REGISTERS;
+                    let mut row_builder = SharedRow::get();
+                    let chunk_key =
+                        row_builder.pack_using(std::iter::once(Datum::from(chunk)).chain(&key));
+                    let rank = row_builder.pack_using([Datum::from(register), Datum::from(rank)]);
+                    (chunk_key, rank)
+                })
+                .collect::<Vec<_>>()
+        });
+        let arranged_ranks = ranks
+            .mz_arrange::<
+                ColumnationChunker<_>,
+                RowRowBatcher<_, _>,
+                RowRowBuilder<_, _>,
+                RowRowSpine<_, _>,
+            >("Arranged ReduceHll input");
+
+        // Determine the maximum rank of each register of a chunk, which is the register's rank in
+        // the sketch, and pack the ranks of the chunk's registers into bytes.
+        let chunks = arranged_ranks
+            .clone()
+            .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                "ReduceHll Registers",
+                move |key, source, target| {
+                    let chunk = key.clone().next().unwrap().unwrap_uint16();
+                    let mut ranks = [0; HLL_CHUNK_REGISTERS as usize];
+                    // Non-positive counts are reported by the error check below.
+                    for &(mut register_rank, count) in source.iter() {
+                        if !count.is_positive() {
+                            continue;
+                        }
+                        let register = register_rank.next().unwrap().unwrap_uint16();
+                        let rank = register_rank.next().unwrap().unwrap_uint8();
+                        let register = usize::from(register - chunk * HLL_CHUNK_REGISTERS);
+                        ranks[register] = std::cmp::max(ranks[register], rank);
+                    }
+                    if ranks.iter().any(|rank| *rank > 0) {
+                        let ranks = Datum::Bytes(&ranks);
+                        target.push((SharedRow::pack(std::iter::once(ranks)), Diff::ONE));
+                    }
+                },
+            );
+
+        let mut err_output = None;
+        if validating {
+            let error_logger = self.error_logger();
+            let errs = arranged_ranks
+                .mz_reduce_abelian::<_, RowErrBuilder<_, _>, RowErrSpine<_, _>, _>(
+                    "ReduceHll Registers Error Check",
+                    move |_key, source, target| {
+                        // Negative counts would be surprising, but until we are 100% certain we
+                        // won't see them, we should report when we do.
+                        for (rank, count) in source.iter() {
+                            if count.is_positive() {
+                                continue;
+                            }
+                            let rank = rank.to_row();
+                            let message = "Non-positive accumulation in ReduceHll";
+                            error_logger.log(message, &format!("rank={rank:?}, count={count}"));
+                            target.push((EvalError::Internal(message.into()).into(), Diff::ONE));
+                            return;
+                        }
+                    },
+                )
+                .as_collection(|_, v| v.clone());
+            err_output = Some(errs);
+        }
+
+        // Move the chunk from the key to the value, and collect the chunks of each group.
+        let partial = chunks.as_collection(move |chunk_key, ranks| {
+            let chunk_key = chunk_key.to_row();
+            let mut chunk_key_iter = chunk_key.iter();
+            let chunk = chunk_key_iter.next().unwrap();
+            let ranks = ranks.to_row();
+            let mut row_builder = SharedRow::get();
+            let chunk_ranks = row_builder.pack_using(std::iter::once(chunk).chain(&ranks));
+            (SharedRow::pack(chunk_key_iter.take(key_arity)), chunk_ranks)
+        });
+        let arranged_chunks = partial
+            .mz_arrange::<
+                ColumnationChunker<_>,
+                RowRowBatcher<_, _>,
+                RowRowBuilder<_, _>,
+                RowRowSpine<_, _>,
+            >("Arranged ReduceHll");
+
+        // Allocations for the two closures.
+        let mut datums1 = DatumVec::new();
+        let mut datums2 = DatumVec::new();
+        let mfp_after1 = mfp_after.clone();
+        let mfp_after2 = mfp_after.filter(|mfp| mfp.could_error());
+        let func2 = func.clone();
+
+        let oks = arranged_chunks
+            .clone()
+            .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>, _>(
+                "ReduceHll",
+                move |key, source, target| {
+                    let temp_storage = RowArena::new();
+                    let ranks = source
+                        .iter()
+                        .flat_map(|&(chunk_ranks, _count)| hll_chunk_ranks(chunk_ranks));
+                    let result = func.eval_hll(ranks, &temp_storage);
+
+                    let mut datums_local = datums1.borrow();
+                    key.extend_datums(&temp_storage, &mut datums_local, None);
+                    let key_len = datums_local.len();
+                    datums_local.push(result);
+                    if let Some(row) =
+                        evaluate_mfp_after(&mfp_after1, &mut datums_local, &temp_storage, key_len)
+                    {
+                        target.push((row, Diff::ONE));
+                    }
+                },
+            );
+
+        if let Some(mfp) = mfp_after2 {
+            let mfp_errs = arranged_chunks
+                .mz_reduce_abelian::<_, RowErrBuilder<_, _>, RowErrSpine<_, _>, _>(
+                    "ReduceHll Error Check",
+                    move |key, source, target| {
+                        let temp_storage = RowArena::new();
+                        let ranks = source
+                            .iter()
+                            .flat_map(|&(chunk_ranks, _count)| hll_chunk_ranks(chunk_ranks));
+                        let result = func2.eval_hll(ranks, &temp_storage);
+
+                        let mut datums_local = datums2.borrow();
+                        key.extend_datums(&temp_storage, &mut datums_local, None);
+                        datums_local.push(result);
+                        // We know that `mfp_after` can error if it exists, so try to evaluate it here.
+                        if let Err(e) = mfp.evaluate_inner(&mut datums_local, &temp_storage) {
+                            target.push((e.into(), Diff::ONE));
+                        }
+                    },
+                )
+                .as_collection(|_, v| v.clone());
+            err_output = Some(match err_output {
+                Some(errs) => errs.concat(mfp_errs),
+                None => mfp_errs,
+            });
+        }
+        (oks, err_output)
+    }
+
     fn build_reduce_inaccumulable_distinct<'s, Bu, Tr>(
         &self,
         input: VecCollection<'s, T, Row, Diff>,
@@ -1608,6 +2276,146 @@ fn evaluate_mfp_after<'a, 'b>(
     }
 }
 
+/// Returns a key for the non-null `datum` that is monotonic in its order, i.e., for datums
+/// `a <= b` of the same type, `ordered_set_key(a) <= ordered_set_key(b)`. Datums that differ only
+/// in what the key can't tell apart, like strings with the same first eight bytes, and all datums
+/// of types without such a key have equal keys.
+fn ordered_set_key(datum: Datum) -> u64 {
+    // Flipping the sign bit orders signed integers like unsigned ones.
+    fn signed(i: i64) -> u64 {
+        u64::from_ne_bytes(i.to_ne_bytes()) ^ (1 << 63)
+    }
+    fn float(f: f64) -> u64 {
+        if f.is_nan() {
+            return u64::MAX;
+        }
+        // `-0.0` and `0.0` are equal.
+        let bits = if f == 0.0 { 0 } else { f.to_bits() };
+        if bits >> 63 == 1 {
+            !bits
+        } else {
+            bits | (1 << 63)
+        }
+    }
+    fn prefix(bytes: &[u8]) -> u64 {
+        let mut prefix = [0; 8];
+        let len = bytes.len().min(prefix.len());
+        prefix[..len].copy_from_slice(&bytes[..len]);
+        u64::from_be_bytes(prefix)
+    }
+    match datum {
+        Datum::Int16(i) => signed(i.into()),
+        Datum::Int32(i) => signed(i.into()),
+        Datum::Int64(i) => signed(i),
+        Datum::UInt8(u) => u.into(),
+        Datum::UInt16(u) => u.into(),
+        Datum::UInt32(u) => u.into(),
+        Datum::UInt64(u) => u,
+        Datum::Float32(f) => float(f.into_inner().into()),
+        Datum::Float64(f) => float(f.into_inner()),
+        Datum::Numeric(n) => float(n.0.to_string().parse().unwrap_or(f64::NAN)),
+        Datum::Date(d) => signed(d.pg_epoch_days().into()),
+        Datum::Timestamp(ts) => signed(ts.to_naive().and_utc().timestamp_micros()),
+        Datum::TimestampTz(ts) => signed(ts.timestamp_micros()),
+        Datum::MzTimestamp(t) => t.into(),
+        Datum::String(s) => prefix(s.as_bytes()),
+        Datum::Bytes(b) => prefix(b),
+        _ => 0,
+    }
+}
+
+/// Returns the prefix of the key of a value (see [`ordered_set_key`]) that identifies the node
+/// at `depth` of the tree of an ordered-set aggregate that contains it. The root has depth zero,
+/// and the leaves have depth [`ORDERED_SET_LEVELS`].
+fn ordered_set_prefix(order_key: u64, depth: u32) -> u64 {
+    order_key
+        .checked_shr(u64::BITS - depth * ORDERED_SET_DIGIT_BITS)
+        .unwrap_or(0)
+}
+
+/// Sorts the values of a leaf of the tree of an ordered-set aggregate along with their counts,
+/// and merges the counts of equal values.
+fn sorted_ordered_set_values<'a>(mut values: Vec<(Datum<'a>, i64)>) -> Vec<(Datum<'a>, i64)> {
+    values.sort_by(|(a, _), (b, _)| a.cmp(b));
+    // Values that differ only in their representation, like `-0.0` and `0.0`, are distinct in
+    // the arrangement.
+    values
+        .into_iter()
+        .coalesce(|(a, x), (b, y)| {
+            if a == b {
+                Ok((a, x + y))
+            } else {
+                Err(((a, x), (b, y)))
+            }
+        })
+        .collect()
+}
+
+/// Returns the most frequent of the distinct `values` along with its count. Like PostgreSQL, ties
+/// are broken in favor of the value that comes first in the order of the aggregate, which is
+/// descending if `desc`.
+fn ordered_set_mode<'a>(
+    desc: bool,
+    values: impl IntoIterator<Item = (Datum<'a>, i64)>,
+) -> Option<(Datum<'a>, i64)> {
+    values.into_iter().reduce(|mode, (value, count)| {
+        let first = if desc { value > mode.0 } else { value < mode.0 };
+        if count > mode.1 || (count == mode.1 && first) {
+            (value, count)
+        } else {
+            mode
+        }
+    })
+}
+
+/// Evaluates the ordered-set aggregate `func` on the values of the final reduction of a group
+/// (see `build_ordered_set_aggregate`), or returns `None` if the group has no values.
+fn eval_ordered_set_root<'a>(
+    func: &AggregateFunc,
+    vals: impl Iterator<Item = DatumSeq<'a>>,
+    temp_storage: &'a RowArena,
+) -> Option<Datum<'a>> {
+    if let AggregateFunc::Mode { desc } = func {
+        let modes = vals.map(|mut mode| {
+            let count = mode.next().unwrap().unwrap_int64();
+            (mode.next().unwrap(), count)
+        });
+        return ordered_set_mode(*desc, modes).map(|(value, _count)| value);
+    }
+
+    let mut total = None;
+    let mut values = BTreeMap::new();
+    for mut val in vals {
+        if val.next().unwrap() == Datum::True {
+            let index = val.next().unwrap().unwrap_uint64();
+            values.insert(index, val.next().unwrap());
+        } else {
+            let record = val.next().unwrap();
+            total = Some((record, val.next().unwrap().unwrap_int64()));
+        }
+    }
+    let (record, total) = total?;
+    // The values at some positions are missing only if the counts of the group are not positive,
+    // which the error check of the leaves reports.
+    let values = values.into_values().collect::<Vec<_>>();
+    if values.len() != func.ordered_set_positions(record, total).len() {
+        return None;
+    }
+    Some(func.eval_ordered_set_at(record, total, &values, temp_storage))
+}
+
+/// Returns the registers of a chunk of a HyperLogLog sketch that hold a rank, along with the rank,
+/// from a row holding the chunk and the ranks of its registers (see `build_hll_aggregate`).
+fn hll_chunk_ranks(mut chunk_ranks: DatumSeq) -> impl Iterator<Item = (u16, u8)> + '_ {
+    let chunk = chunk_ranks.next().unwrap().unwrap_uint16();
+    let ranks = chunk_ranks.next().unwrap().unwrap_bytes();
+    ranks
+        .iter()
+        .zip(chunk * HLL_CHUNK_REGISTERS..)
+        .filter(|(rank, _register)| **rank > 0)
+        .map(|(rank, register)| (register, *rank))
+}
+
 fn accumulable_zero(aggr_func: &AggregateFunc) -> Accum {
     match aggr_func {
         AggregateFunc::Any | AggregateFunc::All => Accum::Bool {
@@ -2511,12 +3319,22 @@ mod monoids {
             | AggregateFunc::ArrayConcat { .. }
             | AggregateFunc::ListConcat { .. }
             | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketch
+            | AggregateFunc::HllMerge
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
             | AggregateFunc::LagLead { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => None,
@@ -2722,4 +3540,47 @@ mod tests {
         let datum = finalize_accum(&func, &acc, Diff::from(2_i64));
         assert_eq!(datum, Datum::from(0.0_f64));
     }
+
+    #[mz_ore::test]
+    fn ordered_set_key_is_monotonic() {
+        let sorted = [
+            vec![
+                Datum::from(i64::MIN),
+                Datum::from(-1_i64),
+                Datum::from(0_i64),
+                Datum::from(1_i64),
+            ],
+            vec![
+                Datum::from(f64::NEG_INFINITY),
+                Datum::from(-1.5_f64),
+                Datum::from(-0.0_f64),
+                Datum::from(0.0_f64),
+                Datum::from(f64::MIN_POSITIVE),
+                Datum::from(f64::INFINITY),
+                Datum::from(f64::NAN),
+            ],
+            vec![
+                Datum::from(""),
+                Datum::from("a"),
+                Datum::from("ab"),
+                Datum::from("abcdefghz"),
+            ],
+        ];
+        for datums in sorted {
+            for (a, b) in datums.iter().tuple_windows() {
+                assert!(a <= b, "{a:?} > {b:?}");
+                assert!(
+                    ordered_set_key(*a) <= ordered_set_key(*b),
+                    "{a:?} has a greater key than {b:?}"
+                );
+            }
+        }
+        assert_eq!(
+            ordered_set_key(Datum::from(-0.0_f64)),
+            ordered_set_key(Datum::from(0.0_f64))
+        );
+        assert_eq!(ordered_set_prefix(u64::MAX, 0), 0);
+        assert_eq!(ordered_set_prefix(u64::MAX, 1), 0xff);
+        assert_eq!(ordered_set_prefix(u64::MAX, ORDERED_SET_LEVELS), u64::MAX);
+    }
 }
@@ -66,6 +66,7 @@ use mz_persist_client::rpc::{
 };
 use mz_secrets::SecretsController;
 use mz_server_core::TlsCliArgs;
+use mz_server_core::listeners::AuthenticatorKind;
 use mz_service::emit_boot_diagnostics;
 use mz_service::secrets::{SecretsControllerKind, SecretsReaderCliArgs};
 use mz_sql::catalog::EnvironmentId;
@@ -122,6 +123,14 @@ pub struct Args {
         action = ArgAction::Set,
     )]
     external_login_password_mz_system: Option<Password>,
+    /// Password of the service account (`ldap_search_bind_dn`) that searches
+    /// the directory for users on LDAP listeners.
+    #[clap(
+        long,
+        env = "LDAP_SEARCH_BIND_PASSWORD",
+        action = ArgAction::Set,
+    )]
+    ldap_search_bind_password: Option<Password>,
     /// The address on which to listen for Persist PubSub connections.
     ///
     /// Connections to this address are not subject to encryption, authentication,
@@ -399,8 +408,9 @@ pub struct Args {
     storage_usage_retention_period: Option<Duration>,
 
     // === Adapter options. ===
-    /// The PostgreSQL URL for the Postgres-backed timestamp oracle.
-    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "POSTGRES_URL")]
+    /// The URL of the timestamp oracle's backing store: a PostgreSQL URL, or a
+    /// `sqlite://` URL naming a local database file.
+    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "URL")]
     timestamp_oracle_url: Option<SensitiveUrl>,
     /// Availability zones in which storage and compute resources may be
     /// deployed.
@@ -781,6 +791,26 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
             .map_err(|e| anyhow::anyhow!("invalid HTTP listener: {}", e))?;
     }
 
+    let uses_certificate_auth = listeners_config
+        .sql
+        .values()
+        .map(|listener| listener.authenticator_kind())
+        .chain(
+            listeners_config
+                .http
+                .values()
+                .map(|listener| listener.authenticator_kind()),
+        )
+        .any(|kind| kind == AuthenticatorKind::Certificate);
+    if uses_certificate_auth
+        && tls
+            .as_ref()
+            .and_then(|tls| tls.client_ca.as_ref())
+            .is_none()
+    {
+        bail!("certificate authentication requires --tls-client-ca");
+    }
+
     // Configure CORS.
     let allowed_origins = if !args.cors_allowed_origin.is_empty() {
         args.cors_allowed_origin
@@ -1007,32 +1037,30 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         })
     };
 
+    // A SQLite metadata backend keeps consensus and the timestamp oracle in
+    // separate tables of the same file, so there's no schema to select.
+    let metadata_backend_url_with_schema = |metadata_backend_url: &SensitiveUrl, schema: &str| {
+        if metadata_backend_url.scheme() == "sqlite" {
+            return metadata_backend_url.clone();
+        }
+        SensitiveUrl(
+            Url::parse_with_params(
+                metadata_backend_url.0.as_ref(),
+                &[("options", format!("--search_path={schema}"))],
+            )
+            .unwrap(),
+        )
+    };
     let consensus_uri = args.persist_consensus_url.unwrap_or_else(|| {
         args.metadata_backend_url
             .as_ref()
-            .map(|metadata_backend_url| {
-                SensitiveUrl(
-                    Url::parse_with_params(
-                        metadata_backend_url.0.as_ref(),
-                        &[("options", "--search_path=consensus")],
-                    )
-                    .unwrap(),
-                )
-            })
+            .map(|url| metadata_backend_url_with_schema(url, "consensus"))
             .expect("either --persist-consensus-url or --metadata-backend-url must be provided")
     });
     let timestamp_oracle_url = args.timestamp_oracle_url.or_else(|| {
         args.metadata_backend_url
             .as_ref()
-            .map(|metadata_backend_url| {
-                SensitiveUrl(
-                    Url::parse_with_params(
-                        metadata_backend_url.0.as_ref(),
-                        &[("options", "--search_path=tsoracle")],
-                    )
-                    .unwrap(),
-                )
-            })
+            .map(|url| metadata_backend_url_with_schema(url, "tsoracle"))
     });
 
     let persist_clients = Arc::new(persist_clients);
@@ -1105,6 +1133,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                 tls,
                 tls_reload_certs: mz_server_core::default_cert_reload_ticker(),
                 external_login_password_mz_system: args.external_login_password_mz_system,
+                ldap_search_bind_password: args.ldap_search_bind_password,
                 frontegg,
                 frontegg_oauth_issuer_url,
                 cors_allowed_origin,
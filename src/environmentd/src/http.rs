@@ -68,7 +68,7 @@ use axum::extract::{ConnectInfo, DefaultBodyLimit, FromRequestParts, Query, Requ
 use axum::middleware::{self, Next};
 use axum::response::{IntoResponse, Redirect, Response};
 use axum::{Extension, Json, Router, routing};
-use futures::future::{Shared, TryFutureExt};
+use futures::future::{FutureExt, Shared, TryFutureExt};
 use headers::authorization::{Authorization, Basic, Bearer};
 use headers::{HeaderMapExt, HeaderName};
 use http::header::{AUTHORIZATION, CONTENT_TYPE};
@@ -78,11 +78,13 @@ use hyper_openssl::SslStream;
 use hyper_openssl::client::legacy::MaybeHttpsStream;
 use hyper_util::rt::TokioIo;
 use mz_adapter::session::{Session as AdapterSession, SessionConfig as AdapterSessionConfig};
-use mz_adapter::{AdapterError, AdapterNotice, Client, SessionClient, WebhookAppenderCache};
+use mz_adapter::{
+    AdapterError, AdapterNotice, AuthFailure, Client, SessionClient, WebhookAppenderCache,
+};
 use mz_adapter_types::dyncfgs::OIDC_GROUP_CLAIM;
 use mz_auth::Authenticated;
 use mz_auth::password::Password;
-use mz_authenticator::Authenticator;
+use mz_authenticator::{Authenticator, CertificateAuthenticator, ClientCertificate};
 use mz_controller::ReplicaHttpLocator;
 use mz_dyncfg::ConfigSet;
 use mz_frontegg_auth::Error as FronteggError;
@@ -157,6 +159,7 @@ pub struct HttpConfig {
     pub authenticator_kind: listeners::AuthenticatorKind,
     pub frontegg: Option<mz_frontegg_auth::Authenticator>,
     pub oidc_rx: Delayed<mz_authenticator::GenericOidcAuthenticator>,
+    pub ldap_rx: Delayed<mz_authenticator::LdapAuthenticator>,
     pub adapter_client_rx: Shared<Receiver<Client>>,
     pub allowed_origin: AllowOrigin,
     /// Raw list of allowed CORS origins, used by the MCP endpoints for
@@ -200,6 +203,7 @@ pub struct InternalRouteConfig {
 pub struct WsState {
     frontegg: Option<mz_frontegg_auth::Authenticator>,
     oidc_rx: Delayed<mz_authenticator::GenericOidcAuthenticator>,
+    ldap_rx: Delayed<mz_authenticator::LdapAuthenticator>,
     authenticator_kind: listeners::AuthenticatorKind,
     adapter_client_rx: Delayed<mz_adapter::Client>,
     active_connection_counter: ConnectionCounter,
@@ -220,6 +224,7 @@ struct HelmChartVersion(Option<String>);
 #[derive(Debug)]
 pub struct HttpServer {
     tls: Option<ReloadingSslContext>,
+    authenticator_kind: listeners::AuthenticatorKind,
     router: Router,
 }
 
@@ -231,6 +236,7 @@ impl HttpServer {
             authenticator_kind,
             frontegg,
             oidc_rx,
+            ldap_rx,
             adapter_client_rx,
             allowed_origin,
             allowed_origin_list,
@@ -271,10 +277,12 @@ impl HttpServer {
 
         let frontegg_middleware = frontegg.clone();
         let oidc_middleware_rx = oidc_rx.clone();
+        let ldap_middleware_rx = ldap_rx.clone();
         let adapter_client_middleware_rx = adapter_client_rx.clone();
         let auth_middleware = middleware::from_fn(move |req, next| {
             let frontegg = frontegg_middleware.clone();
             let oidc_rx = oidc_middleware_rx.clone();
+            let ldap_rx = ldap_middleware_rx.clone();
             let adapter_client_rx = adapter_client_middleware_rx.clone();
             async move {
                 http_auth(
@@ -284,6 +292,7 @@ impl HttpServer {
                     authenticator_kind,
                     frontegg,
                     oidc_rx,
+                    ldap_rx,
                     adapter_client_rx,
                 )
                 .await
@@ -325,6 +334,7 @@ impl HttpServer {
                 .with_state(WsState {
                     frontegg,
                     oidc_rx: oidc_rx.clone(),
+                    ldap_rx: ldap_rx.clone(),
                     authenticator_kind,
                     adapter_client_rx: adapter_client_rx.clone(),
                     active_connection_counter: active_connection_counter.clone(),
@@ -679,7 +689,11 @@ impl HttpServer {
             .merge(base_router)
             .apply_default_layers(source, metrics);
 
-        HttpServer { tls, router }
+        HttpServer {
+            tls,
+            authenticator_kind,
+            router,
+        }
     }
 }
 
@@ -693,6 +707,8 @@ impl Server for HttpServer {
     ) -> ConnectionHandler {
         let router = self.router.clone();
         let tls_context = self.tls.clone();
+        let require_client_cert =
+            self.authenticator_kind == listeners::AuthenticatorKind::Certificate;
         let mut conn = TokioIo::new(conn);
 
         Box::pin(async {
@@ -706,12 +722,23 @@ impl Server for HttpServer {
 
             let (conn, conn_protocol) = match tls_context {
                 Some(tls_context) => {
-                    let mut ssl_stream = SslStream::new(Ssl::new(&tls_context.get())?, conn)?;
+                    let mut ssl = Ssl::new(&tls_context.get())?;
+                    if require_client_cert {
+                        mz_server_core::require_client_cert(&mut ssl);
+                    }
+                    let mut ssl_stream = SslStream::new(ssl, conn)?;
                     if let Err(e) = Pin::new(&mut ssl_stream).accept().await {
                         let _ = ssl_stream.get_mut().inner_mut().shutdown().await;
                         return Err(e.into());
                     }
-                    (MaybeHttpsStream::Https(ssl_stream), ConnProtocol::Https)
+                    let client_cert = ssl_stream
+                        .ssl()
+                        .peer_certificate()
+                        .map(|cert| ClientCertificate::from_x509(&cert));
+                    (
+                        MaybeHttpsStream::Https(ssl_stream),
+                        ConnProtocol::Https { client_cert },
+                    )
                 }
                 _ => (MaybeHttpsStream::Http(conn), ConnProtocol::Http),
             };
@@ -817,7 +844,20 @@ async fn group_claim_for(adapter_client_rx: &Delayed<Client>) -> String {
 #[derive(Clone)]
 enum ConnProtocol {
     Http,
-    Https,
+    Https {
+        /// The verified client certificate, on listeners that use certificate
+        /// authentication.
+        client_cert: Option<ClientCertificate>,
+    },
+}
+
+impl ConnProtocol {
+    fn client_cert(&self) -> Option<&ClientCertificate> {
+        match self {
+            ConnProtocol::Http => None,
+            ConnProtocol::Https { client_cert } => client_cert.as_ref(),
+        }
+    }
 }
 
 /// The `allowed_roles` policy for a route group, attached as a request
@@ -887,6 +927,10 @@ impl AuthedClient {
         );
         let connection_guard = active_connection_counter.allocate_connection(session.user())?;
 
+        // HTTP requests and WebSockets get a session each, which serves the
+        // request rather than a client connection, so it is not audited as a
+        // login. Their authentication failures are audited by `audited_auth`.
+        session.skip_login_audit();
         session_config(&mut session);
         let system_vars = adapter_client.get_system_vars().await;
         for (key, val) in options {
@@ -1043,6 +1087,25 @@ pub(crate) enum AuthError {
     /// Payload is `OidcError`'s sanitized `Display` (no expected-values leaks).
     #[error("{0}")]
     OidcFailed(String),
+    #[error("{0}")]
+    CertificateFailed(String),
+    #[error("{0}")]
+    LdapFailed(String),
+}
+
+impl AuthError {
+    /// Whether the client presented credentials that were rejected, as
+    /// opposed to presenting none or failing authorization.
+    fn is_rejected_credentials(&self) -> bool {
+        matches!(
+            self,
+            AuthError::Frontegg(_)
+                | AuthError::InvalidCredentials
+                | AuthError::OidcFailed(_)
+                | AuthError::CertificateFailed(_)
+                | AuthError::LdapFailed(_)
+        )
+    }
 }
 
 impl IntoResponse for AuthError {
@@ -1103,6 +1166,7 @@ impl IntoResponse for AuthError {
 pub async fn handle_login(
     session: Option<Extension<TowerSession>>,
     Extension(adapter_client_rx): Extension<Delayed<Client>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     Json(LoginCredentials { username, password }): Json<LoginCredentials>,
 ) -> impl IntoResponse {
     // The listener's `allowed_roles` policy is not enforced here. Login only
@@ -1116,6 +1180,13 @@ pub async fn handle_login(
         Ok(authenticated) => authenticated,
         Err(err) => {
             warn!(?err, "HTTP login failed authentication");
+            adapter_client.record_auth_failure(AuthFailure {
+                role_name: username,
+                authenticator_kind: mz_auth::AuthenticatorKind::Password,
+                client_ip: Some(addr.ip()),
+                application_name: None,
+                reason: err.to_string(),
+            });
             return StatusCode::UNAUTHORIZED;
         }
     };
@@ -1159,9 +1230,18 @@ async fn http_auth(
     authenticator_kind: listeners::AuthenticatorKind,
     frontegg: Option<mz_frontegg_auth::Authenticator>,
     oidc_rx: Delayed<mz_authenticator::GenericOidcAuthenticator>,
+    ldap_rx: Delayed<mz_authenticator::LdapAuthenticator>,
     adapter_client_rx: Delayed<Client>,
 ) -> Result<impl IntoResponse, AuthError> {
-    let creds = if let Some(basic) = req.headers().typed_get::<Authorization<Basic>>() {
+    let client_cert = req
+        .extensions()
+        .get::<ConnProtocol>()
+        .and_then(|conn_protocol| conn_protocol.client_cert());
+    let creds = if let Some(cert) = client_cert {
+        // Only requested on certificate listeners, where it takes precedence
+        // over any credentials in the request.
+        Some(Credentials::Certificate { cert: cert.clone() })
+    } else if let Some(basic) = req.headers().typed_get::<Authorization<Basic>>() {
         Some(Credentials::Password {
             username: basic.username().to_owned(),
             password: Password(basic.password().to_owned()),
@@ -1249,6 +1329,7 @@ async fn http_auth(
         creds.as_ref(),
         frontegg,
         &oidc_rx,
+        &ldap_rx,
         &adapter_client_rx,
     )
     .await;
@@ -1263,7 +1344,19 @@ async fn http_auth(
     } else {
         None
     };
-    let user = auth(&authenticator, creds, &challenges, group_claim.as_deref()).await?;
+    let client_ip = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let user = audited_auth(
+        &authenticator,
+        creds,
+        &challenges,
+        group_claim.as_deref(),
+        &adapter_client_rx,
+        client_ip,
+    )
+    .await?;
 
     // Add the authenticated user as an extension so downstream handlers can
     // inspect it if necessary.
@@ -1301,6 +1394,7 @@ async fn init_ws(
     WsState {
         frontegg,
         oidc_rx,
+        ldap_rx,
         authenticator_kind,
         adapter_client_rx,
         active_connection_counter,
@@ -1308,6 +1402,7 @@ async fn init_ws(
         allowed_roles,
     }: WsState,
     existing_user: Option<ExistingUser>,
+    client_cert: Option<ClientCertificate>,
     peer_addr: IpAddr,
     ws: &mut WebSocket,
 ) -> Result<AuthedClient, anyhow::Error> {
@@ -1351,6 +1446,12 @@ async fn init_ws(
         }
         WebSocketAuth::OptionsOnly { options } => (None, options),
     };
+    // As in `http_auth`, a client certificate takes precedence over any
+    // credentials in the init message.
+    let creds = match client_cert {
+        Some(cert) => Some(Credentials::Certificate { cert }),
+        None => creds,
+    };
 
     let user = match (existing_user, creds) {
         (Some(ExistingUser::XMaterializeUserHeader(_)), Some(_creds)) => {
@@ -1365,6 +1466,7 @@ async fn init_ws(
                 Some(&creds),
                 frontegg,
                 &oidc_rx,
+                &ldap_rx,
                 &adapter_client_rx,
             )
             .await;
@@ -1379,11 +1481,13 @@ async fn init_ws(
             } else {
                 None
             };
-            let user = auth(
+            let user = audited_auth(
                 &authenticator,
                 Some(creds),
                 &no_challenges,
                 group_claim.as_deref(),
+                &adapter_client_rx,
+                Some(peer_addr),
             )
             .await?;
             user
@@ -1420,6 +1524,9 @@ enum Credentials {
     Token {
         token: String,
     },
+    Certificate {
+        cert: ClientCertificate,
+    },
 }
 
 async fn get_authenticator(
@@ -1427,6 +1534,7 @@ async fn get_authenticator(
     creds: Option<&Credentials>,
     frontegg: Option<mz_frontegg_auth::Authenticator>,
     oidc_rx: &Delayed<mz_authenticator::GenericOidcAuthenticator>,
+    ldap_rx: &Delayed<mz_authenticator::LdapAuthenticator>,
     adapter_client_rx: &Delayed<Client>,
 ) -> Authenticator {
     match kind {
@@ -1445,6 +1553,13 @@ async fn get_authenticator(
             }
             _ => Authenticator::Oidc(oidc_rx.clone().await.expect("sender not dropped")),
         },
+        listeners::AuthenticatorKind::Certificate => {
+            let client = adapter_client_rx.clone().await.expect("sender not dropped");
+            Authenticator::Certificate(CertificateAuthenticator::new(client))
+        }
+        listeners::AuthenticatorKind::Ldap => {
+            Authenticator::Ldap(ldap_rx.clone().await.expect("sender not dropped"))
+        }
         listeners::AuthenticatorKind::None => Authenticator::None,
     }
 }
@@ -1494,6 +1609,40 @@ pub(crate) async fn ensure_session_unexpired(
     })
 }
 
+/// Like [`auth`], but reports rejected credentials to the audit log.
+async fn audited_auth(
+    authenticator: &Authenticator,
+    creds: Option<Credentials>,
+    challenges: &WwwAuthenticateChallenges,
+    group_claim: Option<&str>,
+    adapter_client_rx: &Delayed<Client>,
+    client_ip: Option<IpAddr>,
+) -> Result<AuthedUser, AuthError> {
+    // Bearer tokens name the role only once they are validated.
+    let role_name = match &creds {
+        Some(Credentials::Password { username, .. }) => username.clone(),
+        _ => String::new(),
+    };
+    let result = auth(authenticator, creds, challenges, group_claim).await;
+    if let Err(err) = &result
+        && err.is_rejected_credentials()
+    {
+        // Without a coordinator there is nothing to log in to, and waiting
+        // for one here would hold up the response, so the failure goes
+        // unrecorded.
+        if let Some(Ok(adapter_client)) = adapter_client_rx.clone().now_or_never() {
+            adapter_client.record_auth_failure(AuthFailure {
+                role_name,
+                authenticator_kind: authenticator.kind(),
+                client_ip,
+                application_name: None,
+                reason: err.to_string(),
+            });
+        }
+    }
+    result
+}
+
 async fn auth(
     authenticator: &Authenticator,
     creds: Option<Credentials>,
@@ -1525,7 +1674,21 @@ async fn auth(
                     claims.groups,
                 )
             }
-            None => {
+            None | Some(Credentials::Certificate { .. }) => {
+                return Err(AuthError::MissingHttpAuthentication {
+                    challenges: challenges.clone(),
+                });
+            }
+        },
+        Authenticator::Ldap(ldap) => match creds {
+            Some(Credentials::Password { username, password }) => {
+                let (groups, authenticated) = ldap
+                    .authenticate(&username, password.as_str())
+                    .await
+                    .map_err(|e| AuthError::LdapFailed(e.to_string()))?;
+                (username, None, authenticated, groups)
+            }
+            _ => {
                 return Err(AuthError::MissingHttpAuthentication {
                     challenges: challenges.clone(),
                 });
@@ -1569,6 +1732,20 @@ async fn auth(
                 });
             }
         },
+        Authenticator::Certificate(certificate) => match creds {
+            Some(Credentials::Certificate { cert }) => {
+                let (name, authenticated) = certificate
+                    .authenticate(Some(&cert), None)
+                    .await
+                    .map_err(|e| AuthError::CertificateFailed(e.to_string()))?;
+                (name, None, authenticated, None)
+            }
+            _ => {
+                return Err(AuthError::MissingHttpAuthentication {
+                    challenges: challenges.clone(),
+                });
+            }
+        },
         Authenticator::None => {
             // If no authentication, use whatever is in the HTTP auth
             // header (without checking the password), or fall back to the
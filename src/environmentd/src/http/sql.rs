@@ -33,6 +33,7 @@ use mz_adapter::{
     PeekResponseUnary, SessionClient, verify_datum_desc,
 };
 use mz_auth::password::Password;
+use mz_authenticator::ClientCertificate;
 use mz_catalog::memory::objects::{Cluster, ClusterReplica};
 use mz_interchange::encode::TypedDatum;
 use mz_interchange::json::{JsonNumberPolicy, ToJson};
@@ -57,8 +58,8 @@ use tungstenite::protocol::frame::coding::CloseCode;
 
 use crate::http::prometheus::PrometheusSqlQuery;
 use crate::http::{
-    AuthError, AuthedClient, AuthedUser, MAX_REQUEST_SIZE, WsState, ensure_session_unexpired,
-    init_ws, maybe_get_authenticated_session,
+    AuthError, AuthedClient, AuthedUser, ConnProtocol, MAX_REQUEST_SIZE, WsState,
+    ensure_session_unexpired, init_ws, maybe_get_authenticated_session,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -303,6 +304,7 @@ pub(crate) async fn handle_sql_ws(
     existing_user: Option<Extension<AuthedUser>>,
     ws: WebSocketUpgrade,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Extension(conn_protocol): Extension<ConnProtocol>,
     tower_session: Option<Extension<TowerSession>>,
 ) -> Result<impl IntoResponse, AuthError> {
     let session = tower_session.map(|Extension(session)| session);
@@ -320,10 +322,11 @@ pub(crate) async fn handle_sql_ws(
         }
     };
 
+    let client_cert = conn_protocol.client_cert().cloned();
     let addr = Box::new(addr.ip());
     Ok(ws
         .max_message_size(MAX_REQUEST_SIZE)
-        .on_upgrade(|ws| async move { run_ws(state, user, *addr, ws).await }))
+        .on_upgrade(|ws| async move { run_ws(state, user, client_cert, *addr, ws).await }))
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -346,8 +349,14 @@ pub enum WebSocketAuth {
     },
 }
 
-async fn run_ws(state: WsState, user: Option<ExistingUser>, peer_addr: IpAddr, mut ws: WebSocket) {
-    let mut client = match init_ws(state, user, peer_addr, &mut ws).await {
+async fn run_ws(
+    state: WsState,
+    user: Option<ExistingUser>,
+    client_cert: Option<ClientCertificate>,
+    peer_addr: IpAddr,
+    mut ws: WebSocket,
+) {
+    let mut client = match init_ws(state, user, client_cert, peer_addr, &mut ws).await {
         Ok(client) => client,
         Err(e) => {
             // We omit most detail from the error message we send to the client, to
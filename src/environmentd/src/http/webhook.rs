@@ -130,6 +130,7 @@ async fn append_webhook(
     let AppendWebhookResponse {
         tx,
         body_format,
+        decoder,
         header_tys,
         validator,
     } = async {
@@ -181,8 +182,25 @@ async fn append_webhook(
         }
     }
 
-    // Pack our body and headers into a Row.
-    let rows = pack_rows(body, &body_format, headers, &header_tys)?;
+    // Pack our body and headers into a Row, decoding the body first if required.
+    let rows = match decoder {
+        Some(decoder) => {
+            let bodies = transform_body(body, &body_format)?
+                .into_iter()
+                .map(BodyRow::into_inner)
+                .collect();
+            decoder
+                .decode(bodies)
+                .await?
+                .into_iter()
+                .map(|row| {
+                    let body_row = BodyRow::new(row, decoder.num_columns());
+                    pack_header(body_row, headers, &header_tys).map(|row| (row, Diff::ONE))
+                })
+                .collect::<Result<_, _>>()?
+        }
+        None => pack_rows(body, &body_format, headers, &header_tys)?,
+    };
 
     // Send the row to get appended.
     tx.append(rows).await?;
@@ -257,7 +275,7 @@ fn transform_body(
 
     // A `Row` cannot describe its schema without unpacking it. To add some safety we wrap the
     // returned `Row`s in a newtype to signify they already have the "body" column packed.
-    let body_rows = rows.into_iter().map(BodyRow).collect();
+    let body_rows = rows.into_iter().map(|row| BodyRow::new(row, 1)).collect();
 
     Ok(body_rows)
 }
@@ -268,10 +286,10 @@ fn pack_header(
     headers: &BTreeMap<String, String>,
     header_tys: &WebhookHeaders,
 ) -> Result<Row, AppendWebhookError> {
-    // 1 column for the body plus however many are needed for the headers.
-    let num_cols = 1 + header_tys.num_columns();
+    // The body columns plus however many are needed for the headers.
+    let num_cols = body_row.num_columns() + header_tys.num_columns();
     // The provided Row already has the Body written.
-    let mut num_cols_written = 1;
+    let mut num_cols_written = body_row.num_columns();
 
     let mut packer = RowPacker::for_existing_row(body_row.inner_mut());
 
@@ -320,20 +338,32 @@ fn filter_headers<'a: 'b, 'b>(
 
 /// A [`Row`] that has the body of a request already packed into it.
 ///
-/// Note: if you're constructing a [`BodyRow`] you need to guarantee the only column packed into
-/// the [`Row`] is a single "body" column.
-#[repr(transparent)]
-struct BodyRow(Row);
+/// Note: if you're constructing a [`BodyRow`] you need to guarantee the only columns packed into
+/// the [`Row`] are the body columns, either a single "body" column or the decoded body columns.
+struct BodyRow {
+    row: Row,
+    num_columns: usize,
+}
 
 impl BodyRow {
+    /// Wraps a [`Row`] that contains exactly `num_columns` body columns.
+    fn new(row: Row, num_columns: usize) -> Self {
+        BodyRow { row, num_columns }
+    }
+
+    /// Returns the number of body columns packed into this row.
+    fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
     /// Obtain a mutable reference to the inner [`Row`].
     fn inner_mut(&mut self) -> &mut Row {
-        &mut self.0
+        &mut self.row
     }
 
     /// Return the inner [`Row`].
     fn into_inner(self) -> Row {
-        self.0
+        self.row
     }
 }
 
@@ -357,6 +387,8 @@ pub enum WebhookError {
     InvalidHeaders(String),
     #[error("failed to deserialize body as {ty:?}: {msg}")]
     InvalidBody { ty: SqlScalarType, msg: String },
+    #[error("failed to decode body: {0}")]
+    DecodeFailed(String),
     #[error("failed to validate the request")]
     ValidationFailed,
     #[error("error occurred while running validation")]
@@ -384,6 +416,7 @@ impl From<AppendWebhookError> for WebhookError {
                 ty: SqlScalarType::Jsonb,
                 msg,
             },
+            AppendWebhookError::DecodeError { msg } => WebhookError::DecodeFailed(msg),
             AppendWebhookError::UnknownWebhook {
                 database,
                 schema,
@@ -415,6 +448,7 @@ impl IntoResponse for WebhookError {
                 (StatusCode::NOT_FOUND, e.to_string()).into_response()
             }
             e @ WebhookError::InvalidBody { .. }
+            | e @ WebhookError::DecodeFailed(_)
             | e @ WebhookError::ValidationFailed
             | e @ WebhookError::ValidationError => {
                 (StatusCode::BAD_REQUEST, e.to_string()).into_response()
@@ -449,13 +483,13 @@ mod tests {
     use http::StatusCode;
     use mz_adapter::AppendWebhookError;
     use mz_ore::assert_none;
-    use mz_repr::{GlobalId, Row};
+    use mz_repr::{Datum, GlobalId, Row};
     use mz_sql::plan::{WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders};
     use mz_storage_types::controller::StorageError;
     use proptest::prelude::*;
     use proptest::strategy::Union;
 
-    use super::{WebhookError, filter_headers, pack_rows};
+    use super::{BodyRow, WebhookError, filter_headers, pack_header, pack_rows};
 
     // TODO(parkmycar): Move this strategy to `ore`?
     fn arbitrary_json() -> impl Strategy<Value = serde_json::Value> {
@@ -498,6 +532,33 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::NOT_FOUND);
     }
 
+    #[mz_ore::test]
+    fn smoke_test_decode_error_response_status() {
+        let resp = WebhookError::from(AppendWebhookError::DecodeError {
+            msg: "null value in column \"id\"".to_string(),
+        })
+        .into_response();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[mz_ore::test]
+    fn smoke_test_pack_header_decoded_body() {
+        let headers = BTreeMap::from([("x-id".to_string(), "1".to_string())]);
+        let mut header_tys = WebhookHeaders::default();
+        header_tys.header_column = Some(WebhookHeaderFilters::default());
+        header_tys
+            .mapped_headers
+            .insert(3, ("x-id".to_string(), false));
+
+        // Two decoded body columns, followed by the headers.
+        let body_row = BodyRow::new(Row::pack_slice(&[Datum::Int32(1), Datum::String("a")]), 2);
+        let row = pack_header(body_row, &headers, &header_tys).expect("valid headers");
+        let datums = row.unpack();
+        assert_eq!(datums.len(), 4);
+        assert_eq!(datums[0], Datum::Int32(1));
+        assert_eq!(datums[3], Datum::String("1"));
+    }
+
     #[mz_ore::test]
     fn smoke_test_filter_headers() {
         let block = BTreeSet::from(["foo".to_string()]);
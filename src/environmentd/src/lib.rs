@@ -39,7 +39,7 @@ use mz_adapter_types::dyncfgs::{
     WITH_0DT_DEPLOYMENT_MAX_WAIT,
 };
 use mz_auth::password::Password;
-use mz_authenticator::GenericOidcAuthenticator;
+use mz_authenticator::{GenericOidcAuthenticator, LdapAuthenticator};
 use mz_build_info::{BuildInfo, build_info};
 use mz_catalog::config::ClusterReplicaSizeMap;
 use mz_catalog::durable::BootstrapArgs;
@@ -107,6 +107,8 @@ pub struct Config {
     pub tls_reload_certs: ReloadTrigger,
     /// Password of the mz_system user.
     pub external_login_password_mz_system: Option<Password>,
+    /// Password of the LDAP service account that searches for users.
+    pub ldap_search_bind_password: Option<Password>,
     /// Frontegg JWT authenticator.
     pub frontegg: Option<FronteggAuthenticator>,
     /// Frontegg workspace URL advertised in MCP OAuth discovery.
@@ -278,6 +280,7 @@ impl Listener<SqlListenerConfig> {
         frontegg: Option<FronteggAuthenticator>,
         adapter_client: AdapterClient,
         oidc: GenericOidcAuthenticator,
+        ldap: LdapAuthenticator,
         metrics: MetricsConfig,
         helm_chart_version: Option<String>,
     ) -> ListenerHandle {
@@ -299,6 +302,7 @@ impl Listener<SqlListenerConfig> {
                 authenticator_kind: self.config.authenticator_kind,
                 frontegg,
                 oidc,
+                ldap,
                 metrics,
                 active_connection_counter,
                 helm_chart_version,
@@ -411,6 +415,8 @@ impl Listeners {
 
         let (authenticator_oidc_tx, authenticator_oidc_rx) = oneshot::channel();
         let authenticator_oidc_rx = authenticator_oidc_rx.shared();
+        let (authenticator_ldap_tx, authenticator_ldap_rx) = oneshot::channel();
+        let authenticator_ldap_rx = authenticator_ldap_rx.shared();
         let (adapter_client_tx, adapter_client_rx) = oneshot::channel();
         let adapter_client_rx = adapter_client_rx.shared();
 
@@ -439,6 +445,7 @@ impl Listeners {
                 authenticator_kind,
                 frontegg: config.frontegg.clone(),
                 oidc_rx: authenticator_oidc_rx.clone(),
+                ldap_rx: authenticator_ldap_rx.clone(),
                 allowed_origin: config.cors_allowed_origin.clone(),
                 allowed_origin_list: config.cors_allowed_origin_list.clone(),
                 concurrent_webhook_req: webhook_concurrency_limit.semaphore(),
@@ -819,8 +826,13 @@ impl Listeners {
         .instrument(info_span!("adapter::serve"))
         .await?;
 
-        // Initialize the OIDC authenticator, shared between the HTTP and SQL servers.
+        // Initialize the OIDC and LDAP authenticators, shared between the HTTP
+        // and SQL servers.
         let oidc = GenericOidcAuthenticator::new(adapter_client.clone());
+        let ldap = LdapAuthenticator::new(
+            adapter_client.clone(),
+            config.ldap_search_bind_password.clone(),
+        );
 
         info!(
             "startup: envd serve: coordinator init complete in {:?}",
@@ -830,10 +842,13 @@ impl Listeners {
         let serve_postamble_start = Instant::now();
         info!("startup: envd serve: postamble beginning");
 
-        // Send adapter client and OIDC authenticator to the HTTP servers.
+        // Send adapter client and OIDC and LDAP authenticators to the HTTP servers.
         authenticator_oidc_tx
             .send(oidc.clone())
             .expect("rx known to be live");
+        authenticator_ldap_tx
+            .send(ldap.clone())
+            .expect("rx known to be live");
         adapter_client_tx
             .send(adapter_client.clone())
             .expect("internal HTTP server should not drop first");
@@ -853,6 +868,7 @@ impl Listeners {
                         config.frontegg.clone(),
                         adapter_client.clone(),
                         oidc.clone(),
+                        ldap.clone(),
                         metrics.clone(),
                         config.helm_chart_version.clone(),
                     )
@@ -81,7 +81,7 @@ use postgres_openssl::MakeTlsConnector;
 use tempfile::TempDir;
 use tokio::net::TcpListener;
 use tokio::runtime::Runtime;
-use tokio_postgres::config::{Host, SslMode};
+use tokio_postgres::config::{ChannelBinding, Host, SslMode};
 use tokio_postgres::{AsyncMessage, Client};
 use tokio_stream::wrappers::TcpListenerStream;
 use tower_http::cors::AllowOrigin;
@@ -299,6 +299,7 @@ impl TestHarness {
         self.tls = Some(TlsCertConfig {
             cert: cert_path.into(),
             key: key_path.into(),
+            client_ca: None,
         });
         for (_, listener) in &mut self.listeners_config.sql {
             listener.enable_tls = true;
@@ -381,6 +382,66 @@ impl TestHarness {
         self
     }
 
+    /// Authenticates external connections by client certificate, verified
+    /// against the CA bundle at `client_ca_path`.
+    ///
+    /// Must be called after [`TestHarness::with_tls`].
+    pub fn with_certificate_auth(mut self, client_ca_path: impl Into<PathBuf>) -> Self {
+        self.tls
+            .as_mut()
+            .expect("certificate authentication requires TLS")
+            .client_ca = Some(client_ca_path.into());
+        self.listeners_config = ListenersConfig {
+            sql: btreemap! {
+                "external".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Certificate,
+                    allowed_roles: AllowedRoles::Normal,
+                    enable_tls: true,
+                },
+                "internal".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::None,
+                    allowed_roles: AllowedRoles::NormalAndInternal,
+                    enable_tls: false,
+                },
+            },
+            http: btreemap! {
+                "external".to_owned() => HttpListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Certificate,
+                    enable_tls: true,
+                    routes: HttpRoutesEnabled {
+                        base: RouteGroup::Enabled(AllowedRoles::Normal),
+                        webhook: RouteGroup::Enabled(AllowedRoles::Normal),
+                        internal: RouteGroup::Disabled,
+                        metrics: RouteGroup::Disabled,
+                        profiling: RouteGroup::Disabled,
+                        mcp_agent: RouteGroup::Disabled,
+                        mcp_developer: RouteGroup::Disabled,
+                        console_config: RouteGroup::Enabled(AllowedRoles::Normal),
+                    },
+                },
+                "internal".to_owned() => HttpListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::None,
+                    enable_tls: false,
+                    routes: HttpRoutesEnabled {
+                        base: RouteGroup::Enabled(AllowedRoles::NormalAndInternal),
+                        webhook: RouteGroup::Enabled(AllowedRoles::NormalAndInternal),
+                        internal: RouteGroup::Enabled(AllowedRoles::NormalAndInternal),
+                        metrics: RouteGroup::Enabled(AllowedRoles::NormalAndInternal),
+                        profiling: RouteGroup::Enabled(AllowedRoles::NormalAndInternal),
+                        mcp_agent: RouteGroup::Disabled,
+                        mcp_developer: RouteGroup::Disabled,
+                        console_config: RouteGroup::Enabled(AllowedRoles::NormalAndInternal),
+                    },
+                },
+            },
+        };
+        self
+    }
+
     pub fn with_oidc_auth(
         mut self,
         issuer: Option<String>,
@@ -944,6 +1005,7 @@ impl Listeners {
                 helm_chart_version: None,
                 license_key: ValidatedLicenseKey::for_tests(),
                 external_login_password_mz_system: config.external_login_password_mz_system,
+                ldap_search_bind_password: None,
                 force_builtin_schema_migration: None,
             })
             .await?;
@@ -1110,6 +1172,12 @@ impl<'s, T, H> ConnectBuilder<'s, T, H> {
         self
     }
 
+    /// Set the [`ChannelBinding`] mode to be used with the resulting connection.
+    pub fn channel_binding(mut self, mode: ChannelBinding) -> Self {
+        self.pg_config.channel_binding(mode);
+        self
+    }
+
     /// Set the user for the pgwire connection.
     pub fn user(mut self, user: &str) -> Self {
         self.pg_config.user(user);
@@ -18,6 +18,7 @@ use std::future::IntoFuture;
 use std::io::{Read, Write};
 use std::net::{IpAddr, Ipv4Addr, TcpStream};
 use std::num::NonZeroUsize;
+use std::path::Path;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -56,8 +57,10 @@ use mz_ore::{assert_contains, assert_err, assert_none, assert_ok};
 use mz_sql::names::PUBLIC_ROLE_NAME;
 use mz_sql::session::user::{HTTP_DEFAULT_USER, SYSTEM_USER};
 use openssl::error::ErrorStack;
-use openssl::ssl::{SslConnector, SslConnectorBuilder, SslMethod, SslOptions, SslVerifyMode};
-use postgres::config::SslMode;
+use openssl::ssl::{
+    SslConnector, SslConnectorBuilder, SslFiletype, SslMethod, SslOptions, SslVerifyMode,
+};
+use postgres::config::{ChannelBinding, SslMode};
 use postgres::error::SqlState;
 use serde::Deserialize;
 use serde_json::json;
@@ -4333,6 +4336,364 @@ async fn test_password_auth() {
         .unwrap();
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+#[allow(clippy::disallowed_methods)]
+async fn test_auth_audit_events() {
+    let server = test_util::TestHarness::default()
+        .with_system_parameter_default("enable_password_auth".to_string(), "true".to_string())
+        .with_system_parameter_default("enable_auth_audit_events".to_string(), "true".to_string())
+        .with_system_parameter_default(
+            "auth_audit_failures_per_window".to_string(),
+            "2".to_string(),
+        )
+        .with_system_parameter_default("auth_audit_failure_window".to_string(), "10s".to_string())
+        .with_password_auth(Password("mz_system_password".to_owned()))
+        .start()
+        .await;
+
+    let mz_system_client = server
+        .connect()
+        .no_tls()
+        .user("mz_system")
+        .password("mz_system_password")
+        .await
+        .unwrap();
+    mz_system_client
+        .execute("CREATE ROLE foo WITH LOGIN PASSWORD 'bar'", &[])
+        .await
+        .unwrap();
+
+    // HTTP requests run in sessions of their own, which are not logins. They
+    // are made first, so that their events, if any, are written no later than
+    // the events below.
+    let http_client =
+        hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build_http();
+    let login_response = http_client
+        .request(
+            Request::post(format!("http://{}/api/login", server.http_local_addr()))
+                .header("Content-Type", "application/json")
+                .body(r#"{"username":"foo","password":"bar"}"#.to_owned())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let session_cookie = login_response
+        .headers()
+        .get(SET_COOKIE)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .split("; ")
+        .find(|v| v.starts_with("mz_session="))
+        .unwrap()
+        .to_owned();
+    let sql_response = http_client
+        .request(
+            Request::post(format!("http://{}/api/sql", server.http_local_addr()))
+                .header("Content-Type", "application/json")
+                .header(COOKIE, session_cookie)
+                .body(r#"{"query":"SELECT 1"}"#.to_owned())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(sql_response.status(), StatusCode::OK);
+
+    let foo_client = server
+        .connect()
+        .no_tls()
+        .user("foo")
+        .password("bar")
+        .application_name("audit_app")
+        .await
+        .unwrap();
+    drop(foo_client);
+
+    for _ in 0..5 {
+        assert_err!(
+            server
+                .connect()
+                .no_tls()
+                .user("foo")
+                .password("wrong")
+                .application_name("audit_app")
+                .await
+        );
+    }
+
+    // Events are written in the background, and the suppressed failures only
+    // once the rate-limiting window closes.
+    let events = Retry::default()
+        .max_duration(Duration::from_secs(60))
+        .retry_async(|_| async {
+            let rows = mz_system_client
+                .query(
+                    "SELECT event_type, object_type, \"user\", details \
+                     FROM mz_audit_events \
+                     WHERE event_type IN ('login', 'logout', 'auth-failure') \
+                     AND (\"user\" = 'foo' OR details->>'role_name' = 'foo') \
+                     ORDER BY id",
+                    &[],
+                )
+                .await
+                .unwrap();
+            let events: Vec<_> = rows
+                .iter()
+                .map(|row| {
+                    (
+                        row.get::<_, String>("event_type"),
+                        row.get::<_, String>("object_type"),
+                        row.get::<_, Option<String>>("user"),
+                        row.get::<_, serde_json::Value>("details"),
+                    )
+                })
+                .collect();
+            if events.len() == 5 {
+                Ok(events)
+            } else {
+                Err(events)
+            }
+        })
+        .await
+        .unwrap();
+
+    // The logout races with the failed attempts, so only the order of
+    // events of the same type is deterministic.
+    let of_type = |ty: &str| -> Vec<_> {
+        events
+            .iter()
+            .filter(|(event_type, ..)| event_type == ty)
+            .collect()
+    };
+
+    let logins = of_type("login");
+    let [(_, object_type, user, login)] = logins[..] else {
+        panic!("expected one login: {events:?}");
+    };
+    assert_eq!(object_type, "role");
+    assert_eq!(user.as_deref(), Some("foo"));
+    assert_eq!(login["authenticator_kind"], "password");
+    assert_eq!(login["application_name"], "audit_app");
+    assert!(login["client_ip"].is_string());
+
+    let logouts = of_type("logout");
+    let [(_, _, user, logout)] = logouts[..] else {
+        panic!("expected one logout: {events:?}");
+    };
+    assert_eq!(user.as_deref(), Some("foo"));
+    assert_eq!(logout["session_id"], login["session_id"]);
+
+    let suppressed: Vec<_> = of_type("auth-failure")
+        .into_iter()
+        .map(|(_, object_type, user, details)| {
+            assert_eq!(object_type, "role");
+            assert_none!(user);
+            assert_eq!(details["authenticator_kind"], "password");
+            assert_eq!(details["application_name"], "audit_app");
+            details["suppressed_failures"].as_u64().unwrap()
+        })
+        .collect();
+    assert_eq!(suppressed, [0, 0, 3]);
+
+    // Internal users are the system itself, and their sessions are not
+    // logins either.
+    let internal_sessions: i64 = mz_system_client
+        .query_one(
+            "SELECT count(*) FROM mz_audit_events \
+             WHERE event_type IN ('login', 'logout') AND \"user\" = 'mz_system'",
+            &[],
+        )
+        .await
+        .unwrap()
+        .get(0);
+    assert_eq!(internal_sessions, 0);
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+#[allow(clippy::disallowed_methods)]
+async fn test_auth_certificate() {
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+    let (app_cert, app_key) = ca.request_client_cert("svc-app").unwrap();
+    let (unknown_cert, unknown_key) = ca.request_client_cert("svc-unknown").unwrap();
+    let rogue_ca = Ca::new_root("rogue ca").unwrap();
+    let (rogue_cert, rogue_key) = rogue_ca.request_client_cert("svc-app").unwrap();
+
+    let server = test_util::TestHarness::default()
+        .with_tls(server_cert, server_key)
+        .with_certificate_auth(ca.ca_cert_path())
+        .start()
+        .await;
+    let admin_client = server.connect().internal().await.unwrap();
+    admin_client
+        .batch_execute("CREATE ROLE \"svc-app\" LOGIN; CREATE ROLE app LOGIN")
+        .await
+        .unwrap();
+
+    fn with_client_cert<'a>(
+        cert: &'a Path,
+        key: &'a Path,
+    ) -> Box<dyn Fn(&mut SslConnectorBuilder) -> Result<(), ErrorStack> + 'a> {
+        Box::new(move |b| {
+            b.set_verify(SslVerifyMode::NONE);
+            b.set_certificate_file(cert, SslFiletype::PEM)?;
+            b.set_private_key_file(key, SslFiletype::PEM)
+        })
+    }
+    let no_headers = HeaderMap::new();
+
+    run_tests(
+        "Certificate auth",
+        &server,
+        &[
+            // A certificate signed by the client CA maps to the role named by
+            // its common name.
+            TestCase::Pgwire {
+                user_to_auth_as: "svc-app",
+                user_reported_by_system: "svc-app",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: with_client_cert(&app_cert, &app_key),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "svc-app",
+                user_reported_by_system: "svc-app",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: with_client_cert(&app_cert, &app_key),
+                assert: Assert::Success,
+            },
+            TestCase::Ws {
+                user_reported_by_system: "svc-app",
+                auth: &WebSocketAuth::OptionsOnly {
+                    options: BTreeMap::default(),
+                },
+                headers: &no_headers,
+                configure: with_client_cert(&app_cert, &app_key),
+                assert: Assert::Success,
+            },
+            // The requested user must be the one the certificate maps to.
+            TestCase::Pgwire {
+                user_to_auth_as: "materialize",
+                user_reported_by_system: "materialize",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: with_client_cert(&app_cert, &app_key),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(err.message(), "wrong user");
+                    assert_eq!(*err.code(), SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+                })),
+            },
+            // Roles are not auto-provisioned.
+            TestCase::Pgwire {
+                user_to_auth_as: "svc-unknown",
+                user_reported_by_system: "svc-unknown",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: with_client_cert(&unknown_cert, &unknown_key),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(err.message(), "role does not exist");
+                    assert_eq!(*err.code(), SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "svc-unknown",
+                user_reported_by_system: "svc-unknown",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: with_client_cert(&unknown_cert, &unknown_key),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            // Connections without a certificate, or with one from another CA,
+            // fail the handshake.
+            TestCase::Pgwire {
+                user_to_auth_as: "svc-app",
+                user_reported_by_system: "svc-app",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Err(Box::new(|err| {
+                    assert_none!(err.as_db_error(), "expected a TLS error, got {err}");
+                })),
+            },
+            TestCase::Pgwire {
+                user_to_auth_as: "svc-app",
+                user_reported_by_system: "svc-app",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: with_client_cert(&rogue_cert, &rogue_key),
+                assert: Assert::Err(Box::new(|err| {
+                    assert_none!(err.as_db_error(), "expected a TLS error, got {err}");
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "svc-app",
+                user_reported_by_system: "svc-app",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, None, "expected a TLS error, got {message}");
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "svc-app",
+                user_reported_by_system: "svc-app",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: with_client_cert(&rogue_cert, &rogue_key),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, None, "expected a TLS error, got {message}");
+                })),
+            },
+        ],
+    )
+    .await;
+
+    // A role pattern with a capture group maps only the captured part.
+    admin_client
+        .batch_execute("ALTER SYSTEM SET cert_auth_role_pattern = 'svc-(.+)'")
+        .await
+        .unwrap();
+    run_tests(
+        "Certificate auth with role pattern",
+        &server,
+        &[
+            TestCase::Pgwire {
+                user_to_auth_as: "app",
+                user_reported_by_system: "app",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: with_client_cert(&app_cert, &app_key),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "app",
+                user_reported_by_system: "app",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: with_client_cert(&app_cert, &app_key),
+                assert: Assert::Success,
+            },
+        ],
+    )
+    .await;
+}
+
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
 #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
 #[allow(clippy::disallowed_methods)]
@@ -4436,6 +4797,79 @@ async fn test_sasl_auth_failure() {
     assert_err!(external_client);
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+#[allow(clippy::disallowed_methods)]
+async fn test_sasl_auth_channel_binding() {
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+
+    let server = test_util::TestHarness::default()
+        .with_system_parameter_default("enable_password_auth".to_string(), "true".to_string())
+        .with_tls(server_cert, server_key)
+        .with_sasl_scram_auth(Password("mz_system_password".to_owned()))
+        .start()
+        .await;
+
+    let tls = || {
+        make_pg_tls(Box::new(|b: &mut SslConnectorBuilder| {
+            Ok(b.set_verify(SslVerifyMode::NONE))
+        }))
+    };
+
+    let mz_system_client = server
+        .connect()
+        .ssl_mode(SslMode::Require)
+        .with_tls(tls())
+        .user("mz_system")
+        .password("mz_system_password")
+        .await
+        .unwrap();
+    mz_system_client
+        .execute("CREATE ROLE foo WITH LOGIN PASSWORD 'bar'", &[])
+        .await
+        .unwrap();
+
+    // Over TLS the server advertises SCRAM-SHA-256-PLUS, so clients that
+    // require channel binding, prefer it, or disable it can all connect.
+    for channel_binding in [
+        ChannelBinding::Require,
+        ChannelBinding::Prefer,
+        ChannelBinding::Disable,
+    ] {
+        let client = server
+            .connect()
+            .ssl_mode(SslMode::Require)
+            .with_tls(tls())
+            .channel_binding(channel_binding)
+            .user("foo")
+            .password("bar")
+            .await
+            .unwrap();
+        assert_eq!(
+            client
+                .query_one("SELECT current_user", &[])
+                .await
+                .unwrap()
+                .get::<_, String>(0),
+            "foo"
+        );
+    }
+
+    // Channel binding still protects the password check.
+    let result = server
+        .connect()
+        .ssl_mode(SslMode::Require)
+        .with_tls(tls())
+        .channel_binding(ChannelBinding::Require)
+        .user("foo")
+        .password("wrong_password")
+        .await;
+    assert_err!(result);
+}
+
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
 #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
 #[allow(clippy::disallowed_methods)]
@@ -0,0 +1,163 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! HyperLogLog sketches, which estimate the number of distinct values of a
+//! multiset in a fixed amount of space.
+//!
+//! A value is hashed to 64 bits. The first [`PRECISION`] bits of the hash
+//! select one of the sketch's registers, and the number of leading zeros of the
+//! remaining bits, plus one, is the rank of the value. A register holds the
+//! maximum rank of the values that select it. This makes a sketch independent
+//! of the multiplicities of its values, and lets sketches be merged by taking
+//! the maximum rank of each register.
+//!
+//! Sketches are stored as `bytea`s that hold [`PRECISION`] followed by the rank
+//! of each register.
+
+use mz_ore::cast::CastLossy;
+use mz_repr::{Datum, Row};
+
+use crate::EvalError;
+
+/// The number of bits of a hash that select a register.
+const PRECISION: u8 = 12;
+
+/// The number of registers of a sketch.
+const REGISTERS: usize = 1 << PRECISION;
+
+/// Returns the register that `value` selects, along with its rank.
+pub(crate) fn register_and_rank(value: Datum) -> (u16, u8) {
+    // Sketches are stored, so the hash of a value must not change between
+    // releases. We hash the encoding of the value in a `Row`, which is stable.
+    let hash = seahash::hash(Row::pack_slice(&[value]).data());
+    let register = u16::try_from(hash >> (64 - PRECISION)).expect("PRECISION bits fit in u16");
+    // Set the bit past the remaining bits, so that the rank of a value whose
+    // remaining bits are all zero is the number of remaining bits plus one.
+    let rest = (hash << PRECISION) | (1 << (PRECISION - 1));
+    let rank = u8::try_from(rest.leading_zeros() + 1).expect("at most 64 leading zeros");
+    (register, rank)
+}
+
+/// Returns an error if `sketch` isn't a sketch.
+pub(crate) fn check_sketch(sketch: &[u8]) -> Result<(), EvalError> {
+    if sketch.len() != REGISTERS + 1 || sketch[0] != PRECISION {
+        return Err(EvalError::InvalidParameterValue(
+            "invalid HyperLogLog sketch".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the registers of `sketch` that hold a rank, along with the rank.
+/// Returns nothing if `sketch` isn't a sketch (see [`check_sketch`]).
+pub(crate) fn sketch_ranks(sketch: &[u8]) -> impl Iterator<Item = (u16, u8)> + '_ {
+    let registers = match check_sketch(sketch) {
+        Ok(()) => &sketch[1..],
+        Err(_) => &[],
+    };
+    registers
+        .iter()
+        .zip(0..)
+        .filter(|(rank, _)| **rank > 0)
+        .map(|(rank, register)| (register, *rank))
+}
+
+/// Collects the ranks of registers into the registers of a sketch, keeping the
+/// maximum rank of each register. Returns `None` if there are no ranks.
+pub(crate) fn registers<I>(ranks: I) -> Option<Vec<u8>>
+where
+    I: IntoIterator<Item = (u16, u8)>,
+{
+    let mut registers = None;
+    for (register, rank) in ranks {
+        let registers = registers.get_or_insert_with(|| vec![0; REGISTERS]);
+        let register = &mut registers[usize::from(register)];
+        *register = std::cmp::max(*register, rank);
+    }
+    registers
+}
+
+/// Encodes `registers` as a sketch.
+pub(crate) fn encode(registers: &[u8]) -> Vec<u8> {
+    std::iter::once(PRECISION)
+        .chain(registers.iter().copied())
+        .collect()
+}
+
+/// Estimates the number of distinct values of the sketch with `registers`.
+pub(crate) fn estimate(registers: &[u8]) -> i64 {
+    let m = f64::cast_lossy(REGISTERS);
+    let alpha = 0.7213 / (1.0 + 1.079 / m);
+    let sum: f64 = registers
+        .iter()
+        .map(|rank| (-f64::from(*rank)).exp2())
+        .sum();
+    let estimate = alpha * m * m / sum;
+    // Small numbers of distinct values are estimated better by the number of
+    // empty registers. (Our hashes are too wide for collisions to matter at
+    // large numbers of distinct values.)
+    let empty = registers.iter().filter(|rank| **rank == 0).count();
+    let estimate = if estimate <= 2.5 * m && empty > 0 {
+        m * (m / f64::cast_lossy(empty)).ln()
+    } else {
+        estimate
+    };
+    i64::cast_lossy(estimate.round())
+}
+
+/// Estimates the number of distinct values of `sketch`.
+pub(crate) fn sketch_estimate(sketch: &[u8]) -> Result<i64, EvalError> {
+    check_sketch(sketch)?;
+    Ok(estimate(&sketch[1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sketch_of(values: impl IntoIterator<Item = i64>) -> Vec<u8> {
+        let ranks = values
+            .into_iter()
+            .map(|value| register_and_rank(Datum::Int64(value)));
+        encode(&registers(ranks).unwrap())
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn test_estimate() {
+        for n in [1, 10, 100, 1_000, 10_000, 100_000] {
+            let estimate = sketch_estimate(&sketch_of(0..n)).unwrap();
+            // The standard error with 4096 registers is about 1.6%.
+            let tolerance = std::cmp::max(n / 20, 1);
+            assert!(
+                (estimate - n).abs() <= tolerance,
+                "estimated {estimate} distinct values for {n}"
+            );
+        }
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn test_merge() {
+        let merged =
+            registers(sketch_ranks(&sketch_of(0..600)).chain(sketch_ranks(&sketch_of(400..1_000))))
+                .unwrap();
+        assert_eq!(encode(&merged), sketch_of(0..1_000));
+        assert_eq!(sketch_of((0..1_000).chain(0..1_000)), sketch_of(0..1_000));
+    }
+
+    #[mz_ore::test]
+    fn test_check_sketch() {
+        assert!(check_sketch(&sketch_of([1])).is_ok());
+        assert!(check_sketch(&[]).is_err());
+        assert!(check_sketch(&[PRECISION]).is_err());
+        assert!(check_sketch(&vec![PRECISION + 1; REGISTERS + 1]).is_err());
+        assert_eq!(sketch_ranks(&[PRECISION]).count(), 0);
+    }
+}
@@ -17,6 +17,7 @@ use std::ops::Deref;
 use mz_repr::GlobalId;
 use serde::{Deserialize, Serialize};
 
+mod hll;
 mod id;
 mod interpret;
 mod linear;
@@ -2528,6 +2528,9 @@ impl AggregateExpr {
             | AggregateFunc::MinTime
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketch
+            | AggregateFunc::HllMerge
             | AggregateFunc::Dummy => self.expr.is_literal(),
             AggregateFunc::Count => self.expr.is_literal_null(),
             AggregateFunc::SumInt16
@@ -2545,18 +2548,49 @@ impl AggregateExpr {
             | AggregateFunc::ArrayConcat { .. }
             | AggregateFunc::ListConcat { .. }
             | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
             | AggregateFunc::LagLead { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => self.expr.is_literal_err(),
         }
     }
 
+    /// Returns whether `on_unique` can compute `self`. This is not the case for
+    /// `percentile_cont` and `percentile_disc` with an array of fractions, and
+    /// for `hll_sketch`, also as a window aggregate.
+    pub fn can_compute_on_unique(&self, input_type: &[ReprColumnType]) -> bool {
+        match &self.func {
+            AggregateFunc::HllSketch => false,
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate, ..
+            } => !matches!(**wrapped_aggregate, AggregateFunc::HllSketch),
+            AggregateFunc::FusedWindowAggregate {
+                wrapped_aggregates, ..
+            } => !wrapped_aggregates.contains(&AggregateFunc::HllSketch),
+            AggregateFunc::PercentileCont { .. } | AggregateFunc::PercentileDisc { .. } => {
+                match self.expr.typ(input_type).scalar_type {
+                    ReprScalarType::Record { fields, .. } => {
+                        !matches!(fields[0].scalar_type, ReprScalarType::Array(_))
+                    }
+                    _ => true,
+                }
+            }
+            _ => true,
+        }
+    }
+
     /// Returns an expression that computes `self` on a group that has exactly one row.
     /// Instead of performing a `Reduce` with `self`, one can perform a `Map` with the expression
     /// returned by `on_unique`, which is cheaper. (See `ReduceElision`.)
@@ -2660,20 +2694,77 @@ impl AggregateExpr {
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)))
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
 
+            // ApproxCountDistinct is exact on a single row, like Count.
+            AggregateFunc::ApproxCountDistinct => self
+                .expr
+                .clone()
+                .call_unary(UnaryFunc::IsNull(crate::func::IsNull))
+                .if_then_else(
+                    MirScalarExpr::literal_ok(Datum::Int64(0), ReprScalarType::Int64),
+                    MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+                ),
+
+            // HllMerge of a single sketch is that sketch. (HllSketch can't be
+            // computed on a single row, see `can_compute_on_unique`.)
+            AggregateFunc::HllMerge => self.expr.clone(),
+
+            // Mode takes a record of the value, which is its result on a single row.
+            AggregateFunc::Mode { .. } => self
+                .expr
+                .clone()
+                .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
+
+            // PercentileCont and PercentileDisc take a record of the fraction and the value, and
+            // output the value unless the fraction is null. (This doesn't hold for an array of
+            // fractions, see `can_compute_on_unique`.)
+            AggregateFunc::PercentileCont { .. } | AggregateFunc::PercentileDisc { .. } => {
+                let fraction = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+                let value = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+                fraction.call_is_null().if_then_else(
+                    MirScalarExpr::literal_null(self.typ(input_type).scalar_type),
+                    value,
+                )
+            }
+
             // ListConcat and ArrayConcat take a single level of records and output a list containing exactly 1 element
             AggregateFunc::ListConcat { .. } | AggregateFunc::ArrayConcat { .. } => self
                 .expr
                 .clone()
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
 
-            // RowNumber, Rank, DenseRank take a list of records and output a list containing exactly 1 element
-            AggregateFunc::RowNumber { .. } => {
-                self.on_unique_ranking_window_funcs(input_type, "?row_number?")
-            }
-            AggregateFunc::Rank { .. } => self.on_unique_ranking_window_funcs(input_type, "?rank?"),
-            AggregateFunc::DenseRank { .. } => {
-                self.on_unique_ranking_window_funcs(input_type, "?dense_rank?")
-            }
+            // RowNumber, Rank, DenseRank, PercentRank, CumeDist take a list of records and output a
+            // list containing exactly 1 element
+            AggregateFunc::RowNumber { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+                "?row_number?",
+            ),
+            AggregateFunc::Rank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+                "?rank?",
+            ),
+            AggregateFunc::DenseRank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+                "?dense_rank?",
+            ),
+            AggregateFunc::PercentRank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                MirScalarExpr::literal_ok(Datum::from(0.0), ReprScalarType::Float64),
+                "?percent_rank?",
+            ),
+            AggregateFunc::CumeDist { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                MirScalarExpr::literal_ok(Datum::from(1.0), ReprScalarType::Float64),
+                "?cume_dist?",
+            ),
 
             // The input type for LagLead is ((OriginalRow, (InputValue, Offset, Default)), OrderByExprs...)
             AggregateFunc::LagLead { lag_lead, .. } => {
@@ -2802,6 +2893,84 @@ impl AggregateExpr {
                 )
             }
 
+            // The input type for Ntile is ((OriginalRow, InputValue), OrderByExprs...)
+            AggregateFunc::Ntile { .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type_with_orig_row = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the input value
+                let arg = tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                let (result_expr, column_name) = Self::on_unique_ntile(arg);
+
+                MirScalarExpr::call_variadic(
+                    ListCreate {
+                        elem_type: SqlScalarType::from_repr(&return_type_with_orig_row),
+                    },
+                    vec![MirScalarExpr::call_variadic(
+                        RecordCreate {
+                            field_names: vec![column_name, ColumnName::from("?record?")],
+                        },
+                        vec![result_expr, original_row],
+                    )],
+                )
+            }
+
+            // The input type for NthValue is ((OriginalRow, (InputValue, N)), OrderByExprs...)
+            AggregateFunc::NthValue { window_frame, .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type_with_orig_row = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+                let nth_value_return_type =
+                    return_type_with_orig_row.unwrap_record_element_type()[0].clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the encoded args
+                let encoded_args =
+                    tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                let (result_expr, column_name) =
+                    Self::on_unique_nth_value(window_frame, encoded_args, nth_value_return_type);
+
+                MirScalarExpr::call_variadic(
+                    ListCreate {
+                        elem_type: SqlScalarType::from_repr(&return_type_with_orig_row),
+                    },
+                    vec![MirScalarExpr::call_variadic(
+                        RecordCreate {
+                            field_names: vec![column_name, ColumnName::from("?record?")],
+                        },
+                        vec![result_expr, original_row],
+                    )],
+                )
+            }
+
             // The input type for window aggs is ((OriginalRow, InputValue), OrderByExprs...)
             // See an example MIR in `window_func_applied_to`.
             AggregateFunc::WindowAggregate {
@@ -2991,6 +3160,21 @@ impl AggregateExpr {
                                 return_type_for_func,
                             )
                         }
+                        AggregateFunc::Ntile { order_by } => {
+                            assert_eq!(order_by, outer_order_by);
+                            Self::on_unique_ntile(args_for_func)
+                        }
+                        AggregateFunc::NthValue {
+                            window_frame,
+                            order_by,
+                        } => {
+                            assert_eq!(order_by, outer_order_by);
+                            Self::on_unique_nth_value(
+                                window_frame,
+                                args_for_func,
+                                return_type_for_func,
+                            )
+                        }
                         _ => panic!("unknown function in FusedValueWindowFunc"),
                     };
                     func_result_exprs.push(result);
@@ -3065,10 +3249,12 @@ impl AggregateExpr {
         }
     }
 
-    /// `on_unique` for ROW_NUMBER, RANK, DENSE_RANK
+    /// `on_unique` for ROW_NUMBER, RANK, DENSE_RANK, PERCENT_RANK, CUME_DIST. `result` is the
+    /// result of the function on a window partition with a single row.
     fn on_unique_ranking_window_funcs(
         &self,
         input_type: &[ReprColumnType],
+        result: MirScalarExpr,
         col_name: &str,
     ) -> MirScalarExpr {
         let sql_input_type: Vec<SqlColumnType> =
@@ -3100,10 +3286,7 @@ impl AggregateExpr {
                 RecordCreate {
                     field_names: vec![ColumnName::from(col_name), ColumnName::from("?record?")],
                 },
-                vec![
-                    MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
-                    record,
-                ],
+                vec![result, record],
             )],
         )
     }
@@ -3159,6 +3342,40 @@ impl AggregateExpr {
         (result_expr, ColumnName::from("?first_value?"))
     }
 
+    /// `on_unique` for `ntile`
+    fn on_unique_ntile(arg: MirScalarExpr) -> (MirScalarExpr, ColumnName) {
+        // A single row is always in the first bucket, unless the number of buckets is null.
+        let result_expr = arg.call_is_null().if_then_else(
+            MirScalarExpr::literal_null(ReprScalarType::Int32),
+            MirScalarExpr::literal_ok(Datum::Int32(1), ReprScalarType::Int32),
+        );
+        (result_expr, ColumnName::from("?ntile?"))
+    }
+
+    /// `on_unique` for `nth_value`
+    fn on_unique_nth_value(
+        window_frame: &WindowFrame,
+        encoded_args: MirScalarExpr,
+        return_type: ReprScalarType,
+    ) -> (MirScalarExpr, ColumnName) {
+        // If the window frame includes the current (single) row, it's the only row of the frame,
+        // so return its value if the offset is 1, and null otherwise.
+        let result_expr = if window_frame.includes_current_row() {
+            let expr = encoded_args
+                .clone()
+                .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+            let n = encoded_args.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+            n.call_binary(
+                MirScalarExpr::literal_ok(Datum::Int32(1), ReprScalarType::Int32),
+                crate::func::Eq,
+            )
+            .if_then_else(expr, MirScalarExpr::literal_null(return_type))
+        } else {
+            MirScalarExpr::literal_null(return_type)
+        };
+        (result_expr, ColumnName::from("?nth_value?"))
+    }
+
     /// `on_unique` for window aggregations
     fn on_unique_window_agg(
         window_frame: &WindowFrame,
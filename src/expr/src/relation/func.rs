@@ -18,7 +18,7 @@ use std::{fmt, iter};
 use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
 use dec::OrderedDecimal;
 use itertools::{Either, Itertools};
-use mz_ore::cast::CastFrom;
+use mz_ore::cast::{CastFrom, CastLossy};
 
 use mz_ore::str::separated;
 use mz_ore::{soft_assert_eq_no_log, soft_assert_or_log};
@@ -38,7 +38,6 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
-use crate::EvalError;
 use crate::WindowFrameBound::{
     CurrentRow, OffsetFollowing, OffsetPreceding, UnboundedFollowing, UnboundedPreceding,
 };
@@ -48,6 +47,7 @@ use crate::relation::{
     ColumnOrder, WindowFrame, WindowFrameBound, WindowFrameUnits, compare_columns,
 };
 use crate::scalar::func::{add_timestamp_months, jsonb_stringify};
+use crate::{EvalError, hll};
 
 // TODO(jamii) be careful about overflow in sum/avg
 // see https://timely.zulipchat.com/#narrow/stream/186635-engineering/topic/additional.20work/near/163507435
@@ -271,6 +271,84 @@ where
     Datum::String(temp_storage.push_string(s))
 }
 
+/// Returns the `n`th (0-based) value of the multiset given by `values`, which
+/// are pairs of distinct values and their multiplicities.
+fn nth_value_counted<'a>(values: &[(Datum<'a>, i64)], n: i64) -> Datum<'a> {
+    let mut seen = 0;
+    for (value, count) in values {
+        seen += count;
+        if n < seen {
+            return *value;
+        }
+    }
+    unreachable!("multiset of {seen} values has no value {n}")
+}
+
+/// Returns the positions (0-based) of the values in an ordered multiset of
+/// `total` values from which `percentile_cont` (if `cont`) or
+/// `percentile_disc` of `fraction` is computed, or `None` if `fraction` is not
+/// between 0 and 1. `percentile_disc` depends on a single value, which is
+/// returned twice.
+fn percentile_positions(cont: bool, fraction: f64, total: i64) -> Option<(i64, i64)> {
+    if !(0.0..=1.0).contains(&fraction) {
+        return None;
+    }
+    if cont {
+        #[allow(clippy::as_conversions)]
+        let position = fraction * (total - 1) as f64;
+        #[allow(clippy::as_conversions)]
+        Some((position.floor() as i64, position.ceil() as i64))
+    } else {
+        // Like PostgreSQL, this is the first value whose position in the
+        // ordering is at least `fraction`.
+        #[allow(clippy::as_conversions)]
+        let row = (fraction * total as f64).ceil() as i64;
+        let position = max(row, 1) - 1;
+        Some((position, position))
+    }
+}
+
+/// Computes `percentile_cont` (if `cont`) or `percentile_disc` of `fraction`
+/// over an ordered multiset of `total` values, whose value at a position (see
+/// `percentile_positions`) is given by `nth`. Like PostgreSQL,
+/// `percentile_cont` interpolates linearly between the two values adjacent to
+/// the requested position. A `fraction` that is not between 0 and 1 yields
+/// `Datum::Null`.
+fn percentile<'a>(
+    cont: bool,
+    fraction: f64,
+    total: i64,
+    nth: &impl Fn(i64) -> Datum<'a>,
+) -> Datum<'a> {
+    let Some((first, second)) = percentile_positions(cont, fraction, total) else {
+        return Datum::Null;
+    };
+    if !cont {
+        return nth(first);
+    }
+    let first_value = nth(first).unwrap_float64();
+    if first == second {
+        return Datum::from(first_value);
+    }
+    let second_value = nth(second).unwrap_float64();
+    #[allow(clippy::as_conversions)]
+    let proportion = fraction * (total - 1) as f64 - first as f64;
+    Datum::from(first_value + (second_value - first_value) * proportion)
+}
+
+/// Computes `mode` of the non-empty multiset `values` (see
+/// `nth_value_counted`). Like PostgreSQL, ties are broken in favor of the
+/// value that comes first in the order of `values`.
+fn mode<'a>(values: &[(Datum<'a>, i64)]) -> Datum<'a> {
+    let mut mode = values[0];
+    for value in &values[1..] {
+        if value.1 > mode.1 {
+            mode = *value;
+        }
+    }
+    mode.0
+}
+
 fn jsonb_agg<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -625,6 +703,135 @@ where
     })
 }
 
+/// Sorts the input of a ranking window function by the ORDER BY expressions, and groups the
+/// original rows into peer groups, i.e., into runs of rows that are equal in the ORDER BY.
+/// The expected input is in the format of `[((OriginalRow, [EncodedArgs]), OrderByExprs...)]`
+fn order_peer_groups<'a, I>(datums: I, order_by: &[ColumnOrder]) -> Vec<Vec<Datum<'a>>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut peer_groups: Vec<(Row, Vec<Datum>)> = Vec::new();
+    for (d, order_row) in order_aggregate_datums_with_rank(datums, order_by) {
+        match peer_groups.last_mut() {
+            Some((last_order_row, peers)) if *last_order_row == order_row => {
+                peers.extend(d.unwrap_list().iter())
+            }
+            _ => peer_groups.push((order_row, d.unwrap_list().iter().collect())),
+        }
+    }
+    peer_groups.into_iter().map(|(_, peers)| peers).collect()
+}
+
+/// The expected input is in the format of `[((OriginalRow, [EncodedArgs]), OrderByExprs...)]`
+/// The output is in the format of `[result_value, original_row]`.
+/// See an example at `lag_lead`, where the input-output formats are similar.
+fn percent_rank<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let datums = percent_rank_no_list(datums, &temp_storage, order_by);
+
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
+/// Like `percent_rank`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn percent_rank_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let peer_groups = order_peer_groups(datums, order_by);
+    let count: usize = peer_groups.iter().map(|peers| peers.len()).sum();
+
+    // The relative rank of a row is `(rank - 1) / (count - 1)`, where `rank - 1` is the number of
+    // rows that precede its peer group. A single row has a relative rank of 0.
+    let mut preceding = 0;
+    let mut output = Vec::with_capacity(count);
+    for peers in peer_groups {
+        let percent_rank = if count > 1 {
+            f64::cast_lossy(preceding) / f64::cast_lossy(count - 1)
+        } else {
+            0.0
+        };
+        preceding += peers.len();
+        output.extend(peers.into_iter().map(|d| (d, percent_rank)));
+    }
+
+    callers_temp_storage.reserve(output.len());
+    output.into_iter().map(|(d, percent_rank)| {
+        callers_temp_storage.make_datum(|packer| {
+            packer.push_list_with(|packer| {
+                packer.push(Datum::from(percent_rank));
+                packer.push(d);
+            });
+        })
+    })
+}
+
+/// The expected input is in the format of `[((OriginalRow, [EncodedArgs]), OrderByExprs...)]`
+/// The output is in the format of `[result_value, original_row]`.
+/// See an example at `lag_lead`, where the input-output formats are similar.
+fn cume_dist<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let datums = cume_dist_no_list(datums, &temp_storage, order_by);
+
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
+/// Like `cume_dist`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn cume_dist_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let peer_groups = order_peer_groups(datums, order_by);
+    let count: usize = peer_groups.iter().map(|peers| peers.len()).sum();
+
+    // The cumulative distribution of a row is the fraction of the rows that precede it or are its
+    // peers.
+    let mut through = 0;
+    let mut output = Vec::with_capacity(count);
+    for peers in peer_groups {
+        through += peers.len();
+        let cume_dist = f64::cast_lossy(through) / f64::cast_lossy(count);
+        output.extend(peers.into_iter().map(|d| (d, cume_dist)));
+    }
+
+    callers_temp_storage.reserve(output.len());
+    output.into_iter().map(|(d, cume_dist)| {
+        callers_temp_storage.make_datum(|packer| {
+            packer.push_list_with(|packer| {
+                packer.push(Datum::from(cume_dist));
+                packer.push(d);
+            });
+        })
+    })
+}
+
 /// The expected input is in the format of `[((OriginalRow, EncodedArgs), OrderByExprs...)]`
 /// For example,
 ///
@@ -892,6 +1099,93 @@ fn lag_lead_inner_ignore_nulls<'a>(
     result
 }
 
+/// The expected input is in the format of [((OriginalRow, InputValue), OrderByExprs...)]
+fn ntile<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let iter = ntile_no_list(datums, &temp_storage, order_by);
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(iter);
+    })
+}
+
+/// Like `ntile`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn ntile_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the (OriginalRow, InputValue) record
+    let datums = order_aggregate_datums(datums, order_by);
+
+    // Decode the input (OriginalRow, InputValue) into separate datums
+    let (orig_rows, args): (Vec<_>, Vec<_>) = datums
+        .into_iter()
+        .map(|d| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let arg = iter.next().unwrap();
+
+            (original_row, arg)
+        })
+        .unzip();
+
+    let results = ntile_inner(args);
+
+    callers_temp_storage.reserve(results.len());
+    results
+        .into_iter()
+        .zip_eq(orig_rows)
+        .map(|(result_value, original_row)| {
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(result_value);
+                    packer.push(original_row);
+                });
+            })
+        })
+}
+
+/// `args` has the number of buckets evaluated for each row of a sorted window partition. Like in
+/// Postgres, the number of buckets is taken from the first row of the partition.
+fn ntile_inner<'a>(args: Vec<Datum<'a>>) -> Vec<Datum<'a>> {
+    let length = args.len();
+    let buckets = match args.first() {
+        Some(Datum::Int32(buckets)) => usize::try_from(*buckets).ok().filter(|b| *b > 0),
+        _ => None,
+    };
+    // Null buckets are acceptable, and always return null. (Non-positive buckets are rejected
+    // during planning.)
+    let Some(buckets) = buckets else {
+        return vec![Datum::Null; length];
+    };
+
+    // The buckets hold `length / buckets` rows, except for the first `length % buckets` buckets,
+    // which hold one more row each.
+    let (per_bucket, remainder) = (length / buckets, length % buckets);
+    let in_larger_buckets = remainder * (per_bucket + 1);
+    (0..length)
+        .map(|idx| {
+            let bucket = if idx < in_larger_buckets {
+                idx / (per_bucket + 1)
+            } else {
+                remainder + (idx - in_larger_buckets) / per_bucket
+            };
+            Datum::Int32(i32::try_from(bucket + 1).expect("at most the number of buckets"))
+        })
+        .collect()
+}
+
 /// The expected input is in the format of [((OriginalRow, InputValue), OrderByExprs...)]
 fn first_value<'a, I>(
     datums: I,
@@ -1164,11 +1458,155 @@ fn last_value_inner<'a>(
     results
 }
 
+/// The expected input is in the format of [((OriginalRow, (InputValue, N)), OrderByExprs...)]
+fn nth_value<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let iter = nth_value_no_list(datums, &temp_storage, order_by, window_frame);
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(iter);
+    })
+}
+
+/// Like `nth_value`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn nth_value_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, EncodedArgs), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the peer groups in RANGE mode
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
+
+    // Decode the input (OriginalRow, EncodedArgs) into separate datums, while keeping the OrderByRow
+    let size_hint = datums.size_hint().0;
+    let mut args = Vec::with_capacity(size_hint);
+    let mut original_rows = Vec::with_capacity(size_hint);
+    let mut order_by_rows = Vec::with_capacity(size_hint);
+    for (d, order_by_row) in datums.into_iter() {
+        let mut iter = d.unwrap_list().iter();
+        let original_row = iter.next().unwrap();
+        let encoded_args = iter.next().unwrap();
+        order_by_rows.push(order_by_row);
+        original_rows.push(original_row);
+        args.push(unwrap_nth_value_encoded_args(encoded_args));
+    }
+
+    let results = nth_value_inner(args, &order_by_rows, window_frame);
+
+    callers_temp_storage.reserve(results.len());
+    results
+        .into_iter()
+        .zip_eq(original_rows)
+        .map(|(result_value, original_row)| {
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(result_value);
+                    packer.push(original_row);
+                });
+            })
+        })
+}
+
+/// nth_value's arguments are in a record. This function unwraps this record.
+fn unwrap_nth_value_encoded_args(encoded_args: Datum) -> (Datum, Datum) {
+    let mut encoded_args_iter = encoded_args.unwrap_list().iter();
+    let (input_value, n) = (
+        encoded_args_iter.next().unwrap(),
+        encoded_args_iter.next().unwrap(),
+    );
+    (input_value, n)
+}
+
+/// Each element of `args` has the 2 arguments evaluated for a single input row.
+/// Returns the results for each input row.
+fn nth_value_inner<'a>(
+    args: Vec<(Datum<'a>, Datum<'a>)>,
+    order_by_rows: &[Row],
+    window_frame: &WindowFrame,
+) -> Vec<Datum<'a>> {
+    let length = args.len();
+    let mut results: Vec<Datum> = Vec::with_capacity(length);
+    for (idx, (_, n)) in args.iter().enumerate() {
+        // Null offsets are acceptable, and always return null. (Non-positive offsets are rejected
+        // during planning.)
+        let n = match n {
+            Datum::Int32(n) => usize::try_from(*n).ok().filter(|n| *n > 0),
+            _ => None,
+        };
+        let Some(n) = n else {
+            results.push(Datum::Null);
+            continue;
+        };
+
+        // The window frame of the current row is `frame_start..frame_end`, which is empty if
+        // `frame_start >= frame_end`.
+        let frame_start = match &window_frame.start_bound {
+            WindowFrameBound::UnboundedPreceding => 0,
+            WindowFrameBound::OffsetPreceding(offset) => {
+                idx.saturating_sub(usize::cast_from(*offset))
+            }
+            // RANGE is only supported for the default window frame (RANGE BETWEEN UNBOUNDED
+            // PRECEDING AND CURRENT ROW), so we don't need to consider peers here.
+            WindowFrameBound::CurrentRow => idx,
+            WindowFrameBound::OffsetFollowing(offset) => {
+                idx.saturating_add(usize::cast_from(*offset))
+            }
+            // Forbidden during planning
+            WindowFrameBound::UnboundedFollowing => unreachable!(),
+        };
+        let frame_end = match &window_frame.end_bound {
+            WindowFrameBound::OffsetPreceding(offset) => {
+                (idx + 1).saturating_sub(usize::cast_from(*offset))
+            }
+            WindowFrameBound::CurrentRow => match &window_frame.units {
+                WindowFrameUnits::Rows => idx + 1,
+                // When in RANGE mode, the frame ends with the last row of the peer group
+                // The peer group is the group of rows with the same ORDER BY value
+                WindowFrameUnits::Range => {
+                    idx + order_by_rows[idx..]
+                        .iter()
+                        .take_while(|row| *row == &order_by_rows[idx])
+                        .count()
+                }
+                // GROUPS is not supported, and forbidden during planning
+                WindowFrameUnits::Groups => unreachable!(),
+            },
+            WindowFrameBound::OffsetFollowing(offset) => {
+                min(idx.saturating_add(usize::cast_from(*offset)) + 1, length)
+            }
+            WindowFrameBound::UnboundedFollowing => length,
+            // Forbidden during planning
+            WindowFrameBound::UnboundedPreceding => unreachable!(),
+        };
+
+        let nth_value = match frame_start.checked_add(n - 1) {
+            Some(target_idx) if target_idx < frame_end => args[target_idx].0,
+            // The frame has fewer than `n` rows
+            _ => Datum::Null,
+        };
+        results.push(nth_value);
+    }
+    results
+}
+
 /// Executes `FusedValueWindowFunc` on a reduction group.
 /// The expected input is in the format of `[((OriginalRow, (Args1, Args2, ...)), OrderByExprs...)]`
 /// where `Args1`, `Args2`, are the arguments of each of the fused functions. For functions that
-/// have only a single argument (first_value/last_value), these are simple values. For functions
-/// that have multiple arguments (lag/lead), these are also records.
+/// have only a single argument (first_value/last_value/ntile), these are simple values. For
+/// functions that have multiple arguments (lag/lead/nth_value), these are also records.
 fn fused_value_window_func<'a, I>(
     input_datums: I,
     callers_temp_storage: &'a RowArena,
@@ -1196,9 +1634,12 @@ fn fused_value_window_func_no_list<'a: 'b, 'b, I>(
 where
     I: IntoIterator<Item = Datum<'a>>,
 {
-    let has_last_value = funcs
-        .iter()
-        .any(|f| matches!(f, AggregateFunc::LastValue { .. }));
+    let needs_order_by_rows = funcs.iter().any(|f| {
+        matches!(
+            f,
+            AggregateFunc::LastValue { .. } | AggregateFunc::NthValue { .. }
+        )
+    });
 
     let input_datums_with_ranks = order_aggregate_datums_with_rank(input_datums, order_by);
 
@@ -1215,7 +1656,7 @@ where
             let encoded_args = argss_iter.next().unwrap();
             encoded_argsss[i].push(encoded_args);
         }
-        if has_last_value {
+        if needs_order_by_rows {
             order_by_rows.push(order_by_row);
         }
     }
@@ -1253,6 +1694,25 @@ where
                 // wrapped into a record.)
                 last_value_inner(encoded_argss, &order_by_rows, window_frame)
             }
+            AggregateFunc::Ntile {
+                order_by: inner_order_by,
+            } => {
+                assert_eq!(order_by, inner_order_by);
+                // (No unwrapping to do on the args here, because there is only 1 arg, so it's not
+                // wrapped into a record.)
+                ntile_inner(encoded_argss)
+            }
+            AggregateFunc::NthValue {
+                order_by: inner_order_by,
+                window_frame,
+            } => {
+                assert_eq!(order_by, inner_order_by);
+                let unwrapped_argss = encoded_argss
+                    .into_iter()
+                    .map(|encoded_args| unwrap_nth_value_encoded_args(encoded_args))
+                    .collect();
+                nth_value_inner(unwrapped_argss, &order_by_rows, window_frame)
+            }
             _ => panic!("unknown window function in FusedValueWindowFunc"),
         };
         for (results, result) in results_per_row.iter_mut().zip_eq(results) {
@@ -1957,6 +2417,38 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// The ordered-set aggregate `percentile_cont`. Accumulates
+    /// `SqlScalarType::Record`s whose first field is the fraction (or the
+    /// `Datum::Array` of fractions) of the percentile(s) to compute, and whose
+    /// last field is the `Datum::Float64` to aggregate. `desc` indicates that
+    /// the values are ordered descendingly.
+    PercentileCont {
+        desc: bool,
+    },
+    /// The ordered-set aggregate `percentile_disc`. Accumulates
+    /// `SqlScalarType::Record`s whose first field is the fraction (or the
+    /// `Datum::Array` of fractions) of the percentile(s) to compute, and whose
+    /// last field is the value to aggregate. `desc` indicates that the values
+    /// are ordered descendingly.
+    PercentileDisc {
+        desc: bool,
+    },
+    /// The ordered-set aggregate `mode`. Accumulates `SqlScalarType::Record`s
+    /// whose only field is the value to aggregate. `desc` indicates that the
+    /// values are ordered descendingly, which decides ties.
+    Mode {
+        desc: bool,
+    },
+    /// Estimates the number of distinct non-null values it accumulates with a
+    /// HyperLogLog sketch (see [`AggregateFunc::eval_hll`]).
+    ApproxCountDistinct,
+    /// Accumulates non-null values into a HyperLogLog sketch, which is output
+    /// as a `Datum::Bytes`.
+    HllSketch,
+    /// Merges the HyperLogLog sketches it accumulates, which are
+    /// `Datum::Bytes` that are known to be valid sketches, into a single
+    /// sketch.
+    HllMerge,
     RowNumber {
         order_by: Vec<ColumnOrder>,
     },
@@ -1966,11 +2458,22 @@ pub enum AggregateFunc {
     DenseRank {
         order_by: Vec<ColumnOrder>,
     },
+    PercentRank {
+        order_by: Vec<ColumnOrder>,
+    },
+    CumeDist {
+        order_by: Vec<ColumnOrder>,
+    },
     LagLead {
         order_by: Vec<ColumnOrder>,
         lag_lead: LagLeadType,
         ignore_nulls: bool,
     },
+    /// Divides a window partition into as equal as possible buckets. The number of buckets is
+    /// taken from the first row of the partition.
+    Ntile {
+        order_by: Vec<ColumnOrder>,
+    },
     FirstValue {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
@@ -1979,6 +2482,10 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    NthValue {
+        order_by: Vec<ColumnOrder>,
+        window_frame: WindowFrame,
+    },
     /// Several value window functions fused into one function, to amortize overheads.
     FusedValueWindowFunc {
         funcs: Vec<AggregateFunc>,
@@ -2098,6 +2605,37 @@ impl AggregateFunc {
                 })
             }
             AggregateFunc::SumInt64 => sum_signed_int_counted(datums, Datum::from),
+            _ if self.is_ordered_set() => {
+                let mut record = None;
+                let mut values = Vec::new();
+                for (datum, diff) in datums {
+                    if datum.is_null() || !diff.is_positive() {
+                        continue;
+                    }
+                    // The direct arguments are the same in all records.
+                    record.get_or_insert(datum);
+                    let value = datum.unwrap_list().iter().last().unwrap();
+                    if !value.is_null() {
+                        values.push((value, diff));
+                    }
+                }
+                values.sort_by(|(a, _), (b, _)| a.cmp(b));
+                let values = values.into_iter().coalesce(|(a, x), (b, y)| {
+                    if a == b {
+                        Ok((a, x + y))
+                    } else {
+                        Err(((a, x), (b, y)))
+                    }
+                });
+                self.eval_ordered_set(record, values, temp_storage)
+            }
+            _ if self.is_hll() => {
+                let ranks = datums
+                    .into_iter()
+                    .filter(|(_datum, diff)| diff.is_positive())
+                    .flat_map(|(datum, _diff)| self.hll_ranks(datum));
+                self.eval_hll(ranks, temp_storage)
+            }
             _ if self.ignores_multiplicity() => {
                 self.eval_datums(datums.into_iter().map(|(datum, _diff)| datum), temp_storage)
             }
@@ -2176,14 +2714,27 @@ impl AggregateFunc {
             AggregateFunc::ArrayConcat { order_by } => array_concat(datums, temp_storage, order_by),
             AggregateFunc::ListConcat { order_by } => list_concat(datums, temp_storage, order_by),
             AggregateFunc::StringAgg { order_by } => string_agg(datums, temp_storage, order_by),
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => {
+                unreachable!("ordered-set aggregates are handled in `eval`")
+            }
+            AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketch
+            | AggregateFunc::HllMerge => {
+                unreachable!("HyperLogLog aggregates are handled in `eval`")
+            }
             AggregateFunc::RowNumber { order_by } => row_number(datums, temp_storage, order_by),
             AggregateFunc::Rank { order_by } => rank(datums, temp_storage, order_by),
             AggregateFunc::DenseRank { order_by } => dense_rank(datums, temp_storage, order_by),
+            AggregateFunc::PercentRank { order_by } => percent_rank(datums, temp_storage, order_by),
+            AggregateFunc::CumeDist { order_by } => cume_dist(datums, temp_storage, order_by),
             AggregateFunc::LagLead {
                 order_by,
                 lag_lead: lag_lead_type,
                 ignore_nulls,
             } => lag_lead(datums, temp_storage, order_by, lag_lead_type, ignore_nulls),
+            AggregateFunc::Ntile { order_by } => ntile(datums, temp_storage, order_by),
             AggregateFunc::FirstValue {
                 order_by,
                 window_frame,
@@ -2192,6 +2743,10 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => nth_value(datums, temp_storage, order_by, window_frame),
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate,
                 order_by,
@@ -2221,6 +2776,212 @@ impl AggregateFunc {
         }
     }
 
+    /// Whether this is an ordered-set aggregate, i.e., one that is called as
+    /// `f(direct_args...) WITHIN GROUP (ORDER BY value)` in SQL.
+    ///
+    /// The input of these aggregates is a record of the direct arguments
+    /// followed by the value to aggregate. They are evaluated by
+    /// [`AggregateFunc::eval_ordered_set`].
+    pub fn is_ordered_set(&self) -> bool {
+        matches!(
+            self,
+            AggregateFunc::PercentileCont { .. }
+                | AggregateFunc::PercentileDisc { .. }
+                | AggregateFunc::Mode { .. }
+        )
+    }
+
+    /// Evaluates an ordered-set aggregate.
+    ///
+    /// `record` is the input record of any of the aggregated rows, from which
+    /// the direct arguments are taken, or `None` if there are no rows.
+    /// `values` are the distinct non-null values to aggregate along with their
+    /// multiplicities, in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not an ordered-set aggregate.
+    pub fn eval_ordered_set<'a, I>(
+        &self,
+        record: Option<Datum<'a>>,
+        values: I,
+        temp_storage: &'a RowArena,
+    ) -> Datum<'a>
+    where
+        I: IntoIterator<Item = (Datum<'a>, Diff)>,
+    {
+        let Some(record) = record else {
+            return Datum::Null;
+        };
+        let mut values = values
+            .into_iter()
+            .map(|(value, diff)| (value, diff.into_inner()))
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            return Datum::Null;
+        }
+        match self {
+            AggregateFunc::Mode { desc } => {
+                if *desc {
+                    values.reverse();
+                }
+                mode(&values)
+            }
+            AggregateFunc::PercentileCont { .. } | AggregateFunc::PercentileDisc { .. } => {
+                let total = values.iter().map(|(_, count)| count).sum();
+                let nth = |n| nth_value_counted(&values, n);
+                self.eval_percentile(record, total, nth, temp_storage)
+            }
+            _ => panic!("eval_ordered_set called on {self:?}"),
+        }
+    }
+
+    /// Returns the positions (0-based) in the ascending order of `total`
+    /// values of those on which the percentile aggregate `self` with the
+    /// direct arguments of `record` depends, in ascending order and without
+    /// duplicates.
+    ///
+    /// Together with [`AggregateFunc::eval_ordered_set_at`], this evaluates
+    /// the aggregate from the values at a few positions rather than from all
+    /// of the values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not `PercentileCont` or `PercentileDisc`.
+    pub fn ordered_set_positions(&self, record: Datum, total: i64) -> Vec<i64> {
+        let (cont, desc) = self.percentile_order();
+        let fractions = match record.unwrap_list().iter().next().unwrap() {
+            Datum::Null => vec![],
+            Datum::Array(fractions) => fractions
+                .elements()
+                .iter()
+                .filter(|fraction| !fraction.is_null())
+                .map(|fraction| fraction.unwrap_float64())
+                .collect(),
+            fraction => vec![fraction.unwrap_float64()],
+        };
+        let mut positions = fractions
+            .into_iter()
+            .filter_map(|fraction| percentile_positions(cont, fraction, total))
+            .flat_map(|(first, second)| [first, second])
+            .map(|n| if desc { total - 1 - n } else { n })
+            .collect::<Vec<_>>();
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+
+    /// Evaluates the percentile aggregate `self` with the direct arguments of
+    /// `record` over `total` values, given the values at the positions
+    /// returned by [`AggregateFunc::ordered_set_positions`], in the same
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not `PercentileCont` or `PercentileDisc`, or if
+    /// `values` is shorter than the positions.
+    pub fn eval_ordered_set_at<'a>(
+        &self,
+        record: Datum<'a>,
+        total: i64,
+        values: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+    ) -> Datum<'a> {
+        let positions = self.ordered_set_positions(record, total);
+        let nth = |n| values[positions.binary_search(&n).expect("requested position")];
+        self.eval_percentile(record, total, nth, temp_storage)
+    }
+
+    /// Evaluates the percentile aggregate `self` with the direct arguments of
+    /// `record` over `total` values, whose value at a position in ascending
+    /// order is given by `nth`.
+    fn eval_percentile<'a>(
+        &self,
+        record: Datum<'a>,
+        total: i64,
+        nth: impl Fn(i64) -> Datum<'a>,
+        temp_storage: &'a RowArena,
+    ) -> Datum<'a> {
+        let (cont, desc) = self.percentile_order();
+        let nth = |n| if desc { nth(total - 1 - n) } else { nth(n) };
+        match record.unwrap_list().iter().next().unwrap() {
+            Datum::Null => Datum::Null,
+            Datum::Array(fractions) => {
+                let dims = fractions.dims().into_iter().collect::<Vec<_>>();
+                let elements = fractions.elements().iter().map(|fraction| match fraction {
+                    Datum::Null => Datum::Null,
+                    fraction => percentile(cont, fraction.unwrap_float64(), total, &nth),
+                });
+                temp_storage.make_datum(|packer| packer.try_push_array(&dims, elements).unwrap())
+            }
+            fraction => percentile(cont, fraction.unwrap_float64(), total, &nth),
+        }
+    }
+
+    /// Returns whether the percentile aggregate `self` is `percentile_cont`,
+    /// and whether its values are in descending order.
+    fn percentile_order(&self) -> (bool, bool) {
+        match self {
+            AggregateFunc::PercentileCont { desc } => (true, *desc),
+            AggregateFunc::PercentileDisc { desc } => (false, *desc),
+            _ => panic!("percentile_order called on {self:?}"),
+        }
+    }
+
+    /// Whether this aggregate maintains a HyperLogLog sketch of its input.
+    ///
+    /// The input of these aggregates is broken down into the ranks of the
+    /// sketch's registers by [`AggregateFunc::hll_ranks`], and the maximum rank
+    /// of each register is evaluated by [`AggregateFunc::eval_hll`].
+    pub fn is_hll(&self) -> bool {
+        matches!(
+            self,
+            AggregateFunc::ApproxCountDistinct | AggregateFunc::HllSketch | AggregateFunc::HllMerge
+        )
+    }
+
+    /// Returns the registers of the HyperLogLog sketch of `datum` that hold a
+    /// rank, along with the rank. This is nothing for `Datum::Null`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is `HllMerge` and `datum` isn't a `Datum::Bytes`.
+    pub fn hll_ranks<'a>(&self, datum: Datum<'a>) -> impl Iterator<Item = (u16, u8)> + use<'a> {
+        let (value, sketch) = match (self, datum) {
+            (_, Datum::Null) => (None, None),
+            (AggregateFunc::HllMerge, sketch) => (None, Some(sketch.unwrap_bytes())),
+            (_, value) => (Some(hll::register_and_rank(value)), None),
+        };
+        value
+            .into_iter()
+            .chain(sketch.into_iter().flat_map(hll::sketch_ranks))
+    }
+
+    /// Evaluates a HyperLogLog aggregate on the HyperLogLog sketch whose
+    /// registers hold the maximum of the given `ranks` (see
+    /// [`AggregateFunc::hll_ranks`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not a HyperLogLog aggregate.
+    pub fn eval_hll<'a, I>(&self, ranks: I, temp_storage: &'a RowArena) -> Datum<'a>
+    where
+        I: IntoIterator<Item = (u16, u8)>,
+    {
+        let registers = hll::registers(ranks);
+        match (self, registers) {
+            (AggregateFunc::ApproxCountDistinct, None) => Datum::Int64(0),
+            (AggregateFunc::ApproxCountDistinct, Some(registers)) => {
+                Datum::Int64(hll::estimate(&registers))
+            }
+            (AggregateFunc::HllSketch | AggregateFunc::HllMerge, None) => Datum::Null,
+            (AggregateFunc::HllSketch | AggregateFunc::HllMerge, Some(registers)) => {
+                Datum::Bytes(temp_storage.push_bytes(hll::encode(&registers)))
+            }
+            _ => panic!("eval_hll called on {self:?}"),
+        }
+    }
+
     /// Like `eval`, but it's given a [OneByOneAggr]. If `self` is a `WindowAggregate`, then
     /// the given [OneByOneAggr] will be used to evaluate the wrapped aggregate inside the
     /// `WindowAggregate`. If `self` is not a `WindowAggregate`, then it simply calls `eval`.
@@ -2283,12 +3044,21 @@ impl AggregateFunc {
             AggregateFunc::DenseRank { order_by } => {
                 dense_rank_no_list(datums, temp_storage, order_by).collect_vec()
             }
+            AggregateFunc::PercentRank { order_by } => {
+                percent_rank_no_list(datums, temp_storage, order_by).collect_vec()
+            }
+            AggregateFunc::CumeDist { order_by } => {
+                cume_dist_no_list(datums, temp_storage, order_by).collect_vec()
+            }
             AggregateFunc::LagLead {
                 order_by,
                 lag_lead: lag_lead_type,
                 ignore_nulls,
             } => lag_lead_no_list(datums, temp_storage, order_by, lag_lead_type, ignore_nulls)
                 .collect_vec(),
+            AggregateFunc::Ntile { order_by } => {
+                ntile_no_list(datums, temp_storage, order_by).collect_vec()
+            }
             AggregateFunc::FirstValue {
                 order_by,
                 window_frame,
@@ -2297,6 +3067,10 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value_no_list(datums, temp_storage, order_by, window_frame).collect_vec(),
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => nth_value_no_list(datums, temp_storage, order_by, window_frame).collect_vec(),
             AggregateFunc::FusedValueWindowFunc { funcs, order_by } => {
                 fused_value_window_func_no_list(datums, temp_storage, funcs, order_by).collect_vec()
             }
@@ -2333,7 +3107,7 @@ impl AggregateFunc {
     /// input relation.
     pub fn default(&self) -> Datum<'static> {
         match self {
-            AggregateFunc::Count => Datum::Int64(0),
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => Datum::Int64(0),
             AggregateFunc::Any => Datum::False,
             AggregateFunc::All => Datum::True,
             AggregateFunc::Dummy => Datum::Dummy,
@@ -2353,9 +3127,13 @@ impl AggregateFunc {
             AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
             | AggregateFunc::LagLead { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => Datum::empty_list(),
@@ -2406,7 +3184,13 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => Datum::Null,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketch
+            | AggregateFunc::HllMerge => Datum::Null,
         }
     }
 
@@ -2415,9 +3199,13 @@ impl AggregateFunc {
             AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
             | AggregateFunc::LagLead { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => true,
@@ -2473,7 +3261,13 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => false,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketch
+            | AggregateFunc::HllMerge => false,
         }
     }
 
@@ -2511,15 +3305,52 @@ impl AggregateFunc {
                 }
             }
             AggregateFunc::StringAgg { .. } => SqlScalarType::String,
-            AggregateFunc::RowNumber { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?row_number?")
-            }
-            AggregateFunc::Rank { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?rank?")
-            }
-            AggregateFunc::DenseRank { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?dense_rank?")
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => {
+                // The input is a record of the direct arguments followed by the
+                // aggregated value.
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let value_type = fields[fields.len() - 1].clone();
+                let is_array = matches!(fields[0], SqlScalarType::Array(_));
+                match self {
+                    AggregateFunc::PercentileCont { .. } if is_array => {
+                        SqlScalarType::Array(Box::new(SqlScalarType::Float64))
+                    }
+                    AggregateFunc::PercentileCont { .. } => SqlScalarType::Float64,
+                    AggregateFunc::PercentileDisc { .. } if is_array => {
+                        SqlScalarType::Array(Box::new(value_type))
+                    }
+                    _ => value_type,
+                }
             }
+            AggregateFunc::ApproxCountDistinct => SqlScalarType::Int64,
+            AggregateFunc::HllSketch | AggregateFunc::HllMerge => SqlScalarType::Bytes,
+            AggregateFunc::RowNumber { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?row_number?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::Rank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?rank?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::DenseRank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?dense_rank?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::PercentRank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?percent_rank?",
+                SqlScalarType::Float64,
+            ),
+            AggregateFunc::CumeDist { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?cume_dist?",
+                SqlScalarType::Float64,
+            ),
             AggregateFunc::LagLead { lag_lead: lag_lead_type, .. } => {
                 // The input type for Lag is ((OriginalRow, EncodedArgs), OrderByExprs...)
                 let fields = input_type.scalar_type.unwrap_record_element_type();
@@ -2542,6 +3373,27 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::Ntile { .. } => {
+                // The input type for Ntile is ((OriginalRow, Arg), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Record {
+                        fields: [
+                            (
+                                ColumnName::from("?ntile?"),
+                                SqlScalarType::Int32.nullable(true), // null when the arg is null
+                            ),
+                            (ColumnName::from("?orig_row?"), original_row_type),
+                        ].into(),
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
             AggregateFunc::FirstValue { .. } => {
                 // The input type for FirstValue is ((OriginalRow, Arg), OrderByExprs...)
                 let fields = input_type.scalar_type.unwrap_record_element_type();
@@ -2584,6 +3436,26 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::NthValue { .. } => {
+                // The input type for NthValue is ((OriginalRow, (Arg, N)), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let encoded_args = fields[0].unwrap_record_element_type()[1];
+                let value_type = Self::nth_value_output_type_inner_from_encoded_args(encoded_args);
+
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Record {
+                        fields: [
+                            (ColumnName::from("?nth_value?"), value_type),
+                            (ColumnName::from("?orig_row?"), original_row_type),
+                        ].into(),
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate, ..
             } => {
@@ -2644,8 +3516,8 @@ impl AggregateFunc {
             AggregateFunc::FusedValueWindowFunc { funcs, order_by: _ } => {
                 // The input type is ((OriginalRow, EncodedArgs), OrderByExprs...)
                 // where EncodedArgs is a record, where each element is the argument to one of the
-                // function calls that got fused. This is a record for lag/lead, and a simple type
-                // for first_value/last_value.
+                // function calls that got fused. This is a record for lag/lead/nth_value, and a
+                // simple type for first_value/last_value/ntile.
                 let fields = input_type.scalar_type.unwrap_record_element_type();
                 let original_row_type = fields[0].unwrap_record_element_type()[0]
                     .clone()
@@ -2687,6 +3559,19 @@ impl AggregateFunc {
                                                 arg_type.clone().nullable(true),
                                             )
                                         }
+                                        AggregateFunc::Ntile { .. } => {
+                                            (
+                                                ColumnName::from("?ntile?"),
+                                                SqlScalarType::Int32.nullable(true),
+                                            )
+                                        }
+                                        AggregateFunc::NthValue { .. } => {
+                                            let ty = Self
+                                                ::nth_value_output_type_inner_from_encoded_args(
+                                                    arg_type,
+                                                );
+                                            (ColumnName::from("?nth_value?"), ty)
+                                        }
                                         _ => panic!("FusedValueWindowFunc has an unknown function"),
                                     }
                                 }).collect(),
@@ -2744,7 +3629,7 @@ impl AggregateFunc {
         // Count never produces null, and other aggregations only produce
         // null in the presence of null inputs.
         let nullable = match self {
-            AggregateFunc::Count => false,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => false,
             // Use the nullability of the underlying column being aggregated, not the Records wrapping it
             AggregateFunc::StringAgg { .. } => match input_type.scalar_type {
                 // The outer Record wraps the input in the first position, and any ORDER BY expressions afterwards
@@ -2755,6 +3640,11 @@ impl AggregateFunc {
                 },
                 _ => unreachable!(),
             },
+            // Ordered-set aggregates are null if all values are null, but also
+            // if a direct argument is.
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => true,
             _ => input_type.nullable,
         };
         scalar_type.nullable(nullable)
@@ -2767,19 +3657,17 @@ impl AggregateFunc {
         ReprColumnType::from(&self.output_sql_type(SqlColumnType::from_repr(&input_type)))
     }
 
-    /// Compute output type for ROW_NUMBER, RANK, DENSE_RANK
+    /// Compute output type for ROW_NUMBER, RANK, DENSE_RANK, PERCENT_RANK, CUME_DIST
     fn output_type_ranking_window_funcs(
         input_type: &SqlColumnType,
         col_name: &str,
+        result_type: SqlScalarType,
     ) -> SqlScalarType {
         match input_type.scalar_type {
             SqlScalarType::Record { ref fields, .. } => SqlScalarType::List {
                 element_type: Box::new(SqlScalarType::Record {
                     fields: [
-                        (
-                            ColumnName::from(col_name),
-                            SqlScalarType::Int64.nullable(false),
-                        ),
+                        (ColumnName::from(col_name), result_type.nullable(false)),
                         (ColumnName::from("?orig_row?"), {
                             let inner = match &fields[0].1.scalar_type {
                                 SqlScalarType::List { element_type, .. } => element_type.clone(),
@@ -2811,6 +3699,18 @@ impl AggregateFunc {
             .nullable(true)
     }
 
+    /// Given the `EncodedArgs` part of `((OriginalRow, EncodedArgs), OrderByExprs...)` of
+    /// `nth_value`, this computes the type of the first field of the output type.
+    fn nth_value_output_type_inner_from_encoded_args(
+        encoded_args_type: &SqlScalarType,
+    ) -> SqlColumnType {
+        // The output type is the type of the value argument, but always nullable. (It's null when
+        // the window frame has fewer rows than the offset argument.)
+        encoded_args_type.unwrap_record_element_type()[0]
+            .clone()
+            .nullable(true)
+    }
+
     fn lag_lead_result_column_name(lag_lead_type: &LagLeadType) -> ColumnName {
         ColumnName::from(match lag_lead_type {
             LagLeadType::Lag => "?lag?",
@@ -2867,9 +3767,12 @@ impl AggregateFunc {
             | AggregateFunc::SumFloat32
             | AggregateFunc::SumFloat64
             | AggregateFunc::SumNumeric
-            | AggregateFunc::StringAgg { .. } => true,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::HllSketch
+            | AggregateFunc::HllMerge => true,
             // Count is never null
             AggregateFunc::Count
+            | AggregateFunc::ApproxCountDistinct
             | AggregateFunc::Any
             | AggregateFunc::All
             | AggregateFunc::JsonbAgg { .. }
@@ -2880,12 +3783,19 @@ impl AggregateFunc {
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
             | AggregateFunc::LagLead { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedWindowAggregate { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
             | AggregateFunc::Dummy => false,
         }
     }
@@ -3232,9 +4142,17 @@ impl AggregateFunc {
             Self::ArrayConcat { .. } => "array_agg",
             Self::ListConcat { .. } => "list_agg",
             Self::StringAgg { .. } => "string_agg",
+            Self::PercentileCont { .. } => "percentile_cont",
+            Self::PercentileDisc { .. } => "percentile_disc",
+            Self::Mode { .. } => "mode",
+            Self::ApproxCountDistinct => "approx_count_distinct",
+            Self::HllSketch => "hll_sketch",
+            Self::HllMerge => "hll_merge",
             Self::RowNumber { .. } => "row_number",
             Self::Rank { .. } => "rank",
             Self::DenseRank { .. } => "dense_rank",
+            Self::PercentRank { .. } => "percent_rank",
+            Self::CumeDist { .. } => "cume_dist",
             Self::LagLead {
                 lag_lead: LagLeadType::Lag,
                 ..
@@ -3243,8 +4161,10 @@ impl AggregateFunc {
                 lag_lead: LagLeadType::Lead,
                 ..
             } => "lead",
+            Self::Ntile { .. } => "ntile",
             Self::FirstValue { .. } => "first_value",
             Self::LastValue { .. } => "last_value",
+            Self::NthValue { .. } => "nth_value",
             Self::WindowAggregate { .. } => "window_agg",
             Self::FusedValueWindowFunc { .. } => "fused_value_window_func",
             Self::FusedWindowAggregate { .. } => "fused_window_agg",
@@ -3269,7 +4189,10 @@ where
             | StringAgg { order_by }
             | RowNumber { order_by }
             | Rank { order_by }
-            | DenseRank { order_by } => {
+            | DenseRank { order_by }
+            | PercentRank { order_by }
+            | CumeDist { order_by }
+            | Ntile { order_by } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 write!(f, "{}[order_by=[{}]]", name, separated(", ", order_by))
             }
@@ -3303,6 +4226,10 @@ where
             LastValue {
                 order_by,
                 window_frame,
+            }
+            | NthValue {
+                order_by,
+                window_frame,
             } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 f.write_str(name)?;
@@ -3338,6 +4265,9 @@ where
                 write!(f, "order_by=[{}]", separated(", ", order_by))?;
                 f.write_str("]")
             }
+            PercentileCont { desc: true } | PercentileDisc { desc: true } | Mode { desc: true } => {
+                write!(f, "{}[desc=true]", name)
+            }
             _ => f.write_str(name),
         }
     }
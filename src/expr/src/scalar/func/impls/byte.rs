@@ -11,7 +11,7 @@ use mz_expr_derive::sqlfunc;
 use mz_ore::cast::CastFrom;
 use mz_repr::strconv;
 
-use crate::EvalError;
+use crate::{EvalError, hll};
 
 #[sqlfunc(
     sqlname = "bytea_to_text",
@@ -71,3 +71,14 @@ fn byte_length_bytes<'a>(a: &'a [u8]) -> Result<i32, EvalError> {
     let val = a.len();
     i32::try_from(val).or_else(|_| Err(EvalError::Int32OutOfRange(val.to_string().into())))
 }
+
+#[sqlfunc(sqlname = "hll_cardinality")]
+fn hll_cardinality<'a>(a: &'a [u8]) -> Result<i64, EvalError> {
+    hll::sketch_estimate(a)
+}
+
+#[sqlfunc(sqlname = "hll_check_sketch", preserves_uniqueness = true)]
+fn hll_check_sketch<'a>(a: &'a [u8]) -> Result<Vec<u8>, EvalError> {
+    hll::check_sketch(a)?;
+    Ok(a.to_vec())
+}
@@ -20,9 +20,10 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
     AstInfo, AvroSchema, ConnectionOption, ConnectionOptionName, CreateConnectionType,
     CreateSinkConnection, CreateSubsourceOptionName, Format, FormatSpecifier,
-    IcebergSinkConfigOptionName, IcebergSinkMode, KafkaSinkConfigOptionName,
-    KafkaSourceConfigOptionName, PgConfigOptionName, ProtobufSchema, RawClusterName, RawItemName,
-    SinkEnvelope, SourceEnvelope, SourceErrorPolicy, UnresolvedItemName, Value, WithOptionValue,
+    IcebergSinkConfigOptionName, KafkaSinkConfigOptionName, KafkaSourceConfigOptionName,
+    PgConfigOptionName, PostgresSinkConfigOptionName, ProtobufSchema, RawClusterName, RawItemName,
+    SinkEnvelope, SinkMode, SourceEnvelope, SourceErrorPolicy, UnresolvedItemName, Value,
+    WithOptionValue,
 };
 use prost::Message as _;
 use serde::{Deserialize, Serialize};
@@ -503,6 +504,7 @@ fn parse_catalog_create_sql<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
                     Postgres { connection, .. } => ("postgres", Some(connection)),
                     MySql { connection, .. } => ("mysql", Some(connection)),
                     SqlServer { connection, .. } => ("sql-server", Some(connection)),
+                    Iceberg { connection, .. } => ("iceberg", Some(connection)),
                     LoadGenerator { .. } => ("load-generator", None),
                 };
                 info.insert("source_type", json!(source_type));
@@ -655,15 +657,16 @@ fn parse_catalog_create_sql<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
                             }
                             info.insert("value_format", json!(value_format));
 
-                            // The deprecated combined `format`. Only avro/avro
-                            // and json/json collapse to a single name.
+                            // The deprecated combined `format`. Only avro/avro,
+                            // protobuf/protobuf, and json/json collapse to a
+                            // single name.
                             // Everything else, text/text and bytes/bytes
                             // included, gets the composite form.
                             let combined = match key_format {
                                 None => value_format.to_string(),
                                 Some(key_format)
                                     if key_format == value_format
-                                        && matches!(value_format, "avro" | "json") =>
+                                        && matches!(value_format, "avro" | "protobuf" | "json") =>
                                 {
                                     value_format.to_string()
                                 }
@@ -693,6 +696,8 @@ fn parse_catalog_create_sql<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
                                 IcebergSinkConfigOptionName::Table => {
                                     table = option.value.as_ref().and_then(option_string)
                                 }
+                                IcebergSinkConfigOptionName::PartitionBy
+                                | IcebergSinkConfigOptionName::SortBy => {}
                             }
                         }
                         info.insert(
@@ -705,8 +710,50 @@ fn parse_catalog_create_sql<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
                         // columns at all.
                         if let Some(mode) = stmt.mode {
                             let envelope_type = match mode {
-                                IcebergSinkMode::Upsert => "upsert",
-                                IcebergSinkMode::Append => "append",
+                                SinkMode::Upsert => "upsert",
+                                SinkMode::Append => "append",
+                            };
+                            info.insert("envelope_type", json!(envelope_type));
+                        }
+                    }
+                    CreateSinkConnection::Postgres {
+                        connection,
+                        options,
+                        ..
+                    } => {
+                        info.insert("sink_type", json!("postgres"));
+                        info.insert("connection_id", json!(get_item_id(connection)?));
+
+                        // `SCHEMA` defaults to `public` at planning time, but the
+                        // default isn't written back into the statement.
+                        let mut schema = None;
+                        let mut table = None;
+                        for option in options {
+                            match option.name {
+                                PostgresSinkConfigOptionName::Schema => {
+                                    schema = option.value.as_ref().and_then(option_string)
+                                }
+                                PostgresSinkConfigOptionName::Table => {
+                                    table = option.value.as_ref().and_then(option_string)
+                                }
+                            }
+                        }
+                        info.insert("schema", json!(schema.unwrap_or_else(|| "public".into())));
+                        info.insert("table", json!(table.ok_or("postgres sink missing TABLE")?));
+
+                        // Postgres sinks only support `MODE UPSERT`.
+                        if let Some(SinkMode::Upsert) = stmt.mode {
+                            info.insert("envelope_type", json!("upsert"));
+                        }
+                    }
+                    CreateSinkConnection::Http { .. } => {
+                        // HTTP sinks have no connection and always send JSON,
+                        // so there are no connection or format columns.
+                        info.insert("sink_type", json!("http"));
+                        if let Some(envelope) = stmt.envelope {
+                            let envelope_type = match envelope {
+                                SinkEnvelope::Upsert => "upsert",
+                                SinkEnvelope::Debezium => "debezium",
                             };
                             info.insert("envelope_type", json!(envelope_type));
                         }
@@ -2080,6 +2127,48 @@ mod tests {
         );
     }
 
+    #[mz_ore::test]
+    fn sink_http() {
+        let sql = "CREATE SINK \"materialize\".\"public\".\"hook\" \
+             IN CLUSTER [u42] \
+             FROM [u1 AS \"materialize\".\"public\".\"t\"] \
+             INTO HTTP (URL = 'https://example.com/hook', \
+             HEADERS = MAP['Authorization' => SECRET [u30 AS \"materialize\".\"public\".\"tok\"]]) \
+             ENVELOPE DEBEZIUM";
+        let out = super::parse_catalog_create_sql(sql).expect("ok");
+        assert_eq!(
+            as_serde(out),
+            json!({
+                "type": "sink",
+                "sink_type": "http",
+                "cluster_id": "u42",
+                "envelope_type": "debezium",
+            }),
+        );
+    }
+
+    #[mz_ore::test]
+    fn sink_postgres() {
+        let sql = "CREATE SINK \"materialize\".\"public\".\"pg\" \
+             IN CLUSTER [u42] \
+             FROM [u1 AS \"materialize\".\"public\".\"t\"] \
+             INTO POSTGRES CONNECTION [u20 AS \"materialize\".\"public\".\"pg_conn\"] \
+             (TABLE 'tbl') KEY (a) MODE UPSERT";
+        let out = super::parse_catalog_create_sql(sql).expect("ok");
+        assert_eq!(
+            as_serde(out),
+            json!({
+                "type": "sink",
+                "sink_type": "postgres",
+                "cluster_id": "u42",
+                "connection_id": "u20",
+                "schema": "public",
+                "table": "tbl",
+                "envelope_type": "upsert",
+            }),
+        );
+    }
+
     #[mz_ore::test]
     #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `rust_psm_stack_pointer` on OS `linux`
     fn sink_arm_leaves_other_item_types_alone() {
@@ -543,6 +543,8 @@ derive_unary!(
     KafkaMurmur2String,
     SeahashBytes,
     SeahashString,
+    HllCardinality,
+    HllCheckSketch,
     Reverse
 );
 
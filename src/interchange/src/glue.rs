@@ -25,6 +25,9 @@
 //! layer is straightforward but no consumer in Materialize asks for it yet,
 //! and silently decompressing would mask producer misconfiguration.
 //!
+//! Protobuf records follow the header with a varint message index before the
+//! serialized message. Materialize only writes and reads index `0`.
+//!
 //! The Confluent analogue lives in [`crate::confluent`].
 
 use anyhow::{Result, bail};
@@ -39,16 +42,17 @@ const COMPRESSION_NONE: u8 = 0x00;
 /// Length of the Glue header in bytes (version + compression + UUID).
 pub const HEADER_LEN: usize = 1 + 1 + 16;
 
-/// Parse the Glue Avro header from the front of `buf`, returning the
+/// Parse the Glue header from the front of `buf`, returning the
 /// schema-version UUID and a subslice covering the record payload.
 ///
 /// Returns an error if the buffer is shorter than the fixed header, if the
 /// header-version byte is not `0x03`, or if the compression byte is
 /// anything other than `0x00`.
-pub fn extract_avro_header(buf: &[u8]) -> Result<(Uuid, &[u8])> {
+fn extract_header<'buf>(buf: &'buf [u8], protocol: &str) -> Result<(Uuid, &'buf [u8])> {
     if buf.len() < HEADER_LEN {
         bail!(
-            "Glue-style avro datum is too few bytes: expected at least {} bytes, got {}",
+            "Glue-style {} datum is too few bytes: expected at least {} bytes, got {}",
+            protocol,
             HEADER_LEN,
             buf.len()
         );
@@ -56,7 +60,8 @@ pub fn extract_avro_header(buf: &[u8]) -> Result<(Uuid, &[u8])> {
     let version = buf[0];
     if version != HEADER_VERSION {
         bail!(
-            "wrong Glue-style avro serialization header version: expected {:#04x}, got {:#04x}",
+            "wrong Glue-style {} serialization header version: expected {:#04x}, got {:#04x}",
+            protocol,
             HEADER_VERSION,
             version
         );
@@ -64,8 +69,9 @@ pub fn extract_avro_header(buf: &[u8]) -> Result<(Uuid, &[u8])> {
     let compression = buf[1];
     if compression != COMPRESSION_NONE {
         bail!(
-            "unsupported Glue-style avro compression byte: \
+            "unsupported Glue-style {} compression byte: \
              expected {:#04x} (uncompressed), got {:#04x}",
+            protocol,
             COMPRESSION_NONE,
             compression
         );
@@ -76,6 +82,34 @@ pub fn extract_avro_header(buf: &[u8]) -> Result<(Uuid, &[u8])> {
     Ok((uuid, &buf[HEADER_LEN..]))
 }
 
+/// Parse the Glue Avro header from the front of `buf`, returning the
+/// schema-version UUID and a subslice covering the record payload.
+///
+/// See [`extract_header`] for the validation performed.
+pub fn extract_avro_header(buf: &[u8]) -> Result<(Uuid, &[u8])> {
+    extract_header(buf, "avro")
+}
+
+/// Parse the Glue Protobuf header from the front of `buf`, returning the
+/// schema-version UUID and a subslice covering the message payload.
+///
+/// Protobuf records carry a varint message index after the common header,
+/// naming the message within the registered schema. Like the Confluent
+/// framing, only the first message (index `0`) is supported.
+pub fn extract_protobuf_header(buf: &[u8]) -> Result<(Uuid, &[u8])> {
+    let (uuid, buf) = extract_header(buf, "protobuf")?;
+    match buf.first() {
+        Some(0) => Ok((uuid, &buf[1..])),
+        Some(message_index) => bail!(
+            "unsupported Glue-style protobuf message index: expected 0, but found: {}",
+            message_index
+        ),
+        None => bail!(
+            "Glue-style protobuf datum is too few bytes: expected a message index after the header"
+        ),
+    }
+}
+
 /// Write the Glue Avro header to `buf`, using the uncompressed framing
 /// (`compression = 0x00`). Callers append the serialized record payload
 /// directly after the header, so the framed record needs only a single
@@ -87,6 +121,13 @@ pub fn write_avro_header(buf: &mut Vec<u8>, schema_version_id: Uuid) {
     buf.extend_from_slice(schema_version_id.as_bytes());
 }
 
+/// Write the Glue Protobuf header to `buf`: the Avro header followed by the
+/// message index `0`, which names the first message in the registered schema.
+pub fn write_protobuf_header(buf: &mut Vec<u8>, schema_version_id: Uuid) {
+    write_avro_header(buf, schema_version_id);
+    buf.push(0);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +204,16 @@ mod tests {
         assert_eq!(parsed_uuid, uuid);
         assert!(rest.is_empty());
     }
+
+    #[mz_ore::test]
+    fn protobuf_roundtrip() {
+        let uuid = fixture_uuid();
+        let mut framed = Vec::new();
+        write_protobuf_header(&mut framed, uuid);
+        framed.extend_from_slice(b"proto-bytes");
+        assert_eq!(framed[HEADER_LEN], 0, "message index");
+        let (parsed_uuid, rest) = extract_protobuf_header(&framed).unwrap();
+        assert_eq!(parsed_uuid, uuid);
+        assert_eq!(rest, b"proto-bytes");
+    }
 }
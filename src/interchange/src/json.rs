@@ -10,6 +10,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
+use byteorder::{NetworkEndian, WriteBytesExt};
 use itertools::Itertools;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
@@ -28,22 +29,54 @@ const MICROS_PER_MILLIS: u32 = 1_000;
 // Manages encoding of JSON-encoded bytes
 pub struct JsonEncoder {
     columns: Vec<(ColumnName, SqlColumnType)>,
+    /// The Confluent schema registry id of the JSON Schema the documents were
+    /// registered under, if any. When set, each document is prefixed with the
+    /// Confluent wire-format header.
+    schema_id: Option<i32>,
 }
 
 impl JsonEncoder {
-    pub fn new(desc: RelationDesc, debezium: bool) -> Self {
+    pub fn new(desc: RelationDesc, debezium: bool, schema_id: Option<i32>) -> Self {
         let mut columns = column_names_and_types(desc);
         if debezium {
             columns = envelopes::dbz_envelope(columns);
         };
-        JsonEncoder { columns }
+        JsonEncoder { columns, schema_id }
     }
 }
 
 impl Encode for JsonEncoder {
     fn encode_unchecked(&self, row: mz_repr::Row) -> Vec<u8> {
         let value = encode_datums_as_json(row.iter(), self.columns.as_ref());
-        value.to_string().into_bytes()
+        match self.schema_id {
+            None => value.to_string().into_bytes(),
+            Some(id) => {
+                // The Confluent framing for JSON Schema is the same as for
+                // Avro: a magic byte (0) and a 32-bit schema ID.
+                //
+                // https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+                let mut buf = vec![];
+                buf.write_u8(0).expect("writing to vec cannot fail");
+                buf.write_i32::<NetworkEndian>(id)
+                    .expect("writing to vec cannot fail");
+                serde_json::to_writer(&mut buf, &value).expect("writing to vec cannot fail");
+                buf
+            }
+        }
+    }
+
+    fn hash(&self, buf: &[u8]) -> u64 {
+        // Compute a stable hash by ignoring the header, which carries a schema
+        // id that may vary run-to-run.
+        let payload = match self.schema_id {
+            None => buf,
+            Some(_) => {
+                crate::confluent::extract_json_header(buf)
+                    .expect("encode_unchecked wrote a Confluent header")
+                    .1
+            }
+        };
+        seahash::hash(payload)
     }
 }
 
@@ -502,6 +535,108 @@ pub fn build_row_schema_json(
     }
 }
 
+/// Builds a JSON Schema describing the documents that a [`JsonEncoder`] for
+/// `desc` produces.
+///
+/// The schema uses draft-07, the default dialect of the Confluent schema
+/// registry. Every column is a required property, and nullable columns also
+/// admit `null`.
+pub fn build_json_schema(desc: RelationDesc, debezium: bool) -> serde_json::Value {
+    let mut columns = column_names_and_types(desc);
+    if debezium {
+        columns = envelopes::dbz_envelope(columns);
+    }
+    let mut schema = build_json_schema_object(&columns);
+    schema.as_object_mut().expect("built as an object").insert(
+        "$schema".into(),
+        json!("http://json-schema.org/draft-07/schema#"),
+    );
+    schema
+}
+
+fn build_json_schema_object(columns: &[(ColumnName, SqlColumnType)]) -> serde_json::Value {
+    let properties: Map<String, serde_json::Value> = columns
+        .iter()
+        .map(|(name, typ)| (name.to_string(), build_json_schema_type(typ)))
+        .collect();
+    let required: Vec<_> = columns.iter().map(|(name, _)| name.to_string()).collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+/// Mirrors the encoding choices of [`ToJson`] for [`TypedDatum`].
+fn build_json_schema_type(typ: &SqlColumnType) -> serde_json::Value {
+    let mut schema = match &typ.scalar_type {
+        SqlScalarType::Bool => json!({"type": "boolean"}),
+        SqlScalarType::PgLegacyChar
+        | SqlScalarType::Int16
+        | SqlScalarType::Int32
+        | SqlScalarType::Int64
+        | SqlScalarType::UInt16
+        | SqlScalarType::UInt32
+        | SqlScalarType::Oid
+        | SqlScalarType::RegClass
+        | SqlScalarType::RegProc
+        | SqlScalarType::RegType
+        | SqlScalarType::UInt64 => json!({"type": "integer"}),
+        // NaN and infinite values have no JSON representation and are
+        // written as `null`.
+        SqlScalarType::Float32 | SqlScalarType::Float64 => json!({"type": ["number", "null"]}),
+        SqlScalarType::Bytes => json!({"type": "array", "items": {"type": "integer"}}),
+        SqlScalarType::AclItem
+        | SqlScalarType::Numeric { .. }
+        | SqlScalarType::Date
+        | SqlScalarType::Time
+        | SqlScalarType::Timestamp { .. }
+        | SqlScalarType::TimestampTz { .. }
+        | SqlScalarType::Interval
+        | SqlScalarType::String
+        | SqlScalarType::Char { .. }
+        | SqlScalarType::VarChar { .. }
+        | SqlScalarType::PgLegacyName
+        | SqlScalarType::Uuid
+        | SqlScalarType::MzTimestamp
+        | SqlScalarType::Range { .. }
+        | SqlScalarType::MzAclItem => json!({"type": "string"}),
+        // Any JSON value.
+        SqlScalarType::Jsonb => json!({}),
+        // Arrays are written as nested JSON arrays, one level per dimension,
+        // and the number of dimensions is not part of the type.
+        SqlScalarType::Array(_) | SqlScalarType::Int2Vector => json!({"type": "array"}),
+        SqlScalarType::List { element_type, .. } => json!({
+            "type": "array",
+            "items": build_json_schema_type(&SqlColumnType {
+                nullable: true,
+                scalar_type: (**element_type).clone(),
+            }),
+        }),
+        SqlScalarType::Map { value_type, .. } => json!({
+            "type": "object",
+            "additionalProperties": build_json_schema_type(&SqlColumnType {
+                nullable: true,
+                scalar_type: (**value_type).clone(),
+            }),
+        }),
+        SqlScalarType::Record { fields, .. } => build_json_schema_object(fields),
+    };
+    if typ.nullable {
+        match schema.get_mut("type") {
+            Some(serde_json::Value::Array(types)) => {
+                if !types.contains(&json!("null")) {
+                    types.push(json!("null"));
+                }
+            }
+            Some(ty) => *ty = json!([ty.take(), "null"]),
+            // Schemas without a type already admit `null`.
+            None => (),
+        }
+    }
+    schema
+}
+
 /// Naming helper for use when constructing an Avro schema.
 #[derive(Default)]
 struct Namer {
@@ -571,3 +706,71 @@ impl Namer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::{Datum, RelationDesc, Row, SqlScalarType};
+
+    use super::*;
+
+    fn desc() -> RelationDesc {
+        RelationDesc::builder()
+            .with_column("a", SqlScalarType::Int64.nullable(false))
+            .with_column("b", SqlScalarType::String.nullable(true))
+            .with_column("c", SqlScalarType::Jsonb.nullable(true))
+            .finish()
+    }
+
+    #[mz_ore::test]
+    fn json_schema() {
+        assert_eq!(
+            build_json_schema(desc(), false),
+            json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object",
+                "properties": {
+                    "a": {"type": "integer"},
+                    "b": {"type": ["string", "null"]},
+                    "c": {},
+                },
+                "required": ["a", "b", "c"],
+            })
+        );
+    }
+
+    #[mz_ore::test]
+    fn json_schema_debezium() {
+        let row = json!({
+            "type": ["object", "null"],
+            "properties": {
+                "a": {"type": "integer"},
+                "b": {"type": ["string", "null"]},
+                "c": {},
+            },
+            "required": ["a", "b", "c"],
+        });
+        assert_eq!(
+            build_json_schema(desc(), true),
+            json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "type": "object",
+                "properties": {"before": row, "after": row},
+                "required": ["before", "after"],
+            })
+        );
+    }
+
+    #[mz_ore::test]
+    fn confluent_framing() {
+        let row = Row::pack_slice(&[Datum::Int64(1), Datum::Null, Datum::JsonNull]);
+        let unframed = JsonEncoder::new(desc(), false, None).encode_unchecked(row.clone());
+        assert_eq!(unframed, br#"{"a":1,"b":null,"c":null}"#);
+
+        let a = JsonEncoder::new(desc(), false, Some(1));
+        let b = JsonEncoder::new(desc(), false, Some(2));
+        let framed = a.encode_unchecked(row.clone());
+        assert_eq!(framed[..5], [0, 0, 0, 0, 1]);
+        assert_eq!(framed[5..], unframed[..]);
+        assert_eq!(a.hash(&framed), b.hash(&b.encode_unchecked(row)));
+    }
+}
@@ -10,12 +10,25 @@
 use std::collections::BTreeSet;
 
 use anyhow::{Context, anyhow, bail};
+use byteorder::{NetworkEndian, WriteBytesExt};
+use bytes::BytesMut;
+use chrono::Timelike;
+use itertools::Itertools;
 use mz_ore::str::StrExt;
-use mz_repr::{ColumnName, Datum, Row, RowPacker, SqlColumnType, SqlScalarType};
+use mz_repr::{ColumnName, Datum, RelationDesc, Row, RowPacker, SqlColumnType, SqlScalarType};
+use prost::Message;
 use prost_reflect::{
     Cardinality, DescriptorPool, DynamicMessage, FieldDescriptor, Kind, MessageDescriptor,
     ReflectMessage, Value,
 };
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{
+    DescriptorProto, FieldDescriptorProto, FileDescriptorProto, OneofDescriptorProto,
+};
+use uuid::Uuid;
+
+use crate::encode::{Encode, column_names_and_types};
+use crate::envelopes::{self, DBZ_ROW_TYPE_ID};
 
 /// A decoded description of the schema of a Protobuf message.
 #[derive(Debug, PartialEq)]
@@ -238,3 +251,559 @@ fn pack_value(
     }
     Ok(())
 }
+
+/// Identifies the schema-registry wire framing a [`ProtobufEncoder`] prepends
+/// to each message, carrying the registry-specific schema id.
+///
+/// Both framings are followed by the message index `0`, which names the
+/// top-level message of the generated schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtobufSchemaId {
+    /// Confluent wire format: magic byte `0x00` then a 4-byte big-endian id.
+    Confluent(i32),
+    /// AWS Glue wire format: an 18-byte header carrying a schema-version UUID.
+    /// See [`crate::glue`].
+    Glue(Uuid),
+}
+
+/// Generates a proto3 schema describing the rows of a relation.
+///
+/// The schema contains a single top-level message with one field per column,
+/// numbered in column order. Nullable scalar columns are `optional` fields, so
+/// that a SQL `NULL` is distinguishable from the field's default value. Record
+/// columns are encoded as messages nested inside the top-level message, which
+/// keeps the top-level message first in the file: both the Confluent and Glue
+/// wire formats reference it by index `0`.
+///
+/// Scalar types without a natural Protobuf counterpart (e.g. `numeric`,
+/// `interval`, `uuid`, `jsonb`) are encoded as strings in their PostgreSQL text
+/// representation. Lists, arrays, and maps are not supported.
+#[derive(Debug)]
+pub struct ProtobufSchemaGenerator {
+    columns: Vec<(ColumnName, SqlColumnType)>,
+    file: FileDescriptorProto,
+    message_descriptor: MessageDescriptor,
+}
+
+impl ProtobufSchemaGenerator {
+    pub fn new(
+        desc: RelationDesc,
+        debezium: bool,
+        message_name: &str,
+    ) -> Result<Self, anyhow::Error> {
+        let mut columns = column_names_and_types(desc);
+        if debezium {
+            columns = envelopes::dbz_envelope(columns);
+        }
+
+        let field_names = protobuf_field_names(&columns);
+        let mut nested = NestedMessages {
+            parent: message_name,
+            taken: field_names.iter().cloned().collect(),
+            messages: vec![],
+        };
+        let (field, oneof_decl) = build_message_fields(&columns, field_names, &mut nested)?;
+        let message = DescriptorProto {
+            name: Some(message_name.to_owned()),
+            field,
+            oneof_decl,
+            nested_type: nested.messages.into_iter().map(|(_, m)| m).collect(),
+            ..Default::default()
+        };
+        let file = FileDescriptorProto {
+            name: Some(format!("{message_name}.proto")),
+            syntax: Some("proto3".into()),
+            message_type: vec![message],
+            ..Default::default()
+        };
+
+        let mut pool = DescriptorPool::new();
+        pool.add_file_descriptor_proto(file.clone())
+            .context("building protobuf descriptor")?;
+        let message_descriptor = pool
+            .get_message_by_name(message_name)
+            .expect("message added to pool above");
+
+        Ok(ProtobufSchemaGenerator {
+            columns,
+            file,
+            message_descriptor,
+        })
+    }
+
+    /// Renders the schema as the text of a `.proto` file, suitable for
+    /// registering with a schema registry.
+    pub fn schema(&self) -> String {
+        let mut out = String::from("syntax = \"proto3\";\n");
+        for message in &self.file.message_type {
+            out.push('\n');
+            render_message(&mut out, message, 0);
+        }
+        out
+    }
+
+    pub fn message_descriptor(&self) -> &MessageDescriptor {
+        &self.message_descriptor
+    }
+
+    pub fn columns(&self) -> &[(ColumnName, SqlColumnType)] {
+        &self.columns
+    }
+}
+
+/// The record messages nested inside a generated top-level message, keyed by
+/// the record type they describe so that repeated record types, like the
+/// Debezium `before` and `after` fields, share a single message.
+struct NestedMessages<'a> {
+    parent: &'a str,
+    /// Names already in use in the top-level message's scope. Protobuf field
+    /// and nested message names share a scope, so they must not collide.
+    taken: BTreeSet<String>,
+    messages: Vec<(SqlScalarType, DescriptorProto)>,
+}
+
+impl NestedMessages<'_> {
+    /// Returns the fully qualified name of the message describing the record
+    /// type `typ`, generating the message if it doesn't exist yet.
+    fn message_for(&mut self, typ: &SqlScalarType) -> Result<String, anyhow::Error> {
+        let SqlScalarType::Record { fields, custom_id } = typ else {
+            unreachable!("message_for called with non-record type {typ:?}");
+        };
+        let name = match self.messages.iter().find(|(t, _)| t == typ) {
+            Some((_, message)) => message.name().to_owned(),
+            None => {
+                let field_names = protobuf_field_names(fields);
+                let (field, oneof_decl) = build_message_fields(fields, field_names, self)?;
+                let mut name = match custom_id {
+                    Some(id) if *id == DBZ_ROW_TYPE_ID => "Row".to_owned(),
+                    _ => format!("Record{}", self.messages.len()),
+                };
+                while self.taken.contains(&name) {
+                    name.push('_');
+                }
+                self.taken.insert(name.clone());
+                let message = DescriptorProto {
+                    name: Some(name.clone()),
+                    field,
+                    oneof_decl,
+                    ..Default::default()
+                };
+                self.messages.push((typ.clone(), message));
+                name
+            }
+        };
+        Ok(format!(".{}.{}", self.parent, name))
+    }
+}
+
+/// Derives valid, unique Protobuf field names for `columns`.
+///
+/// Protobuf identifiers are restricted to `[A-Za-z_][A-Za-z0-9_]*`, so any
+/// other character is replaced with an underscore, names starting with a digit
+/// are prefixed with one, and collisions are resolved by appending the
+/// smallest number that makes the name unique.
+fn protobuf_field_names(columns: &[(ColumnName, SqlColumnType)]) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    let mut names = Vec::with_capacity(columns.len());
+    for (column_name, _) in columns {
+        let mut name: String = column_name
+            .as_str()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            name.insert(0, '_');
+        }
+        let stem_len = name.len();
+        let mut i = 1;
+        while seen.contains(&name) {
+            name.truncate(stem_len);
+            name.push_str(&i.to_string());
+            i += 1;
+        }
+        seen.insert(name.clone());
+        names.push(name);
+    }
+    names
+}
+
+fn build_message_fields(
+    columns: &[(ColumnName, SqlColumnType)],
+    field_names: Vec<String>,
+    nested: &mut NestedMessages<'_>,
+) -> Result<(Vec<FieldDescriptorProto>, Vec<OneofDescriptorProto>), anyhow::Error> {
+    let mut taken: BTreeSet<_> = field_names.iter().cloned().collect();
+    let mut fields = Vec::with_capacity(columns.len());
+    let mut oneofs = vec![];
+    for (i, ((column_name, typ), name)) in columns.iter().zip_eq(field_names).enumerate() {
+        let number = i32::try_from(i + 1).context("too many columns for protobuf message")?;
+        let mut field = FieldDescriptorProto {
+            number: Some(number),
+            label: Some(Label::Optional.into()),
+            ..Default::default()
+        };
+        match &typ.scalar_type {
+            record @ SqlScalarType::Record { .. } => {
+                // Message fields always track presence, so a `NULL` record is
+                // simply an unset field.
+                field.set_type(Type::Message);
+                field.type_name = Some(nested.message_for(record)?);
+            }
+            SqlScalarType::List { .. } | SqlScalarType::Array(_) | SqlScalarType::Map { .. } => {
+                bail!(
+                    "column {} has a list, array, or map type, which FORMAT PROTOBUF does not support",
+                    column_name.as_str().quoted()
+                );
+            }
+            scalar_type => {
+                field.set_type(scalar_protobuf_type(scalar_type));
+                if typ.nullable {
+                    // A proto3 `optional` field is represented as the sole
+                    // member of a synthetic oneof.
+                    let mut oneof_name = format!("_{name}");
+                    while taken.contains(&oneof_name) {
+                        oneof_name.push('X');
+                    }
+                    taken.insert(oneof_name.clone());
+                    field.proto3_optional = Some(true);
+                    field.oneof_index = Some(i32::try_from(oneofs.len()).expect("fits in i32"));
+                    oneofs.push(OneofDescriptorProto {
+                        name: Some(oneof_name),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+        field.name = Some(name);
+        fields.push(field);
+    }
+    Ok((fields, oneofs))
+}
+
+/// Returns the Protobuf type used to encode a non-record, non-collection SQL
+/// type. Keep in sync with [`datum_to_value`].
+fn scalar_protobuf_type(scalar_type: &SqlScalarType) -> Type {
+    match scalar_type {
+        SqlScalarType::Bool => Type::Bool,
+        SqlScalarType::Int16 | SqlScalarType::Int32 | SqlScalarType::Date => Type::Int32,
+        SqlScalarType::Int64
+        | SqlScalarType::Time
+        | SqlScalarType::Timestamp { .. }
+        | SqlScalarType::TimestampTz { .. } => Type::Int64,
+        SqlScalarType::UInt16
+        | SqlScalarType::UInt32
+        | SqlScalarType::Oid
+        | SqlScalarType::RegClass
+        | SqlScalarType::RegProc
+        | SqlScalarType::RegType => Type::Uint32,
+        SqlScalarType::UInt64 => Type::Uint64,
+        SqlScalarType::Float32 => Type::Float,
+        SqlScalarType::Float64 => Type::Double,
+        SqlScalarType::Bytes => Type::Bytes,
+        _ => Type::String,
+    }
+}
+
+fn render_message(out: &mut String, message: &DescriptorProto, depth: usize) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&format!("{indent}message {} {{\n", message.name()));
+    for field in &message.field {
+        let label = if field.proto3_optional() {
+            "optional "
+        } else {
+            ""
+        };
+        let typ = match field.r#type() {
+            Type::Message => field.type_name(),
+            Type::Bool => "bool",
+            Type::Int32 => "int32",
+            Type::Int64 => "int64",
+            Type::Uint32 => "uint32",
+            Type::Uint64 => "uint64",
+            Type::Float => "float",
+            Type::Double => "double",
+            Type::Bytes => "bytes",
+            Type::String => "string",
+            other => unreachable!("generated schemas do not use {other:?}"),
+        };
+        out.push_str(&format!(
+            "{indent}  {label}{typ} {} = {};\n",
+            field.name(),
+            field.number()
+        ));
+    }
+    for nested in &message.nested_type {
+        out.push('\n');
+        render_message(out, nested, depth + 1);
+    }
+    out.push_str(&format!("{indent}}}\n"));
+}
+
+/// Manages encoding of Protobuf-encoded bytes.
+#[derive(Debug)]
+pub struct ProtobufEncoder {
+    columns: Vec<(ColumnName, SqlColumnType)>,
+    message_descriptor: MessageDescriptor,
+    schema_id: ProtobufSchemaId,
+}
+
+impl ProtobufEncoder {
+    pub fn new(generator: ProtobufSchemaGenerator, schema_id: ProtobufSchemaId) -> Self {
+        ProtobufEncoder {
+            columns: generator.columns,
+            message_descriptor: generator.message_descriptor,
+            schema_id,
+        }
+    }
+}
+
+impl Encode for ProtobufEncoder {
+    fn encode_unchecked(&self, row: Row) -> Vec<u8> {
+        let message = encode_datums_as_message(&self.message_descriptor, row.iter(), &self.columns);
+        let mut buf = vec![];
+        match self.schema_id {
+            ProtobufSchemaId::Confluent(id) => {
+                // The Confluent framing is a magic byte (0), a 32-bit schema
+                // ID, and the message index. A single 0 byte is shorthand for
+                // the first message in the schema.
+                //
+                // https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+                buf.write_u8(0).expect("writing to vec cannot fail");
+                buf.write_i32::<NetworkEndian>(id)
+                    .expect("writing to vec cannot fail");
+                buf.write_u8(0).expect("writing to vec cannot fail");
+            }
+            ProtobufSchemaId::Glue(id) => crate::glue::write_protobuf_header(&mut buf, id),
+        }
+        message
+            .encode(&mut buf)
+            .expect("writing to vec cannot fail");
+        buf
+    }
+
+    fn hash(&self, buf: &[u8]) -> u64 {
+        // Compute a stable hash by ignoring the header, which carries a schema
+        // id that may vary run-to-run.
+        let payload = match self.schema_id {
+            ProtobufSchemaId::Confluent(_) => {
+                crate::confluent::extract_protobuf_header(buf)
+                    .expect("encode_unchecked wrote a Confluent header")
+                    .1
+            }
+            ProtobufSchemaId::Glue(_) => {
+                crate::glue::extract_protobuf_header(buf)
+                    .expect("encode_unchecked wrote a Glue header")
+                    .1
+            }
+        };
+        seahash::hash(payload)
+    }
+}
+
+/// Encodes a sequence of `Datum`s as a message of type `descriptor`, whose
+/// fields correspond positionally to `columns`.
+fn encode_datums_as_message<'a, I>(
+    descriptor: &MessageDescriptor,
+    datums: I,
+    columns: &[(ColumnName, SqlColumnType)],
+) -> DynamicMessage
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut message = DynamicMessage::new(descriptor.clone());
+    for (i, ((_, typ), datum)) in columns.iter().zip_eq(datums).enumerate() {
+        if datum.is_null() {
+            // Leave the field unset.
+            continue;
+        }
+        let number = u32::try_from(i + 1).expect("validated by ProtobufSchemaGenerator");
+        let field = descriptor
+            .get_field(number)
+            .expect("generated message has a field per column");
+        message.set_field(&field, datum_to_value(datum, &typ.scalar_type, &field));
+    }
+    message
+}
+
+fn datum_to_value(datum: Datum, scalar_type: &SqlScalarType, field: &FieldDescriptor) -> Value {
+    match scalar_type {
+        SqlScalarType::Bool => Value::Bool(datum.unwrap_bool()),
+        SqlScalarType::Int16 => Value::I32(i32::from(datum.unwrap_int16())),
+        SqlScalarType::Int32 => Value::I32(datum.unwrap_int32()),
+        SqlScalarType::Int64 => Value::I64(datum.unwrap_int64()),
+        SqlScalarType::UInt16 => Value::U32(u32::from(datum.unwrap_uint16())),
+        SqlScalarType::UInt32
+        | SqlScalarType::Oid
+        | SqlScalarType::RegClass
+        | SqlScalarType::RegProc
+        | SqlScalarType::RegType => Value::U32(datum.unwrap_uint32()),
+        SqlScalarType::UInt64 => Value::U64(datum.unwrap_uint64()),
+        SqlScalarType::Float32 => Value::F32(datum.unwrap_float32()),
+        SqlScalarType::Float64 => Value::F64(datum.unwrap_float64()),
+        SqlScalarType::Bytes => Value::Bytes(datum.unwrap_bytes().to_vec().into()),
+        SqlScalarType::Date => Value::I32(datum.unwrap_date().unix_epoch_days()),
+        SqlScalarType::Time => Value::I64({
+            let time = datum.unwrap_time();
+            i64::from(time.num_seconds_from_midnight()) * 1_000_000
+                + i64::from(time.nanosecond()) / 1_000
+        }),
+        SqlScalarType::Timestamp { .. } => Value::I64(
+            datum
+                .unwrap_timestamp()
+                .to_naive()
+                .and_utc()
+                .timestamp_micros(),
+        ),
+        SqlScalarType::TimestampTz { .. } => Value::I64(
+            datum
+                .unwrap_timestamptz()
+                .to_naive()
+                .and_utc()
+                .timestamp_micros(),
+        ),
+        SqlScalarType::String | SqlScalarType::VarChar { .. } | SqlScalarType::PgLegacyName => {
+            Value::String(datum.unwrap_str().to_owned())
+        }
+        SqlScalarType::Char { length } => Value::String(mz_repr::adt::char::format_str_pad(
+            datum.unwrap_str(),
+            *length,
+        )),
+        SqlScalarType::Record { fields, .. } => {
+            let kind = field.kind();
+            let descriptor = kind
+                .as_message()
+                .expect("record columns are generated as message fields");
+            Value::Message(encode_datums_as_message(
+                descriptor,
+                datum.unwrap_list().iter(),
+                fields,
+            ))
+        }
+        SqlScalarType::List { .. } | SqlScalarType::Array(_) | SqlScalarType::Map { .. } => {
+            unreachable!("rejected by ProtobufSchemaGenerator")
+        }
+        scalar_type => {
+            // Everything else is encoded in its PostgreSQL text representation.
+            let mut buf = BytesMut::new();
+            mz_pgrepr::Value::from_datum(datum, scalar_type)
+                .expect("datum is not null")
+                .encode_text(&mut buf, mz_pgrepr::TextEncodeSettings::STABLE);
+            Value::String(String::from_utf8(buf.to_vec()).expect("text encoding is valid UTF-8"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::{Datum, RelationDesc, Row, SqlScalarType};
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn desc() -> RelationDesc {
+        RelationDesc::builder()
+            .with_column("a", SqlScalarType::Int64.nullable(false))
+            .with_column("b c", SqlScalarType::String.nullable(true))
+            .with_column(
+                "n",
+                SqlScalarType::Numeric { max_scale: None }.nullable(true),
+            )
+            .finish()
+    }
+
+    #[mz_ore::test]
+    fn schema() {
+        let generator = ProtobufSchemaGenerator::new(desc(), false, "Value").unwrap();
+        assert_eq!(
+            generator.schema(),
+            "syntax = \"proto3\";
+
+message Value {
+  int64 a = 1;
+  optional string b_c = 2;
+  optional string n = 3;
+}
+"
+        );
+    }
+
+    #[mz_ore::test]
+    fn debezium_schema() {
+        let generator = ProtobufSchemaGenerator::new(desc(), true, "Value").unwrap();
+        assert_eq!(
+            generator.schema(),
+            "syntax = \"proto3\";
+
+message Value {
+  .Value.Row before = 1;
+  .Value.Row after = 2;
+
+  message Row {
+    int64 a = 1;
+    optional string b_c = 2;
+    optional string n = 3;
+  }
+}
+"
+        );
+    }
+
+    #[mz_ore::test]
+    fn rejects_lists() {
+        let desc = RelationDesc::builder()
+            .with_column(
+                "l",
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Int32),
+                    custom_id: None,
+                }
+                .nullable(false),
+            )
+            .finish();
+        let err = ProtobufSchemaGenerator::new(desc, false, "Value").unwrap_err();
+        assert!(err.to_string().contains("list, array, or map"), "{err}");
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `decNumberFromString`
+    fn roundtrip() {
+        let generator = ProtobufSchemaGenerator::new(desc(), false, "Value").unwrap();
+        let descriptor = generator.message_descriptor().clone();
+        let encoder = ProtobufEncoder::new(generator, ProtobufSchemaId::Confluent(7));
+        let n = Datum::from(mz_repr::adt::numeric::Numeric::from(15));
+        let row = Row::pack_slice(&[Datum::Int64(42), Datum::Null, n]);
+        let bytes = encoder.encode_unchecked(row);
+
+        let (id, payload) = crate::confluent::extract_protobuf_header(&bytes).unwrap();
+        assert_eq!(id, 7);
+        let mut decoder = Decoder::new(
+            DecodedDescriptors {
+                message_descriptor: descriptor.clone(),
+                columns: vec![],
+                message_name: descriptor.full_name().to_owned(),
+            },
+            false,
+        )
+        .unwrap();
+        let decoded = decoder.decode(payload).unwrap().unwrap();
+        assert_eq!(
+            decoded,
+            Row::pack_slice(&[Datum::Int64(42), Datum::String(""), Datum::String("15")])
+        );
+    }
+
+    #[mz_ore::test]
+    fn hash_ignores_framing() {
+        let encode = |schema_id| {
+            let generator = ProtobufSchemaGenerator::new(desc(), false, "Value").unwrap();
+            let encoder = ProtobufEncoder::new(generator, schema_id);
+            let row = Row::pack_slice(&[Datum::Int64(42), Datum::String("x"), Datum::Null]);
+            let bytes = encoder.encode_unchecked(row);
+            encoder.hash(&bytes)
+        };
+        assert_eq!(
+            encode(ProtobufSchemaId::Confluent(7)),
+            encode(ProtobufSchemaId::Glue(Uuid::from_u128(0x1234_5678)))
+        );
+    }
+}
@@ -28,7 +28,7 @@ pub use desc::{
 mod replication;
 pub use replication::{
     ensure_full_row_binlog_format, ensure_gtid_consistency, ensure_replication_commit_order,
-    query_sys_var,
+    is_mariadb, query_gtid_executed, query_gtid_purged, query_sys_var,
 };
 
 pub mod schemas;
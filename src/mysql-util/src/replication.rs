@@ -26,6 +26,39 @@ pub async fn query_sys_var(conn: &mut Conn, name: &str) -> Result<String, MySqlE
     Ok(value)
 }
 
+/// Whether the server is a MariaDB server rather than a MySQL server.
+///
+/// MariaDB speaks the MySQL protocol but uses its own GTID format, made of a replication domain
+/// id, the id of the server that originated the transaction and a sequence number, e.g.
+/// `0-1-42`. It also names the related system variables differently.
+pub async fn is_mariadb(conn: &mut Conn) -> Result<bool, MySqlError> {
+    Ok(query_sys_var(conn, "version").await?.contains("MariaDB"))
+}
+
+/// Query the set of GTIDs of all the transactions executed on the server.
+///
+/// For MySQL this is `@@gtid_executed`. For MariaDB this is `@@gtid_binlog_state`, which holds
+/// the last GTID logged for every domain and server id pair.
+pub async fn query_gtid_executed(conn: &mut Conn) -> Result<String, MySqlError> {
+    if is_mariadb(conn).await? {
+        query_sys_var(conn, "global.gtid_binlog_state").await
+    } else {
+        query_sys_var(conn, "global.gtid_executed").await
+    }
+}
+
+/// Query the set of GTIDs of the transactions that have been purged from the binlog.
+///
+/// MariaDB does not keep track of this set, so an empty set is returned for it and a replication
+/// stream requested from a purged position fails to start instead.
+pub async fn query_gtid_purged(conn: &mut Conn) -> Result<String, MySqlError> {
+    if is_mariadb(conn).await? {
+        Ok(String::new())
+    } else {
+        query_sys_var(conn, "global.gtid_purged").await
+    }
+}
+
 fn is_safe_sys_var_name(name: &str) -> bool {
     !name.is_empty()
         && name.split('.').all(|segment| {
@@ -60,6 +93,11 @@ pub async fn ensure_full_row_binlog_format(conn: &mut Conn) -> Result<(), MySqlE
 }
 
 pub async fn ensure_gtid_consistency(conn: &mut Conn) -> Result<(), MySqlError> {
+    // MariaDB always assigns GTIDs. Strict mode makes sure that the sequence numbers within each
+    // replication domain only ever increase, which is what we track progress with.
+    if is_mariadb(conn).await? {
+        return verify_sys_setting(conn, "gtid_strict_mode", "1").await;
+    }
     verify_sys_setting(conn, "gtid_mode", "ON").await?;
     verify_sys_setting(conn, "enforce_gtid_consistency", "ON").await?;
     verify_sys_setting(conn, "gtid_next", "AUTOMATIC").await?;
@@ -77,7 +115,14 @@ pub async fn ensure_gtid_consistency(conn: &mut Conn) -> Result<(), MySqlError>
 /// 8.0.27 multi-threaded is default and preserve-commit-order is default on. So both of those
 /// default scenarios are fine. Unfortunately on some versions of MySQL on RDS, the default
 /// parameters use multi-threading without the preserve-commit-order setting on.
+///
+/// MariaDB replicas always commit the transactions of a replication domain in order, even with
+/// parallel replication enabled, so there is nothing to check for them.
 pub async fn ensure_replication_commit_order(conn: &mut Conn) -> Result<(), MySqlError> {
+    if is_mariadb(conn).await? {
+        return Ok(());
+    }
+
     // This system variables were renamed between MySQL 5.7 and 8.0
     let is_multi_threaded = match query_sys_var(conn, "replica_parallel_workers").await {
         Ok(val) => val != "0" && val != "1",
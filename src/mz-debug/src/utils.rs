@@ -96,9 +96,12 @@ pub async fn get_k8s_auth_mode(
 
     match authenticator_kind {
         AuthenticatorKind::None => Ok(AuthMode::None),
-        // Sasl and Oidc both authenticate with a username and password, so they
-        // are handled identically to Password here.
-        AuthenticatorKind::Password | AuthenticatorKind::Sasl | AuthenticatorKind::Oidc => {
+        // Sasl, Oidc, and Ldap all authenticate with a username and password, so
+        // they are handled identically to Password here.
+        AuthenticatorKind::Password
+        | AuthenticatorKind::Sasl
+        | AuthenticatorKind::Oidc
+        | AuthenticatorKind::Ldap => {
             if let (Some(mz_username), Some(mz_password)) = (&mz_username, &mz_password) {
                 Ok(AuthMode::Password(PasswordAuthCredentials {
                     username: mz_username.clone(),
@@ -110,7 +113,7 @@ pub async fn get_k8s_auth_mode(
                 ))
             }
         }
-        AuthenticatorKind::Frontegg => Err(anyhow::anyhow!(
+        AuthenticatorKind::Frontegg | AuthenticatorKind::Certificate => Err(anyhow::anyhow!(
             "Unsupported authenticator kind: {:?}",
             authenticator_kind
         )),
@@ -774,7 +774,8 @@ pub(crate) async fn validate_sink_connections_exist_impl(
     for obj in planned_project.iter_objects() {
         if let Statement::CreateSink(ref stmt) = obj.typed_object.stmt {
             let connection_ids = match &stmt.connection {
-                CreateSinkConnection::Kafka { connection, .. } => {
+                CreateSinkConnection::Kafka { connection, .. }
+                | CreateSinkConnection::Postgres { connection, .. } => {
                     vec![ObjectId::from_raw_item_name(
                         connection,
                         obj.id.expect_database(),
@@ -800,6 +801,8 @@ pub(crate) async fn validate_sink_connections_exist_impl(
                     }
                     ids
                 }
+                // HTTP sinks don't reference a connection.
+                CreateSinkConnection::Http { .. } => vec![],
             };
 
             for conn_id in connection_ids {
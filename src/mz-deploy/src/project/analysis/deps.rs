@@ -347,14 +347,20 @@ impl<'ast> Visit<'ast, Raw> for DependencyVisitor<'_> {
         // `src/sql/src/names.rs`): a simple CTE's body is resolved with only its
         // *earlier* siblings in scope, so a simple CTE whose name shadows a
         // catalog object still depends on that object via references in its own
-        // body. Mutually-recursive blocks make every name visible up front.
-        if matches!(node.ctes, CteBlock::Simple(_)) {
+        // body. A recursive CTE's body additionally sees its own name.
+        // Mutually-recursive blocks make every name visible up front.
+        if matches!(node.ctes, CteBlock::Simple(_) | CteBlock::Recursive(_)) {
+            let recursive = matches!(node.ctes, CteBlock::Recursive(_));
             self.cte_scope.push(BTreeSet::new());
-            if let CteBlock::Simple(ctes) = &node.ctes {
+            if let CteBlock::Simple(ctes) | CteBlock::Recursive(ctes) = &node.ctes {
                 for cte in ctes {
-                    // Visit this body before its own name is in scope.
+                    if recursive {
+                        self.cte_scope.insert_current(cte.alias.name.to_string());
+                    }
                     self.visit_query(&cte.query);
-                    self.cte_scope.insert_current(cte.alias.name.to_string());
+                    if !recursive {
+                        self.cte_scope.insert_current(cte.alias.name.to_string());
+                    }
                 }
             }
             // The main query body sees all simple CTE names. Replicate the rest
@@ -477,7 +483,8 @@ fn extract_source_connection_dep(
         CreateSourceConnection::Kafka { connection, .. }
         | CreateSourceConnection::Postgres { connection, .. }
         | CreateSourceConnection::SqlServer { connection, .. }
-        | CreateSourceConnection::MySql { connection, .. } => {
+        | CreateSourceConnection::MySql { connection, .. }
+        | CreateSourceConnection::Iceberg { connection, .. } => {
             deps.insert(ObjectId::from_raw_item_name(
                 connection,
                 default_database,
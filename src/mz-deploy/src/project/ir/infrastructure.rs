@@ -215,6 +215,14 @@ fn extract_source(stmt: &mz_sql_parser::ast::CreateSourceStatement<Raw>) -> Infr
             Some(raw_item_name_to_string(connection)),
             options_to_properties!(options),
         ),
+        CreateSourceConnection::Iceberg {
+            connection,
+            options,
+        } => (
+            "Iceberg".to_string(),
+            Some(raw_item_name_to_string(connection)),
+            options_to_properties!(options),
+        ),
         CreateSourceConnection::LoadGenerator { generator, options } => (
             format!("Load Generator ({})", generator),
             None,
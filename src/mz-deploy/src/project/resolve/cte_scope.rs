@@ -31,6 +31,10 @@
 //!   drive this by pushing an empty scope, then visiting each body and calling
 //!   [`insert_current`](CteScope::insert_current) after each.
 //!
+//! - **Recursive CTEs** (`WITH RECURSIVE a AS (...) SELECT ...`): like simple
+//!   CTEs, except that the name of CTE `i` is already in scope while visiting
+//!   CTE `i`'s own body, so that it can refer to itself.
+//!
 //! - **Mutually Recursive CTEs** (`WITH MUTUALLY RECURSIVE a AS (...), b AS (...)
 //!   SELECT ...`): All CTE names are visible to all CTE definitions and the main
 //!   query body. Self-references and forward references are valid, so all names
@@ -122,7 +126,9 @@ impl CteScope {
     /// docs.
     pub(crate) fn collect_cte_names(ctes: &CteBlock<Raw>) -> BTreeSet<String> {
         match ctes {
-            CteBlock::Simple(ctes) => ctes.iter().map(|cte| cte.alias.name.to_string()).collect(),
+            CteBlock::Simple(ctes) | CteBlock::Recursive(ctes) => {
+                ctes.iter().map(|cte| cte.alias.name.to_string()).collect()
+            }
             CteBlock::MutuallyRecursive(block) => {
                 block.ctes.iter().map(|cte| cte.name.to_string()).collect()
             }
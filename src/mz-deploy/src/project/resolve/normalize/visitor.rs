@@ -136,8 +136,8 @@ impl<T: NameTransformer> NormalizingVisitor<T> {
 
     /// Normalize connection references in CREATE SINK statements.
     ///
-    /// Handles both Kafka and Iceberg sink types, ensuring their connection
-    /// references are normalized.
+    /// Handles Kafka, Iceberg, Postgres, and HTTP sink types, ensuring their connection
+    /// and secret references are normalized.
     pub fn normalize_sink_connection(&self, connection: &mut CreateSinkConnection<Raw>) {
         match connection {
             CreateSinkConnection::Kafka { connection, .. } => {
@@ -153,6 +153,18 @@ impl<T: NameTransformer> NormalizingVisitor<T> {
                     self.normalize_raw_item_name(aws_connection);
                 }
             }
+            CreateSinkConnection::Postgres { connection, .. } => {
+                self.normalize_raw_item_name(connection);
+            }
+            CreateSinkConnection::Http { options, .. } => {
+                // HTTP sinks have no connection, but their headers may
+                // reference secrets.
+                for option in options {
+                    if let Some(ref mut value) = option.value {
+                        self.normalize_with_option_value(value);
+                    }
+                }
+            }
         }
     }
 
@@ -165,7 +177,8 @@ impl<T: NameTransformer> NormalizingVisitor<T> {
             CreateSourceConnection::Kafka { connection, .. }
             | CreateSourceConnection::Postgres { connection, .. }
             | CreateSourceConnection::SqlServer { connection, .. }
-            | CreateSourceConnection::MySql { connection, .. } => {
+            | CreateSourceConnection::MySql { connection, .. }
+            | CreateSourceConnection::Iceberg { connection, .. } => {
                 self.normalize_raw_item_name(connection);
             }
             CreateSourceConnection::LoadGenerator { .. } => {}
@@ -205,6 +218,11 @@ impl<T: NameTransformer> NormalizingVisitor<T> {
                     self.normalize_with_option_value(item);
                 }
             }
+            WithOptionValue::Map(entries) => {
+                for item in entries.values_mut() {
+                    self.normalize_with_option_value(item);
+                }
+            }
             _ => {}
         }
     }
@@ -319,14 +337,20 @@ impl<T: NameTransformer> VisitMut<'_, Raw> for NormalizingVisitor<T> {
         // `src/sql/src/names.rs`): a simple CTE's body is resolved with only its
         // *earlier* siblings in scope, so a simple CTE whose name shadows a
         // catalog object can still reference that object inside its own body.
+        // A recursive CTE's body additionally sees its own name.
         // Mutually-recursive blocks make every name visible up front.
-        if matches!(node.ctes, CteBlock::Simple(_)) {
+        if matches!(node.ctes, CteBlock::Simple(_) | CteBlock::Recursive(_)) {
+            let recursive = matches!(node.ctes, CteBlock::Recursive(_));
             self.cte_scope.push(std::collections::BTreeSet::new());
-            if let CteBlock::Simple(ctes) = &mut node.ctes {
+            if let CteBlock::Simple(ctes) | CteBlock::Recursive(ctes) = &mut node.ctes {
                 for cte in ctes.iter_mut() {
-                    // Visit this body before its own name is in scope.
+                    if recursive {
+                        self.cte_scope.insert_current(cte.alias.name.to_string());
+                    }
                     self.visit_query_mut(&mut cte.query);
-                    self.cte_scope.insert_current(cte.alias.name.to_string());
+                    if !recursive {
+                        self.cte_scope.insert_current(cte.alias.name.to_string());
+                    }
                 }
             }
             // The main query body sees all simple CTE names. Replicate the rest
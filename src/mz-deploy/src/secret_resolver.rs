@@ -238,6 +238,7 @@ mod tests {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         })
     }
 
@@ -251,6 +252,7 @@ mod tests {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         })
     }
 
@@ -415,6 +417,7 @@ mod tests {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         });
         let err = resolver.resolve_expr(expr).await.unwrap_err();
         match err {
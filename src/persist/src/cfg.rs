@@ -10,6 +10,7 @@
 //! Configuration for [crate::location] implementations.
 
 use std::collections::BTreeMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -25,11 +26,13 @@ use crate::azure::{AzureBlob, AzureBlobConfig};
 use crate::file::{FileBlob, FileBlobConfig};
 #[cfg(feature = "foundationdb")]
 use crate::foundationdb::{FdbConsensus, FdbConsensusConfig};
+use crate::gcs::{GcsBlob, GcsBlobConfig, GcsCredentials};
 use crate::location::{Blob, Consensus, Determinate, ExternalError};
 use crate::mem::{MemBlob, MemBlobConfig, MemConsensus};
 use crate::metrics::S3BlobMetrics;
 use crate::postgres::{PostgresConsensus, PostgresConsensusConfig};
 use crate::s3::{S3Blob, S3BlobConfig};
+use crate::sqlite::{SqliteConsensus, SqliteConsensusConfig};
 
 /// Adds the full set of all mz_persist `Config`s.
 pub fn all_dyn_configs(configs: ConfigSet) -> ConfigSet {
@@ -48,6 +51,8 @@ pub enum BlobConfig {
     Mem(bool),
     /// Config for [AzureBlob].
     Azure(AzureBlobConfig),
+    /// Config for [GcsBlob].
+    Gcs(GcsBlobConfig),
     #[cfg(feature = "turmoil")]
     /// Config for [crate::turmoil::TurmoilBlob].
     Turmoil(crate::turmoil::BlobConfig),
@@ -74,6 +79,7 @@ impl BlobConfig {
             BlobConfig::File(config) => Ok(Arc::new(FileBlob::open(config).await?)),
             BlobConfig::S3(config) => Ok(Arc::new(S3Blob::open(config).await?)),
             BlobConfig::Azure(config) => Ok(Arc::new(AzureBlob::open(config).await?)),
+            BlobConfig::Gcs(config) => Ok(Arc::new(GcsBlob::open(config).await?)),
             BlobConfig::Mem(tombstone) => {
                 Ok(Arc::new(MemBlob::open(MemBlobConfig::new(tombstone))))
             }
@@ -138,6 +144,45 @@ impl BlobConfig {
 
                 Ok(BlobConfig::S3(config))
             }
+            "gs" => {
+                let bucket = url
+                    .host()
+                    .ok_or_else(|| anyhow!("missing bucket: {}", url))?
+                    .to_string();
+                let prefix = url
+                    .path()
+                    .strip_prefix('/')
+                    .unwrap_or_else(|| url.path())
+                    .to_string();
+                let endpoint = query_params.remove("endpoint").map(|x| x.into_owned());
+                let credentials_file = query_params
+                    .remove("credentials_file")
+                    .map(|x| x.into_owned());
+                let anonymous = match query_params.remove("anonymous").as_deref() {
+                    None | Some("false") => false,
+                    Some("true") => true,
+                    Some(other) => Err(Determinate::new(anyhow!(
+                        "invalid anonymous param value: {other}"
+                    )))?,
+                };
+
+                let credentials = match (credentials_file, anonymous) {
+                    (Some(_), true) => Err(Determinate::new(anyhow!(
+                        "credentials_file and anonymous are mutually exclusive: {}",
+                        url
+                    )))?,
+                    (Some(path), false) => {
+                        GcsCredentials::from_service_account_file(Path::new(&path))?
+                    }
+                    (None, true) => GcsCredentials::Anonymous,
+                    (None, false) => GcsCredentials::from_env().await?,
+                };
+
+                let config =
+                    GcsBlobConfig::new(bucket, prefix, endpoint, credentials, knobs, metrics)?;
+
+                Ok(BlobConfig::Gcs(config))
+            }
             "mem" => {
                 if !cfg!(debug_assertions) {
                     warn!("persist unexpectedly using in-mem blob in a release binary");
@@ -217,6 +262,8 @@ pub enum ConsensusConfig {
     FoundationDB(FdbConsensusConfig),
     /// Config for [PostgresConsensus].
     Postgres(PostgresConsensusConfig),
+    /// Config for [SqliteConsensus].
+    Sqlite(SqliteConsensusConfig),
     /// Config for [MemConsensus], only available in testing.
     Mem,
     #[cfg(feature = "turmoil")]
@@ -235,6 +282,7 @@ impl ConsensusConfig {
             ConsensusConfig::Postgres(config) => {
                 Ok(Arc::new(PostgresConsensus::open(config).await?))
             }
+            ConsensusConfig::Sqlite(config) => Ok(Arc::new(SqliteConsensus::open(config).await?)),
             ConsensusConfig::Mem => Ok(Arc::new(MemConsensus::default())),
             #[cfg(feature = "turmoil")]
             ConsensusConfig::Turmoil(config) => {
@@ -258,6 +306,7 @@ impl ConsensusConfig {
             "postgres" | "postgresql" => Ok(ConsensusConfig::Postgres(
                 PostgresConsensusConfig::new(url, knobs, metrics, dyncfg)?,
             )),
+            "sqlite" => Ok(ConsensusConfig::Sqlite(SqliteConsensusConfig::new(url)?)),
             "mem" => {
                 if !cfg!(debug_assertions) {
                     warn!("persist unexpectedly using in-mem consensus in a release binary");
@@ -0,0 +1,558 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A Google Cloud Storage implementation of [Blob] storage.
+//!
+//! This talks to the GCS JSON API directly rather than going through the S3
+//! interoperability (XML) API, whose multipart upload semantics differ from
+//! S3's in ways that persist trips over.
+
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use gcp_auth::{CustomServiceAccount, TokenProvider};
+use mz_ore::bytes::SegmentedBytes;
+use mz_ore::cast::CastFrom;
+use mz_ore::metrics::MetricsRegistry;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::cfg::BlobKnobs;
+use crate::error::Error;
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+use crate::metrics::S3BlobMetrics;
+
+/// The public GCS JSON API endpoint.
+const GCS_ENDPOINT: &str = "https://storage.googleapis.com";
+
+/// The scope requested for access tokens. `devstorage.read_write` would be
+/// sufficient, but every service-account key is allowed to mint tokens for
+/// the blanket scope, which matches what `storage_types::connections::gcp`
+/// requests.
+const GCS_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
+/// How [GcsBlob] authenticates its requests.
+#[derive(Clone)]
+pub enum GcsCredentials {
+    /// Send requests without an `Authorization` header. Only useful against a
+    /// local fake-GCS stand-in.
+    Anonymous,
+    /// Mint access tokens from the given provider.
+    Provider(Arc<dyn TokenProvider>),
+}
+
+impl Debug for GcsCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GcsCredentials::Anonymous => write!(f, "Anonymous"),
+            GcsCredentials::Provider(_) => write!(f, "Provider(<redacted>)"),
+        }
+    }
+}
+
+impl GcsCredentials {
+    /// Loads a service-account key from the given file.
+    ///
+    /// The file must hold the JSON output of `gcloud iam service-accounts keys
+    /// create`, the same format accepted by GCP connections.
+    pub fn from_service_account_file(path: &Path) -> Result<Self, Error> {
+        let json = std::fs::read_to_string(path)?;
+        let account = CustomServiceAccount::from_json(&json).map_err(|err| {
+            Error::from(format!(
+                "invalid GCP service-account key {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        Ok(GcsCredentials::Provider(Arc::new(account)))
+    }
+
+    /// Discovers credentials from the environment, e.g.
+    /// `GOOGLE_APPLICATION_CREDENTIALS` or the GKE metadata server.
+    pub async fn from_env() -> Result<Self, Error> {
+        let provider = gcp_auth::provider()
+            .await
+            .map_err(|err| Error::from(format!("discovering GCP credentials: {}", err)))?;
+        Ok(GcsCredentials::Provider(provider))
+    }
+}
+
+/// Configuration for opening a [GcsBlob].
+#[derive(Clone, Debug)]
+pub struct GcsBlobConfig {
+    metrics: S3BlobMetrics,
+    client: reqwest::Client,
+    credentials: GcsCredentials,
+    endpoint: String,
+    bucket: String,
+    prefix: String,
+}
+
+impl GcsBlobConfig {
+    const EXTERNAL_TESTS_GCS_BUCKET: &'static str = "MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET";
+
+    /// Returns a new [GcsBlobConfig] for use in production.
+    ///
+    /// Stores objects in the given bucket prepended with the (possibly empty)
+    /// prefix. If no endpoint is given, the public GCS endpoint is used.
+    pub fn new(
+        bucket: String,
+        prefix: String,
+        endpoint: Option<String>,
+        credentials: GcsCredentials,
+        knobs: Box<dyn BlobKnobs>,
+        metrics: S3BlobMetrics,
+    ) -> Result<Self, Error> {
+        let client = reqwest::ClientBuilder::new()
+            .timeout(knobs.operation_attempt_timeout())
+            .read_timeout(knobs.read_timeout())
+            .connect_timeout(knobs.connect_timeout())
+            .build()
+            .map_err(|err| Error::from(format!("building GCS HTTP client: {}", err)))?;
+        let endpoint = endpoint
+            .as_deref()
+            .unwrap_or(GCS_ENDPOINT)
+            .trim_end_matches('/')
+            .to_string();
+        Ok(GcsBlobConfig {
+            metrics,
+            client,
+            credentials,
+            endpoint,
+            bucket,
+            prefix,
+        })
+    }
+
+    /// Returns a new [GcsBlobConfig] for use in unit tests.
+    ///
+    /// By default, persist tests that use external storage are no-ops, so that
+    /// `cargo test` does the right thing without any configuration. To
+    /// activate the tests, set the `MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET`
+    /// environment variable and run a fake-GCS server on `localhost:40112`,
+    /// which `ci/test/cargo-test/mzcompose.py` does.
+    pub fn new_for_test() -> Result<Option<Self>, Error> {
+        struct TestBlobKnobs;
+        impl Debug for TestBlobKnobs {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("TestBlobKnobs").finish_non_exhaustive()
+            }
+        }
+        impl BlobKnobs for TestBlobKnobs {
+            fn operation_timeout(&self) -> Duration {
+                Duration::from_secs(30)
+            }
+
+            fn operation_attempt_timeout(&self) -> Duration {
+                Duration::from_secs(10)
+            }
+
+            fn connect_timeout(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn read_timeout(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn is_cc_active(&self) -> bool {
+                false
+            }
+        }
+
+        let bucket = match std::env::var(Self::EXTERNAL_TESTS_GCS_BUCKET) {
+            Ok(bucket) => bucket,
+            Err(_) => {
+                assert!(
+                    !mz_ore::env::is_var_truthy("CI"),
+                    "CI is supposed to run this test but something has gone wrong!"
+                );
+                return Ok(None);
+            }
+        };
+
+        let prefix = Uuid::new_v4().to_string();
+        let metrics = S3BlobMetrics::new(&MetricsRegistry::new());
+        let config = GcsBlobConfig::new(
+            bucket,
+            prefix,
+            Some("http://localhost:40112".to_string()),
+            GcsCredentials::Anonymous,
+            Box::new(TestBlobKnobs),
+            metrics,
+        )?;
+        Ok(Some(config))
+    }
+}
+
+/// A single entry of a GCS `objects.list` response, or the response to an
+/// `objects.get` metadata request.
+#[derive(Debug, Deserialize)]
+struct ObjectMetadata {
+    name: String,
+    /// GCS encodes 64-bit integers as JSON strings.
+    size: String,
+}
+
+impl ObjectMetadata {
+    fn size(&self) -> Result<u64, ExternalError> {
+        self.size
+            .parse()
+            .map_err(|err| anyhow!("invalid size {:?} for {}: {}", self.size, self.name, err))
+            .map_err(ExternalError::from)
+    }
+}
+
+/// A page of a GCS `objects.list` response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListObjectsResponse {
+    #[serde(default)]
+    items: Vec<ObjectMetadata>,
+    next_page_token: Option<String>,
+}
+
+/// The body of a GCS `buckets.insert` request.
+#[derive(Debug, Serialize)]
+struct CreateBucketRequest<'a> {
+    name: &'a str,
+}
+
+/// Implementation of [Blob] backed by Google Cloud Storage.
+#[derive(Debug)]
+pub struct GcsBlob {
+    metrics: S3BlobMetrics,
+    client: reqwest::Client,
+    credentials: GcsCredentials,
+    endpoint: String,
+    bucket: String,
+    prefix: String,
+    // Maximum number of keys we get information about per list request.
+    //
+    // Defaults to 1000 which is the current GCS max.
+    max_keys: usize,
+}
+
+impl GcsBlob {
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: GcsBlobConfig) -> Result<Self, ExternalError> {
+        let ret = GcsBlob {
+            metrics: config.metrics,
+            client: config.client,
+            credentials: config.credentials,
+            endpoint: config.endpoint,
+            bucket: config.bucket,
+            prefix: config.prefix,
+            max_keys: 1_000,
+        };
+        if let GcsCredentials::Anonymous = ret.credentials {
+            // TODO: we could move this logic into the test harness. It's
+            // currently here because it's surprisingly annoying to create the
+            // bucket out-of-band, mirroring what AzureBlob does for Azurite.
+            if let Err(error) = ret.create_bucket().await {
+                info!(
+                    ?error,
+                    "failed to create fake-GCS bucket; this is expected on repeat runs"
+                );
+            }
+        }
+        // Connect before returning success. We don't particularly care about
+        // what's stored in this blob (nothing writes to it, so presumably it's
+        // empty) just that we were able and allowed to fetch it.
+        let _ = ret.get("HEALTH_CHECK").await?;
+        Ok(ret)
+    }
+
+    fn get_path(&self, key: &str) -> String {
+        format!("{}/{}", self.prefix, key)
+    }
+
+    fn object_url(&self, key: &str) -> String {
+        format!(
+            "{}/storage/v1/b/{}/o/{}",
+            self.endpoint,
+            urlencoding::encode(&self.bucket),
+            urlencoding::encode(&self.get_path(key))
+        )
+    }
+
+    /// Attaches an access token to the request, if configured to.
+    async fn authorize(&self, req: RequestBuilder) -> Result<RequestBuilder, ExternalError> {
+        match &self.credentials {
+            GcsCredentials::Anonymous => Ok(req),
+            GcsCredentials::Provider(provider) => {
+                let token = provider
+                    .token(&[GCS_SCOPE])
+                    .await
+                    .map_err(|err| anyhow!("gcs blob auth error: {}", err))?;
+                Ok(req.bearer_auth(token.as_str()))
+            }
+        }
+    }
+
+    /// Sends the request, mapping transport failures to [ExternalError].
+    async fn send(&self, req: RequestBuilder, op: &str) -> Result<Response, ExternalError> {
+        let req = self.authorize(req).await?;
+        req.send().await.map_err(|err| {
+            if err.is_timeout() {
+                self.metrics.operation_attempt_timeouts.inc();
+            }
+            self.metrics
+                .error_counts
+                .with_label_values(&[op, "transport"])
+                .inc();
+            ExternalError::from(anyhow::Error::new(err).context(format!("gcs blob {} error", op)))
+        })
+    }
+
+    /// Returns an error describing an unexpected response status.
+    async fn status_error(&self, resp: Response, op: &str) -> ExternalError {
+        let status = resp.status();
+        self.metrics
+            .error_counts
+            .with_label_values(&[op, status.as_str()])
+            .inc();
+        let body = resp.text().await.unwrap_or_default();
+        ExternalError::from(anyhow!("gcs blob {} error: {}: {}", op, status, body))
+    }
+
+    /// Fetches the metadata of the object at `key`, if it exists.
+    async fn head(&self, key: &str) -> Result<Option<ObjectMetadata>, ExternalError> {
+        let req = self
+            .client
+            .get(self.object_url(key))
+            .query(&[("fields", "name,size")]);
+        let resp = self.send(req, "head").await?;
+        match resp.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => {
+                let metadata = resp
+                    .json::<ObjectMetadata>()
+                    .await
+                    .context("gcs blob head body error")?;
+                Ok(Some(metadata))
+            }
+            _ => Err(self.status_error(resp, "head").await),
+        }
+    }
+
+    async fn create_bucket(&self) -> Result<(), ExternalError> {
+        let req = self
+            .client
+            .post(format!("{}/storage/v1/b", self.endpoint))
+            .query(&[("project", "materialize")])
+            .json(&CreateBucketRequest { name: &self.bucket });
+        let resp = self.send(req, "create_bucket").await?;
+        if !resp.status().is_success() {
+            return Err(self.status_error(resp, "create_bucket").await);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Blob for GcsBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        self.metrics.get_part.inc();
+        let req = self
+            .client
+            .get(self.object_url(key))
+            .query(&[("alt", "media")]);
+        let resp = self.send(req, "get").await?;
+        match resp.status() {
+            StatusCode::NOT_FOUND => return Ok(None),
+            status if status.is_success() => {}
+            _ => return Err(self.status_error(resp, "get").await),
+        }
+
+        let content_length = resp.content_length();
+        let mut segments = SegmentedBytes::new();
+        let mut total_len: u64 = 0;
+        let mut body = resp.bytes_stream();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.context("gcs blob get body error")?;
+            total_len += u64::cast_from(chunk.len());
+            segments.push(chunk);
+        }
+
+        // Report if the content-length header didn't match the number of
+        // bytes we read from the network.
+        if content_length.is_some_and(|len| len != total_len) {
+            self.metrics.get_invalid_resp.inc();
+        }
+
+        Ok(Some(segments))
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        let blob_key_prefix = self.get_path(key_prefix);
+        let strippable_root_prefix = format!("{}/", self.prefix);
+        let max_results = self.max_keys.to_string();
+        let url = format!(
+            "{}/storage/v1/b/{}/o",
+            self.endpoint,
+            urlencoding::encode(&self.bucket)
+        );
+
+        let mut page_token = None;
+        loop {
+            self.metrics.list_objects.inc();
+            let mut req = self.client.get(&url).query(&[
+                ("prefix", blob_key_prefix.as_str()),
+                ("maxResults", max_results.as_str()),
+                ("fields", "items(name,size),nextPageToken"),
+            ]);
+            if let Some(page_token) = &page_token {
+                req = req.query(&[("pageToken", page_token)]);
+            }
+            let resp = self.send(req, "list").await?;
+            if !resp.status().is_success() {
+                return Err(self.status_error(resp, "list").await);
+            }
+            let page = resp
+                .json::<ListObjectsResponse>()
+                .await
+                .context("gcs blob list body error")?;
+
+            for object in page.items.iter() {
+                if let Some(key) = object.name.strip_prefix(&strippable_root_prefix) {
+                    let size_in_bytes = object.size()?;
+                    f(BlobMetadata { key, size_in_bytes });
+                } else {
+                    return Err(ExternalError::from(anyhow!(
+                        "found key with invalid prefix: {}",
+                        object.name
+                    )));
+                }
+            }
+
+            match page.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        // A single-request media upload only becomes visible once the entire
+        // body has been received, so unlike S3 there's no need to switch to a
+        // multipart (resumable) upload for atomicity. GCS accepts objects of
+        // up to 5 TiB this way.
+        self.metrics.set_single.inc();
+        let value_len = value.len();
+        let req = self
+            .client
+            .post(format!(
+                "{}/upload/storage/v1/b/{}/o",
+                self.endpoint,
+                urlencoding::encode(&self.bucket)
+            ))
+            .query(&[
+                ("uploadType", "media"),
+                ("name", self.get_path(key).as_str()),
+                ("fields", "name"),
+            ])
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(value);
+        let resp = self.send(req, "set").await?;
+        if !resp.status().is_success() {
+            return Err(self.status_error(resp, "set").await);
+        }
+        debug!("gcs set {} ({} bytes)", key, value_len);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        // There is a race condition here where, if two delete calls for the
+        // same key occur simultaneously, both might think they did the actual
+        // deletion. This return value is only used for metrics, so it's
+        // unfortunate, but fine.
+        self.metrics.delete_head.inc();
+        let size = match self.head(key).await? {
+            Some(metadata) => usize::cast_from(metadata.size()?),
+            None => return Ok(None),
+        };
+
+        self.metrics.delete_object.inc();
+        let req = self.client.delete(self.object_url(key));
+        let resp = self.send(req, "delete").await?;
+        match resp.status() {
+            // Someone else deleted it out from under us, see above.
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => Ok(Some(size)),
+            _ => Err(self.status_error(resp, "delete").await),
+        }
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        // TODO: GCS soft delete would let us restore recently-deleted objects,
+        // but restoring requires the object's generation, which persist
+        // doesn't track. For now, succeed iff the key exists.
+        match self.head(key).await? {
+            Some(_) => Ok(()),
+            None => Err(Determinate::new(anyhow!(
+                "gcs blob error: unable to restore non-existent key {key}"
+            ))
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::info;
+
+    use crate::location::tests::blob_impl_test;
+
+    use super::*;
+
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    async fn gcs_blob() -> Result<(), ExternalError> {
+        let config = match GcsBlobConfig::new_for_test()? {
+            Some(config) => config,
+            None => {
+                info!(
+                    "{} env not set: skipping test that uses external service",
+                    GcsBlobConfig::EXTERNAL_TESTS_GCS_BUCKET
+                );
+                return Ok(());
+            }
+        };
+
+        blob_impl_test(move |path| {
+            let path = path.to_owned();
+            let config = config.clone();
+            async move {
+                let config = GcsBlobConfig {
+                    prefix: format!("{}/gcs_blob_impl_test/{}", config.prefix, path),
+                    ..config
+                };
+                let mut blob = GcsBlob::open(config).await?;
+                blob.max_keys = 2;
+                Ok(blob)
+            }
+        })
+        .await
+    }
+}
@@ -382,6 +382,14 @@ impl From<deadpool_postgres::PoolError> for ExternalError {
     }
 }
 
+impl From<rusqlite::Error> for ExternalError {
+    fn from(x: rusqlite::Error) -> Self {
+        ExternalError::Indeterminate(Indeterminate {
+            inner: anyhow::Error::new(x),
+        })
+    }
+}
+
 impl From<tokio::task::JoinError> for ExternalError {
     fn from(x: tokio::task::JoinError) -> Self {
         ExternalError::Indeterminate(Indeterminate {
@@ -0,0 +1,340 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Implementation of [Consensus] backed by an embedded SQLite database.
+//!
+//! This exists so that single-node deployments can run with nothing but a
+//! local data directory, instead of needing a separate Postgres or
+//! CockroachDB process just for consensus.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_stream::try_stream;
+use async_trait::async_trait;
+use bytes::Bytes;
+use mz_ore::url::SensitiveUrl;
+use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params};
+use tracing::warn;
+
+use crate::error::Error;
+use crate::location::{CaSResult, Consensus, ExternalError, ResultStream, SeqNo, VersionedData};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS consensus (
+    shard TEXT NOT NULL,
+    sequence_number INTEGER NOT NULL,
+    data BLOB NOT NULL,
+    PRIMARY KEY(shard, sequence_number)
+) WITHOUT ROWID
+";
+
+/// How long a connection waits on a lock held by another connection (e.g. a
+/// second process that opened the same file) before giving up with
+/// `SQLITE_BUSY`.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Configuration for opening a [SqliteConsensus].
+#[derive(Clone, Debug)]
+pub struct SqliteConsensusConfig {
+    path: PathBuf,
+}
+
+impl SqliteConsensusConfig {
+    /// Returns the config for the database file named by a `sqlite:///abs/path` URL.
+    pub fn new(url: &SensitiveUrl) -> Result<Self, anyhow::Error> {
+        // `sqlite://consensus.db` names `consensus.db` as the host and leaves the path
+        // empty, and SQLite opens an empty path as a private temporary database.
+        if url.host_str().is_some_and(|host| !host.is_empty()) || url.path().is_empty() {
+            anyhow::bail!("sqlite consensus URL must be sqlite:///abs/path: {}", url);
+        }
+        Ok(SqliteConsensusConfig::from(url.path()))
+    }
+}
+
+impl<P: AsRef<Path>> From<P> for SqliteConsensusConfig {
+    fn from(path: P) -> Self {
+        SqliteConsensusConfig {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+/// Implementation of [Consensus] over a single SQLite database file.
+///
+/// Every write runs in its own `BEGIN IMMEDIATE` transaction, which takes the
+/// database's write lock up front. That serializes compare-and-set and
+/// truncate against each other, both within this process and against any
+/// other process with the same file open, so the checks and writes in each
+/// operation are trivially linearizable.
+pub struct SqliteConsensus {
+    path: PathBuf,
+    // NB: rusqlite connections are `Send` but not `Sync`, and all calls are
+    // blocking, so every operation runs on a blocking thread while holding
+    // this lock.
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl std::fmt::Debug for SqliteConsensus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqliteConsensus")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SqliteConsensus {
+    /// Opens (creating if necessary) the SQLite [Consensus] at `config`.
+    pub async fn open(config: SqliteConsensusConfig) -> Result<Self, ExternalError> {
+        let SqliteConsensusConfig { path } = config;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(Error::from)?;
+        }
+
+        let conn = {
+            let path = path.clone();
+            mz_ore::task::spawn_blocking(
+                || "persist::sqlite::open",
+                move || -> Result<Connection, rusqlite::Error> {
+                    let conn = Connection::open(path)?;
+                    conn.busy_timeout(BUSY_TIMEOUT)?;
+                    // WAL lets readers proceed concurrently with a writer, and
+                    // FULL synchronous mode fsyncs the WAL on every commit, so
+                    // a committed CaS survives a power loss.
+                    let journal_mode: String =
+                        conn.query_row("PRAGMA journal_mode = WAL", [], |row| row.get(0))?;
+                    if !journal_mode.eq_ignore_ascii_case("wal") {
+                        warn!("sqlite consensus unable to enable WAL mode, using {journal_mode}");
+                    }
+                    conn.execute_batch("PRAGMA synchronous = FULL")?;
+                    conn.execute_batch(SCHEMA)?;
+                    Ok(conn)
+                },
+            )
+            .await?
+        };
+
+        Ok(SqliteConsensus {
+            path,
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Runs `f` against the connection on a blocking thread.
+    async fn with_conn<T, F>(&self, name: &'static str, f: F) -> Result<T, ExternalError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<T, ExternalError> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        mz_ore::task::spawn_blocking(
+            || name,
+            move || {
+                let mut conn = conn.lock().map_err(Error::from)?;
+                f(&mut conn)
+            },
+        )
+        .await
+    }
+}
+
+/// Converts a sequence number to its SQLite representation.
+///
+/// We can only represent sequence numbers in the range [0, i64::MAX].
+fn seqno_to_sql(seqno: SeqNo) -> Result<i64, ExternalError> {
+    i64::try_from(seqno.0).map_err(|_| {
+        ExternalError::from(anyhow!(
+            "sequence numbers must fit within [0, i64::MAX], received: {:?}",
+            seqno
+        ))
+    })
+}
+
+/// Converts a sequence number from its SQLite representation.
+fn seqno_from_sql(sequence_number: i64) -> Result<SeqNo, ExternalError> {
+    // Sanity check that the sequence number we read falls in the [0, i64::MAX]
+    // range.
+    let sequence_number = u64::try_from(sequence_number)
+        .map_err(|_| anyhow!("invalid sequence number: {}", sequence_number))?;
+    Ok(SeqNo(sequence_number))
+}
+
+/// Returns the current head sequence number of `key`, if any.
+fn head_seqno(conn: &Connection, key: &str) -> Result<Option<SeqNo>, ExternalError> {
+    let q = "SELECT max(sequence_number) FROM consensus WHERE shard = ?1";
+    let seqno: Option<i64> = conn.query_row(q, params![key], |row| row.get(0))?;
+    seqno.map(seqno_from_sql).transpose()
+}
+
+#[async_trait]
+impl Consensus for SqliteConsensus {
+    fn list_keys(&self) -> ResultStream<'_, String> {
+        Box::pin(try_stream! {
+            let keys = self
+                .with_conn("persist::sqlite::list_keys", |conn| {
+                    let q = "SELECT DISTINCT shard FROM consensus";
+                    let mut statement = conn.prepare_cached(q)?;
+                    let keys = statement
+                        .query_map([], |row| row.get(0))?
+                        .collect::<Result<Vec<String>, _>>()?;
+                    Ok(keys)
+                })
+                .await?;
+            for key in keys {
+                yield key;
+            }
+        })
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.with_conn("persist::sqlite::head", move |conn| {
+            let q = "SELECT sequence_number, data FROM consensus
+                 WHERE shard = ?1 ORDER BY sequence_number DESC LIMIT 1";
+            let row = conn
+                .prepare_cached(q)?
+                .query_row(params![key], |row| {
+                    Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+                })
+                .optional()?;
+            row.map(|(seqno, data)| {
+                Ok(VersionedData {
+                    seqno: seqno_from_sql(seqno)?,
+                    data: Bytes::from(data),
+                })
+            })
+            .transpose()
+        })
+        .await
+    }
+
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        new: VersionedData,
+    ) -> Result<CaSResult, ExternalError> {
+        let key = key.to_owned();
+        let expected = new.seqno.previous();
+        let new_seqno = seqno_to_sql(new.seqno)?;
+        self.with_conn("persist::sqlite::cas", move |conn| {
+            let txn = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+            if head_seqno(&txn, &key)? != expected {
+                return Ok(CaSResult::ExpectationMismatch);
+            }
+            let q = "INSERT INTO consensus (shard, sequence_number, data) VALUES (?1, ?2, ?3)";
+            txn.prepare_cached(q)?
+                .execute(params![key, new_seqno, new.data.as_ref()])?;
+            txn.commit()?;
+            Ok(CaSResult::Committed)
+        })
+        .await
+    }
+
+    async fn scan(
+        &self,
+        key: &str,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        let from = seqno_to_sql(from)?;
+        // SQLite limits are i64s, and `SCAN_ALL` is i64::MAX.
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        self.with_conn("persist::sqlite::scan", move |conn| {
+            let q = "SELECT sequence_number, data FROM consensus
+                 WHERE shard = ?1 AND sequence_number >= ?2
+                 ORDER BY sequence_number ASC LIMIT ?3";
+            let mut statement = conn.prepare_cached(q)?;
+            let rows = statement.query_map(params![key, from, limit], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+            })?;
+            let mut results = Vec::new();
+            for row in rows {
+                let (seqno, data) = row?;
+                results.push(VersionedData {
+                    seqno: seqno_from_sql(seqno)?,
+                    data: Bytes::from(data),
+                });
+            }
+            Ok(results)
+        })
+        .await
+    }
+
+    async fn truncate(&self, key: &str, seqno: SeqNo) -> Result<Option<usize>, ExternalError> {
+        let key = key.to_owned();
+        let seqno_sql = seqno_to_sql(seqno)?;
+        self.with_conn("persist::sqlite::truncate", move |conn| {
+            let txn = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+            if head_seqno(&txn, &key)?.map_or(true, |head| head < seqno) {
+                return Err(ExternalError::from(anyhow!(
+                    "upper bound too high for truncate: {:?}",
+                    seqno
+                )));
+            }
+            let q = "DELETE FROM consensus WHERE shard = ?1 AND sequence_number < ?2";
+            let deleted = txn.prepare_cached(q)?.execute(params![key, seqno_sql])?;
+            txn.commit()?;
+            Ok(Some(deleted))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::location::tests::consensus_impl_test;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `sqlite3_open_v2` on OS `linux`
+    async fn sqlite_consensus() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let path = temp_dir.path().join("consensus.db");
+        consensus_impl_test(|| SqliteConsensus::open(path.clone().into())).await?;
+
+        // Everything written survives reopening the file.
+        let consensus = SqliteConsensus::open(path.clone().into()).await?;
+        let key = "reopen";
+        let data = VersionedData {
+            seqno: SeqNo(0),
+            data: Bytes::from("abc"),
+        };
+        assert_eq!(
+            consensus.compare_and_set(key, data.clone()).await,
+            Ok(CaSResult::Committed)
+        );
+        drop(consensus);
+        let consensus = SqliteConsensus::open(path.into()).await?;
+        assert_eq!(consensus.head(key).await, Ok(Some(data)));
+
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    fn sqlite_consensus_config_url() {
+        let config = |url: &str| SqliteConsensusConfig::new(&url.parse().expect("valid url"));
+
+        assert_eq!(
+            config("sqlite:///var/lib/mz/consensus.db")
+                .expect("valid config")
+                .path,
+            Path::new("/var/lib/mz/consensus.db")
+        );
+        // A relative path parses as the host, with an empty path.
+        assert!(config("sqlite://consensus.db").is_err());
+        assert!(config("sqlite://localhost/consensus.db").is_err());
+        assert!(config("sqlite:").is_err());
+    }
+}
@@ -826,3 +826,7 @@ pub const VIEW_MZ_OBJECT_GRAPH_EDGES_OID: u32 = 17116;
 pub const INDEX_MZ_OBJECT_GRAPH_EDGES_IND_OID: u32 = 17117;
 pub const VIEW_MZ_BUILTIN_TABLES_OID: u32 = 17118;
 pub const VIEW_MZ_BUILTIN_VIEWS_OID: u32 = 17119;
+pub const FUNC_APPROX_COUNT_DISTINCT_OID: u32 = 17120;
+pub const FUNC_HLL_SKETCH_OID: u32 = 17121;
+pub const FUNC_HLL_MERGE_OID: u32 = 17122;
+pub const FUNC_HLL_CARDINALITY_OID: u32 = 17123;
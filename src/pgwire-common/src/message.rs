@@ -214,6 +214,25 @@ impl GS2Header {
     pub fn channel_binding_enabled(&self) -> bool {
         matches!(self.cbind_flag, ChannelBinding::Required(_))
     }
+
+    /// Returns the header as it appeared on the wire, e.g. `n,,` or
+    /// `p=tls-server-end-point,a=alice,`. The client echoes these bytes back
+    /// (base64-encoded, with any channel binding data appended) in the `c=`
+    /// attribute of its final message.
+    pub fn to_wire(&self) -> String {
+        let mut header = match &self.cbind_flag {
+            ChannelBinding::None => "n".to_string(),
+            ChannelBinding::ClientSupported => "y".to_string(),
+            ChannelBinding::Required(cbname) => format!("p={cbname}"),
+        };
+        header.push(',');
+        if let Some(authzid) = &self.authzid {
+            header.push_str("a=");
+            header.push_str(authzid);
+        }
+        header.push(',');
+        header
+    }
 }
 
 #[derive(Debug)]
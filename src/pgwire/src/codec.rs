@@ -257,7 +257,7 @@ impl Codec {
         let byte = match &msg {
             BackendMessage::AuthenticationOk => b'R',
             BackendMessage::AuthenticationCleartextPassword
-            | BackendMessage::AuthenticationSASL
+            | BackendMessage::AuthenticationSASL(_)
             | BackendMessage::AuthenticationSASLContinue(_)
             | BackendMessage::AuthenticationSASLFinal(_) => b'R',
             BackendMessage::RowDescription(_) => b'T',
@@ -327,9 +327,11 @@ impl Codec {
             BackendMessage::AuthenticationCleartextPassword => {
                 dst.put_u32(3);
             }
-            BackendMessage::AuthenticationSASL => {
+            BackendMessage::AuthenticationSASL(mechanisms) => {
                 dst.put_u32(10);
-                dst.put_string("SCRAM-SHA-256");
+                for mechanism in mechanisms {
+                    dst.put_string(mechanism);
+                }
                 dst.put_u8(b'\0');
             }
             BackendMessage::AuthenticationSASLContinue(data) => {
@@ -21,7 +21,9 @@ use mz_repr::{ColumnName, RelationDesc};
 pub enum BackendMessage {
     AuthenticationOk,
     AuthenticationCleartextPassword,
-    AuthenticationSASL,
+    /// Lists the SASL mechanisms the server is willing to use, in order of
+    /// preference.
+    AuthenticationSASL(Vec<&'static str>),
     AuthenticationSASLContinue(SASLServerFirstMessage),
     AuthenticationSASLFinal(SASLServerFinalMessage),
     CommandComplete {
@@ -27,13 +27,16 @@ use mz_adapter::session::{
 };
 use mz_adapter::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use mz_adapter::{
-    AdapterError, AdapterNotice, ExecuteContextGuard, ExecuteResponse, PeekResponseUnary, metrics,
-    verify_datum_desc,
+    AdapterError, AdapterNotice, AuthFailure, ExecuteContextGuard, ExecuteResponse,
+    PeekResponseUnary, metrics, verify_datum_desc,
 };
 use mz_adapter_types::dyncfgs::OIDC_GROUP_CLAIM;
 use mz_auth::Authenticated;
 use mz_auth::password::Password;
-use mz_authenticator::{Authenticator, GenericOidcAuthenticator};
+use mz_authenticator::{
+    Authenticator, CertificateAuthenticator, ClientCertificate, GenericOidcAuthenticator,
+    LdapAuthenticator,
+};
 use mz_frontegg_auth::Authenticator as FronteggAuthenticator;
 use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
@@ -42,8 +45,8 @@ use mz_ore::str::StrExt;
 use mz_ore::{assert_none, assert_ok, instrument, soft_assert_eq_or_log, soft_assert_or_log};
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_pgwire_common::{
-    ConnectionCounter, Cursor, ErrorResponse, Format, FrontendMessage, Severity, VERSION_3,
-    VERSIONS,
+    ChannelBinding, Conn, ConnectionCounter, Cursor, ErrorResponse, Format, FrontendMessage,
+    Severity, VERSION_3, VERSIONS,
 };
 use mz_repr::{
     CatalogItemId, ColumnIndex, Datum, RelationDesc, RowArena, RowIterator, RowRef,
@@ -61,6 +64,8 @@ use mz_sql::plan::{CopyFormat, ExecuteTimeout, StatementDesc};
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::user::INTERNAL_USER_NAMES;
 use mz_sql::session::vars::VarInput;
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
 use postgres::error::SqlState;
 use tokio::io::{self, AsyncRead, AsyncWrite};
 use tokio::select;
@@ -118,6 +123,8 @@ where
     pub frontegg: Option<FronteggAuthenticator>,
     /// OIDC authenticator.
     pub oidc: GenericOidcAuthenticator,
+    /// LDAP authenticator.
+    pub ldap: LdapAuthenticator,
     /// The authentication method defined by the server's listener
     /// configuration.
     pub authenticator_kind: listeners::AuthenticatorKind,
@@ -151,6 +158,7 @@ pub async fn run<'a, A, I>(
         mut params,
         frontegg,
         oidc,
+        ldap,
         authenticator_kind,
         active_connection_counter,
         helm_chart_version,
@@ -173,8 +181,15 @@ where
 
     let user = params.remove("user").unwrap_or_else(String::new);
     let options = parse_options(params.get("options").unwrap_or(&String::new()));
-    let authenticator =
-        get_authenticator(authenticator_kind, frontegg, oidc, adapter_client.clone());
+    let authenticator = get_authenticator(
+        authenticator_kind,
+        frontegg,
+        oidc,
+        ldap,
+        adapter_client.clone(),
+    );
+    let authenticator_kind = authenticator.kind();
+    let application_name = params.get("application_name").cloned();
     // TODO move this somewhere it can be shared with HTTP
     let is_internal_user = INTERNAL_USER_NAMES.contains(&user);
     // this is a superset of internal users
@@ -186,6 +201,14 @@ where
     };
     if !role_allowed {
         let msg = format!("unauthorized login to user '{user}'");
+        record_auth_failure(
+            &adapter_client,
+            conn,
+            application_name.as_deref(),
+            &user,
+            authenticator_kind,
+            msg.clone(),
+        );
         return conn
             .send(ErrorResponse::fatal(SqlState::INSUFFICIENT_PRIVILEGE, msg))
             .await;
@@ -195,8 +218,6 @@ where
         return conn.send(err).await;
     }
 
-    let authenticator_kind = authenticator.kind();
-
     let (mut session, expired) = match authenticator {
         Authenticator::Frontegg(frontegg) => {
             let password = match request_cleartext_password(conn).await {
@@ -239,6 +260,14 @@ where
                 }
                 Err(err) => {
                     warn!(?err, "pgwire connection failed authentication");
+                    record_auth_failure(
+                        &adapter_client,
+                        conn,
+                        application_name.as_deref(),
+                        &user,
+                        authenticator_kind,
+                        err.to_string(),
+                    );
                     return conn
                         .send(ErrorResponse::fatal(
                             SqlState::INVALID_PASSWORD,
@@ -282,6 +311,14 @@ where
                     }
                     Err(err) => {
                         warn!(?err, "pgwire connection failed authentication");
+                        record_auth_failure(
+                            &adapter_client,
+                            conn,
+                            application_name.as_deref(),
+                            &user,
+                            authenticator_kind,
+                            err.to_string(),
+                        );
                         return conn.send(err.into_response()).await;
                     }
                 }
@@ -293,6 +330,7 @@ where
                     Password(password),
                     conn_uuid,
                     helm_chart_version,
+                    application_name.as_deref(),
                 )
                 .await
                 {
@@ -305,6 +343,47 @@ where
                 (session, pending().right_future())
             }
         }
+        Authenticator::Ldap(ldap) => {
+            let password = match request_cleartext_password(conn).await {
+                Ok(password) => password,
+                Err(PasswordRequestError::IoError(e)) => return Err(e),
+                Err(PasswordRequestError::InvalidPasswordError(e)) => {
+                    return conn.send(e).await;
+                }
+            };
+            match ldap.authenticate(&user, &password).await {
+                Ok((groups, authenticated)) => {
+                    let session = adapter_client.new_session(
+                        SessionConfig {
+                            conn_id: conn.conn_id().clone(),
+                            uuid: conn_uuid,
+                            user,
+                            client_ip: conn.peer_addr().clone(),
+                            external_metadata_rx: None,
+                            helm_chart_version,
+                            authenticator_kind,
+                            groups,
+                        },
+                        authenticated,
+                    );
+                    // The directory is only consulted at login, so the auth
+                    // session lasts indefinitely.
+                    (session, pending().right_future())
+                }
+                Err(err) => {
+                    warn!(?err, "pgwire connection failed authentication");
+                    record_auth_failure(
+                        &adapter_client,
+                        conn,
+                        application_name.as_deref(),
+                        &user,
+                        authenticator_kind,
+                        err.to_string(),
+                    );
+                    return conn.send(err.into_response()).await;
+                }
+            }
+        }
         Authenticator::Password(adapter_client) => {
             let password = match request_cleartext_password(conn).await {
                 Ok(password) => password,
@@ -320,6 +399,7 @@ where
                 Password(password),
                 conn_uuid,
                 helm_chart_version,
+                application_name.as_deref(),
             )
             .await
             {
@@ -333,29 +413,27 @@ where
             (session, pending().right_future())
         }
         Authenticator::Sasl(adapter_client) => {
+            // Channel binding is only possible over TLS, where we can bind
+            // the exchange to the server certificate the client saw.
+            let cbind_data = tls_server_end_point(conn.inner());
+            let mechanisms = if cbind_data.is_some() {
+                vec![SCRAM_SHA_256_PLUS, SCRAM_SHA_256]
+            } else {
+                vec![SCRAM_SHA_256]
+            };
             // Start the handshake
-            conn.send(BackendMessage::AuthenticationSASL).await?;
+            conn.send(BackendMessage::AuthenticationSASL(mechanisms))
+                .await?;
             conn.flush().await?;
             // Get the initial response indicating chosen mechanism
             let (mechanism, initial_response) = match conn.recv().await? {
                 Some(FrontendMessage::RawAuthentication(data)) => {
                     match decode_sasl_initial_response(Cursor::new(&data)).ok() {
                         Some(FrontendMessage::SASLInitialResponse {
-                            gs2_header,
+                            gs2_header: _,
                             mechanism,
                             initial_response,
-                        }) => {
-                            // We do not support channel binding
-                            if gs2_header.channel_binding_enabled() {
-                                return conn
-                                    .send(ErrorResponse::fatal(
-                                        SqlState::PROTOCOL_VIOLATION,
-                                        "channel binding not supported",
-                                    ))
-                                    .await;
-                            }
-                            (mechanism, initial_response)
-                        }
+                        }) => (mechanism, initial_response),
                         _ => {
                             return conn
                                 .send(ErrorResponse::fatal(
@@ -376,13 +454,73 @@ where
                 }
             };
 
-            if mechanism != "SCRAM-SHA-256" {
-                return conn
-                    .send(ErrorResponse::fatal(
-                        SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
-                        "unsupported SASL mechanism",
-                    ))
-                    .await;
+            // Work out which channel binding data, if any, the client must
+            // echo back in its final message. Per RFC 5802, the `c=` attribute
+            // carries the GS2 header followed by the channel binding data.
+            let gs2_header = &initial_response.gs2_header;
+            let mut expected_cbind = gs2_header.to_wire().into_bytes();
+            match (mechanism.as_str(), &gs2_header.cbind_flag, &cbind_data) {
+                (SCRAM_SHA_256, ChannelBinding::None, _) => {}
+                (SCRAM_SHA_256, ChannelBinding::ClientSupported, None) => {}
+                (SCRAM_SHA_256, ChannelBinding::ClientSupported, Some(_)) => {
+                    // The client supports channel binding but believes we do
+                    // not, even though we advertised SCRAM-SHA-256-PLUS. Someone
+                    // may have stripped the mechanism from our advertisement.
+                    return conn
+                        .send(ErrorResponse {
+                            detail: Some(
+                                "The client supports SCRAM channel binding but thinks the \
+                                 server does not. However, this server does support channel \
+                                 binding."
+                                    .into(),
+                            ),
+                            ..ErrorResponse::fatal(
+                                SqlState::PROTOCOL_VIOLATION,
+                                "SCRAM channel binding negotiation error",
+                            )
+                        })
+                        .await;
+                }
+                (SCRAM_SHA_256, ChannelBinding::Required(_), _) => {
+                    return conn
+                        .send(ErrorResponse::fatal(
+                            SqlState::PROTOCOL_VIOLATION,
+                            "the client selected SCRAM-SHA-256 without channel binding, \
+                             but the SCRAM message includes channel binding data",
+                        ))
+                        .await;
+                }
+                (SCRAM_SHA_256_PLUS, ChannelBinding::Required(cbname), Some(cbind_data)) => {
+                    if cbname != TLS_SERVER_END_POINT {
+                        return conn
+                            .send(ErrorResponse::fatal(
+                                SqlState::PROTOCOL_VIOLATION,
+                                format!(
+                                    "unsupported SCRAM channel-binding type {}",
+                                    cbname.quoted()
+                                ),
+                            ))
+                            .await;
+                    }
+                    expected_cbind.extend_from_slice(cbind_data);
+                }
+                (SCRAM_SHA_256_PLUS, _, Some(_)) => {
+                    return conn
+                        .send(ErrorResponse::fatal(
+                            SqlState::PROTOCOL_VIOLATION,
+                            "the client selected SCRAM-SHA-256-PLUS, \
+                             but the SCRAM message does not include channel binding data",
+                        ))
+                        .await;
+                }
+                _ => {
+                    return conn
+                        .send(ErrorResponse::fatal(
+                            SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                            "unsupported SASL mechanism",
+                        ))
+                        .await;
+                }
             }
 
             if initial_response.nonce.len() > 256 {
@@ -448,6 +586,25 @@ where
                                     ))
                                     .await;
                             }
+                            let cbind_matches = BASE64_STANDARD
+                                .decode(&response.channel_binding)
+                                .is_ok_and(|cbind| cbind == expected_cbind);
+                            if !cbind_matches {
+                                record_auth_failure(
+                                    &adapter_client,
+                                    conn,
+                                    application_name.as_deref(),
+                                    &user,
+                                    authenticator_kind,
+                                    "SCRAM channel binding check failed".into(),
+                                );
+                                return conn
+                                    .send(ErrorResponse::fatal(
+                                        SqlState::PROTOCOL_VIOLATION,
+                                        "SCRAM channel binding check failed",
+                                    ))
+                                    .await;
+                            }
                             match adapter_client
                                 .verify_sasl_proof(
                                     &user,
@@ -470,7 +627,15 @@ where
                                     conn.flush().await?;
                                     authenticated
                                 }
-                                Err(_) => {
+                                Err(err) => {
+                                    record_auth_failure(
+                                        &adapter_client,
+                                        conn,
+                                        application_name.as_deref(),
+                                        &user,
+                                        authenticator_kind,
+                                        err.to_string(),
+                                    );
                                     return conn
                                         .send(ErrorResponse::fatal(
                                             SqlState::INVALID_PASSWORD,
@@ -518,6 +683,51 @@ where
             (session, auth_session)
         }
 
+        Authenticator::Certificate(certificate) => {
+            // The certificate was already verified against the client CA
+            // during the TLS handshake; all that remains is mapping it to a
+            // role.
+            let cert = match conn.inner() {
+                Conn::Ssl(ssl_stream) => ssl_stream
+                    .ssl()
+                    .peer_certificate()
+                    .map(|cert| ClientCertificate::from_x509(&cert)),
+                Conn::Unencrypted(_) => None,
+            };
+            match certificate.authenticate(cert.as_ref(), Some(&user)).await {
+                Ok((user, authenticated)) => {
+                    let session = adapter_client.new_session(
+                        SessionConfig {
+                            conn_id: conn.conn_id().clone(),
+                            uuid: conn_uuid,
+                            user,
+                            client_ip: conn.peer_addr().clone(),
+                            external_metadata_rx: None,
+                            helm_chart_version,
+                            authenticator_kind,
+                            groups: None,
+                        },
+                        authenticated,
+                    );
+                    // Certificates are only checked at connection time, so the
+                    // auth session lasts indefinitely.
+                    (session, pending().right_future())
+                }
+                Err(err) => {
+                    warn!(?err, "pgwire connection failed authentication");
+                    record_auth_failure(
+                        &adapter_client,
+                        conn,
+                        application_name.as_deref(),
+                        &user,
+                        authenticator_kind,
+                        err.to_string(),
+                    );
+                    return conn.send(err.into_response()).await;
+                }
+            }
+        }
+
         Authenticator::None => {
             let session = adapter_client.new_session(
                 SessionConfig {
@@ -805,6 +1015,7 @@ async fn authenticate_with_password<A>(
     password: Password,
     conn_uuid: Uuid,
     helm_chart_version: Option<String>,
+    application_name: Option<&str>,
 ) -> Result<Session, PasswordRequestError>
 where
     A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin,
@@ -813,6 +1024,14 @@ where
         Ok(authenticated) => authenticated,
         Err(err) => {
             warn!(?err, "pgwire connection failed authentication");
+            record_auth_failure(
+                adapter_client,
+                conn,
+                application_name,
+                &user,
+                mz_auth::AuthenticatorKind::Password,
+                err.to_string(),
+            );
             return Err(PasswordRequestError::InvalidPasswordError(
                 ErrorResponse::fatal(SqlState::INVALID_PASSWORD, "invalid password"),
             ));
@@ -836,6 +1055,54 @@ where
     Ok(session)
 }
 
+const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+const SCRAM_SHA_256_PLUS: &str = "SCRAM-SHA-256-PLUS";
+const TLS_SERVER_END_POINT: &str = "tls-server-end-point";
+
+/// Computes the `tls-server-end-point` channel binding data (RFC 5929) for
+/// the connection: a hash of the server certificate, using the certificate's
+/// signature hash function, or SHA-256 if that function is MD5 or SHA-1.
+///
+/// Returns `None` if the connection is not encrypted or if the hash function
+/// cannot be determined from the certificate, in which case channel binding is
+/// not offered.
+fn tls_server_end_point<A>(conn: &Conn<A>) -> Option<Vec<u8>> {
+    let Conn::Ssl(ssl_stream) = conn else {
+        return None;
+    };
+    let cert = ssl_stream.ssl().certificate()?;
+    let algorithms = cert
+        .signature_algorithm()
+        .object()
+        .nid()
+        .signature_algorithms()?;
+    let digest = match algorithms.digest {
+        Nid::MD5 | Nid::SHA1 => MessageDigest::sha256(),
+        nid => MessageDigest::from_nid(nid)?,
+    };
+    cert.digest(digest).ok().map(|digest| digest.to_vec())
+}
+
+/// Reports a rejected authentication attempt to the audit log.
+fn record_auth_failure<A>(
+    adapter_client: &mz_adapter::Client,
+    conn: &FramedConn<A>,
+    application_name: Option<&str>,
+    role_name: &str,
+    authenticator_kind: mz_auth::AuthenticatorKind,
+    reason: String,
+) where
+    A: AsyncRead + AsyncWrite + Unpin,
+{
+    adapter_client.record_auth_failure(AuthFailure {
+        role_name: role_name.to_string(),
+        authenticator_kind,
+        client_ip: *conn.peer_addr(),
+        application_name: application_name.map(|name| name.to_string()),
+        reason,
+    });
+}
+
 #[derive(Debug)]
 enum State {
     Ready,
@@ -3376,6 +3643,7 @@ fn get_authenticator(
     authenticator_kind: listeners::AuthenticatorKind,
     frontegg: Option<FronteggAuthenticator>,
     oidc: GenericOidcAuthenticator,
+    ldap: LdapAuthenticator,
     adapter_client: mz_adapter::Client,
 ) -> Authenticator {
     match authenticator_kind {
@@ -3385,6 +3653,10 @@ fn get_authenticator(
         listeners::AuthenticatorKind::Password => Authenticator::Password(adapter_client),
         listeners::AuthenticatorKind::Sasl => Authenticator::Sasl(adapter_client),
         listeners::AuthenticatorKind::Oidc => Authenticator::Oidc(oidc),
+        listeners::AuthenticatorKind::Certificate => {
+            Authenticator::Certificate(CertificateAuthenticator::new(adapter_client))
+        }
+        listeners::AuthenticatorKind::Ldap => Authenticator::Ldap(ldap),
         listeners::AuthenticatorKind::None => Authenticator::None,
     }
 }
@@ -14,7 +14,7 @@ use std::str::FromStr;
 
 use anyhow::Context;
 use async_trait::async_trait;
-use mz_authenticator::GenericOidcAuthenticator;
+use mz_authenticator::{GenericOidcAuthenticator, LdapAuthenticator};
 use mz_frontegg_auth::Authenticator as FronteggAuthenticator;
 use mz_ore::now::{SYSTEM_TIME, epoch_to_uuid_v7};
 use mz_pgwire_common::{
@@ -49,6 +49,8 @@ pub struct Config {
     pub frontegg: Option<FronteggAuthenticator>,
     /// OIDC authenticator.
     pub oidc: GenericOidcAuthenticator,
+    /// LDAP authenticator.
+    pub ldap: LdapAuthenticator,
     /// The authentication method defined by the server's listener
     /// configuration.
     pub authenticator_kind: AuthenticatorKind,
@@ -69,6 +71,7 @@ pub struct Server {
     authenticator_kind: AuthenticatorKind,
     frontegg: Option<FronteggAuthenticator>,
     oidc: GenericOidcAuthenticator,
+    ldap: LdapAuthenticator,
     metrics: Metrics,
     active_connection_counter: ConnectionCounter,
     helm_chart_version: Option<String>,
@@ -104,6 +107,7 @@ impl Server {
             authenticator_kind: config.authenticator_kind,
             frontegg: config.frontegg,
             oidc: config.oidc,
+            ldap: config.ldap,
             metrics: Metrics::new(config.metrics, config.label),
             active_connection_counter: config.active_connection_counter,
             helm_chart_version: config.helm_chart_version,
@@ -121,6 +125,7 @@ impl Server {
         let authenticator_kind = self.authenticator_kind;
         let frontegg = self.frontegg.clone();
         let oidc = self.oidc.clone();
+        let ldap = self.ldap.clone();
         let tls = self.tls.clone();
         let metrics = self.metrics.clone();
         let active_connection_counter = self.active_connection_counter.clone();
@@ -214,6 +219,7 @@ impl Server {
                                     params,
                                     frontegg,
                                     oidc,
+                                    ldap,
                                     authenticator_kind,
                                     active_connection_counter,
                                     helm_chart_version,
@@ -239,8 +245,11 @@ impl Server {
                                 (Conn::Unencrypted(mut conn), Some(tls)) => {
                                     trace!("cid={} send=AcceptSsl", conn_id);
                                     conn.write_all(&[ACCEPT_SSL_ENCRYPTION]).await?;
-                                    let mut ssl_stream =
-                                        SslStream::new(Ssl::new(&tls.context.get())?, conn)?;
+                                    let mut ssl = Ssl::new(&tls.context.get())?;
+                                    if authenticator_kind == AuthenticatorKind::Certificate {
+                                        mz_server_core::require_client_cert(&mut ssl);
+                                    }
+                                    let mut ssl_stream = SslStream::new(ssl, conn)?;
                                     if let Err(e) = Pin::new(&mut ssl_stream).accept().await {
                                         let _ = ssl_stream.get_mut().shutdown().await;
                                         return Err(e.into());
@@ -29,7 +29,8 @@ use mz_ore::error::ErrorExt;
 use mz_ore::netio::AsyncReady;
 use mz_ore::option::OptionExt;
 use mz_ore::task::JoinSetExt;
-use openssl::ssl::{SslAcceptor, SslContext, SslFiletype, SslMethod};
+use openssl::ssl::{Ssl, SslAcceptor, SslContext, SslFiletype, SslMethod, SslVerifyMode};
+use openssl::x509::X509Name;
 use proxy_header::{ParseConfig, ProxiedAddress, ProxyHeader};
 use schemars::JsonSchema;
 use scopeguard::ScopeGuard;
@@ -480,6 +481,12 @@ pub struct TlsCertConfig {
     pub cert: PathBuf,
     /// The path to the TLS key.
     pub key: PathBuf,
+    /// The path to a bundle of CA certificates used to verify client
+    /// certificates, if any.
+    ///
+    /// Client certificates are only requested on listeners that authenticate
+    /// users by certificate; see [`require_client_cert`].
+    pub client_ca: Option<PathBuf>,
 }
 
 impl TlsCertConfig {
@@ -494,6 +501,13 @@ impl TlsCertConfig {
         let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
         builder.set_certificate_chain_file(&self.cert)?;
         builder.set_private_key_file(&self.key, SslFiletype::PEM)?;
+        if let Some(client_ca) = &self.client_ca {
+            // Trust the CA for verifying client certificates and advertise it
+            // to clients, but leave the verification mode untouched. Listeners
+            // that want a client certificate opt in per connection.
+            builder.set_ca_file(client_ca)?;
+            builder.set_client_ca_list(X509Name::load_client_ca_file(client_ca)?);
+        }
         Ok(builder.build().into_context())
     }
 
@@ -531,6 +545,16 @@ impl TlsCertConfig {
     }
 }
 
+/// Configures `ssl` to require and verify a client certificate during the
+/// handshake.
+///
+/// Certificates are verified against the client CA of the [`TlsCertConfig`]
+/// that produced the connection's context. If none was configured, every
+/// handshake fails.
+pub fn require_client_cert(ssl: &mut Ssl) {
+    ssl.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+}
+
 /// An SslContext whose inner value can be updated.
 #[derive(Clone, Debug)]
 pub struct ReloadingSslContext {
@@ -610,6 +634,11 @@ pub struct TlsCliArgs {
         value_name = "PATH"
     )]
     tls_key: Option<PathBuf>,
+    /// CA certificate bundle used to verify TLS client certificates.
+    ///
+    /// Required by listeners that use the `Certificate` authenticator.
+    #[clap(long, env = "TLS_CLIENT_CA", value_name = "PATH")]
+    tls_client_ca: Option<PathBuf>,
 }
 
 impl TlsCliArgs {
@@ -622,11 +651,18 @@ impl TlsCliArgs {
             if self.tls_key.is_some() {
                 bail!("cannot specify --tls-mode=disable and --tls-key simultaneously");
             }
+            if self.tls_client_ca.is_some() {
+                bail!("cannot specify --tls-mode=disable and --tls-client-ca simultaneously");
+            }
             Ok(None)
         } else {
             let cert = self.tls_cert.unwrap();
             let key = self.tls_key.unwrap();
-            Ok(Some(TlsCertConfig { cert, key }))
+            Ok(Some(TlsCertConfig {
+                cert,
+                key,
+                client_ca: self.tls_client_ca,
+            }))
         }
     }
 }
@@ -32,6 +32,12 @@ pub enum AuthenticatorKind {
     Sasl,
     /// Authenticate users using OIDC (JWT tokens).
     Oidc,
+    /// Authenticate users by the client certificate presented during the TLS
+    /// handshake. Requires TLS and a client CA (`--tls-client-ca`).
+    Certificate,
+    /// Authenticate users by binding to an LDAP directory with the password
+    /// they supply. Configured by the `ldap_*` system variables.
+    Ldap,
     /// Do not authenticate users. Trust they are who they say they are without verification.
     #[default]
     None,
@@ -198,7 +204,11 @@ impl ListenerConfig for SqlListenerConfig {
     }
 
     fn validate(&self) -> Result<(), String> {
-        Ok(())
+        if self.authenticator_kind == AuthenticatorKind::Certificate && !self.enable_tls {
+            Err("certificate authentication requires TLS".to_string())
+        } else {
+            Ok(())
+        }
     }
 }
 impl ListenerConfig for HttpListenerConfig {
@@ -217,6 +227,8 @@ impl ListenerConfig for HttpListenerConfig {
     fn validate(&self) -> Result<(), String> {
         if self.authenticator_kind == AuthenticatorKind::Sasl {
             Err("SASL authentication is not supported for HTTP listeners".to_string())
+        } else if self.authenticator_kind == AuthenticatorKind::Certificate && !self.enable_tls {
+            Err("certificate authentication requires TLS".to_string())
         } else {
             Ok(())
         }
@@ -261,6 +261,14 @@ pub enum ProtobufSchema<T: AstInfo> {
         message_name: String,
         schema: Schema,
     },
+    /// `USING AWS GLUE SCHEMA REGISTRY CONNECTION <name> (…)`.
+    ///
+    /// Only valid for sinks, which generate and register their own schema.
+    /// Shares its options with [`AvroSchema::Glue`].
+    Glue {
+        connection: T::ItemName,
+        with_options: Vec<GlueAvroOption<T>>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for ProtobufSchema<T> {
@@ -278,6 +286,18 @@ impl<T: AstInfo> AstDisplay for ProtobufSchema<T> {
                 f.write_str("' USING ");
                 f.write_str(schema);
             }
+            Self::Glue {
+                connection,
+                with_options,
+            } => {
+                f.write_str("USING AWS GLUE SCHEMA REGISTRY CONNECTION ");
+                f.write_node(connection);
+                if !with_options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(with_options));
+                    f.write_str(")");
+                }
+            }
         }
     }
 }
@@ -600,6 +620,9 @@ pub enum Format<T: AstInfo> {
     },
     Json {
         array: bool,
+        /// The schema registry to register a JSON Schema with. Only
+        /// supported for sinks.
+        csr_connection: Option<CsrConnection<T>>,
     },
     Text,
 }
@@ -798,13 +821,14 @@ impl AstDisplay for SinkEnvelope {
 }
 impl_display!(SinkEnvelope);
 
+/// The `MODE` of a sink, which Iceberg and Postgres sinks use in place of an `ENVELOPE`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum IcebergSinkMode {
+pub enum SinkMode {
     Upsert,
     Append,
 }
 
-impl AstDisplay for IcebergSinkMode {
+impl AstDisplay for SinkMode {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             Self::Upsert => {
@@ -816,7 +840,7 @@ impl AstDisplay for IcebergSinkMode {
         }
     }
 }
-impl_display!(IcebergSinkMode);
+impl_display!(SinkMode);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SubscribeOutput<T: AstInfo> {
@@ -876,11 +900,18 @@ impl<T: AstInfo> AstDisplay for Format<T> {
                     f.write_str("'");
                 }
             }
-            Self::Json { array } => {
+            Self::Json {
+                array,
+                csr_connection,
+            } => {
                 f.write_str("JSON");
                 if *array {
                     f.write_str(" ARRAY");
                 }
+                if let Some(csr_connection) = csr_connection {
+                    f.write_str(" USING CONFLUENT SCHEMA REGISTRY ");
+                    f.write_node(csr_connection);
+                }
             }
             Self::Text => f.write_str("TEXT"),
         }
@@ -1279,6 +1310,8 @@ impl_display_t!(KafkaSinkConfigOption);
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IcebergSinkConfigOptionName {
     Namespace,
+    PartitionBy,
+    SortBy,
     Table,
 }
 
@@ -1286,6 +1319,8 @@ impl AstDisplay for IcebergSinkConfigOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
             IcebergSinkConfigOptionName::Namespace => "NAMESPACE",
+            IcebergSinkConfigOptionName::PartitionBy => "PARTITION BY",
+            IcebergSinkConfigOptionName::SortBy => "SORT BY",
             IcebergSinkConfigOptionName::Table => "TABLE",
         })
     }
@@ -1300,7 +1335,10 @@ impl WithOptionName for IcebergSinkConfigOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            IcebergSinkConfigOptionName::Namespace | IcebergSinkConfigOptionName::Table => false,
+            IcebergSinkConfigOptionName::Namespace
+            | IcebergSinkConfigOptionName::PartitionBy
+            | IcebergSinkConfigOptionName::SortBy
+            | IcebergSinkConfigOptionName::Table => false,
         }
     }
 }
@@ -1313,6 +1351,167 @@ pub struct IcebergSinkConfigOption<T: AstInfo> {
 impl_display_for_with_option!(IcebergSinkConfigOption);
 impl_display_t!(IcebergSinkConfigOption);
 
+/// A transform applied to a column to derive an Iceberg partition value.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IcebergPartitionTransform {
+    Identity,
+    Bucket(u32),
+    Truncate(u32),
+    Day,
+    Hour,
+}
+
+/// A field of the `PARTITION BY` option of an Iceberg sink, e.g. `bucket(16, id)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IcebergPartitionField {
+    pub transform: IcebergPartitionTransform,
+    pub column: Ident,
+}
+
+impl AstDisplay for IcebergPartitionField {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match &self.transform {
+            IcebergPartitionTransform::Identity => {
+                f.write_node(&self.column);
+            }
+            IcebergPartitionTransform::Bucket(n) => {
+                f.write_str("bucket(");
+                f.write_str(n);
+                f.write_str(", ");
+                f.write_node(&self.column);
+                f.write_str(")");
+            }
+            IcebergPartitionTransform::Truncate(w) => {
+                f.write_str("truncate(");
+                f.write_str(w);
+                f.write_str(", ");
+                f.write_node(&self.column);
+                f.write_str(")");
+            }
+            IcebergPartitionTransform::Day => {
+                f.write_str("day(");
+                f.write_node(&self.column);
+                f.write_str(")");
+            }
+            IcebergPartitionTransform::Hour => {
+                f.write_str("hour(");
+                f.write_node(&self.column);
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display!(IcebergPartitionField);
+
+/// A field of the `SORT BY` option of an Iceberg sink, e.g. `ts DESC NULLS LAST`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IcebergSortField {
+    pub column: Ident,
+    /// Optional `ASC` or `DESC`
+    pub asc: Option<bool>,
+    /// Optional `NULLS FIRST` or `NULLS LAST`
+    pub nulls_last: Option<bool>,
+}
+
+impl AstDisplay for IcebergSortField {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.column);
+        match self.asc {
+            Some(true) => f.write_str(" ASC"),
+            Some(false) => f.write_str(" DESC"),
+            None => (),
+        }
+        match self.nulls_last {
+            Some(true) => f.write_str(" NULLS LAST"),
+            Some(false) => f.write_str(" NULLS FIRST"),
+            None => (),
+        }
+    }
+}
+impl_display!(IcebergSortField);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HttpSinkConfigOptionName {
+    /// The maximum number of updates to send in a single request.
+    BatchSize,
+    /// Additional headers to send with each request, e.g. for authentication.
+    Headers,
+    /// The URL to which to `POST` updates.
+    Url,
+}
+
+impl AstDisplay for HttpSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            HttpSinkConfigOptionName::BatchSize => "BATCH SIZE",
+            HttpSinkConfigOptionName::Headers => "HEADERS",
+            HttpSinkConfigOptionName::Url => "URL",
+        })
+    }
+}
+impl_display!(HttpSinkConfigOptionName);
+
+impl WithOptionName for HttpSinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            HttpSinkConfigOptionName::BatchSize => false,
+            // Header values and URLs commonly carry credentials, like tokens
+            // and API keys.
+            HttpSinkConfigOptionName::Headers | HttpSinkConfigOptionName::Url => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HttpSinkConfigOption<T: AstInfo> {
+    pub name: HttpSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(HttpSinkConfigOption);
+impl_display_t!(HttpSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PostgresSinkConfigOptionName {
+    Schema,
+    Table,
+}
+
+impl AstDisplay for PostgresSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            PostgresSinkConfigOptionName::Schema => "SCHEMA",
+            PostgresSinkConfigOptionName::Table => "TABLE",
+        })
+    }
+}
+impl_display!(PostgresSinkConfigOptionName);
+
+impl WithOptionName for PostgresSinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            PostgresSinkConfigOptionName::Schema | PostgresSinkConfigOptionName::Table => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PostgresSinkConfigOption<T: AstInfo> {
+    pub name: PostgresSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(PostgresSinkConfigOption);
+impl_display_t!(PostgresSinkConfigOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PgConfigOptionName {
     /// Hex encoded string of binary serialization of
@@ -1487,6 +1686,50 @@ pub struct SqlServerConfigOption<T: AstInfo> {
 impl_display_for_with_option!(SqlServerConfigOption);
 impl_display_t!(SqlServerConfigOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IcebergSourceConfigOptionName {
+    /// Hex encoded string of binary serialization of
+    /// `mz_storage_types::sources::iceberg::IcebergSourceDetails`.
+    Details,
+    Namespace,
+    Table,
+}
+
+impl AstDisplay for IcebergSourceConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            IcebergSourceConfigOptionName::Details => "DETAILS",
+            IcebergSourceConfigOptionName::Namespace => "NAMESPACE",
+            IcebergSourceConfigOptionName::Table => "TABLE",
+        })
+    }
+}
+impl_display!(IcebergSourceConfigOptionName);
+
+impl WithOptionName for IcebergSourceConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            IcebergSourceConfigOptionName::Details
+            | IcebergSourceConfigOptionName::Namespace
+            | IcebergSourceConfigOptionName::Table => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `FROM ICEBERG CONNECTION ...` statement.
+pub struct IcebergSourceConfigOption<T: AstInfo> {
+    pub name: IcebergSourceConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(IcebergSourceConfigOption);
+impl_display_t!(IcebergSourceConfigOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSourceConnection<T: AstInfo> {
     Kafka {
@@ -1505,6 +1748,10 @@ pub enum CreateSourceConnection<T: AstInfo> {
         connection: T::ItemName,
         options: Vec<MySqlConfigOption<T>>,
     },
+    Iceberg {
+        connection: T::ItemName,
+        options: Vec<IcebergSourceConfigOption<T>>,
+    },
     LoadGenerator {
         generator: LoadGenerator,
         options: Vec<LoadGeneratorOption<T>>,
@@ -1562,6 +1809,18 @@ impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
                     f.write_str(")");
                 }
             }
+            CreateSourceConnection::Iceberg {
+                connection,
+                options,
+            } => {
+                f.write_str("ICEBERG CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
             CreateSourceConnection::LoadGenerator { generator, options } => {
                 f.write_str("LOAD GENERATOR ");
                 f.write_node(generator);
@@ -1706,6 +1965,15 @@ pub enum CreateSinkConnection<T: AstInfo> {
         key: Option<SinkKey>,
         options: Vec<IcebergSinkConfigOption<T>>,
     },
+    Http {
+        options: Vec<HttpSinkConfigOption<T>>,
+        key: Option<SinkKey>,
+    },
+    Postgres {
+        connection: T::ItemName,
+        options: Vec<PostgresSinkConfigOption<T>>,
+        key: Option<SinkKey>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
@@ -1755,6 +2023,32 @@ impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
                     f.write_node(key);
                 }
             }
+            CreateSinkConnection::Http { options, key } => {
+                f.write_str("HTTP (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+                if let Some(key) = key.as_ref() {
+                    f.write_str(" ");
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::Postgres {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("POSTGRES CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_str(" ");
+                    f.write_node(key);
+                }
+            }
         }
     }
 }
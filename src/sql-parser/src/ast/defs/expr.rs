@@ -133,6 +133,14 @@ pub enum Expr<T: AstInfo> {
         l_expr: Box<Expr<T>>,
         r_expr: Box<Expr<T>>,
     },
+    /// `GROUPING(<expr>, ...)`
+    ///
+    /// While GROUPING has the same syntax as a function call, its result
+    /// depends on which grouping set of the enclosing `GROUP BY` clause
+    /// produced the row, and so it is planned alongside that clause.
+    Grouping {
+        exprs: Vec<Expr<T>>,
+    },
     /// Nested expression e.g. `(foo > bar)` or `(1)`
     Nested(Box<Expr<T>>),
     /// A row constructor like `ROW(<expr>...)` or `(<expr>, <expr>...)`.
@@ -415,6 +423,11 @@ impl<T: AstInfo> AstDisplay for Expr<T> {
                 f.write_node(&display::comma_separated(&[l_expr, r_expr]));
                 f.write_str(")");
             }
+            Expr::Grouping { exprs } => {
+                f.write_str("GROUPING(");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
             Expr::Nested(ast) => {
                 f.write_str("(");
                 f.write_node(&ast);
@@ -594,6 +607,7 @@ fn write_dot_receiver<W: fmt::Write, T: AstInfo>(f: &mut AstFormatter<W>, expr:
             | Expr::Subscript { .. }
             | Expr::HomogenizingFunction { .. }
             | Expr::NullIf { .. }
+            | Expr::Grouping { .. }
             | Expr::Value(
                 Value::String(_)
                     | Value::Boolean(_)
@@ -847,6 +861,7 @@ fn prints_self_delimiting<T: AstInfo>(expr: &Expr<T>) -> bool {
         | Expr::Function(_)
         | Expr::HomogenizingFunction { .. }
         | Expr::NullIf { .. }
+        | Expr::Grouping { .. }
         | Expr::Subquery(_)
         | Expr::Exists(_)
         | Expr::Nested(_)
@@ -924,6 +939,7 @@ fn write_subscript_receiver<W: fmt::Write, T: AstInfo>(f: &mut AstFormatter<W>,
         | Expr::Function(_)
         | Expr::HomogenizingFunction { .. }
         | Expr::NullIf { .. }
+        | Expr::Grouping { .. }
         | Expr::Nested(_)
         | Expr::Subquery(_)
         | Expr::Exists(_)
@@ -1049,6 +1065,7 @@ impl<T: AstInfo> Expr<T> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         })
     }
 
@@ -1288,6 +1305,10 @@ pub struct Function<T: AstInfo> {
     pub over: Option<WindowSpec<T>>,
     // aggregate functions may specify eg `COUNT(DISTINCT x)`
     pub distinct: bool,
+    // ordered-set aggregate functions specify their ordering as e.g.
+    // `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`, in which case the
+    // ordering is stored in the `order_by` of `args`
+    pub within_group: bool,
 }
 
 impl<T: AstInfo> AstDisplay for Function<T> {
@@ -1379,6 +1400,7 @@ impl<T: AstInfo> Function<T> {
                 | r#""exists""#
                 | r#""extract""#
                 | r#""greatest""#
+                | r#""grouping""#
                 | r#""least""#
                 | r#""list""#
                 | r#""map""#
@@ -1398,8 +1420,18 @@ impl<T: AstInfo> Function<T> {
         if self.distinct {
             f.write_str("DISTINCT ")
         }
-        f.write_node(&self.args);
-        f.write_str(")");
+        match &self.args {
+            FunctionArgs::Args { args, order_by } if self.within_group => {
+                f.write_node(&display::comma_separated(args));
+                f.write_str(") WITHIN GROUP (ORDER BY ");
+                f.write_node(&display::comma_separated(order_by));
+                f.write_str(")");
+            }
+            args => {
+                f.write_node(args);
+                f.write_str(")");
+            }
+        }
         if let Some(filter) = &self.filter {
             f.write_str(" FILTER (WHERE ");
             f.write_node(&filter);
@@ -255,7 +255,7 @@ pub struct Select<T: AstInfo> {
     /// WHERE
     pub selection: Option<Expr<T>>,
     /// GROUP BY
-    pub group_by: Vec<Expr<T>>,
+    pub group_by: Vec<GroupByItem<T>>,
     /// HAVING
     pub having: Option<Expr<T>>,
     /// QUALIFY
@@ -341,14 +341,63 @@ impl<T: AstInfo> AstDisplay for Distinct<T> {
     }
 }
 
+/// An item in a `GROUP BY` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GroupByItem<T: AstInfo> {
+    /// A plain grouping expression, e.g. `GROUP BY a`.
+    Expr(Expr<T>),
+    /// A parenthesized list of grouping expressions, e.g. `(a, b)`, or the
+    /// empty grouping set `()`.
+    Set(Vec<Expr<T>>),
+    /// `ROLLUP (<item>, ...)`, where each item is an expression or a
+    /// parenthesized list of expressions.
+    Rollup(Vec<GroupByItem<T>>),
+    /// `CUBE (<item>, ...)`, where each item is an expression or a
+    /// parenthesized list of expressions.
+    Cube(Vec<GroupByItem<T>>),
+    /// `GROUPING SETS (<item>, ...)`.
+    GroupingSets(Vec<GroupByItem<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for GroupByItem<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            GroupByItem::Expr(expr) => f.write_node(expr),
+            GroupByItem::Set(exprs) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
+            GroupByItem::Rollup(items) => {
+                f.write_str("ROLLUP (");
+                f.write_node(&display::comma_separated(items));
+                f.write_str(")");
+            }
+            GroupByItem::Cube(items) => {
+                f.write_str("CUBE (");
+                f.write_node(&display::comma_separated(items));
+                f.write_str(")");
+            }
+            GroupByItem::GroupingSets(items) => {
+                f.write_str("GROUPING SETS (");
+                f.write_node(&display::comma_separated(items));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(GroupByItem);
+
 /// A block of common table expressions (CTEs).
 ///
 /// The block can either be entirely "simple" (traditional SQL `WITH` block),
-/// or "mutually recursive", which introduce their bindings before the block
-/// and may result in mutually recursive definitions.
+/// "recursive" (SQL `WITH RECURSIVE` block), whose bindings may also refer to
+/// themselves, or "mutually recursive", which introduce their bindings before
+/// the block and may result in mutually recursive definitions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum CteBlock<T: AstInfo> {
     Simple(Vec<Cte<T>>),
+    Recursive(Vec<Cte<T>>),
     MutuallyRecursive(MutRecBlock<T>),
 }
 
@@ -366,7 +415,7 @@ impl<T: AstInfo> CteBlock<T> {
     /// True if there are no bindings in the block.
     pub fn is_empty(&self) -> bool {
         match self {
-            CteBlock::Simple(list) => list.is_empty(),
+            CteBlock::Simple(list) | CteBlock::Recursive(list) => list.is_empty(),
             CteBlock::MutuallyRecursive(list) => list.ctes.is_empty(),
         }
     }
@@ -374,7 +423,7 @@ impl<T: AstInfo> CteBlock<T> {
     pub fn bound_identifiers(&self) -> impl Iterator<Item = &Ident> {
         let mut names = Vec::new();
         match self {
-            CteBlock::Simple(list) => {
+            CteBlock::Simple(list) | CteBlock::Recursive(list) => {
                 for cte in list.iter() {
                     names.push(&cte.alias.name);
                 }
@@ -397,6 +446,10 @@ impl<T: AstInfo> AstDisplay for CteBlock<T> {
                     f.write_str("WITH ");
                     f.write_node(&display::comma_separated(list));
                 }
+                CteBlock::Recursive(list) => {
+                    f.write_str("WITH RECURSIVE ");
+                    f.write_node(&display::comma_separated(list));
+                }
                 CteBlock::MutuallyRecursive(MutRecBlock { options, ctes }) => {
                     f.write_str("WITH MUTUALLY RECURSIVE ");
                     if !options.is_empty() {
@@ -29,11 +29,11 @@ use crate::ast::display::{self, AstDisplay, AstFormatter, WithOptionName};
 use crate::ast::{
     AstInfo, ColumnDef, ConnectionOption, ConnectionOptionName, CreateConnectionOption,
     CreateConnectionType, CreateSinkConnection, CreateSourceConnection, CreateSourceOption,
-    CreateSourceOptionName, DeferredItemName, Expr, Format, FormatSpecifier, IcebergSinkMode,
-    Ident, IntervalValue, KeyConstraint, MaterializedViewOption, Query, SelectItem, SinkEnvelope,
-    SourceEnvelope, SourceIncludeMetadata, SubscribeOutput, TableAlias, TableConstraint,
-    TableWithJoins, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
-    UnresolvedSchemaName, Value,
+    CreateSourceOptionName, DeferredItemName, Expr, Format, FormatSpecifier, IcebergPartitionField,
+    IcebergSortField, Ident, IntervalValue, KeyConstraint, MaterializedViewOption, Query,
+    SelectItem, SinkEnvelope, SinkMode, SourceEnvelope, SourceIncludeMetadata, SubscribeOutput,
+    TableAlias, TableConstraint, TableWithJoins, UnresolvedDatabaseName, UnresolvedItemName,
+    UnresolvedObjectName, UnresolvedSchemaName, Value,
 };
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
@@ -859,6 +859,8 @@ pub struct CreateWebhookSourceStatement<T: AstInfo> {
     pub is_table: bool,
     pub if_not_exists: bool,
     pub body_format: Format<T>,
+    /// Columns to decode each object of a `JSON` body into.
+    pub body_columns: Vec<ColumnDef<T>>,
     pub include_headers: CreateWebhookSourceIncludeHeaders,
     pub validate_using: Option<CreateWebhookSourceCheck<T>>,
     pub in_cluster: Option<T::ClusterName>,
@@ -892,6 +894,12 @@ impl<T: AstInfo> AstDisplay for CreateWebhookSourceStatement<T> {
         f.write_str("BODY FORMAT ");
         f.write_node(&self.body_format);
 
+        if !self.body_columns.is_empty() {
+            f.write_str(" (");
+            f.write_node(&display::comma_separated(&self.body_columns));
+            f.write_str(")");
+        }
+
         f.write_node(&self.include_headers);
 
         if let Some(validate) = &self.validate_using {
@@ -1390,7 +1398,7 @@ pub struct CreateSinkStatement<T: AstInfo> {
     pub connection: CreateSinkConnection<T>,
     pub format: Option<FormatSpecifier<T>>,
     pub envelope: Option<SinkEnvelope>,
-    pub mode: Option<IcebergSinkMode>,
+    pub mode: Option<SinkMode>,
     pub with_options: Vec<CreateSinkOption<T>>,
 }
 
@@ -1782,6 +1790,8 @@ pub enum TableFromSourceOptionName {
     PartitionBy,
     // The `RETAIN HISTORY` option
     RetainHistory,
+    /// Retract the contents of the table when the upstream table is truncated
+    RetractOnTruncate,
 }
 
 impl AstDisplay for TableFromSourceOptionName {
@@ -1792,6 +1802,7 @@ impl AstDisplay for TableFromSourceOptionName {
             TableFromSourceOptionName::Details => "DETAILS",
             TableFromSourceOptionName::PartitionBy => "PARTITION BY",
             TableFromSourceOptionName::RetainHistory => "RETAIN HISTORY",
+            TableFromSourceOptionName::RetractOnTruncate => "RETRACT ON TRUNCATE",
         })
     }
 }
@@ -1808,7 +1819,8 @@ impl WithOptionName for TableFromSourceOptionName {
             TableFromSourceOptionName::Details
             | TableFromSourceOptionName::TextColumns
             | TableFromSourceOptionName::ExcludeColumns
-            | TableFromSourceOptionName::RetainHistory => false,
+            | TableFromSourceOptionName::RetainHistory
+            | TableFromSourceOptionName::RetractOnTruncate => false,
             // The value is an arbitrary user expression/literal that may embed
             // sensitive data, so redact it (mirrors `KafkaSinkConfigOptionName`).
             TableFromSourceOptionName::PartitionBy => true,
@@ -4287,7 +4299,26 @@ impl_display_t!(ExplainPushdownStatement);
 pub enum ExplainAnalyzeComputationProperty {
     Cpu,
     Memory,
+    /// Records flowing into and out of each operator.
+    Updates,
+    /// How long each operator took to hydrate.
+    Hydration,
+    /// Records held in the arrangements of each operator.
+    Records,
+}
+
+impl AstDisplay for ExplainAnalyzeComputationProperty {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            ExplainAnalyzeComputationProperty::Cpu => f.write_str("CPU"),
+            ExplainAnalyzeComputationProperty::Memory => f.write_str("MEMORY"),
+            ExplainAnalyzeComputationProperty::Updates => f.write_str("UPDATES"),
+            ExplainAnalyzeComputationProperty::Hydration => f.write_str("HYDRATION"),
+            ExplainAnalyzeComputationProperty::Records => f.write_str("RECORDS"),
+        }
+    }
 }
+impl_display!(ExplainAnalyzeComputationProperty);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ExplainAnalyzeProperty {
@@ -4304,8 +4335,10 @@ pub struct ExplainAnalyzeComputationProperties {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExplainAnalyzeObjectStatement<T: AstInfo> {
     pub properties: ExplainAnalyzeProperty,
-    /// Should only be `Explainee::Index` or `Explainee::MaterializedView`
+    /// Should only be `Explainee::Index`, `Explainee::MaterializedView` or a
+    /// non-broken `Explainee::Select`.
     pub explainee: Explainee<T>,
+    /// Never set for `Explainee::Select`.
     pub as_sql: bool,
 }
 
@@ -4324,10 +4357,8 @@ impl<T: AstInfo> AstDisplay for ExplainAnalyzeObjectStatement<T> {
                     } else {
                         f.write_str(",");
                     }
-                    match property {
-                        ExplainAnalyzeComputationProperty::Cpu => f.write_str(" CPU"),
-                        ExplainAnalyzeComputationProperty::Memory => f.write_str(" MEMORY"),
-                    }
+                    f.write_str(" ");
+                    f.write_node(property);
                 }
                 if *skew {
                     f.write_str(" WITH SKEW");
@@ -4361,10 +4392,8 @@ impl AstDisplay for ExplainAnalyzeClusterStatement {
             } else {
                 f.write_str(",");
             }
-            match property {
-                ExplainAnalyzeComputationProperty::Cpu => f.write_str(" CPU"),
-                ExplainAnalyzeComputationProperty::Memory => f.write_str(" MEMORY"),
-            }
+            f.write_str(" ");
+            f.write_node(property);
         }
 
         if self.properties.skew {
@@ -4553,6 +4582,8 @@ pub enum WithOptionValue<T: AstInfo> {
     ClusterAutoScalingStrategyOptionValue(ClusterAutoScalingStrategyOptionValue),
     ClusterAlterStrategy(ClusterAlterOptionValue<T>),
     NetworkPolicyRules(Vec<NetworkPolicyRuleDefinition<T>>),
+    IcebergPartitionBy(Vec<IcebergPartitionField>),
+    IcebergSortBy(Vec<IcebergSortField>),
 }
 
 impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
@@ -4589,7 +4620,9 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 | WithOptionValue::ClusterScheduleOptionValue(_)
                 | WithOptionValue::ClusterAutoScalingStrategyOptionValue(_)
                 | WithOptionValue::ClusterAlterStrategy(_)
-                | WithOptionValue::NetworkPolicyRules(_) => {
+                | WithOptionValue::NetworkPolicyRules(_)
+                | WithOptionValue::IcebergPartitionBy(_)
+                | WithOptionValue::IcebergSortBy(_) => {
                     // These do not need redaction.
                 }
             }
@@ -4634,6 +4667,16 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 f.write_node(&display::comma_separated(rules));
                 f.write_str(")");
             }
+            WithOptionValue::IcebergPartitionBy(fields) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(fields));
+                f.write_str(")");
+            }
+            WithOptionValue::IcebergSortBy(fields) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(fields));
+                f.write_str(")");
+            }
             WithOptionValue::ConnectionAwsPrivatelink(aws_privatelink) => {
                 f.write_node(aws_privatelink);
             }
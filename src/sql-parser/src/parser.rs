@@ -690,6 +690,7 @@ impl<'a> Parser<'a> {
                 self.parse_homogenizing_function(HomogenizingFunction::Least)
             }
             (Token::Keyword(NULLIF), Some(Token::LParen)) => self.parse_nullif_expr(),
+            (Token::Keyword(GROUPING), Some(Token::LParen)) => self.parse_grouping_expr(),
             (Token::Keyword(EXISTS), Some(Token::LParen)) => self.parse_exists_expr(),
             (Token::Keyword(EXTRACT), Some(Token::LParen)) => self.parse_extract_expr(),
             (Token::Keyword(NOT), _) => Ok(Expr::Not {
@@ -851,7 +852,7 @@ impl<'a> Parser<'a> {
             self.parse_at_most_one_keyword(&[ALL, DISTINCT], &format!("function: {}", name))?,
             Some(DISTINCT),
         );
-        let args = self.parse_optional_args(true)?;
+        let mut args = self.parse_optional_args(true)?;
 
         if distinct && matches!(args, FunctionArgs::Star) {
             return Err(self.error(
@@ -860,6 +861,33 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        let within_group =
+            self.peek_keywords(&[WITHIN, GROUP]) && self.peek_nth_token(2) == Some(Token::LParen);
+        if within_group {
+            let pos = self.peek_pos();
+            self.expect_keywords(&[WITHIN, GROUP])?;
+            self.expect_token(&Token::LParen)?;
+            self.expect_keywords(&[ORDER, BY])?;
+            let within_group_order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            self.expect_token(&Token::RParen)?;
+            match &mut args {
+                FunctionArgs::Star => {
+                    return parser_err!(self, pos, "cannot use * with WITHIN GROUP");
+                }
+                FunctionArgs::Args { order_by, .. } if !order_by.is_empty() => {
+                    return parser_err!(
+                        self,
+                        pos,
+                        "cannot use multiple ORDER BY clauses with WITHIN GROUP"
+                    );
+                }
+                FunctionArgs::Args { .. } if distinct => {
+                    return parser_err!(self, pos, "cannot use DISTINCT with WITHIN GROUP");
+                }
+                FunctionArgs::Args { order_by, .. } => *order_by = within_group_order_by,
+            }
+        }
+
         let filter = if self.parse_keyword(FILTER) {
             self.expect_token(&Token::LParen)?;
             self.expect_keyword(WHERE)?;
@@ -915,6 +943,7 @@ impl<'a> Parser<'a> {
             filter,
             over,
             distinct,
+            within_group,
         })
     }
 
@@ -1008,6 +1037,7 @@ impl<'a> Parser<'a> {
                 | Expr::Identifier { .. }
                 | Expr::HomogenizingFunction { .. }
                 | Expr::NullIf { .. }
+                | Expr::Grouping { .. }
                 | Expr::Subquery { .. }
                 | Expr::Parameter(..) => true,
                 Expr::Cast { expr, .. } | Expr::Collate { expr, .. } => safe_before_pg_cast(expr),
@@ -1062,6 +1092,14 @@ impl<'a> Parser<'a> {
         Ok(Expr::HomogenizingFunction { function, exprs })
     }
 
+    /// Parse a SQL GROUPING expression e.g. `GROUPING(a, b)`.
+    fn parse_grouping_expr(&mut self) -> Result<Expr<Raw>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::Grouping { exprs })
+    }
+
     fn parse_nullif_expr(&mut self) -> Result<Expr<Raw>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let l_expr = Box::new(self.parse_expr()?);
@@ -1090,6 +1128,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -1153,6 +1192,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -1171,6 +1211,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -1200,6 +1241,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -1405,6 +1447,7 @@ impl<'a> Parser<'a> {
                         filter: None,
                         over: None,
                         distinct: false,
+                        within_group: false,
                     }))
                 }
                 COLLATE => Ok(Expr::Collate {
@@ -1540,6 +1583,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         }))
     }
 
@@ -2207,7 +2251,16 @@ impl<'a> Parser<'a> {
             Format::Csv { columns, delimiter }
         } else if self.parse_keyword(JSON) {
             let array = self.parse_keyword(ARRAY);
-            Format::Json { array }
+            let csr_connection = if self.parse_keyword(USING) {
+                self.expect_keywords(&[CONFLUENT, SCHEMA, REGISTRY])?;
+                Some(self.parse_csr_connection_reference()?)
+            } else {
+                None
+            };
+            Format::Json {
+                array,
+                csr_connection,
+            }
         } else if self.parse_keyword(TEXT) {
             Format::Text
         } else if self.parse_keyword(BYTES) {
@@ -2332,6 +2385,20 @@ impl<'a> Parser<'a> {
         if self.parse_keywords(&[USING, CONFLUENT, SCHEMA, REGISTRY]) {
             let csr_connection = self.parse_csr_connection_proto()?;
             Ok(ProtobufSchema::Csr { csr_connection })
+        } else if self.parse_keywords(&[USING, AWS, GLUE, SCHEMA, REGISTRY]) {
+            self.expect_keyword(CONNECTION)?;
+            let connection = self.parse_raw_name()?;
+            let with_options = if self.consume_token(&Token::LParen) {
+                let opts = self.parse_comma_separated(Parser::parse_glue_avro_option)?;
+                self.expect_token(&Token::RParen)?;
+                opts
+            } else {
+                vec![]
+            };
+            Ok(ProtobufSchema::Glue {
+                connection,
+                with_options,
+            })
         } else if self.parse_keyword(MESSAGE) {
             let message_name = self.parse_literal_string()?;
             self.expect_keyword(USING)?;
@@ -2346,7 +2413,7 @@ impl<'a> Parser<'a> {
         } else {
             self.expected(
                 self.peek_pos(),
-                "CONFLUENT SCHEMA REGISTRY or MESSAGE",
+                "CONFLUENT SCHEMA REGISTRY, AWS GLUE SCHEMA REGISTRY, or MESSAGE",
                 self.peek_token(),
             )
         }
@@ -2610,11 +2677,11 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_iceberg_sink_mode(&mut self) -> Result<IcebergSinkMode, ParserError> {
+    fn parse_sink_mode(&mut self) -> Result<SinkMode, ParserError> {
         if self.parse_keyword(UPSERT) {
-            Ok(IcebergSinkMode::Upsert)
+            Ok(SinkMode::Upsert)
         } else if self.parse_keyword(APPEND) {
-            Ok(IcebergSinkMode::Append)
+            Ok(SinkMode::Append)
         } else {
             self.expected(self.peek_pos(), "UPSERT, APPEND", self.peek_token())
         }
@@ -2879,8 +2946,30 @@ impl<'a> Parser<'a> {
     fn parse_iceberg_sink_config_option(
         &mut self,
     ) -> Result<IcebergSinkConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[NAMESPACE, TABLE])? {
+        let name = match self.expect_one_of_keywords(&[NAMESPACE, PARTITION, SORT, TABLE])? {
             NAMESPACE => IcebergSinkConfigOptionName::Namespace,
+            PARTITION => {
+                self.expect_keyword(BY)?;
+                let _ = self.consume_token(&Token::Eq);
+                self.expect_token(&Token::LParen)?;
+                let fields = self.parse_comma_separated(Parser::parse_iceberg_partition_field)?;
+                self.expect_token(&Token::RParen)?;
+                return Ok(IcebergSinkConfigOption {
+                    name: IcebergSinkConfigOptionName::PartitionBy,
+                    value: Some(WithOptionValue::IcebergPartitionBy(fields)),
+                });
+            }
+            SORT => {
+                self.expect_keyword(BY)?;
+                let _ = self.consume_token(&Token::Eq);
+                self.expect_token(&Token::LParen)?;
+                let fields = self.parse_comma_separated(Parser::parse_iceberg_sort_field)?;
+                self.expect_token(&Token::RParen)?;
+                return Ok(IcebergSinkConfigOption {
+                    name: IcebergSinkConfigOptionName::SortBy,
+                    value: Some(WithOptionValue::IcebergSortBy(fields)),
+                });
+            }
             TABLE => IcebergSinkConfigOptionName::Table,
             _ => unreachable!(),
         };
@@ -2890,6 +2979,121 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_http_sink_config_option(&mut self) -> Result<HttpSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[BATCH, HEADERS, URL])? {
+            BATCH => {
+                self.expect_keyword(SIZE)?;
+                HttpSinkConfigOptionName::BatchSize
+            }
+            HEADERS => HttpSinkConfigOptionName::Headers,
+            URL => HttpSinkConfigOptionName::Url,
+            _ => unreachable!(),
+        };
+        Ok(HttpSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_postgres_sink_config_option(
+        &mut self,
+    ) -> Result<PostgresSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[SCHEMA, TABLE])? {
+            SCHEMA => PostgresSinkConfigOptionName::Schema,
+            TABLE => PostgresSinkConfigOptionName::Table,
+            _ => unreachable!(),
+        };
+        Ok(PostgresSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    /// Parses a single field of an Iceberg sink's `PARTITION BY` option: either a
+    /// bare column name, or one of the `identity`, `bucket`, `truncate`, `day` or
+    /// `hour` transforms applied to a column.
+    fn parse_iceberg_partition_field(&mut self) -> Result<IcebergPartitionField, ParserError> {
+        if self.peek_nth_token(1) != Some(Token::LParen) {
+            return Ok(IcebergPartitionField {
+                transform: IcebergPartitionTransform::Identity,
+                column: self.parse_identifier()?,
+            });
+        }
+        let transform =
+            match self.expect_one_of_keywords(&[IDENTITY, BUCKET, TRUNCATE, DAY, HOUR])? {
+                IDENTITY => {
+                    self.expect_token(&Token::LParen)?;
+                    IcebergPartitionTransform::Identity
+                }
+                kw @ (BUCKET | TRUNCATE) => {
+                    self.expect_token(&Token::LParen)?;
+                    let pos = self.peek_pos();
+                    let Ok(n) = u32::try_from(self.parse_literal_uint()?) else {
+                        return parser_err!(self, pos, "{} argument out of range", kw);
+                    };
+                    self.expect_token(&Token::Comma)?;
+                    if kw == BUCKET {
+                        IcebergPartitionTransform::Bucket(n)
+                    } else {
+                        IcebergPartitionTransform::Truncate(n)
+                    }
+                }
+                DAY => {
+                    self.expect_token(&Token::LParen)?;
+                    IcebergPartitionTransform::Day
+                }
+                HOUR => {
+                    self.expect_token(&Token::LParen)?;
+                    IcebergPartitionTransform::Hour
+                }
+                _ => unreachable!(),
+            };
+        let column = self.parse_identifier()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(IcebergPartitionField { transform, column })
+    }
+
+    /// Parses a single field of an Iceberg sink's `SORT BY` option.
+    fn parse_iceberg_sort_field(&mut self) -> Result<IcebergSortField, ParserError> {
+        let column = self.parse_identifier()?;
+
+        let asc = if self.parse_keyword(ASC) {
+            Some(true)
+        } else if self.parse_keyword(DESC) {
+            Some(false)
+        } else {
+            None
+        };
+
+        let nulls_last = if self.parse_keyword(NULLS) {
+            let last = self.expect_one_of_keywords(&[FIRST, LAST])? == LAST;
+            Some(last)
+        } else {
+            None
+        };
+
+        Ok(IcebergSortField {
+            column,
+            asc,
+            nulls_last,
+        })
+    }
+
+    fn parse_iceberg_source_config_option(
+        &mut self,
+    ) -> Result<IcebergSourceConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[DETAILS, NAMESPACE, TABLE])? {
+            DETAILS => IcebergSourceConfigOptionName::Details,
+            NAMESPACE => IcebergSourceConfigOptionName::Namespace,
+            TABLE => IcebergSourceConfigOptionName::Table,
+            _ => unreachable!(),
+        };
+        Ok(IcebergSourceConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_kafka_sink_config_option(
         &mut self,
     ) -> Result<KafkaSinkConfigOption<Raw>, ParserError> {
@@ -3390,16 +3594,40 @@ impl<'a> Parser<'a> {
 
         // Note: we don't use `parse_format()` here because we support fewer formats than other
         // sources, and the user gets better errors if we reject the formats here.
-        let body_format = match self.expect_one_of_keywords(&[JSON, TEXT, BYTES])? {
+        let body_format = match self.expect_one_of_keywords(&[JSON, TEXT, BYTES, AVRO, PROTOBUF])? {
             JSON => {
                 let array = self.parse_keyword(ARRAY);
-                Format::Json { array }
+                Format::Json {
+                    array,
+                    csr_connection: None,
+                }
             }
             TEXT => Format::Text,
             BYTES => Format::Bytes,
+            AVRO => {
+                self.expect_keyword(USING)?;
+                Format::Avro(self.parse_avro_schema()?)
+            }
+            PROTOBUF => Format::Protobuf(self.parse_protobuf_schema()?),
             _ => unreachable!(),
         };
 
+        // A JSON body can optionally declare the columns to decode each object into.
+        let body_columns = if matches!(body_format, Format::Json { .. }) {
+            let pos = self.peek_pos();
+            let (columns, constraints) = self.parse_columns(Optional)?;
+            if !constraints.is_empty() {
+                return parser_err!(
+                    self,
+                    pos,
+                    "table constraints are not supported for webhook body columns"
+                );
+            }
+            columns
+        } else {
+            vec![]
+        };
+
         let mut include_headers = CreateWebhookSourceIncludeHeaders::default();
         while self.parse_keyword(INCLUDE) {
             match self.expect_one_of_keywords(&[HEADER, HEADERS])? {
@@ -3459,6 +3687,7 @@ impl<'a> Parser<'a> {
                 is_table,
                 if_not_exists,
                 body_format,
+                body_columns,
                 include_headers,
                 validate_using,
                 in_cluster,
@@ -3530,7 +3759,7 @@ impl<'a> Parser<'a> {
         connection: CreateSinkConnection<Raw>,
     ) -> Result<CreateSinkStatement<Raw>, ParserError> {
         let mode = if self.parse_keyword(MODE) {
-            Some(self.parse_iceberg_sink_mode()?)
+            Some(self.parse_sink_mode()?)
         } else {
             None
         };
@@ -3639,6 +3868,16 @@ impl<'a> Parser<'a> {
             conn @ CreateSinkConnection::Iceberg { .. } => {
                 self.parse_create_iceberg_sink(name, in_cluster, from, if_not_exists, conn)
             }
+            // HTTP sinks share the `FORMAT`, `ENVELOPE`, and `WITH` clauses of
+            // Kafka sinks.
+            conn @ CreateSinkConnection::Http { .. } => {
+                self.parse_create_kafka_sink(name, in_cluster, from, if_not_exists, conn)
+            }
+            // Postgres sinks share the `MODE` and `WITH` clauses of Iceberg
+            // sinks.
+            conn @ CreateSinkConnection::Postgres { .. } => {
+                self.parse_create_iceberg_sink(name, in_cluster, from, if_not_exists, conn)
+            }
         }?;
 
         Ok(Statement::CreateSink(statement))
@@ -3708,7 +3947,7 @@ impl<'a> Parser<'a> {
     fn parse_create_source_connection(
         &mut self,
     ) -> Result<CreateSourceConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, POSTGRES, SQL, MYSQL, LOAD])? {
+        match self.expect_one_of_keywords(&[KAFKA, POSTGRES, SQL, MYSQL, ICEBERG, LOAD])? {
             POSTGRES => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_raw_name()?;
@@ -3780,6 +4019,24 @@ impl<'a> Parser<'a> {
                     options,
                 })
             }
+            ICEBERG => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_iceberg_source_config_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::Iceberg {
+                    connection,
+                    options,
+                })
+            }
             LOAD => {
                 self.expect_keyword(GENERATOR)?;
                 let generator = match self.expect_one_of_keywords(&[
@@ -4127,13 +4384,73 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_create_http_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let options = self.parse_comma_separated(Parser::parse_http_sink_config_option)?;
+        self.expect_token(&Token::RParen)?;
+
+        // As with Kafka sinks, `KEY (` introduces the key columns while
+        // `KEY FORMAT` introduces a key/value format specifier.
+        let key =
+            if self.peek_keyword(KEY) && self.peek_nth_token(1) != Some(Token::Keyword(FORMAT)) {
+                self.expect_keyword(KEY)?;
+                let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+                let not_enforced = self.parse_keywords(&[NOT, ENFORCED]);
+                Some(SinkKey {
+                    key_columns,
+                    not_enforced,
+                })
+            } else {
+                None
+            };
+
+        Ok(CreateSinkConnection::Http { options, key })
+    }
+
+    fn parse_create_postgres_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_keyword(CONNECTION)?;
+        let connection = self.parse_raw_name()?;
+
+        let options = if self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_postgres_sink_config_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        let key = if self.parse_keyword(KEY) {
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = self.parse_keywords(&[NOT, ENFORCED]);
+            Some(SinkKey {
+                key_columns,
+                not_enforced,
+            })
+        } else {
+            None
+        };
+
+        Ok(CreateSinkConnection::Postgres {
+            connection,
+            options,
+            key,
+        })
+    }
+
     fn parse_create_sink_connection(&mut self) -> Result<CreateSinkConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, ICEBERG])? {
+        match self.expect_one_of_keywords(&[KAFKA, ICEBERG, HTTP, POSTGRES])? {
             KAFKA => self.parse_create_kafka_sink_connection(),
             ICEBERG => {
                 self.expect_keyword(CATALOG)?;
                 self.parse_create_iceberg_sink_connection()
             }
+            HTTP => self.parse_create_http_sink_connection(),
+            POSTGRES => self.parse_create_postgres_sink_connection(),
             _ => unreachable!(),
         }
     }
@@ -5348,7 +5665,7 @@ impl<'a> Parser<'a> {
         &mut self,
     ) -> Result<TableFromSourceOption<Raw>, ParserError> {
         let option = match self
-            .expect_one_of_keywords(&[TEXT, EXCLUDE, IGNORE, DETAILS, PARTITION, RETAIN])?
+            .expect_one_of_keywords(&[TEXT, EXCLUDE, IGNORE, DETAILS, PARTITION, RETAIN, RETRACT])?
         {
             ref keyword @ (TEXT | EXCLUDE) => {
                 self.expect_keyword(COLUMNS)?;
@@ -5407,6 +5724,13 @@ impl<'a> Parser<'a> {
                     value: self.parse_option_retain_history()?,
                 }
             }
+            RETRACT => {
+                self.expect_keywords(&[ON, TRUNCATE])?;
+                TableFromSourceOption {
+                    name: TableFromSourceOptionName::RetractOnTruncate,
+                    value: self.parse_optional_option_value()?,
+                }
+            }
             _ => unreachable!(),
         };
         Ok(option)
@@ -7723,8 +8047,9 @@ impl<'a> Parser<'a> {
                         options,
                         ctes: parser.parse_comma_separated(Parser::parse_cte_mut_rec)?,
                     })
+                } else if parser.parse_keyword(RECURSIVE) {
+                    CteBlock::Recursive(parser.parse_comma_separated(Parser::parse_cte)?)
                 } else {
-                    // TODO: optional RECURSIVE
                     CteBlock::Simple(parser.parse_comma_separated(Parser::parse_cte)?)
                 }
             } else {
@@ -8082,7 +8407,7 @@ impl<'a> Parser<'a> {
         };
 
         let group_by = if self.parse_keywords(&[GROUP, BY]) {
-            self.parse_comma_separated(Parser::parse_expr)?
+            self.parse_comma_separated(|parser| parser.parse_group_by_item(false))?
         } else {
             vec![]
         };
@@ -8120,6 +8445,54 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses an item of a `GROUP BY` clause, or of a `GROUPING SETS` list if
+    /// `within_grouping_sets` is set.
+    fn parse_group_by_item(
+        &mut self,
+        within_grouping_sets: bool,
+    ) -> Result<GroupByItem<Raw>, ParserError> {
+        if self.peek_token() == Some(Token::LParen) && self.peek_nth_token(1) == Some(Token::RParen)
+        {
+            self.next_token();
+            self.next_token();
+            Ok(GroupByItem::Set(vec![]))
+        } else if self.peek_keyword(ROLLUP) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.next_token();
+            Ok(GroupByItem::Rollup(self.parse_grouping_set_list()?))
+        } else if self.peek_keyword(CUBE) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.next_token();
+            Ok(GroupByItem::Cube(self.parse_grouping_set_list()?))
+        } else if self.parse_keywords(&[GROUPING, SETS]) {
+            self.expect_token(&Token::LParen)?;
+            let items = self.parse_comma_separated(|parser| parser.parse_group_by_item(true))?;
+            self.expect_token(&Token::RParen)?;
+            Ok(GroupByItem::GroupingSets(items))
+        } else if within_grouping_sets {
+            self.parse_grouping_set()
+        } else {
+            Ok(GroupByItem::Expr(self.parse_expr()?))
+        }
+    }
+
+    /// Parses the parenthesized list of items of a `ROLLUP` or `CUBE`.
+    fn parse_grouping_set_list(&mut self) -> Result<Vec<GroupByItem<Raw>>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let items = self.parse_comma_separated(Parser::parse_grouping_set)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(items)
+    }
+
+    /// Parses an expression within a grouping set construct. Unlike elsewhere,
+    /// a parenthesized list of expressions denotes a set of grouping
+    /// expressions rather than a row constructor.
+    fn parse_grouping_set(&mut self) -> Result<GroupByItem<Raw>, ParserError> {
+        let parenthesized = self.peek_token() == Some(Token::LParen);
+        match self.parse_expr()? {
+            Expr::Row { exprs } if parenthesized => Ok(GroupByItem::Set(exprs)),
+            expr => Ok(GroupByItem::Expr(expr)),
+        }
+    }
+
     fn parse_select_option(&mut self) -> Result<SelectOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[EXPECTED, AGGREGATE, DISTINCT, LIMIT])? {
             EXPECTED => {
@@ -8626,6 +8999,7 @@ impl<'a> Parser<'a> {
                         filter: None,
                         over: None,
                         distinct: false,
+                        within_group: false,
                     },
                     alias,
                     with_ordinality,
@@ -8697,6 +9071,7 @@ impl<'a> Parser<'a> {
                         filter: None,
                         over: None,
                         distinct: false,
+                        within_group: false,
                     },
                     alias,
                     with_ordinality,
@@ -8761,6 +9136,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             distinct: false,
+            within_group: false,
         })
     }
 
@@ -9373,25 +9749,58 @@ impl<'a> Parser<'a> {
     fn parse_explain_analyze(&mut self) -> Result<Statement<Raw>, ParserError> {
         // EXPLAIN ANALYZE CLUSTER (MEMORY | CPU) [WITH SKEW] [AS SQL]
         if self.parse_keyword(CLUSTER) {
-            let properties = self.parse_explain_analyze_computation_properties()?;
+            let properties = self.parse_explain_analyze_computation_properties(&[CPU, MEMORY])?;
             let as_sql = self.parse_keywords(&[AS, SQL]);
             return Ok(Statement::ExplainAnalyzeCluster(
                 ExplainAnalyzeClusterStatement { properties, as_sql },
             ));
         }
 
-        // EXPLAIN ANALYZE ((MEMORY | CPU) [WITH SKEW] | HINTS) FOR (INDEX ... | MATERIALIZED VIEW ...) [AS SQL]
+        // EXPLAIN ANALYZE <query> is shorthand for EXPLAIN ANALYZE CPU, MEMORY FOR <query>
+        if self.peek_one_of_keywords(QUERY_START_KEYWORDS) {
+            let query = self.parse_select_statement()?;
+            return Ok(Statement::ExplainAnalyzeObject(
+                ExplainAnalyzeObjectStatement {
+                    properties: ExplainAnalyzeProperty::Computation(
+                        ExplainAnalyzeComputationProperties {
+                            properties: vec![
+                                ExplainAnalyzeComputationProperty::Cpu,
+                                ExplainAnalyzeComputationProperty::Memory,
+                            ],
+                            skew: false,
+                        },
+                    ),
+                    explainee: Explainee::Select(Box::new(query), false),
+                    as_sql: false,
+                },
+            ));
+        }
+
+        // EXPLAIN ANALYZE ((MEMORY | CPU | UPDATES | HYDRATION | RECORDS) [WITH SKEW] | HINTS) FOR (INDEX ... | MATERIALIZED VIEW ...) [AS SQL]
+        // EXPLAIN ANALYZE ((MEMORY | CPU | UPDATES | HYDRATION | RECORDS) [WITH SKEW] | HINTS) FOR <query>
 
         let properties = if self.parse_keyword(HINTS) {
             ExplainAnalyzeProperty::Hints
         } else {
-            ExplainAnalyzeProperty::Computation(
-                self.parse_explain_analyze_computation_properties()?,
-            )
+            ExplainAnalyzeProperty::Computation(self.parse_explain_analyze_computation_properties(
+                &[CPU, MEMORY, UPDATES, HYDRATION, RECORDS],
+            )?)
         };
 
         self.expect_keyword(FOR)?;
 
+        if self.peek_one_of_keywords(QUERY_START_KEYWORDS) {
+            // The query runs as part of the statement, so there is no SQL to show.
+            let query = self.parse_select_statement()?;
+            return Ok(Statement::ExplainAnalyzeObject(
+                ExplainAnalyzeObjectStatement {
+                    properties,
+                    explainee: Explainee::Select(Box::new(query), false),
+                    as_sql: false,
+                },
+            ));
+        }
+
         let explainee = match self.expect_one_of_keywords(&[INDEX, MATERIALIZED])? {
             INDEX => Explainee::Index(self.parse_raw_name()?),
             MATERIALIZED => {
@@ -9412,10 +9821,13 @@ impl<'a> Parser<'a> {
         ))
     }
 
+    /// Parses a comma-separated list of the computation properties in
+    /// `allowed`, each at most once, followed by an optional `WITH SKEW`.
     fn parse_explain_analyze_computation_properties(
         &mut self,
+        allowed: &[Keyword],
     ) -> Result<ExplainAnalyzeComputationProperties, ParserError> {
-        let mut computation_properties = vec![CPU, MEMORY];
+        let mut computation_properties = allowed.to_vec();
         let (kw, property) =
             self.parse_explain_analyze_computation_property(&computation_properties)?;
         let mut properties = vec![property];
@@ -9428,7 +9840,22 @@ impl<'a> Parser<'a> {
             properties.push(property);
         }
 
+        let skew_pos = self.peek_pos();
         let skew = self.parse_keywords(&[WITH, SKEW]);
+        if skew
+            && !properties.iter().any(|p| {
+                matches!(
+                    p,
+                    ExplainAnalyzeComputationProperty::Cpu
+                        | ExplainAnalyzeComputationProperty::Memory
+                )
+            })
+        {
+            return Err(ParserError::new(
+                skew_pos,
+                "WITH SKEW requires CPU or MEMORY",
+            ));
+        }
 
         Ok(ExplainAnalyzeComputationProperties { properties, skew })
     }
@@ -9440,13 +9867,16 @@ impl<'a> Parser<'a> {
         if properties.is_empty() {
             return Err(ParserError::new(
                 self.peek_pos(),
-                "both CPU and MEMORY were specified, expected WITH SKEW or FOR",
+                "all properties were specified, expected WITH SKEW or FOR",
             ));
         }
 
         match self.expect_one_of_keywords(properties)? {
             CPU => Ok((CPU, ExplainAnalyzeComputationProperty::Cpu)),
             MEMORY => Ok((MEMORY, ExplainAnalyzeComputationProperty::Memory)),
+            UPDATES => Ok((UPDATES, ExplainAnalyzeComputationProperty::Updates)),
+            HYDRATION => Ok((HYDRATION, ExplainAnalyzeComputationProperty::Hydration)),
+            RECORDS => Ok((RECORDS, ExplainAnalyzeComputationProperty::Records)),
             _ => unreachable!(),
         }
     }
@@ -123,6 +123,13 @@ impl Pretty {
             "BODY FORMAT",
             self.doc_display_pass(&v.body_format),
         ));
+        if !v.body_columns.is_empty() {
+            docs.push(bracket(
+                "(",
+                comma_separate(|c| self.doc_display_pass(c), &v.body_columns),
+                ")",
+            ));
+        }
 
         if !v.include_headers.mappings.is_empty() || v.include_headers.column.is_some() {
             let mut header_docs = Vec::new();
@@ -961,6 +968,11 @@ impl Pretty {
                 CteBlock::Simple(ctes) => {
                     docs.push(title_comma_separate("WITH", |cte| self.doc_cte(cte), ctes))
                 }
+                CteBlock::Recursive(ctes) => docs.push(title_comma_separate(
+                    "WITH RECURSIVE",
+                    |cte| self.doc_cte(cte),
+                    ctes,
+                )),
                 CteBlock::MutuallyRecursive(mutrec) => {
                     let mut doc = RcDoc::text("WITH MUTUALLY RECURSIVE");
                     if !mutrec.options.is_empty() {
@@ -1178,7 +1190,7 @@ impl Pretty {
         if !v.group_by.is_empty() {
             docs.push(title_comma_separate(
                 "GROUP BY",
-                |e| self.doc_expr(e),
+                |e| self.doc_group_by_item(e),
                 &v.group_by,
             ));
         }
@@ -1198,6 +1210,31 @@ impl Pretty {
         RcDoc::intersperse(docs, Doc::line()).group()
     }
 
+    fn doc_group_by_item<'a, T: AstInfo>(&'a self, v: &'a GroupByItem<T>) -> RcDoc<'a> {
+        match v {
+            GroupByItem::Expr(expr) => self.doc_expr(expr),
+            GroupByItem::Set(exprs) if exprs.is_empty() => RcDoc::text("()"),
+            GroupByItem::Set(exprs) => {
+                bracket("(", comma_separate(|e| self.doc_expr(e), exprs), ")")
+            }
+            GroupByItem::Rollup(items) => bracket(
+                "ROLLUP (",
+                comma_separate(|i| self.doc_group_by_item(i), items),
+                ")",
+            ),
+            GroupByItem::Cube(items) => bracket(
+                "CUBE (",
+                comma_separate(|i| self.doc_group_by_item(i), items),
+                ")",
+            ),
+            GroupByItem::GroupingSets(items) => bracket(
+                "GROUPING SETS (",
+                comma_separate(|i| self.doc_group_by_item(i), items),
+                ")",
+            ),
+        }
+    }
+
     fn doc_select_item<'a, T: AstInfo>(&'a self, v: &'a SelectItem<T>) -> RcDoc<'a> {
         match v {
             SelectItem::Expr { expr, alias } => {
@@ -1252,6 +1289,7 @@ impl Pretty {
                                 | Expr::Function(_)
                                 | Expr::HomogenizingFunction { .. }
                                 | Expr::NullIf { .. }
+                                | Expr::Grouping { .. }
                                 | Expr::Subquery(_)
                                 | Expr::Exists(_)
                                 | Expr::Nested(_)
@@ -1393,6 +1431,11 @@ impl Pretty {
                 comma_separate(|e| self.doc_expr(e), exprs),
                 ")",
             ),
+            Expr::Grouping { exprs } => bracket(
+                "GROUPING(",
+                comma_separate(|e| self.doc_expr(e), exprs),
+                ")",
+            ),
             Expr::ArraySubquery(s) => bracket("ARRAY(", self.doc_query(s), ")"),
             Expr::ListSubquery(s) => bracket("LIST(", self.doc_query(s), ")"),
             Expr::Array(exprs) => {
@@ -1439,6 +1482,7 @@ impl Pretty {
                         | r#""exists""#
                         | r#""extract""#
                         | r#""greatest""#
+                        | r#""grouping""#
                         | r#""least""#
                         | r#""list""#
                         | r#""map""#
@@ -13,6 +13,7 @@
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::fmt;
+use std::iter;
 use std::sync::LazyLock;
 
 use itertools::Itertools;
@@ -3749,6 +3750,53 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Interval) => AggregateFunc::MinInterval => Interval, 2144;
             params!(Time) => AggregateFunc::MinTime => Time, 2139;
         },
+        "mode" => Aggregate {
+            params!(AnyElement) => Operation::unary_ordered(|_ecx, value, order_by| {
+                Ok((
+                    ordered_set_input(vec![], value),
+                    AggregateFunc::Mode { desc: order_by[0].desc },
+                ))
+            }) => AnyElement, 3984;
+        },
+        "percentile_cont" => Aggregate {
+            params!(Float64, Float64) => Operation::binary_ordered(|_ecx, fraction, value, order_by| {
+                let fraction = check_percentile_fraction(fraction);
+                Ok((
+                    ordered_set_input(vec![fraction], value),
+                    AggregateFunc::PercentileCont { desc: order_by[0].desc },
+                ))
+            }) => Float64, 3974;
+            params!(Float64, Interval) => Operation::binary(|_ecx, _fraction, _value| {
+                bail_unsupported!("percentile_cont on interval")
+            }) => Interval, 3976;
+            params!(SqlScalarType::Array(Box::new(SqlScalarType::Float64)), Float64) =>
+                Operation::binary_ordered(|_ecx, fractions, value, order_by| {
+                    Ok((
+                        ordered_set_input(vec![fractions], value),
+                        AggregateFunc::PercentileCont { desc: order_by[0].desc },
+                    ))
+                }) => SqlScalarType::Array(Box::new(SqlScalarType::Float64)), 3980;
+            params!(SqlScalarType::Array(Box::new(SqlScalarType::Float64)), Interval) =>
+                Operation::binary(|_ecx, _fractions, _value| {
+                    bail_unsupported!("percentile_cont on interval")
+                }) => SqlScalarType::Array(Box::new(SqlScalarType::Interval)), 3982;
+        },
+        "percentile_disc" => Aggregate {
+            params!(Float64, AnyElement) => Operation::binary_ordered(|_ecx, fraction, value, order_by| {
+                let fraction = check_percentile_fraction(fraction);
+                Ok((
+                    ordered_set_input(vec![fraction], value),
+                    AggregateFunc::PercentileDisc { desc: order_by[0].desc },
+                ))
+            }) => AnyElement, 3972;
+            params!(SqlScalarType::Array(Box::new(SqlScalarType::Float64)), AnyElement) =>
+                Operation::binary_ordered(|_ecx, fractions, value, order_by| {
+                    Ok((
+                        ordered_set_input(vec![fractions], value),
+                        AggregateFunc::PercentileDisc { desc: order_by[0].desc },
+                    ))
+                }) => ArrayAny, 3978;
+        },
         "jsonb_agg" => Aggregate {
             params!(Any) => Operation::unary_ordered(|ecx, e, order_by| {
                 // TODO(see <materialize#7572>): remove this
@@ -3858,6 +3906,17 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "dense_rank" => ScalarWindow {
             params!() => ScalarWindowFunc::DenseRank => Int64, 3102;
         },
+        "percent_rank" => ScalarWindow {
+            params!() => ScalarWindowFunc::PercentRank => Float64, 3103;
+        },
+        "cume_dist" => ScalarWindow {
+            params!() => ScalarWindowFunc::CumeDist => Float64, 3104;
+        },
+        "ntile" => ValueWindow {
+            params!(Int32) => Operation::unary(|_ecx, buckets| {
+                Ok((check_positive_window_argument("ntile", buckets), ValueWindowFunc::Ntile))
+            }) => Int32, 3105;
+        },
         "lag" => ValueWindow {
             // All args are encoded into a single record to be handled later
             params!(AnyElement) => Operation::unary(|ecx, e| {
@@ -3960,6 +4019,18 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "last_value" => ValueWindow {
             params!(AnyElement) => ValueWindowFunc::LastValue => AnyElement, 3113;
         },
+        "nth_value" => ValueWindow {
+            // All args are encoded into a single record to be handled later
+            params!(AnyElement, Int32) => Operation::binary(|_ecx, e, n| {
+                let e = HirScalarExpr::call_variadic(
+                    variadic::RecordCreate {
+                        field_names: vec![ColumnName::from("expr"), ColumnName::from("n")],
+                    },
+                    vec![e, check_positive_window_argument("nth_value", n)],
+                );
+                Ok((e, ValueWindowFunc::NthValue))
+            }) => AnyElement, 3114;
+        },
 
         // Table functions.
         "generate_series" => Table {
@@ -4256,6 +4327,10 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, String) => BinaryFunc::from(func::ConstantTimeEqString)
                 => Bool, oid::FUNC_CONSTANT_TIME_EQ_STRING_OID;
         },
+        "approx_count_distinct" => Aggregate {
+            params!(Any) => AggregateFunc::ApproxCountDistinct
+                => Int64, oid::FUNC_APPROX_COUNT_DISTINCT_OID;
+        },
         // Note: this is the original version of the AVG(...) function, as it existed prior to
         // v0.66. We updated the internal type promotion used when summing values to increase
         // precision, but objects (e.g. materialized views) that already used the AVG(...) function
@@ -4555,6 +4630,20 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 "has_type_privilege(current_user, $1, $2)",
             ) => Bool, 3143;
         },
+        "hll_cardinality" => Scalar {
+            params!(Bytes) => UnaryFunc::HllCardinality(func::HllCardinality)
+                => Int64, oid::FUNC_HLL_CARDINALITY_OID;
+        },
+        "hll_merge" => Aggregate {
+            params!(Bytes) => Operation::unary(|_ecx, e| {
+                // `HllMerge` relies on its input being valid sketches.
+                let e = e.call_unary(UnaryFunc::HllCheckSketch(func::HllCheckSketch));
+                Ok((e, AggregateFunc::HllMerge))
+            }) => Bytes, oid::FUNC_HLL_MERGE_OID;
+        },
+        "hll_sketch" => Aggregate {
+            params!(Any) => AggregateFunc::HllSketch => Bytes, oid::FUNC_HLL_SKETCH_OID;
+        },
         "kafka_murmur2" => Scalar {
             params!(String) => UnaryFunc::KafkaMurmur2String(func::KafkaMurmur2String)
                 => Int32, oid::FUNC_KAFKA_MURMUR2_STRING_OID;
@@ -5518,6 +5607,80 @@ pub static MZ_UNSAFE_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock
     }
 });
 
+/// The ordered-set aggregates, along with their number of direct arguments,
+/// i.e., the arguments that precede `WITHIN GROUP (ORDER BY ...)`.
+pub const ORDERED_SET_AGGREGATES: &[(&str, usize)] =
+    &[("mode", 0), ("percentile_cont", 1), ("percentile_disc", 1)];
+
+/// Packs the direct arguments of an ordered-set aggregate and the value it
+/// aggregates into the record that `AggregateFunc`s of ordered-set aggregates
+/// expect as input. The value is always the last field of the record.
+fn ordered_set_input(direct_args: Vec<HirScalarExpr>, value: HirScalarExpr) -> HirScalarExpr {
+    let mut exprs = direct_args;
+    exprs.push(value);
+    HirScalarExpr::call_variadic(
+        variadic::RecordCreate {
+            field_names: iter::repeat(ColumnName::from(""))
+                .take(exprs.len())
+                .collect(),
+        },
+        exprs,
+    )
+}
+
+/// Wraps the `fraction` argument of `percentile_cont` and `percentile_disc`
+/// in a check that errors if it is not between 0 and 1, like PostgreSQL does.
+fn check_percentile_fraction(fraction: HirScalarExpr) -> HirScalarExpr {
+    let bound = |f: f64| HirScalarExpr::literal(Datum::from(f), SqlScalarType::Float64);
+    let out_of_range = fraction
+        .clone()
+        .call_binary(bound(0.0), func::Lt)
+        .or(fraction.clone().call_binary(bound(1.0), func::Gt));
+    let message = HirScalarExpr::literal(Datum::String("percentile value "), SqlScalarType::String)
+        .call_binary(
+            fraction
+                .clone()
+                .call_unary(UnaryFunc::CastFloat64ToString(func::CastFloat64ToString)),
+            func::TextConcatBinary,
+        )
+        .call_binary(
+            HirScalarExpr::literal(
+                Datum::String(" is not between 0 and 1"),
+                SqlScalarType::String,
+            ),
+            func::TextConcatBinary,
+        );
+    HirScalarExpr::if_then_else(
+        out_of_range,
+        HirScalarExpr::call_variadic(
+            variadic::ErrorIfNull,
+            vec![HirScalarExpr::literal_null(SqlScalarType::Float64), message],
+        ),
+        fraction,
+    )
+}
+
+/// Wraps the argument of `ntile` or `nth_value` in a check that errors if it is
+/// not positive, like PostgreSQL does.
+fn check_positive_window_argument(name: &str, arg: HirScalarExpr) -> HirScalarExpr {
+    let non_positive = arg.clone().call_binary(
+        HirScalarExpr::literal(Datum::Int32(0), SqlScalarType::Int32),
+        func::Lte,
+    );
+    let message = HirScalarExpr::literal(
+        Datum::String(&format!("argument of {name} must be greater than zero")),
+        SqlScalarType::String,
+    );
+    HirScalarExpr::if_then_else(
+        non_positive,
+        HirScalarExpr::call_variadic(
+            variadic::ErrorIfNull,
+            vec![HirScalarExpr::literal_null(SqlScalarType::Int32), message],
+        ),
+        arg,
+    )
+}
+
 fn digest(algorithm: &'static str) -> Operation<HirScalarExpr> {
     Operation::unary(move |_ecx, input| {
         let algorithm = HirScalarExpr::literal(Datum::String(algorithm), SqlScalarType::String);
@@ -9,10 +9,22 @@
 
 use crate::names::Aug;
 use mz_sql_parser::ast::display::AstDisplay;
-use mz_sql_parser::ast::{IcebergSinkConfigOption, IcebergSinkConfigOptionName};
+use mz_sql_parser::ast::{
+    IcebergPartitionField, IcebergSinkConfigOption, IcebergSinkConfigOptionName, IcebergSortField,
+    IcebergSourceConfigOption, IcebergSourceConfigOptionName,
+};
 
 generate_extracted_config!(
     IcebergSinkConfigOption,
     (Table, String),
-    (Namespace, String)
+    (Namespace, String),
+    (PartitionBy, Vec::<IcebergPartitionField>, Default(vec![])),
+    (SortBy, Vec::<IcebergSortField>, Default(vec![]))
+);
+
+generate_extracted_config!(
+    IcebergSourceConfigOption,
+    (Details, String),
+    (Namespace, String),
+    (Table, String)
 );
@@ -1704,6 +1704,27 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 }
                 CteBlock::Simple(result_ctes)
             }
+            CteBlock::Recursive(ctes) => {
+                let mut result_ctes = Vec::<Cte<Aug>>::new();
+
+                // Unlike in a simple block, each binding goes into scope
+                // before its definition is walked, so that it can refer to
+                // itself.
+                for cte in ctes.into_iter() {
+                    let cte_name = normalize::ident(cte.alias.name.clone());
+                    let local_id = self.allocate_cte_id();
+
+                    let shadowed_id = self.ctes.insert(cte_name.clone(), local_id);
+                    shadowed_cte_ids.push((cte_name, shadowed_id));
+
+                    result_ctes.push(Cte {
+                        alias: cte.alias,
+                        id: local_id,
+                        query: self.fold_query(cte.query),
+                    });
+                }
+                CteBlock::Recursive(result_ctes)
+            }
             CteBlock::MutuallyRecursive(MutRecBlock { options, ctes }) => {
                 let mut result_ctes = Vec::<CteMutRec<Aug>>::new();
 
@@ -2080,6 +2101,8 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                     .map(|r| self.fold_network_policy_rule_definition(r))
                     .collect(),
             ),
+            IcebergPartitionBy(fields) => IcebergPartitionBy(fields),
+            IcebergSortBy(fields) => IcebergSortBy(fields),
         }
     }
 
@@ -2193,6 +2216,7 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 filter: node.filter.map(|expr| Box::new(self.fold_expr(*expr))),
                 over: node.over.map(|over| self.fold_window_spec(over)),
                 distinct: node.distinct,
+                within_group: node.within_group,
             }
         })
     }
@@ -2332,6 +2356,26 @@ where
     Ok((result, ResolvedIds::new(resolver.ids)))
 }
 
+/// Returns one more than the largest CTE id bound in `node`, which is the
+/// number of CTE ids that name resolution allocated for it. `LocalId`s from
+/// this value onwards are free for the planner to use.
+pub fn cte_id_bound<'ast, N>(node: &'ast N) -> u64
+where
+    N: VisitNode<'ast, Aug>,
+{
+    struct CteIdBound(u64);
+
+    impl<'ast> Visit<'ast, Aug> for CteIdBound {
+        fn visit_cte_id(&mut self, id: &'ast LocalId) {
+            self.0 = self.0.max(u64::from(id) + 1);
+        }
+    }
+
+    let mut bound = CteIdBound(0);
+    node.visit(&mut bound);
+    bound.0
+}
+
 /// A set of items and their corresponding collections resolved by name resolution.
 ///
 /// This is a newtype of a [`BTreeMap`] that is provided to make it harder to confuse a set of
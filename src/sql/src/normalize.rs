@@ -202,7 +202,7 @@ pub fn create_statement(
         fn visit_query_mut(&mut self, query: &'ast mut Query<Aug>) {
             let n = self.ctes.len();
             match &query.ctes {
-                CteBlock::Simple(ctes) => {
+                CteBlock::Simple(ctes) | CteBlock::Recursive(ctes) => {
                     for cte in ctes.iter() {
                         self.ctes.push(cte.alias.name.clone());
                     }
@@ -351,10 +351,18 @@ pub fn create_statement(
             if_not_exists,
             include_headers: _,
             body_format: _,
+            body_columns,
             validate_using: _,
             in_cluster: _,
         }) => {
             *name = allocate_name(name)?;
+            let mut normalizer = QueryNormalizer::new();
+            for c in body_columns {
+                normalizer.visit_column_def_mut(c);
+            }
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
             *if_not_exists = false;
         }
 
@@ -52,7 +52,7 @@ use mz_repr::{
 };
 use mz_sql_parser::ast::{
     AlterSourceAddSubsourceOption, ClusterAlterOptionValue, ConnectionOptionName, CreateSinkOption,
-    CreateSinkOptionName, QualifiedReplica, RawDataType, SelectStatement,
+    CreateSinkOptionName, ExplainAnalyzeProperty, QualifiedReplica, RawDataType, SelectStatement,
     TransactionIsolationLevel, TransactionMode, UnresolvedItemName, Value, WithOptionValue,
 };
 use mz_ssh_util::keys::SshKeyPair;
@@ -66,6 +66,7 @@ use mz_storage_types::connections::{
 };
 use mz_storage_types::instances::StorageInstanceId;
 use mz_storage_types::sinks::{S3SinkFormat, SinkEnvelope, StorageSinkConnection};
+use mz_storage_types::sources::encoding::DataEncoding;
 use mz_storage_types::sources::{
     SourceDesc, SourceExportDataConfig, SourceExportDetails, Timeline,
 };
@@ -121,8 +122,8 @@ pub use statement::ddl::{
     SqlServerConfigOptionExtracted,
 };
 pub use statement::{
-    StatementClassification, StatementContext, StatementDesc, describe, plan, plan_copy_from,
-    resolve_cluster_for_materialized_view,
+    StatementClassification, StatementContext, StatementDesc, describe,
+    explain_analyze_dataflow_query, plan, plan_copy_from, resolve_cluster_for_materialized_view,
 };
 pub use with_options::TryFromValue;
 
@@ -172,6 +173,7 @@ pub enum Plan {
     CopyTo(CopyToPlan),
     ExplainPlan(ExplainPlanPlan),
     ExplainPushdown(ExplainPushdownPlan),
+    ExplainAnalyzeSelect(ExplainAnalyzeSelectPlan),
     ExplainTimestamp(ExplainTimestampPlan),
     ExplainSinkSchema(ExplainSinkSchemaPlan),
     Insert(InsertPlan),
@@ -299,7 +301,9 @@ impl Plan {
             StatementKind::Execute => &[PlanKind::Execute],
             StatementKind::ExplainPlan => &[PlanKind::ExplainPlan],
             StatementKind::ExplainPushdown => &[PlanKind::ExplainPushdown],
-            StatementKind::ExplainAnalyzeObject => &[PlanKind::Select],
+            StatementKind::ExplainAnalyzeObject => {
+                &[PlanKind::Select, PlanKind::ExplainAnalyzeSelect]
+            }
             StatementKind::ExplainAnalyzeCluster => &[PlanKind::Select],
             StatementKind::ExplainTimestamp => &[PlanKind::ExplainTimestamp],
             StatementKind::ExplainSinkSchema => &[PlanKind::ExplainSinkSchema],
@@ -395,6 +399,7 @@ impl Plan {
             Plan::CopyTo(_) => "copy to",
             Plan::ExplainPlan(_) => "explain plan",
             Plan::ExplainPushdown(_) => "EXPLAIN FILTER PUSHDOWN",
+            Plan::ExplainAnalyzeSelect(_) => "explain analyze",
             Plan::ExplainTimestamp(_) => "explain timestamp",
             Plan::ExplainSinkSchema(_) => "explain schema",
             Plan::Insert(_) => "insert",
@@ -508,6 +513,7 @@ impl Plan {
             Plan::CopyTo(_) => true,
             Plan::ExplainPlan(_) => true,
             Plan::ExplainPushdown(_) => true,
+            Plan::ExplainAnalyzeSelect(_) => true,
             Plan::ExplainTimestamp(_) => true,
             Plan::ExplainSinkSchema(_) => true,
             Plan::ValidateConnection(_) => true,
@@ -1189,6 +1195,16 @@ pub struct ExplainPushdownPlan {
     pub explainee: Explainee,
 }
 
+/// `EXPLAIN ANALYZE ... FOR <query>`: run the query and report the
+/// introspection data of the dataflow that computed it.
+#[derive(Clone, Debug)]
+pub struct ExplainAnalyzeSelectPlan {
+    pub select: SelectPlan,
+    pub properties: ExplainAnalyzeProperty,
+    /// The shape of the reported rows.
+    pub desc: RelationDesc,
+}
+
 #[derive(Clone, Debug)]
 pub struct ExplainTimestampPlan {
     pub format: ExplainFormat,
@@ -1613,6 +1629,8 @@ pub enum DataSourceDesc {
     Webhook {
         validate_using: Option<WebhookValidation>,
         body_format: WebhookBodyFormat,
+        /// Only `Some` when the body is decoded into typed columns.
+        body_decoding: Option<WebhookBodyDecoding>,
         headers: WebhookHeaders,
         /// Only `Some` when created via `CREATE TABLE ... FROM WEBHOOK`.
         cluster_id: Option<StorageInstanceId>,
@@ -1715,6 +1733,17 @@ impl From<WebhookBodyFormat> for SqlScalarType {
     }
 }
 
+/// Describes how each message of a webhook request, as split by its [`WebhookBodyFormat`], is
+/// decoded into the typed body columns of the source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum WebhookBodyDecoding {
+    /// One expression per body column, evaluated against the JSON message provided as a single
+    /// `jsonb` column.
+    Json { columns: Vec<MirScalarExpr> },
+    /// Messages are decoded with the same decoders as sources using this encoding.
+    Encoding(DataEncoding<ReferencedConnection>),
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct WebhookValidationSecret {
     /// Identifies the secret by [`CatalogItemId`].
@@ -44,8 +44,9 @@ use crate::plan::scope::ScopeItem;
 use crate::plan::typeconv::CastContext;
 use crate::pure::error::{
     CsrPurificationError, GluePurificationError, IcebergSinkPurificationError,
-    KafkaSinkPurificationError, KafkaSourcePurificationError, LoadGeneratorSourcePurificationError,
-    MySqlSourcePurificationError, PgSourcePurificationError, SqlServerSourcePurificationError,
+    IcebergSourcePurificationError, KafkaSinkPurificationError, KafkaSourcePurificationError,
+    LoadGeneratorSourcePurificationError, MySqlSourcePurificationError, PgSourcePurificationError,
+    SqlServerSourcePurificationError,
 };
 use crate::session::vars::VarError;
 
@@ -124,6 +125,11 @@ pub enum PlanError {
         column: String,
         column_type: String,
     },
+    IcebergSinkUnsupportedPartitionType {
+        column: String,
+        column_type: String,
+        transform: &'static str,
+    },
     InvalidWmrRecursionLimit(String),
     InvalidNumericMaxScale(InvalidNumericMaxScaleError),
     InvalidCharLength(InvalidCharLengthError),
@@ -278,6 +284,7 @@ pub enum PlanError {
     InvalidGroupSizeHints,
     PgSourcePurification(PgSourcePurificationError),
     KafkaSourcePurification(KafkaSourcePurificationError),
+    IcebergSourcePurification(IcebergSourcePurificationError),
     KafkaSinkPurification(KafkaSinkPurificationError),
     IcebergSinkPurification(IcebergSinkPurificationError),
     LoadGeneratorSourcePurification(LoadGeneratorSourcePurificationError),
@@ -382,6 +389,7 @@ impl PlanError {
             Self::MySqlSourcePurification(e) => e.detail(),
             Self::SqlServerSourcePurificationError(e) => e.detail(),
             Self::KafkaSourcePurification(e) => e.detail(),
+            Self::IcebergSourcePurification(e) => e.detail(),
             Self::LoadGeneratorSourcePurification(e) => e.detail(),
             Self::CsrPurification(e) => e.detail(),
             Self::GluePurification(e) => e.detail(),
@@ -482,12 +490,23 @@ impl PlanError {
             Self::IcebergSinkUnsupportedKeyType { .. } => {
                 Some("Iceberg equality delete keys must be primitive, non-floating-point columns.".into())
             }
+            Self::IcebergSinkUnsupportedPartitionType { transform, .. } => {
+                let types = match *transform {
+                    "identity" => "boolean, integer, text, date, and timestamp",
+                    "bucket" => "integer, text, date, and timestamp",
+                    "truncate" => "integer and text",
+                    "day" => "date and timestamp",
+                    _ => "timestamp",
+                };
+                Some(format!("The {transform} transform supports {types} columns."))
+            }
             Self::Catalog(e) => e.hint(),
             Self::VarError(e) => e.hint(),
             Self::PgSourcePurification(e) => e.hint(),
             Self::MySqlSourcePurification(e) => e.hint(),
             Self::SqlServerSourcePurificationError(e) => e.hint(),
             Self::KafkaSourcePurification(e) => e.hint(),
+            Self::IcebergSourcePurification(e) => e.hint(),
             Self::LoadGeneratorSourcePurification(e) => e.hint(),
             Self::CsrPurification(e) => e.hint(),
             Self::GluePurification(e) => e.hint(),
@@ -641,6 +660,9 @@ impl fmt::Display for PlanError {
             Self::IcebergSinkUnsupportedKeyType { column, column_type } => {
                 write!(f, "column {column} has type {column_type} which cannot be used as an Iceberg equality delete key")
             }
+            Self::IcebergSinkUnsupportedPartitionType { column, column_type, transform } => {
+                write!(f, "column {column} has type {column_type} which cannot be partitioned by the {transform} transform")
+            }
             Self::InvalidWmrRecursionLimit(msg) => write!(f, "Invalid WITH MUTUALLY RECURSIVE recursion limit. {}", msg),
             Self::InvalidNumericMaxScale(e) => e.fmt(f),
             Self::InvalidCharLength(e) => e.fmt(f),
@@ -819,6 +841,7 @@ impl fmt::Display for PlanError {
                 or LIMIT INPUT GROUP SIZE"),
             Self::PgSourcePurification(e) => write!(f, "POSTGRES source validation: {}", e),
             Self::KafkaSourcePurification(e) => write!(f, "KAFKA source validation: {}", e),
+            Self::IcebergSourcePurification(e) => write!(f, "ICEBERG source validation: {}", e),
             Self::LoadGeneratorSourcePurification(e) => write!(f, "LOAD GENERATOR source validation: {}", e),
             Self::KafkaSinkPurification(e) => write!(f, "KAFKA sink validation: {}", e),
             Self::IcebergSinkPurification(e) => write!(f, "ICEBERG sink validation: {}", e),
@@ -1023,6 +1046,12 @@ impl From<KafkaSourcePurificationError> for PlanError {
     }
 }
 
+impl From<IcebergSourcePurificationError> for PlanError {
+    fn from(e: IcebergSourcePurificationError) -> Self {
+        PlanError::IcebergSourcePurification(e)
+    }
+}
+
 impl From<KafkaSinkPurificationError> for PlanError {
     fn from(e: KafkaSinkPurificationError) -> Self {
         PlanError::KafkaSinkPurification(e)
@@ -574,6 +574,8 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::Rank => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -587,6 +589,8 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::Rank => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -611,6 +615,12 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::DenseRank => mz_expr::AggregateFunc::DenseRank {
                 order_by: self.order_by,
             },
+            ScalarWindowFunc::PercentRank => mz_expr::AggregateFunc::PercentRank {
+                order_by: self.order_by,
+            },
+            ScalarWindowFunc::CumeDist => mz_expr::AggregateFunc::CumeDist {
+                order_by: self.order_by,
+            },
         }
     }
 }
@@ -631,6 +641,8 @@ pub enum ScalarWindowFunc {
     RowNumber,
     Rank,
     DenseRank,
+    PercentRank,
+    CumeDist,
 }
 
 impl Display for ScalarWindowFunc {
@@ -639,6 +651,8 @@ impl Display for ScalarWindowFunc {
             ScalarWindowFunc::RowNumber => write!(f, "row_number"),
             ScalarWindowFunc::Rank => write!(f, "rank"),
             ScalarWindowFunc::DenseRank => write!(f, "dense_rank"),
+            ScalarWindowFunc::PercentRank => write!(f, "percent_rank"),
+            ScalarWindowFunc::CumeDist => write!(f, "cume_dist"),
         }
     }
 }
@@ -649,6 +663,8 @@ impl ScalarWindowFunc {
             ScalarWindowFunc::RowNumber => SqlScalarType::Int64.nullable(false),
             ScalarWindowFunc::Rank => SqlScalarType::Int64.nullable(false),
             ScalarWindowFunc::DenseRank => SqlScalarType::Int64.nullable(false),
+            ScalarWindowFunc::PercentRank => SqlScalarType::Float64.nullable(false),
+            ScalarWindowFunc::CumeDist => SqlScalarType::Float64.nullable(false),
         }
     }
 }
@@ -685,6 +701,8 @@ impl Display for ValueWindowFunc {
             ValueWindowFunc::Lead => write!(f, "lead"),
             ValueWindowFunc::FirstValue => write!(f, "first_value"),
             ValueWindowFunc::LastValue => write!(f, "last_value"),
+            ValueWindowFunc::Ntile => write!(f, "ntile"),
+            ValueWindowFunc::NthValue => write!(f, "nth_value"),
             ValueWindowFunc::Fused(funcs) => write!(f, "fused[{}]", separated(", ", funcs)),
         }
     }
@@ -769,14 +787,17 @@ pub enum ValueWindowFunc {
     Lead,
     FirstValue,
     LastValue,
+    Ntile,
+    NthValue,
     Fused(Vec<ValueWindowFunc>),
 }
 
 impl ValueWindowFunc {
     pub fn output_sql_type(&self, input_type: SqlColumnType) -> SqlColumnType {
         match self {
-            ValueWindowFunc::Lag | ValueWindowFunc::Lead => {
-                // The input is a (value, offset, default) record, so extract the type of the first arg
+            ValueWindowFunc::Lag | ValueWindowFunc::Lead | ValueWindowFunc::NthValue => {
+                // The input is a (value, offset, default) record for lag/lead, and a (value, n)
+                // record for nth_value, so extract the type of the first arg
                 input_type.scalar_type.unwrap_record_element_type()[0]
                     .clone()
                     .nullable(true)
@@ -784,6 +805,7 @@ impl ValueWindowFunc {
             ValueWindowFunc::FirstValue | ValueWindowFunc::LastValue => {
                 input_type.scalar_type.nullable(true)
             }
+            ValueWindowFunc::Ntile => SqlScalarType::Int32.nullable(true),
             ValueWindowFunc::Fused(funcs) => {
                 let input_types = input_type.scalar_type.unwrap_record_element_column_type();
                 SqlScalarType::Record {
@@ -825,6 +847,11 @@ impl ValueWindowFunc {
                 order_by,
                 window_frame,
             },
+            ValueWindowFunc::Ntile => mz_expr::AggregateFunc::Ntile { order_by },
+            ValueWindowFunc::NthValue => mz_expr::AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            },
             ValueWindowFunc::Fused(funcs) => mz_expr::AggregateFunc::FusedValueWindowFunc {
                 funcs: funcs
                     .into_iter()
@@ -1372,6 +1399,27 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// The ordered-set aggregate `percentile_cont`. Its input is a record of
+    /// the fraction (or array of fractions) and the value to aggregate.
+    PercentileCont {
+        desc: bool,
+    },
+    /// The ordered-set aggregate `percentile_disc`. Its input is a record of
+    /// the fraction (or array of fractions) and the value to aggregate.
+    PercentileDisc {
+        desc: bool,
+    },
+    /// The ordered-set aggregate `mode`. Its input is a record of the value to
+    /// aggregate.
+    Mode {
+        desc: bool,
+    },
+    /// Estimates the number of distinct values with a HyperLogLog sketch.
+    ApproxCountDistinct,
+    /// Accumulates values into a HyperLogLog sketch.
+    HllSketch,
+    /// Merges HyperLogLog sketches.
+    HllMerge,
     /// A bundle of fused window aggregations: its input is a record, whose each
     /// component will be the input to one of the `AggregateFunc`s.
     ///
@@ -1455,6 +1503,16 @@ impl AggregateFunc {
                 mz_expr::AggregateFunc::ListConcat { order_by }
             }
             AggregateFunc::StringAgg { order_by } => mz_expr::AggregateFunc::StringAgg { order_by },
+            AggregateFunc::PercentileCont { desc } => {
+                mz_expr::AggregateFunc::PercentileCont { desc }
+            }
+            AggregateFunc::PercentileDisc { desc } => {
+                mz_expr::AggregateFunc::PercentileDisc { desc }
+            }
+            AggregateFunc::Mode { desc } => mz_expr::AggregateFunc::Mode { desc },
+            AggregateFunc::ApproxCountDistinct => mz_expr::AggregateFunc::ApproxCountDistinct,
+            AggregateFunc::HllSketch => mz_expr::AggregateFunc::HllSketch,
+            AggregateFunc::HllMerge => mz_expr::AggregateFunc::HllMerge,
             // `AggregateFunc::FusedWindowAgg` should be specially handled in
             // `AggregateWindowExpr::into_expr`.
             AggregateFunc::FusedWindowAgg { funcs: _ } => {
@@ -1524,7 +1582,13 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => Datum::Null,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::ApproxCountDistinct
+            | AggregateFunc::HllSketch
+            | AggregateFunc::HllMerge => Datum::Null,
             AggregateFunc::FusedWindowAgg { funcs: _ } => {
                 // `identity_datum` is used only in HIR planning, and `FusedWindowAgg` can't occur
                 // in HIR planning, because it is introduced only during HIR transformation.
@@ -1547,7 +1611,8 @@ impl AggregateFunc {
     /// counts.
     pub fn output_sql_type(&self, input_type: SqlColumnType) -> SqlColumnType {
         let scalar_type = match self {
-            AggregateFunc::Count => SqlScalarType::Int64,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => SqlScalarType::Int64,
+            AggregateFunc::HllSketch | AggregateFunc::HllMerge => SqlScalarType::Bytes,
             AggregateFunc::Any => SqlScalarType::Bool,
             AggregateFunc::All => SqlScalarType::Bool,
             AggregateFunc::JsonbAgg { .. } => SqlScalarType::Jsonb,
@@ -1572,6 +1637,25 @@ impl AggregateFunc {
                     _ => unreachable!(),
                 }
             }
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => {
+                // The input is a record of the direct arguments followed by the
+                // aggregated value.
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let value_type = fields[fields.len() - 1].clone();
+                let is_array = matches!(fields[0], SqlScalarType::Array(_));
+                match self {
+                    AggregateFunc::PercentileCont { .. } if is_array => {
+                        SqlScalarType::Array(Box::new(SqlScalarType::Float64))
+                    }
+                    AggregateFunc::PercentileCont { .. } => SqlScalarType::Float64,
+                    AggregateFunc::PercentileDisc { .. } if is_array => {
+                        SqlScalarType::Array(Box::new(value_type))
+                    }
+                    _ => value_type,
+                }
+            }
             AggregateFunc::MaxNumeric
             | AggregateFunc::MaxInt16
             | AggregateFunc::MaxInt32
@@ -1623,7 +1707,10 @@ impl AggregateFunc {
             }
         };
         // max/min/sum return null on empty sets
-        let nullable = !matches!(self, AggregateFunc::Count);
+        let nullable = !matches!(
+            self,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct
+        );
         scalar_type.nullable(nullable)
     }
 
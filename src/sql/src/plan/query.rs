@@ -55,6 +55,7 @@ use mz_expr::{
     Eval, Id, LetRecLimit, LocalId, MapFilterProject, MirScalarExpr, REPEAT_ROW_NAME,
     RowSetFinishing, TableFunc, func as expr_func,
 };
+use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_ore::error::ErrorExt;
 use mz_ore::id_gen::IdGen;
@@ -75,14 +76,15 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit::Visit;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
-    AsOf, Assignment, AstInfo, CreateWebhookSourceBody, CreateWebhookSourceCheck,
-    CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock, DeleteStatement, Distinct,
-    Expr, Function, FunctionArgs, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join,
-    JoinConstraint, JoinOperator, Limit, MapEntry, MutRecBlock, MutRecBlockOption,
-    MutRecBlockOptionName, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName,
-    SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor,
-    TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values, WindowFrame,
-    WindowFrameBound, WindowFrameUnits, WindowSpec, visit,
+    AsOf, Assignment, AstInfo, ColumnDef, ColumnOption, CreateWebhookSourceBody,
+    CreateWebhookSourceCheck, CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock,
+    DeleteStatement, Distinct, Expr, Function, FunctionArgs, GroupByItem, HomogenizingFunction,
+    Ident, InsertSource, IsExprConstruct, Join, JoinConstraint, JoinOperator, Limit, MapEntry,
+    MutRecBlock, MutRecBlockOption, MutRecBlockOptionName, Op, OrderByExpr, Query, Select,
+    SelectItem, SelectOption, SelectOptionName, SetExpr, SetOperator, ShowStatement,
+    SubscriptPosition, TableAlias, TableFactor, TableWithJoins, UnresolvedItemName,
+    UpdateStatement, Value, Values, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    visit,
 };
 use mz_sql_parser::ident;
 
@@ -1248,6 +1250,84 @@ pub fn plan_webhook_validate_using(
     Ok(validation)
 }
 
+/// Plans the expressions that extract the declared `body_columns` of a webhook source from each
+/// JSON message of a request, returning them along with the types of the columns.
+///
+/// Each column is extracted as `CAST(body ->> '<column name>' AS <column type>)`, where `body` is
+/// the JSON message.
+pub fn plan_webhook_body_columns(
+    scx: &StatementContext,
+    body_columns: Vec<ColumnDef<Aug>>,
+) -> Result<(Vec<MirScalarExpr>, Vec<(ColumnName, SqlColumnType)>), PlanError> {
+    let qcx = QueryContext::root(scx, QueryLifetime::Source);
+    let column_names = vec!["body".to_string()];
+    let relation_typ = SqlRelationType::new(vec![SqlColumnType {
+        scalar_type: SqlScalarType::Jsonb,
+        nullable: false,
+    }]);
+    let desc = RelationDesc::new(relation_typ, column_names.clone());
+    let scope = Scope::from_source(None, column_names);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "webhook body column",
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+
+    let mut exprs = Vec::with_capacity(body_columns.len());
+    let mut columns = Vec::with_capacity(body_columns.len());
+    for ColumnDef {
+        name,
+        data_type,
+        collation,
+        options,
+    } in body_columns
+    {
+        if collation.is_some() {
+            sql_bail!("COLLATE is not supported for webhook body columns");
+        }
+        let mut nullable = true;
+        for option in options {
+            match option.option {
+                ColumnOption::NotNull => nullable = false,
+                ColumnOption::Null => nullable = true,
+                other => sql_bail!(
+                    "column option {} is not supported for webhook body columns",
+                    other.to_ast_string_simple()
+                ),
+            }
+        }
+        let scalar_type = scalar_type_from_sql(scx, &data_type)?;
+
+        let expr = Expr::Cast {
+            expr: Box::new(Expr::Op {
+                op: Op::bare("->>"),
+                expr1: Box::new(Expr::Identifier(vec![ident!("body")])),
+                expr2: Some(Box::new(Expr::Value(Value::String(
+                    name.as_str().to_string(),
+                )))),
+            }),
+            data_type,
+        };
+        let expr = plan_expr(ecx, &expr)?
+            .type_as(ecx, &scalar_type)?
+            .lower_uncorrelated(scx.catalog.system_vars())?;
+        exprs.push(expr);
+        columns.push((
+            normalize::column_name(name),
+            SqlColumnType {
+                scalar_type,
+                nullable,
+            },
+        ));
+    }
+    Ok((exprs, columns))
+}
+
 pub fn plan_default_expr(
     scx: &StatementContext,
     expr: &Expr<Aug>,
@@ -1551,9 +1631,17 @@ fn plan_query_inner(qcx: &mut QueryContext, q: &Query<Aug>) -> Result<PlannedQue
         }
     };
 
-    // Both introduce `Let` bindings atop `result` and re-install shadowed bindings.
+    // All introduce `Let` bindings atop `result` and re-install shadowed bindings.
+    // A recursive block without any self-referencing binding is a simple block.
+    let recursive = match &q.ctes {
+        CteBlock::Simple(_) => false,
+        CteBlock::Recursive(ctes) => ctes
+            .iter()
+            .any(|cte| count_cte_references(&cte.query, cte.id) > 0),
+        CteBlock::MutuallyRecursive(_) => true,
+    };
     match &q.ctes {
-        CteBlock::Simple(_) => {
+        CteBlock::Simple(_) | CteBlock::Recursive(_) if !recursive => {
             for (id, value, shadowed_val) in cte_bindings.into_iter().rev() {
                 if let Some(cte) = qcx.ctes.remove(&id) {
                     planned_query.expr = HirRelationExpr::Let {
@@ -1568,6 +1656,26 @@ fn plan_query_inner(qcx: &mut QueryContext, q: &Query<Aug>) -> Result<PlannedQue
                 }
             }
         }
+        CteBlock::Simple(_) | CteBlock::Recursive(_) => {
+            // `WITH RECURSIVE` has no recursion limit; like in PostgreSQL, a
+            // query whose recursion does not converge runs forever.
+            let mut bindings = Vec::new();
+            for (id, value, shadowed_val) in cte_bindings.into_iter() {
+                if let Some(cte) = qcx.ctes.remove(&id) {
+                    bindings.push((cte.name, id, value, cte.desc.into_typ()));
+                }
+                if let Some(shadowed_val) = shadowed_val {
+                    qcx.ctes.insert(id, shadowed_val);
+                }
+            }
+            if !bindings.is_empty() {
+                planned_query.expr = HirRelationExpr::LetRec {
+                    limit: None,
+                    bindings,
+                    body: Box::new(planned_query.expr),
+                }
+            }
+        }
         CteBlock::MutuallyRecursive(MutRecBlock { options, ctes: _ }) => {
             let MutRecBlockOptionExtracted {
                 recursion_limit,
@@ -1696,6 +1804,124 @@ pub fn plan_ctes(
                 result.push((cte.id, val, shadowed));
             }
         }
+        CteBlock::Recursive(ctes) => {
+            for cte in ctes.iter() {
+                let cte_name = normalize::ident(cte.alias.name.clone());
+                if count_cte_references(&cte.query, cte.id) == 0 {
+                    // A binding that does not refer to itself is planned like
+                    // a simple CTE.
+                    let (val, scope) = plan_nested_query(qcx, &cte.query)?;
+                    let typ = qcx.relation_type(&val);
+                    let mut desc = RelationDesc::new(typ, scope.column_names());
+                    plan_utils::maybe_rename_columns(
+                        format!("CTE {}", cte.alias.name),
+                        &mut desc,
+                        &cte.alias.columns,
+                    )?;
+                    let shadowed = qcx.ctes.insert(
+                        cte.id,
+                        CteDesc {
+                            name: cte_name,
+                            desc,
+                        },
+                    );
+                    result.push((cte.id, val, shadowed));
+                    continue;
+                }
+
+                // A recursive binding must be `non-recursive-term UNION [ALL]
+                // recursive-term`, as in PostgreSQL.
+                let (non_recursive_term, recursive_term) = match &cte.query {
+                    Query {
+                        ctes,
+                        body:
+                            SetExpr::SetOperation {
+                                op: SetOperator::Union,
+                                all: _,
+                                left,
+                                right,
+                            },
+                        order_by,
+                        limit: None,
+                        offset: None,
+                    } if ctes.is_empty() && order_by.is_empty() => (left, right),
+                    _ => sql_bail!(
+                        "recursive query {} does not have the form non-recursive-term UNION [ALL] recursive-term",
+                        cte_name.quoted()
+                    ),
+                };
+                if count_cte_references(&**non_recursive_term, cte.id) > 0 {
+                    sql_bail!(
+                        "recursive reference to query {} must not appear within its non-recursive term",
+                        cte_name.quoted()
+                    );
+                }
+                if count_cte_references(&**recursive_term, cte.id) > 1 {
+                    sql_bail!(
+                        "recursive reference to query {} must not appear more than once",
+                        cte_name.quoted()
+                    );
+                }
+                check_recursive_term(qcx.scx, recursive_term, cte.id, &cte_name)?;
+
+                // The column types of the binding are those of the
+                // non-recursive term. They are nullable, because the recursive
+                // term may introduce nulls.
+                let (non_recursive_val, scope) = plan_set_expr(qcx, non_recursive_term)?;
+                let column_types = qcx
+                    .relation_type(&non_recursive_val)
+                    .column_types
+                    .into_iter()
+                    .map(|typ| typ.nullable(true))
+                    .collect();
+                let mut desc =
+                    RelationDesc::new(SqlRelationType::new(column_types), scope.column_names());
+                plan_utils::maybe_rename_columns(
+                    format!("CTE {}", cte.alias.name),
+                    &mut desc,
+                    &cte.alias.columns,
+                )?;
+                let shadowed = qcx.ctes.insert(
+                    cte.id,
+                    CteDesc {
+                        name: cte_name.clone(),
+                        desc,
+                    },
+                );
+
+                // Plan the whole binding, now that its type is known, and
+                // validate that the recursive term preserves that type.
+                let (val, _scope) = plan_nested_query(qcx, &cte.query)?;
+                let typ = qcx.ctes[&cte.id].desc.typ();
+                let derived_typ = qcx.relation_type(&val);
+                let type_err = || {
+                    let humanize = |typ: &SqlRelationType| {
+                        typ.column_types
+                            .iter()
+                            .map(|ty| qcx.humanize_sql_scalar_type(&ty.scalar_type, false))
+                            .join(", ")
+                    };
+                    sql_err!(
+                        "recursive query {} has types ({}) in its non-recursive term, but types ({}) overall",
+                        cte_name.quoted(),
+                        humanize(typ),
+                        humanize(&derived_typ),
+                    )
+                };
+                if derived_typ.column_types.len() != typ.column_types.len() {
+                    return Err(type_err());
+                }
+                let val = cast_relation(
+                    qcx,
+                    CastContext::Implicit,
+                    val,
+                    typ.column_types.iter().map(|c| &c.scalar_type),
+                )
+                .map_err(|_| type_err())?;
+
+                result.push((cte.id, val, shadowed));
+            }
+        }
         CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => {
             // Insert column types into `qcx.ctes` first for recursive bindings.
             for cte in ctes.iter() {
@@ -1793,6 +2019,230 @@ pub fn plan_ctes(
     Ok(result)
 }
 
+/// Counts the references to the CTE with `id` in `node`.
+fn count_cte_references<'ast, N>(node: &'ast N, id: LocalId) -> usize
+where
+    N: visit::VisitNode<'ast, Aug> + 'ast,
+{
+    struct CteReferenceCounter {
+        id: LocalId,
+        count: usize,
+    }
+
+    impl<'ast> Visit<'ast, Aug> for CteReferenceCounter {
+        fn visit_item_name(&mut self, item_name: &'ast ResolvedItemName) {
+            if let ResolvedItemName::Cte { id, .. } = item_name {
+                if *id == self.id {
+                    self.count += 1;
+                }
+            }
+        }
+    }
+
+    let mut counter = CteReferenceCounter { id, count: 0 };
+    node.visit(&mut counter);
+    counter.count
+}
+
+/// Checks that the recursive term of the `WITH RECURSIVE` binding `name` obeys
+/// the restrictions that PostgreSQL places on it. The recursive reference must
+/// not appear within a subquery, on the nullable side of an outer join, or
+/// under `INTERSECT` or `EXCEPT`, and the `SELECT` that contains it must not
+/// deduplicate, group, aggregate, or call window functions. Each iteration only
+/// sees the rows derived in the previous one, so none of these would mean what
+/// they say.
+fn check_recursive_term(
+    scx: &StatementContext,
+    term: &SetExpr<Aug>,
+    id: LocalId,
+    name: &str,
+) -> Result<(), PlanError> {
+    if count_cte_references(term, id) == 0 {
+        return Ok(());
+    }
+    match term {
+        SetExpr::SetOperation {
+            op,
+            all: _,
+            left,
+            right,
+        } => {
+            match op {
+                SetOperator::Union => {}
+                SetOperator::Intersect => sql_bail!(
+                    "recursive reference to query {} must not appear within INTERSECT",
+                    name.quoted()
+                ),
+                SetOperator::Except => sql_bail!(
+                    "recursive reference to query {} must not appear within EXCEPT",
+                    name.quoted()
+                ),
+            }
+            check_recursive_term(scx, left, id, name)?;
+            check_recursive_term(scx, right, id, name)
+        }
+        SetExpr::Query(query) => {
+            if !query.order_by.is_empty() {
+                sql_bail!("ORDER BY in a recursive query is not implemented");
+            }
+            if query.limit.is_some() {
+                sql_bail!("LIMIT in a recursive query is not implemented");
+            }
+            if query.offset.is_some() {
+                sql_bail!("OFFSET in a recursive query is not implemented");
+            }
+            if !query.ctes.is_empty() {
+                sql_bail!(
+                    "recursive reference to query {} must not appear within a subquery",
+                    name.quoted()
+                );
+            }
+            check_recursive_term(scx, &query.body, id, name)
+        }
+        SetExpr::Select(select) => check_recursive_select(scx, select, id, name),
+        SetExpr::Table(_) => Ok(()),
+        SetExpr::Values(_) | SetExpr::Show(_) => sql_bail!(
+            "recursive reference to query {} must not appear within a subquery",
+            name.quoted()
+        ),
+    }
+}
+
+/// Checks a `SELECT` in the recursive term that refers to the binding `name`.
+/// See [`check_recursive_term`].
+fn check_recursive_select(
+    scx: &StatementContext,
+    select: &Select<Aug>,
+    id: LocalId,
+    name: &str,
+) -> Result<(), PlanError> {
+    // Every reference that isn't a table in the `FROM` clause of this
+    // `SELECT` is nested in a subquery.
+    let direct: usize = select
+        .from
+        .iter()
+        .map(|table| direct_cte_references(table, id))
+        .sum();
+    if direct < count_cte_references(select, id) {
+        sql_bail!(
+            "recursive reference to query {} must not appear within a subquery",
+            name.quoted()
+        );
+    }
+    for table in &select.from {
+        check_recursive_join(table, id, name)?;
+    }
+
+    if select.distinct.is_some() {
+        sql_bail!("DISTINCT is not allowed in a recursive query's recursive term");
+    }
+    if !select.group_by.is_empty() {
+        sql_bail!("GROUP BY is not allowed in a recursive query's recursive term");
+    }
+
+    struct FuncFinder<'a> {
+        scx: &'a StatementContext<'a>,
+        aggregates: bool,
+        window_functions: bool,
+    }
+
+    impl<'ast> Visit<'ast, Aug> for FuncFinder<'_> {
+        fn visit_function(&mut self, func: &'ast Function<Aug>) {
+            if func.over.is_some() {
+                self.window_functions = true;
+            } else if let Ok(item) = self.scx.get_item_by_resolved_name(&func.name)
+                && matches!(item.func(), Ok(Func::Aggregate { .. }))
+            {
+                self.aggregates = true;
+            }
+            visit::visit_function(self, func);
+        }
+
+        fn visit_query(&mut self, _query: &'ast Query<Aug>) {
+            // Subqueries that don't refer to the binding may use these freely.
+        }
+    }
+
+    let mut finder = FuncFinder {
+        scx,
+        aggregates: false,
+        window_functions: false,
+    };
+    for item in &select.projection {
+        finder.visit_select_item(item);
+    }
+    for expr in [&select.selection, &select.having, &select.qualify]
+        .into_iter()
+        .flatten()
+    {
+        finder.visit_expr(expr);
+    }
+    if finder.aggregates {
+        sql_bail!("aggregate functions are not allowed in a recursive query's recursive term");
+    }
+    if finder.window_functions {
+        sql_bail!("window functions are not allowed in a recursive query's recursive term");
+    }
+    Ok(())
+}
+
+/// Counts the references to the CTE with `id` that appear as tables in
+/// `table`, outside of any subquery.
+fn direct_cte_references(table: &TableWithJoins<Aug>, id: LocalId) -> usize {
+    fn factor(relation: &TableFactor<Aug>, id: LocalId) -> usize {
+        match relation {
+            TableFactor::Table {
+                name: ResolvedItemName::Cte { id: cte_id, .. },
+                alias: _,
+            } if *cte_id == id => 1,
+            TableFactor::NestedJoin { join, alias: _ } => direct_cte_references(join, id),
+            _ => 0,
+        }
+    }
+    factor(&table.relation, id)
+        + table
+            .joins
+            .iter()
+            .map(|join| factor(&join.relation, id))
+            .sum::<usize>()
+}
+
+/// Checks that the binding `name` isn't referenced on the nullable side of an
+/// outer join in `table`.
+fn check_recursive_join(
+    table: &TableWithJoins<Aug>,
+    id: LocalId,
+    name: &str,
+) -> Result<(), PlanError> {
+    fn check_factor(relation: &TableFactor<Aug>, id: LocalId, name: &str) -> Result<(), PlanError> {
+        match relation {
+            TableFactor::NestedJoin { join, alias: _ } => check_recursive_join(join, id, name),
+            _ => Ok(()),
+        }
+    }
+
+    check_factor(&table.relation, id, name)?;
+    let mut left = count_cte_references(&table.relation, id);
+    for join in &table.joins {
+        check_factor(&join.relation, id, name)?;
+        let right = count_cte_references(&join.relation, id);
+        let nullable = match join.join_operator {
+            JoinOperator::LeftOuter(_) => right,
+            JoinOperator::RightOuter(_) => left,
+            JoinOperator::FullOuter(_) => left + right,
+            JoinOperator::Inner(_) | JoinOperator::CrossJoin => 0,
+        };
+        if nullable > 0 {
+            sql_bail!(
+                "recursive reference to query {} must not appear within an outer join",
+                name.quoted()
+            );
+        }
+        left += right;
+    }
+    Ok(())
+}
+
 pub fn plan_nested_query(
     qcx: &mut QueryContext,
     q: &Query<Aug>,
@@ -2346,6 +2796,14 @@ fn plan_select_from_where(
         }
         visitor.into_result()?
     };
+    let grouping_funcs = {
+        let mut visitor = GroupingFuncCollector::default();
+        visitor.visit_select(&s);
+        for o in order_by_exprs.iter() {
+            visitor.visit_order_by_expr(o);
+        }
+        visitor.into_result()
+    };
     let mut table_func_names: BTreeMap<String, Ident> = BTreeMap::new();
     // Table functions in the SELECT list apply to the output of the reduce
     // (GROUP BY, aggregates, HAVING), but their columns must already be in
@@ -2413,9 +2871,12 @@ fn plan_select_from_where(
         let mut group_hir_exprs = vec![];
         let mut group_scope = Scope::empty();
         let mut select_all_mapping = BTreeMap::new();
+        // The position in the group key of each GROUP BY expression, from
+        // which the grouping sets are assembled.
+        let mut group_positions = BTreeMap::new();
 
-        for group_expr in &s.group_by {
-            let (group_expr, expr) = plan_group_by_expr(ecx, group_expr, &projection)?;
+        for ast_expr in s.group_by.iter().flat_map(group_by_item_exprs) {
+            let (group_expr, expr) = plan_group_by_expr(ecx, ast_expr, &projection)?;
             let new_column = group_key.len();
 
             // Multiple AST expressions can map to the same HIR expression, e.g.
@@ -2435,6 +2896,11 @@ fn plan_select_from_where(
                 if let Some(group_expr) = group_expr {
                     existing_scope_item.exprs.insert(group_expr.clone());
                 }
+                let position = group_hir_exprs
+                    .iter()
+                    .position(|e| *e == expr)
+                    .expect("group key contains deduplicated expression");
+                group_positions.insert(ast_expr, position);
                 continue;
             }
 
@@ -2462,11 +2928,47 @@ fn plan_select_from_where(
                 scope_item.exprs.insert(group_expr);
             }
 
+            group_positions.insert(ast_expr, group_hir_exprs.len());
             group_key.push(from_scope.len() + group_exprs.len());
             group_hir_exprs.push(expr.clone());
             group_exprs.insert(expr, scope_item);
         }
 
+        // A GROUP BY clause consisting only of plain expressions denotes a
+        // single grouping set; otherwise, expand the grouping sets it denotes.
+        let grouping_sets = if s
+            .group_by
+            .iter()
+            .all(|item| matches!(item, GroupByItem::Expr(_)))
+        {
+            None
+        } else {
+            Some(expand_grouping_sets(&s.group_by, &group_positions)?)
+        };
+
+        // Resolve the arguments of each GROUPING call to positions in the
+        // group key.
+        let mut grouping_args = vec![];
+        for grouping_func in &grouping_funcs {
+            let Expr::Grouping { exprs } = grouping_func else {
+                unreachable!("GroupingFuncCollector only collects GROUPING calls");
+            };
+            if exprs.len() > 31 {
+                sql_bail!("GROUPING must have fewer than 32 arguments");
+            }
+            let mut positions = vec![];
+            for arg in exprs {
+                let expr = plan_expr(ecx, arg)?.type_as_any(ecx)?;
+                match group_hir_exprs.iter().position(|e| *e == expr) {
+                    Some(position) => positions.push(position),
+                    None => sql_bail!(
+                        "arguments to GROUPING must be grouping expressions of the associated query level"
+                    ),
+                }
+            }
+            grouping_args.push(positions);
+        }
+
         assert_eq!(group_hir_exprs.len(), group_exprs.len());
         for expr in &group_hir_exprs {
             if let Some(scope_item) = group_exprs.remove(expr) {
@@ -2528,11 +3030,35 @@ fn plan_select_from_where(
             }
 
             // apply GROUP BY / aggregates
-            relation_expr = relation_expr.map(group_hir_exprs).reduce(
-                group_key,
-                agg_exprs,
-                group_size_hints.aggregate_input_group_size,
-            );
+            let input = relation_expr.map(group_hir_exprs);
+            relation_expr = match grouping_sets {
+                None => {
+                    // With a single grouping set, every GROUPING call is zero.
+                    let grouping_values = grouping_args
+                        .iter()
+                        .map(|_| HirScalarExpr::literal(Datum::Int32(0), SqlScalarType::Int32))
+                        .collect();
+                    input
+                        .reduce(
+                            group_key,
+                            agg_exprs,
+                            group_size_hints.aggregate_input_group_size,
+                        )
+                        .map(grouping_values)
+                }
+                Some(grouping_sets) => plan_grouping_sets(
+                    qcx,
+                    input,
+                    &group_key,
+                    agg_exprs,
+                    grouping_sets,
+                    &grouping_args,
+                    group_size_hints.aggregate_input_group_size,
+                ),
+            };
+            for grouping_func in grouping_funcs {
+                group_scope.items.push(ScopeItem::from_expr(grouping_func));
+            }
 
             // For every old column that wasn't a group key, add a scope item
             // that errors when referenced. We can't simply drop these items
@@ -3000,6 +3526,314 @@ fn plan_group_by_expr<'a>(
     }
 }
 
+/// Returns the expressions that appear in an item of a `GROUP BY` clause.
+fn group_by_item_exprs(item: &GroupByItem<Aug>) -> Vec<&Expr<Aug>> {
+    match item {
+        GroupByItem::Expr(expr) => vec![expr],
+        GroupByItem::Set(exprs) => exprs.iter().collect(),
+        GroupByItem::Rollup(items)
+        | GroupByItem::Cube(items)
+        | GroupByItem::GroupingSets(items) => items.iter().flat_map(group_by_item_exprs).collect(),
+    }
+}
+
+/// The maximum number of grouping sets that a `GROUP BY` clause may denote.
+const MAX_GROUPING_SETS: usize = 4096;
+
+/// Expands the items of a `GROUP BY` clause into the grouping sets that they
+/// denote, each given by the positions in the group key of its expressions.
+///
+/// As in PostgreSQL, a list of items denotes the concatenations of one
+/// grouping set from each item, and duplicate grouping sets are retained.
+fn expand_grouping_sets(
+    items: &[GroupByItem<Aug>],
+    positions: &BTreeMap<&Expr<Aug>, usize>,
+) -> Result<Vec<Vec<usize>>, PlanError> {
+    let mut sets = vec![vec![]];
+    for item in items {
+        let item_sets = expand_group_by_item(item, positions)?;
+        sets = sets
+            .iter()
+            .cartesian_product(&item_sets)
+            .map(|(set, item_set)| set.iter().chain(item_set).copied().collect())
+            .collect();
+        if sets.len() > MAX_GROUPING_SETS {
+            sql_bail!("too many grouping sets present (maximum {MAX_GROUPING_SETS})");
+        }
+    }
+    for set in &mut sets {
+        set.sort();
+        set.dedup();
+    }
+    Ok(sets)
+}
+
+/// Expands a single item of a `GROUP BY` clause into the grouping sets that it
+/// denotes. See [`expand_grouping_sets`].
+fn expand_group_by_item(
+    item: &GroupByItem<Aug>,
+    positions: &BTreeMap<&Expr<Aug>, usize>,
+) -> Result<Vec<Vec<usize>>, PlanError> {
+    let set = |item: &GroupByItem<Aug>| -> Vec<usize> {
+        group_by_item_exprs(item)
+            .into_iter()
+            .map(|expr| positions[expr])
+            .collect()
+    };
+    let sets = match item {
+        GroupByItem::Expr(_) | GroupByItem::Set(_) => vec![set(item)],
+        // `ROLLUP (a, b)` denotes `GROUPING SETS ((a, b), (a), ())`.
+        GroupByItem::Rollup(items) => {
+            let elements: Vec<_> = items.iter().map(set).collect();
+            (0..=elements.len())
+                .rev()
+                .map(|len| elements[..len].concat())
+                .collect()
+        }
+        // `CUBE (a, b)` denotes `GROUPING SETS ((a, b), (a), (b), ())`.
+        GroupByItem::Cube(items) => {
+            // Avoid enumerating the 2^n subsets when there are too many.
+            if items.len() > usize::cast_from(MAX_GROUPING_SETS.ilog2()) {
+                sql_bail!("too many grouping sets present (maximum {MAX_GROUPING_SETS})");
+            }
+            let elements: Vec<_> = items.iter().map(set).collect();
+            elements
+                .iter()
+                .powerset()
+                .map(|subset| subset.into_iter().flatten().copied().collect())
+                .collect()
+        }
+        GroupByItem::GroupingSets(items) => {
+            let mut sets = vec![];
+            for item in items {
+                sets.extend(expand_group_by_item(item, positions)?);
+            }
+            sets
+        }
+    };
+    if sets.len() > MAX_GROUPING_SETS {
+        sql_bail!("too many grouping sets present (maximum {MAX_GROUPING_SETS})");
+    }
+    Ok(sets)
+}
+
+/// Plans the reduction of `input` for each of `grouping_sets`, given by
+/// positions in `group_key`, as the union of one reduction per grouping set.
+///
+/// The input is bound once and shared by all of the reductions. If one of the
+/// grouping sets contains all of the others and every aggregate can be
+/// computed from partial aggregates (see [`rollup_aggregate`]), the coarser
+/// grouping sets reduce the output of the finest one rather than the input,
+/// so that the input is arranged only once. The output has the same shape as
+/// that of a single reduction by all of `group_key`, where the key columns
+/// that are absent from the grouping set that produced a row are null. The
+/// output is followed by the value of each `GROUPING` call, described by the
+/// positions of its arguments in `group_key`.
+fn plan_grouping_sets(
+    qcx: &QueryContext,
+    input: HirRelationExpr,
+    group_key: &[usize],
+    aggregates: Vec<AggregateExpr>,
+    grouping_sets: Vec<Vec<usize>>,
+    grouping_args: &[Vec<usize>],
+    expected_group_size: Option<u64>,
+) -> HirRelationExpr {
+    let typ = qcx.relation_type(&input);
+    let id = qcx.scx.allocate_local_id();
+    let get = HirRelationExpr::Get {
+        id: Id::Local(id),
+        typ: typ.clone(),
+    };
+
+    // The finest grouping set, if the others can be rolled up from it.
+    let finest = grouping_sets
+        .iter()
+        .find(|finest| {
+            grouping_sets
+                .iter()
+                .all(|set| set.iter().all(|i| finest.contains(i)))
+        })
+        .filter(|_| {
+            aggregates
+                .iter()
+                .all(|agg| !agg.distinct && rollup_aggregate(&agg.func).is_some())
+        })
+        .cloned();
+    let finest = finest.map(|finest| {
+        let reduced = get.clone().reduce(
+            finest.iter().map(|i| group_key[*i]).collect(),
+            aggregates.clone(),
+            expected_group_size,
+        );
+        let id = qcx.scx.allocate_local_id();
+        let get = HirRelationExpr::Get {
+            id: Id::Local(id),
+            typ: qcx.relation_type(&reduced),
+        };
+        (finest, id, reduced, get)
+    });
+
+    let branches = grouping_sets.into_iter().map(|set| {
+        // The reduction by `set`, whose output is the grouped columns in the
+        // order of `set` followed by the aggregates.
+        let reduced = match &finest {
+            Some((finest, _, _, finest_get)) if *finest == set => finest_get.clone(),
+            Some((finest, _, _, finest_get)) => {
+                let key = set
+                    .iter()
+                    .map(|i| finest.iter().position(|j| j == i).expect("subset"))
+                    .collect();
+                let (partials, finishers): (Vec<_>, Vec<_>) = aggregates
+                    .iter()
+                    .enumerate()
+                    .map(|(i, agg)| {
+                        let partial = AggregateExpr {
+                            func: rollup_aggregate(&agg.func).expect("checked above"),
+                            expr: Box::new(HirScalarExpr::column(finest.len() + i)),
+                            distinct: false,
+                        };
+                        let finished =
+                            finish_rollup(&agg.func, HirScalarExpr::column(set.len() + i));
+                        (partial, finished)
+                    })
+                    .unzip();
+                let arity = set.len() + aggregates.len();
+                finest_get
+                    .clone()
+                    .reduce(key, partials, expected_group_size)
+                    .map(finishers)
+                    .project(
+                        (0..set.len())
+                            .chain(arity..arity + aggregates.len())
+                            .collect(),
+                    )
+            }
+            None => get.clone().reduce(
+                set.iter().map(|i| group_key[*i]).collect(),
+                aggregates.clone(),
+                expected_group_size,
+            ),
+        };
+        let mut outputs = vec![];
+        for (i, column) in group_key.iter().enumerate() {
+            outputs.push(match set.iter().position(|j| *j == i) {
+                Some(position) => HirScalarExpr::column(position),
+                None => HirScalarExpr::literal_null(typ.column_types[*column].scalar_type.clone()),
+            });
+        }
+        for i in 0..aggregates.len() {
+            outputs.push(HirScalarExpr::column(set.len() + i));
+        }
+        for args in grouping_args {
+            // As in PostgreSQL, the most significant bit corresponds to the
+            // first argument, and is set if that argument is not grouped.
+            let value = args
+                .iter()
+                .fold(0, |acc, arg| (acc << 1) | i32::from(!set.contains(arg)));
+            outputs.push(HirScalarExpr::literal(
+                Datum::Int32(value),
+                SqlScalarType::Int32,
+            ));
+        }
+        let arity = set.len() + aggregates.len();
+        let outputs_len = outputs.len();
+        reduced
+            .map(outputs)
+            .project((arity..arity + outputs_len).collect())
+    });
+    let mut body = branches
+        .reduce(|left, right| left.union(right))
+        .expect("at least one grouping set");
+
+    if let Some((_, finest_id, finest_reduced, _)) = finest {
+        body = HirRelationExpr::Let {
+            name: "grouping_sets_finest".into(),
+            id: finest_id,
+            value: Box::new(finest_reduced),
+            body: Box::new(body),
+        };
+    }
+    HirRelationExpr::Let {
+        name: "grouping_sets".into(),
+        id,
+        value: Box::new(input),
+        body: Box::new(body),
+    }
+}
+
+/// Returns the aggregate that combines the partial results of `func` over
+/// finer groups into its result over a coarser group, if there is one.
+///
+/// See [`finish_rollup`] for converting the combined value back to the type of
+/// `func`.
+fn rollup_aggregate(func: &AggregateFunc) -> Option<AggregateFunc> {
+    Some(match func {
+        AggregateFunc::Count | AggregateFunc::SumInt16 | AggregateFunc::SumInt32 => {
+            AggregateFunc::SumInt64
+        }
+        AggregateFunc::SumInt64 | AggregateFunc::SumNumeric => AggregateFunc::SumNumeric,
+        AggregateFunc::MaxNumeric
+        | AggregateFunc::MaxInt16
+        | AggregateFunc::MaxInt32
+        | AggregateFunc::MaxInt64
+        | AggregateFunc::MaxUInt16
+        | AggregateFunc::MaxUInt32
+        | AggregateFunc::MaxUInt64
+        | AggregateFunc::MaxMzTimestamp
+        | AggregateFunc::MaxFloat32
+        | AggregateFunc::MaxFloat64
+        | AggregateFunc::MaxBool
+        | AggregateFunc::MaxString
+        | AggregateFunc::MaxDate
+        | AggregateFunc::MaxTimestamp
+        | AggregateFunc::MaxTimestampTz
+        | AggregateFunc::MaxInterval
+        | AggregateFunc::MaxTime
+        | AggregateFunc::MinNumeric
+        | AggregateFunc::MinInt16
+        | AggregateFunc::MinInt32
+        | AggregateFunc::MinInt64
+        | AggregateFunc::MinUInt16
+        | AggregateFunc::MinUInt32
+        | AggregateFunc::MinUInt64
+        | AggregateFunc::MinMzTimestamp
+        | AggregateFunc::MinFloat32
+        | AggregateFunc::MinFloat64
+        | AggregateFunc::MinBool
+        | AggregateFunc::MinString
+        | AggregateFunc::MinDate
+        | AggregateFunc::MinTimestamp
+        | AggregateFunc::MinTimestampTz
+        | AggregateFunc::MinInterval
+        | AggregateFunc::MinTime
+        | AggregateFunc::Any
+        | AggregateFunc::All => func.clone(),
+        _ => return None,
+    })
+}
+
+/// Converts `expr`, the combined partial results of `func` computed by the
+/// aggregate returned by [`rollup_aggregate`], back to the type of `func`.
+///
+/// The combined value is that of the aggregate over no rows if there are no
+/// partial results, so counts must be coalesced to zero for the empty grouping
+/// set of an empty input.
+fn finish_rollup(func: &AggregateFunc, expr: HirScalarExpr) -> HirScalarExpr {
+    match func {
+        AggregateFunc::Count => HirScalarExpr::call_variadic(
+            Coalesce,
+            vec![
+                expr.call_unary(UnaryFunc::CastNumericToInt64(expr_func::CastNumericToInt64)),
+                HirScalarExpr::literal(Datum::Int64(0), SqlScalarType::Int64),
+            ],
+        ),
+        AggregateFunc::SumInt16 | AggregateFunc::SumInt32 => {
+            expr.call_unary(UnaryFunc::CastNumericToInt64(expr_func::CastNumericToInt64))
+        }
+        _ => expr,
+    }
+}
+
 /// Plans a slice of `ORDER BY` expressions.
 ///
 /// See `plan_order_by_or_distinct_expr` for details on the `output_columns`
@@ -3389,14 +4223,15 @@ fn plan_table_function_internal(
         filter,
         over,
         distinct,
+        within_group,
     }: &Function<Aug>,
     with_ordinality: bool,
     table_name: Option<FullItemName>,
 ) -> Result<(HirRelationExpr, Scope), PlanError> {
-    // The parser rejects FILTER, OVER, and DISTINCT in every table function
-    // position (`FROM f(...)`, `ROWS FROM (...)`), and table functions in
-    // scalar position are only lifted into a `FROM` clause when all three are
-    // absent, so these are defensive.
+    // The parser rejects FILTER, OVER, DISTINCT, and WITHIN GROUP in every
+    // table function position (`FROM f(...)`, `ROWS FROM (...)`), and table
+    // functions in scalar position are only lifted into a `FROM` clause when
+    // all of them are absent, so these are defensive.
     if filter.is_some() {
         sql_bail!("FILTER is not allowed for table functions in FROM");
     }
@@ -3406,6 +4241,9 @@ fn plan_table_function_internal(
     if *distinct {
         sql_bail!("DISTINCT is not allowed for table functions in FROM");
     }
+    if *within_group {
+        sql_bail!("WITHIN GROUP is not allowed for table functions in FROM");
+    }
 
     let ecx = &ExprContext {
         qcx,
@@ -3684,6 +4522,7 @@ fn invent_column_name(
                 NameQuality::High,
             )),
             Expr::NullIf { .. } => Some(("nullif".into(), NameQuality::High)),
+            Expr::Grouping { .. } => Some(("grouping".into(), NameQuality::High)),
             Expr::Array { .. } => Some(("array".into(), NameQuality::High)),
             Expr::List { .. } => Some(("list".into(), NameQuality::High)),
             Expr::Map { .. } | Expr::MapSubquery(_) => Some(("map".into(), NameQuality::High)),
@@ -4215,6 +5054,17 @@ fn plan_expr_inner<'a>(
             &Some(Box::new(*l_expr.clone())),
         )?
         .into()),
+        // `GROUPING` calls are planned alongside the `GROUP BY` clause, so one
+        // that was not found in scope above is in an unsupported context.
+        Expr::Grouping { .. } => {
+            if ecx.allow_aggregates {
+                sql_bail!(
+                    "arguments to GROUPING must be grouping expressions of the associated query level"
+                );
+            } else {
+                sql_bail!("GROUPING is not allowed in {}", ecx.name);
+            }
+        }
         Expr::FieldAccess { expr, field } => plan_field_access(ecx, expr, field),
         Expr::WildcardAccess(expr) => plan_expr(ecx, expr),
         Expr::Subscript { expr, positions } => plan_subscript(ecx, expr, positions),
@@ -5306,6 +6156,22 @@ fn humanize_or_debug(scx: &StatementContext, name: &ResolvedItemName) -> String
         .unwrap_or_else(|_| format!("<error when trying to humanize `{name:?}`>"))
 }
 
+/// Returns the number of direct arguments of the function `name` if it is one
+/// of the built-in ordered-set aggregates.
+fn ordered_set_aggregate_direct_args(name: &ResolvedItemName) -> Option<usize> {
+    match name {
+        ResolvedItemName::Item { full_name, .. }
+            if full_name.schema == mz_repr::namespaces::PG_CATALOG_SCHEMA =>
+        {
+            func::ORDERED_SET_AGGREGATES
+                .iter()
+                .find(|(agg, _)| *agg == full_name.item)
+                .map(|(_, direct_args)| *direct_args)
+        }
+        _ => None,
+    }
+}
+
 /// Common part of the planning of windowed and non-windowed aggregation functions.
 fn plan_aggregate_common(
     ecx: &ExprContext,
@@ -5315,6 +6181,7 @@ fn plan_aggregate_common(
         filter,
         over: _,
         distinct,
+        within_group,
     }: &Function<Aug>,
 ) -> Result<AggregateExpr, PlanError> {
     // Normal aggregate functions, like `sum`, expect as input a single expression
@@ -5336,6 +6203,21 @@ fn plan_aggregate_common(
         _ => bail_internal!("plan_aggregate_common called on non-aggregate function"),
     };
 
+    // Ordered-set aggregates, like `percentile_cont`, take their aggregated
+    // arguments from a `WITHIN GROUP (ORDER BY ...)` clause, which other
+    // aggregates don't accept.
+    match (ordered_set_aggregate_direct_args(name), *within_group) {
+        (Some(_), false) => sql_bail!(
+            "WITHIN GROUP is required for ordered-set aggregate {}",
+            humanize_or_debug(ecx.qcx.scx, name)
+        ),
+        (None, true) => sql_bail!(
+            "{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+            humanize_or_debug(ecx.qcx.scx, name)
+        ),
+        _ => (),
+    }
+
     // We follow PostgreSQL's rule here for mapping `count(*)` into the
     // generalized function selection framework. The rule is simple: the user
     // must type `count(*)`, but the function selection framework sees an empty
@@ -5344,8 +6226,42 @@ fn plan_aggregate_common(
     // rules to all aggregates, not just `count`, since we may one day support
     // user-defined aggregates, including user-defined aggregates that take no
     // parameters.
-    let (args, order_by) = match &args {
-        FunctionArgs::Star => (vec![], vec![]),
+    let (args, order_by_exprs, col_orders) = match &args {
+        FunctionArgs::Star => (vec![], vec![], vec![]),
+        // The direct arguments of an ordered-set aggregate are followed by its
+        // aggregated arguments, which are the expressions of the `WITHIN GROUP`
+        // ordering. The ordering itself is passed on via `col_orders`.
+        FunctionArgs::Args { args, order_by } if *within_group => {
+            let mut args = plan_exprs(ecx, args)?;
+            for arg in &args {
+                let CoercibleScalarExpr::Coerced(arg) = arg else {
+                    continue;
+                };
+                // The direct arguments are evaluated once per group, so they
+                // may not vary across the rows of the group.
+                let mut seen_inner = false;
+                #[allow(deprecated)]
+                arg.visit_columns(0, &mut |depth, col| {
+                    if col.level == depth {
+                        seen_inner = true;
+                    }
+                });
+                if seen_inner {
+                    bail_unsupported!(
+                        "direct arguments of ordered-set aggregates that refer to columns"
+                    );
+                }
+            }
+            for obe in order_by {
+                args.push(plan_expr(ecx, &obe.expr)?);
+            }
+            let col_orders = order_by
+                .iter()
+                .enumerate()
+                .map(|(i, obe)| resolve_desc_and_nulls_last(obe, i))
+                .collect();
+            (args, vec![], col_orders)
+        }
         FunctionArgs::Args { args, order_by } => {
             if args.is_empty() {
                 sql_bail!(
@@ -5354,12 +6270,11 @@ fn plan_aggregate_common(
                 );
             }
             let args = plan_exprs(ecx, args)?;
-            (args, order_by.clone())
+            let (order_by_exprs, col_orders) = plan_function_order_by(ecx, order_by)?;
+            (args, order_by_exprs, col_orders)
         }
     };
 
-    let (order_by_exprs, col_orders) = plan_function_order_by(ecx, &order_by)?;
-
     let (mut expr, func) = func::select_impl(ecx, FuncSpec::Func(name), impls, args, col_orders)?;
     if let Some(filter) = &filter {
         // If a filter is present, as in
@@ -5538,6 +6453,7 @@ fn plan_function<'a>(
         filter,
         over,
         distinct,
+        within_group,
     }: &'a Function<Aug>,
 ) -> Result<HirScalarExpr, PlanError> {
     let impls = match resolve_func(ecx, name, args)? {
@@ -5682,6 +6598,10 @@ fn plan_function<'a>(
                     bail_unsupported!(IGNORE_NULLS_ERROR_MSG);
                 }
 
+                if *within_group {
+                    bail_unsupported!("OVER for ordered-set aggregates");
+                }
+
                 let aggregate_expr = plan_aggregate_common(ecx, f)?;
 
                 if aggregate_expr.distinct {
@@ -5712,6 +6632,12 @@ fn plan_function<'a>(
             humanize_or_debug(ecx.qcx.scx, name)
         );
     }
+    if *within_group {
+        sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            humanize_or_debug(ecx.qcx.scx, name)
+        );
+    }
     if filter.is_some() {
         sql_bail!(
             "FILTER specified, but {} is not an aggregate function",
@@ -5933,6 +6859,7 @@ fn plan_window_function_non_aggr<'a>(
         filter,
         over,
         distinct,
+        within_group,
     }: &'a Function<Aug>,
 ) -> Result<
     (
@@ -5955,6 +6882,13 @@ fn plan_window_function_non_aggr<'a>(
         );
     }
 
+    if *within_group {
+        sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            name
+        );
+    }
+
     if filter.is_some() {
         bail_unsupported!("FILTER in non-aggregate window functions");
     }
@@ -6583,6 +7517,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                     filter,
                     over: _,
                     distinct: _,
+                    within_group: _,
                 } = func;
                 if let Some(filter) = filter {
                     self.visit_expr_mut(filter);
@@ -6650,6 +7585,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 filter: None,
                 over: None,
                 distinct: false,
+                within_group: false,
             } = &func
             {
                 // Identical table functions can be de-duplicated.
@@ -6721,6 +7657,37 @@ impl Visit<'_, Aug> for WindowFuncCollector {
     }
 }
 
+#[derive(Default)]
+struct GroupingFuncCollector {
+    grouping_funcs: Vec<Expr<Aug>>,
+}
+
+impl GroupingFuncCollector {
+    fn into_result(self) -> Vec<Expr<Aug>> {
+        // Dedup while preserving the order.
+        let mut seen = BTreeSet::new();
+        self.grouping_funcs
+            .into_iter()
+            .filter(move |expr| seen.insert(expr.clone()))
+            .collect()
+    }
+}
+
+impl Visit<'_, Aug> for GroupingFuncCollector {
+    fn visit_expr(&mut self, expr: &Expr<Aug>) {
+        match expr {
+            // The arguments of a GROUPING call are grouping expressions, so
+            // there is no need to look inside them.
+            Expr::Grouping { .. } => self.grouping_funcs.push(expr.clone()),
+            _ => visit::visit_expr(self, expr),
+        }
+    }
+
+    fn visit_query(&mut self, _query: &Query<Aug>) {
+        // Don't go into subqueries. Those will be handled by their own `plan_query`.
+    }
+}
+
 /// Specifies how long a query will live.
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum QueryLifetime {
@@ -169,6 +169,7 @@ fn extract_sef_call(
                     filter: None,
                     over: None,
                     distinct: false,
+                    within_group: false,
                 }),
             alias: None,
         },
@@ -15,6 +15,7 @@ use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet};
 use std::sync::{Arc, Mutex};
 
+use mz_expr::LocalId;
 use mz_repr::namespaces::is_system_schema;
 use mz_repr::{
     CatalogItemId, ColumnIndex, RelationDesc, RelationVersionSelector, SqlColumnType, SqlScalarType,
@@ -51,7 +52,8 @@ mod tcl;
 mod validate;
 
 use crate::session::vars;
-pub(crate) use ddl::PgConfigOptionExtracted;
+pub(crate) use ddl::{PgConfigOptionExtracted, PostgresSinkConfigOptionExtracted};
+pub use dml::explain_analyze_dataflow_query;
 use mz_controller_types::ClusterId;
 use mz_pgrepr::oid::{FIRST_MATERIALIZE_OID, FIRST_USER_OID};
 use mz_repr::role_id::RoleId;
@@ -119,6 +121,7 @@ pub fn describe(
         param_types: RefCell::new(param_types),
         ambiguous_columns: RefCell::new(false),
         sql_impl_resolved_ids: Arc::new(Mutex::new(ResolvedIds::empty())),
+        next_local_id: Arc::new(Mutex::new(names::cte_id_bound(&stmt))),
     };
 
     let desc = match stmt {
@@ -312,6 +315,7 @@ pub fn plan(
         param_types: RefCell::new(param_types),
         ambiguous_columns: RefCell::new(false),
         sql_impl_resolved_ids: Arc::new(Mutex::new(ResolvedIds::empty())),
+        next_local_id: Arc::new(Mutex::new(names::cte_id_bound(&stmt))),
     };
 
     if resolved_ids
@@ -547,6 +551,11 @@ pub struct StatementContext<'a> {
     /// share the same underlying storage. `Arc` (vs `Rc`) is needed because
     /// `StatementContext` must be `Send`.
     pub sql_impl_resolved_ids: Arc<Mutex<ResolvedIds>>,
+    /// The next `LocalId` to hand out for a binding that the planner
+    /// introduces itself. Starts past the ids of the statement's CTEs, which
+    /// name resolution allocates from zero, and is shared by cloned
+    /// `StatementContext`s, so that every such binding gets a unique id.
+    next_local_id: Arc<Mutex<u64>>,
 }
 
 impl<'a> StatementContext<'a> {
@@ -560,9 +569,22 @@ impl<'a> StatementContext<'a> {
             param_types: Default::default(),
             ambiguous_columns: RefCell::new(false),
             sql_impl_resolved_ids: Arc::new(Mutex::new(ResolvedIds::empty())),
+            next_local_id: Arc::new(Mutex::new(0)),
         }
     }
 
+    /// Allocates a `LocalId` that is distinct from the ids of the statement's
+    /// CTEs and from all ids previously allocated by this method.
+    pub fn allocate_local_id(&self) -> LocalId {
+        let mut next_local_id = self
+            .next_local_id
+            .lock()
+            .expect("planning is single-threaded");
+        let id = LocalId::new(*next_local_id);
+        *next_local_id += 1;
+        id
+    }
+
     /// Returns the schemas in order of search_path that exist in the catalog.
     pub fn current_schemas(&self) -> &[(ResolvedDatabaseSpecifier, SchemaSpecifier)] {
         self.catalog.search_path()
@@ -26,6 +26,8 @@ use mz_auth::password::Password;
 use mz_controller_types::{ClusterId, DEFAULT_REPLICA_LOGGING_INTERVAL, ReplicaId};
 use mz_expr::{CollectionPlan, UnmaterializableFunc};
 use mz_interchange::avro::{AvroSchemaGenerator, DocTarget};
+use mz_interchange::json::build_json_schema;
+use mz_interchange::protobuf::ProtobufSchemaGenerator;
 use mz_ore::cast::{CastFrom, TryCastFrom};
 use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::num::NonNeg;
@@ -71,27 +73,31 @@ use mz_sql_parser::ast::{
     CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
     CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DeferredItemName,
     DocOnIdentifier, DocOnSchema, DropObjectsStatement, DropOwnedStatement, Expr, Format,
-    FormatSpecifier, GlueAvroOption, GlueAvroOptionName, IcebergSinkConfigOption, Ident,
+    FormatSpecifier, GlueAvroOption, GlueAvroOptionName, HttpSinkConfigOption,
+    HttpSinkConfigOptionName, IcebergSinkConfigOption, IcebergSourceConfigOption, Ident,
     IfExistsBehavior, IndexOption, IndexOptionName, KafkaSinkConfigOption, KeyConstraint,
     LoadGeneratorOption, LoadGeneratorOptionName, MaterializedViewOption,
     MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName, NetworkPolicyOption,
     NetworkPolicyOptionName, NetworkPolicyRuleDefinition, NetworkPolicyRuleOption,
     NetworkPolicyRuleOptionName, OnHydrationOptionValue, PgConfigOption, PgConfigOptionName,
-    ProtobufSchema, QualifiedReplica, RefreshAtOptionValue, RefreshEveryOptionValue,
-    RefreshOptionValue, ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute,
-    SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata, SqlServerConfigOption,
-    SqlServerConfigOptionName, Statement, TableConstraint, TableFromSourceColumns,
-    TableFromSourceOption, TableFromSourceOptionName, TableOption, TableOptionName,
-    UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value,
-    ViewDefinition, WithOptionValue,
+    PostgresSinkConfigOption, PostgresSinkConfigOptionName, ProtobufSchema, QualifiedReplica,
+    RefreshAtOptionValue, RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition,
+    ReplicaOption, ReplicaOptionName, RoleAttribute, SetRoleVar, SourceErrorPolicy,
+    SourceIncludeMetadata, SqlServerConfigOption, SqlServerConfigOptionName, Statement,
+    TableConstraint, TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName,
+    TableOption, TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
+    UnresolvedSchemaName, Value, ViewDefinition, WithOptionValue,
 };
 use mz_sql_parser::ident;
 use mz_sql_parser::parser::StatementParseResult;
 use mz_storage_types::connections::inline::ReferencedConnection;
+use mz_storage_types::connections::string_or_secret::StringOrSecret;
 use mz_storage_types::connections::{Connection, KafkaTopicOptions};
 use mz_storage_types::sinks::{
-    IcebergSinkConnection, KafkaIdStyle, KafkaSinkConnection, KafkaSinkFormat, KafkaSinkFormatType,
-    SinkEnvelope, StorageSinkConnection, iceberg_type_overrides,
+    HttpSinkConnection, IcebergPartitionTransform, IcebergSinkConnection,
+    IcebergSinkPartitionField, IcebergSinkSortField, KafkaIdStyle, KafkaSinkConnection,
+    KafkaSinkFormat, KafkaSinkFormatType, PostgresSinkConnection, SinkEnvelope,
+    StorageSinkConnection, iceberg_type_overrides,
 };
 use mz_storage_types::sources::encoding::{
     AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, ProtobufEncoding, RegexEncoding,
@@ -100,6 +106,10 @@ use mz_storage_types::sources::encoding::{
 use mz_storage_types::sources::envelope::{
     KeyEnvelope, NoneEnvelope, SourceEnvelope, UnplannedSourceEnvelope, UpsertStyle,
 };
+use mz_storage_types::sources::iceberg::{
+    IcebergSourceConnection, IcebergSourceDetails, IcebergSourceExportDetails,
+    ProtoIcebergSourceDetails,
+};
 use mz_storage_types::sources::kafka::{
     KafkaMetadataKind, KafkaSourceConnection, KafkaSourceExportDetails, kafka_metadata_columns_desc,
 };
@@ -131,7 +141,7 @@ use crate::catalog::{
     CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
     CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, SystemObjectType,
 };
-use crate::iceberg::IcebergSinkConfigOptionExtracted;
+use crate::iceberg::{IcebergSinkConfigOptionExtracted, IcebergSourceConfigOptionExtracted};
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
     Aug, CommentObjectId, DatabaseId, DependencyIds, ObjectId, PartialItemName, QualifiedItemName,
@@ -165,14 +175,16 @@ use crate::plan::{
     DropOwnedPlan, HirRelationExpr, Index, MaterializedView, MetricSink, NetworkPolicyRule,
     NetworkPolicyRuleAction, NetworkPolicyRuleDirection, OnHydration, Plan, PlanClusterOption,
     PlanNotice, PolicyAddress, QueryContext, ReplicaConfig, Secret, Sink, Source, Table,
-    TableDataSource, Type, VariableValue, View, WebhookBodyFormat, WebhookHeaderFilters,
-    WebhookHeaders, WebhookValidation, literal, plan_utils, query, transform_ast,
+    TableDataSource, Type, VariableValue, View, WebhookBodyDecoding, WebhookBodyFormat,
+    WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal, plan_utils, query,
+    transform_ast,
 };
 use crate::session::vars::{
     self, ENABLE_AUTO_SCALING_STRATEGY, ENABLE_CLUSTER_SCHEDULE_REFRESH,
-    ENABLE_COLLECTION_PARTITION_BY, ENABLE_CREATE_TABLE_FROM_SOURCE, ENABLE_KAFKA_SINK_HEADERS,
-    ENABLE_METRIC_SINK, ENABLE_REFRESH_EVERY_MVS, ENABLE_REPLICA_TARGETED_MATERIALIZED_VIEWS,
-    VarInput,
+    ENABLE_COLLECTION_PARTITION_BY, ENABLE_CREATE_TABLE_FROM_SOURCE, ENABLE_HTTP_SINK,
+    ENABLE_ICEBERG_SOURCE, ENABLE_KAFKA_SINK_HEADERS, ENABLE_METRIC_SINK, ENABLE_POSTGRES_SINK,
+    ENABLE_POSTGRES_SOURCE_RETRACT_ON_TRUNCATE, ENABLE_REFRESH_EVERY_MVS,
+    ENABLE_REPLICA_TARGETED_MATERIALIZED_VIEWS, ENABLE_WEBHOOK_BODY_DECODING, VarInput,
 };
 use crate::{names, parse};
 
@@ -580,6 +592,7 @@ pub fn plan_create_webhook_source(
         name,
         if_not_exists,
         body_format,
+        body_columns,
         include_headers,
         validate_using,
         is_table,
@@ -604,28 +617,57 @@ pub fn plan_create_webhook_source(
         }
     }
 
-    let body_format = match body_format {
-        Format::Bytes => WebhookBodyFormat::Bytes,
-        Format::Json { array } => WebhookBodyFormat::Json { array },
-        Format::Text => WebhookBodyFormat::Text,
-        // TODO(parkmycar): Make an issue to support more types, or change this to NeverSupported.
-        ty => {
-            return Err(PlanError::Unsupported {
-                feature: format!("{ty} is not a valid BODY FORMAT for a WEBHOOK source"),
-                discussion_no: None,
+    if !body_columns.is_empty() || matches!(body_format, Format::Avro(_) | Format::Protobuf(_)) {
+        scx.require_feature_flag(&ENABLE_WEBHOOK_BODY_DECODING)?;
+    }
+
+    let mut column_ty = vec![];
+    let mut column_names = vec![];
+
+    let (body_format, body_decoding) = match body_format {
+        Format::Json { array, .. } if !body_columns.is_empty() => {
+            let (columns, body_desc) = query::plan_webhook_body_columns(scx, body_columns)?;
+            for (name, typ) in body_desc {
+                column_names.push(name.to_string());
+                column_ty.push(typ);
+            }
+            let decoding = WebhookBodyDecoding::Json { columns };
+            (WebhookBodyFormat::Json { array }, Some(decoding))
+        }
+        format @ (Format::Avro(_) | Format::Protobuf(_)) => {
+            let encoding = get_encoding_inner(scx, &format)?;
+            let (_, body_desc) = encoding.desc()?;
+            for (name, typ) in body_desc.iter() {
+                column_names.push(name.to_string());
+                column_ty.push(typ.clone());
+            }
+            // The decoder is handed the raw bytes of each request.
+            let decoding = WebhookBodyDecoding::Encoding(encoding.value);
+            (WebhookBodyFormat::Bytes, Some(decoding))
+        }
+        format => {
+            let body_format = match format {
+                Format::Bytes => WebhookBodyFormat::Bytes,
+                Format::Json { array, .. } => WebhookBodyFormat::Json { array },
+                Format::Text => WebhookBodyFormat::Text,
+                // TODO(parkmycar): Make an issue to support more types, or change this to NeverSupported.
+                ty => {
+                    return Err(PlanError::Unsupported {
+                        feature: format!("{ty} is not a valid BODY FORMAT for a WEBHOOK source"),
+                        discussion_no: None,
+                    });
+                }
+            };
+            // Include the body of the request as a single column.
+            column_ty.push(SqlColumnType {
+                scalar_type: SqlScalarType::from(body_format),
+                nullable: false,
             });
+            column_names.push("body".to_string());
+            (body_format, None)
         }
     };
 
-    let mut column_ty = vec![
-        // Always include the body of the request as the first column.
-        SqlColumnType {
-            scalar_type: SqlScalarType::from(body_format),
-            nullable: false,
-        },
-    ];
-    let mut column_names = vec!["body".to_string()];
-
     let mut headers = WebhookHeaders::default();
 
     // Include a `headers` column, possibly filtered.
@@ -710,6 +752,7 @@ pub fn plan_create_webhook_source(
         let data_source = DataSourceDesc::Webhook {
             validate_using,
             body_format,
+            body_decoding,
             headers,
             cluster_id: Some(in_cluster.id()),
         };
@@ -732,6 +775,7 @@ pub fn plan_create_webhook_source(
         let data_source = DataSourceDesc::Webhook {
             validate_using,
             body_format,
+            body_decoding,
             headers,
             // Important: The cluster is set at the `Source` level.
             cluster_id: None,
@@ -942,6 +986,9 @@ pub fn plan_create_source(
                         })
                     }
                 },
+                GenericSourceConnection::Iceberg(_) => {
+                    SourceExportDetails::Iceberg(IcebergSourceExportDetails {})
+                }
                 GenericSourceConnection::Postgres(_)
                 | GenericSourceConnection::MySql(_)
                 | GenericSourceConnection::SqlServer(_) => SourceExportDetails::None,
@@ -1055,6 +1102,12 @@ pub fn plan_generic_source_connection(
         } => {
             GenericSourceConnection::MySql(plan_mysql_source_connection(scx, connection, options)?)
         }
+        CreateSourceConnection::Iceberg {
+            connection,
+            options,
+        } => GenericSourceConnection::Iceberg(plan_iceberg_source_connection(
+            scx, connection, options,
+        )?),
         CreateSourceConnection::LoadGenerator { generator, options } => {
             GenericSourceConnection::LoadGenerator(plan_load_generator_source_connection(
                 scx,
@@ -1158,6 +1211,43 @@ fn plan_sqlserver_source_connection(
     })
 }
 
+fn plan_iceberg_source_connection(
+    scx: &StatementContext<'_>,
+    connection: &ResolvedItemName,
+    options: &Vec<IcebergSourceConfigOption<Aug>>,
+) -> Result<IcebergSourceConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_ICEBERG_SOURCE)?;
+    let connection_item = scx.get_item_by_resolved_name(connection)?;
+    match connection_item.connection()? {
+        Connection::IcebergCatalog(connection) => connection,
+        _ => sql_bail!(
+            "{} is not an iceberg catalog connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+    let IcebergSourceConfigOptionExtracted {
+        details,
+        namespace,
+        table,
+        seen: _,
+    } = options.clone().try_into()?;
+    let details = details
+        .as_ref()
+        .ok_or_else(|| internal_err!("Iceberg source missing details"))?;
+    let details = hex::decode(details)
+        .map_err(|e| sql_err!("{e}"))
+        .and_then(|raw| ProtoIcebergSourceDetails::decode(&*raw).map_err(|e| sql_err!("{e}")))
+        .and_then(|proto| IcebergSourceDetails::from_proto(proto).map_err(|e| sql_err!("{e}")))?;
+    Ok(IcebergSourceConnection {
+        connection_id: connection_item.id(),
+        connection: connection_item.id(),
+        // Validated during purification.
+        namespace: namespace.ok_or_else(|| internal_err!("NAMESPACE option is required"))?,
+        table: table.ok_or_else(|| internal_err!("TABLE option is required"))?,
+        details,
+    })
+}
+
 fn plan_postgres_source_connection(
     scx: &StatementContext<'_>,
     connection: &ResolvedItemName,
@@ -1667,6 +1757,7 @@ pub fn plan_create_subsource(
                     cast_oid_full_range,
                 )?,
                 table,
+                retract_on_truncate: false,
             }),
             SourceExportStatementDetails::MySql {
                 table,
@@ -1702,6 +1793,9 @@ pub fn plan_create_subsource(
             SourceExportStatementDetails::Kafka {} => {
                 bail_unsupported!("subsources cannot reference Kafka sources")
             }
+            SourceExportStatementDetails::Iceberg {} => {
+                bail_unsupported!("subsources cannot reference Iceberg sources")
+            }
         };
         DataSourceDesc::IngestionExport {
             ingestion_id,
@@ -1750,6 +1844,7 @@ generate_extracted_config!(
     (ExcludeColumns, Vec::<Ident>, Default(vec![])),
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
+    (RetractOnTruncate, bool, Default(false)),
     (Details, String)
 );
 
@@ -1780,11 +1875,16 @@ pub fn plan_create_table_from_source(
         text_columns,
         exclude_columns,
         retain_history,
+        retract_on_truncate,
         partition_by,
         details,
         seen: _,
     } = with_options.clone().try_into()?;
 
+    if retract_on_truncate {
+        scx.require_feature_flag(&ENABLE_POSTGRES_SOURCE_RETRACT_ON_TRUNCATE)?;
+    }
+
     let source_item = scx.get_item_by_resolved_name(source)?;
     let ingestion_id = source_item.id();
 
@@ -1815,6 +1915,9 @@ pub fn plan_create_table_from_source(
     {
         bail_unsupported!("INCLUDE metadata with non-Kafka source table");
     }
+    if retract_on_truncate && !matches!(details, SourceExportStatementDetails::Postgres { .. }) {
+        sql_bail!("RETRACT ON TRUNCATE is only supported for PostgreSQL source tables");
+    }
 
     let details = match details {
         SourceExportStatementDetails::Postgres {
@@ -1828,6 +1931,7 @@ pub fn plan_create_table_from_source(
                 cast_oid_full_range,
             )?,
             table,
+            retract_on_truncate,
         }),
         SourceExportStatementDetails::MySql {
             table,
@@ -1924,6 +2028,9 @@ pub fn plan_create_table_from_source(
 
             SourceExportDetails::Kafka(KafkaSourceExportDetails { metadata_columns })
         }
+        SourceExportStatementDetails::Iceberg {} => {
+            SourceExportDetails::Iceberg(IcebergSourceExportDetails {})
+        }
     };
 
     let source_connection = &source_item
@@ -2528,6 +2635,11 @@ fn get_encoding_inner(
                     confluent_wire_format: false,
                 })
             }
+            ProtobufSchema::Glue { .. } => {
+                sql_bail!(
+                    "FORMAT PROTOBUF USING AWS GLUE SCHEMA REGISTRY is only supported for sinks"
+                )
+            }
         },
         Format::Regex(regex) => DataEncoding::Regex(RegexEncoding {
             regex: mz_repr::adt::regex::Regex::new(regex, false)
@@ -2551,8 +2663,20 @@ fn get_encoding_inner(
                     .map_err(|_| sql_err!("CSV delimiter must be an ASCII character"))?,
             })
         }
-        Format::Json { array: false } => DataEncoding::Json,
-        Format::Json { array: true } => bail_unsupported!("JSON ARRAY format in sources"),
+        Format::Json {
+            csr_connection: Some(_),
+            ..
+        } => {
+            sql_bail!("FORMAT JSON USING CONFLUENT SCHEMA REGISTRY is only supported for sinks")
+        }
+        Format::Json {
+            array: false,
+            csr_connection: None,
+        } => DataEncoding::Json,
+        Format::Json {
+            array: true,
+            csr_connection: None,
+        } => bail_unsupported!("JSON ARRAY format in sources"),
         Format::Text => DataEncoding::Text,
     };
     Ok(SourceDataEncoding { key: None, value })
@@ -3288,10 +3412,10 @@ fn plan_sink(
             sql_bail!("MODE is not supported for Kafka sinks, use ENVELOPE instead")
         }
         // Iceberg sinks use MODE
-        (CreateSinkConnection::Iceberg { .. }, None, Some(ast::IcebergSinkMode::Upsert)) => {
+        (CreateSinkConnection::Iceberg { .. }, None, Some(ast::SinkMode::Upsert)) => {
             SinkEnvelope::Upsert
         }
-        (CreateSinkConnection::Iceberg { .. }, None, Some(ast::IcebergSinkMode::Append)) => {
+        (CreateSinkConnection::Iceberg { .. }, None, Some(ast::SinkMode::Append)) => {
             SinkEnvelope::Append
         }
         (CreateSinkConnection::Iceberg { .. }, None, None) => {
@@ -3300,6 +3424,32 @@ fn plan_sink(
         (CreateSinkConnection::Iceberg { .. }, Some(_), _) => {
             sql_bail!("ENVELOPE is not supported for Iceberg sinks, use MODE instead")
         }
+        // Postgres sinks use MODE, and can only apply upserts
+        (CreateSinkConnection::Postgres { .. }, None, Some(ast::SinkMode::Upsert)) => {
+            SinkEnvelope::Upsert
+        }
+        (CreateSinkConnection::Postgres { .. }, None, Some(_)) => {
+            sql_bail!("Postgres sinks only support MODE UPSERT")
+        }
+        (CreateSinkConnection::Postgres { .. }, None, None) => {
+            sql_bail!("MODE clause is required")
+        }
+        (CreateSinkConnection::Postgres { .. }, Some(_), _) => {
+            sql_bail!("ENVELOPE is not supported for Postgres sinks, use MODE instead")
+        }
+        // HTTP sinks use ENVELOPE
+        (CreateSinkConnection::Http { .. }, Some(ast::SinkEnvelope::Upsert), None) => {
+            SinkEnvelope::Upsert
+        }
+        (CreateSinkConnection::Http { .. }, Some(ast::SinkEnvelope::Debezium), None) => {
+            SinkEnvelope::Debezium
+        }
+        (CreateSinkConnection::Http { .. }, None, None) => {
+            sql_bail!("ENVELOPE clause is required")
+        }
+        (CreateSinkConnection::Http { .. }, _, Some(_)) => {
+            sql_bail!("MODE is not supported for HTTP sinks, use ENVELOPE instead")
+        }
     };
 
     let from_name = &from;
@@ -3336,7 +3486,9 @@ fn plan_sink(
         .ok_or_else(|| sql_err!("item does not have a relation description"))?;
     let key_indices = match &connection {
         CreateSinkConnection::Kafka { key: Some(key), .. }
-        | CreateSinkConnection::Iceberg { key: Some(key), .. } => {
+        | CreateSinkConnection::Iceberg { key: Some(key), .. }
+        | CreateSinkConnection::Postgres { key: Some(key), .. }
+        | CreateSinkConnection::Http { key: Some(key), .. } => {
             let key_columns = key
                 .key_columns
                 .clone()
@@ -3451,7 +3603,9 @@ fn plan_sink(
             Some(indices)
         }
         CreateSinkConnection::Kafka { key: None, .. }
-        | CreateSinkConnection::Iceberg { key: None, .. } => None,
+        | CreateSinkConnection::Iceberg { key: None, .. }
+        | CreateSinkConnection::Postgres { key: None, .. }
+        | CreateSinkConnection::Http { key: None, .. } => None,
     };
 
     if key_indices.is_some() && envelope == SinkEnvelope::Append {
@@ -3573,6 +3727,28 @@ fn plan_sink(
             commit_interval,
             &desc,
         )?,
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            ..
+        } => postgres_sink_builder(
+            scx,
+            connection,
+            options,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+            commit_interval,
+        )?,
+        CreateSinkConnection::Http { options, .. } => http_sink_builder(
+            scx,
+            options,
+            format,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+            commit_interval,
+        )?,
     };
 
     // WITH SNAPSHOT defaults to true
@@ -3781,6 +3957,8 @@ fn iceberg_sink_builder(
     let IcebergSinkConfigOptionExtracted {
         table,
         namespace,
+        partition_by,
+        sort_by,
         seen: _,
     }: IcebergSinkConfigOptionExtracted = options.try_into()?;
 
@@ -3803,6 +3981,138 @@ fn iceberg_sink_builder(
         Some(_) => {}
     }
 
+    let resolve_column = |column: Ident, option: &str| {
+        let column = normalize::column_name(column);
+        let (idx, typ) = desc
+            .get_by_name(&column)
+            .ok_or_else(|| sql_err!("column referenced in {option} does not exist: {column}"))?;
+        if desc.get_unambiguous_name(idx).is_none() {
+            sql_bail!("column referenced in {option} is ambiguous: {column}");
+        }
+        Ok::<_, PlanError>((idx, column, &typ.scalar_type))
+    };
+
+    let mut seen_partition_fields = BTreeSet::new();
+    let partition_by = partition_by
+        .into_iter()
+        .map(|field| {
+            let (column, name, typ) = resolve_column(field.column, "PARTITION BY")?;
+            let (transform, transform_name) = match field.transform {
+                ast::IcebergPartitionTransform::Identity => {
+                    (IcebergPartitionTransform::Identity, "identity")
+                }
+                ast::IcebergPartitionTransform::Bucket(n) => {
+                    if n == 0 {
+                        sql_bail!("bucket count for column {} must be positive", name.quoted());
+                    }
+                    (IcebergPartitionTransform::Bucket(n), "bucket")
+                }
+                ast::IcebergPartitionTransform::Truncate(w) => {
+                    if w == 0 {
+                        sql_bail!(
+                            "truncate width for column {} must be positive",
+                            name.quoted()
+                        );
+                    }
+                    (IcebergPartitionTransform::Truncate(w), "truncate")
+                }
+                ast::IcebergPartitionTransform::Day => (IcebergPartitionTransform::Day, "day"),
+                ast::IcebergPartitionTransform::Hour => (IcebergPartitionTransform::Hour, "hour"),
+            };
+            // Restrict each transform to the types the sink can compute partition
+            // values for. Use an allow-list so that new types are rejected by default.
+            let is_valid = match transform {
+                IcebergPartitionTransform::Identity => matches!(
+                    typ,
+                    SqlScalarType::Bool
+                        | SqlScalarType::Int16
+                        | SqlScalarType::Int32
+                        | SqlScalarType::Int64
+                        | SqlScalarType::String
+                        | SqlScalarType::VarChar { .. }
+                        | SqlScalarType::Date
+                        | SqlScalarType::Timestamp { .. }
+                        | SqlScalarType::TimestampTz { .. }
+                ),
+                IcebergPartitionTransform::Bucket(_) => matches!(
+                    typ,
+                    SqlScalarType::Int32
+                        | SqlScalarType::Int64
+                        | SqlScalarType::String
+                        | SqlScalarType::VarChar { .. }
+                        | SqlScalarType::Date
+                        | SqlScalarType::Timestamp { .. }
+                        | SqlScalarType::TimestampTz { .. }
+                ),
+                IcebergPartitionTransform::Truncate(_) => matches!(
+                    typ,
+                    SqlScalarType::Int32
+                        | SqlScalarType::Int64
+                        | SqlScalarType::String
+                        | SqlScalarType::VarChar { .. }
+                ),
+                IcebergPartitionTransform::Day => matches!(
+                    typ,
+                    SqlScalarType::Date
+                        | SqlScalarType::Timestamp { .. }
+                        | SqlScalarType::TimestampTz { .. }
+                ),
+                IcebergPartitionTransform::Hour => matches!(
+                    typ,
+                    SqlScalarType::Timestamp { .. } | SqlScalarType::TimestampTz { .. }
+                ),
+            };
+            if !is_valid {
+                return Err(PlanError::IcebergSinkUnsupportedPartitionType {
+                    column: name.to_string(),
+                    column_type: scx.humanize_sql_scalar_type(typ, false),
+                    transform: transform_name,
+                });
+            }
+            if !seen_partition_fields.insert((column, transform_name)) {
+                sql_bail!(
+                    "column {} is partitioned by the {} transform more than once",
+                    name.quoted(),
+                    transform_name
+                );
+            }
+            Ok(IcebergSinkPartitionField { column, transform })
+        })
+        .collect::<Result<Vec<_>, PlanError>>()?;
+
+    let mut seen_sort_columns = BTreeSet::new();
+    let sort_by = sort_by
+        .into_iter()
+        .map(|field| {
+            let (column, name, typ) = resolve_column(field.column, "SORT BY")?;
+            if matches!(
+                typ,
+                SqlScalarType::Record { .. }
+                    | SqlScalarType::List { .. }
+                    | SqlScalarType::Map { .. }
+                    | SqlScalarType::Array(_)
+            ) {
+                sql_bail!(
+                    "column {} has type {} which cannot be used in SORT BY",
+                    name.quoted(),
+                    scx.humanize_sql_scalar_type(typ, false)
+                );
+            }
+            if !seen_sort_columns.insert(column) {
+                sql_bail!("duplicate column referenced in SORT BY: {}", name);
+            }
+            // Match the defaults of `ORDER BY`: ascending, with nulls sorted as
+            // if they were larger than every other value.
+            let descending = field.asc == Some(false);
+            let nulls_first = field.nulls_last.map_or(descending, |last| !last);
+            Ok(IcebergSinkSortField {
+                column,
+                descending,
+                nulls_first,
+            })
+        })
+        .collect::<Result<Vec<_>, PlanError>>()?;
+
     Ok(StorageSinkConnection::Iceberg(IcebergSinkConnection {
         catalog_connection_id,
         catalog_connection: catalog_connection_id,
@@ -3812,6 +4122,189 @@ fn iceberg_sink_builder(
         namespace,
         relation_key_indices,
         key_desc_and_indices,
+        partition_by,
+        sort_by,
+    }))
+}
+
+generate_extracted_config!(
+    PostgresSinkConfigOption,
+    (Schema, String, Default("public".to_string())),
+    (Table, String)
+);
+
+fn postgres_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<PostgresSinkConfigOption<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+    commit_interval: Option<Duration>,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_POSTGRES_SINK)?;
+
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let connection_id = connection_item.id();
+    match connection_item.connection()? {
+        Connection::Postgres(_) => (),
+        _ => sql_bail!(
+            "{} is not a postgres connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+
+    if commit_interval.is_some() {
+        sql_bail!("COMMIT INTERVAL option is not supported with Postgres sinks");
+    }
+
+    let PostgresSinkConfigOptionExtracted {
+        schema,
+        table,
+        seen: _,
+    }: PostgresSinkConfigOptionExtracted = options.try_into()?;
+
+    let Some(table) = table else {
+        sql_bail!("POSTGRES sink must specify TABLE");
+    };
+
+    // Values are sent as text and cast to the column type upstream, so every
+    // type needs a name that the upstream database understands.
+    for (name, typ) in value_desc.iter() {
+        let pg_type = mz_pgrepr::Type::from(&typ.scalar_type);
+        let oid = pg_type.oid();
+        if oid >= mz_pgrepr::oid::FIRST_MATERIALIZE_OID
+            || oid == mz_pgrepr::oid::TYPE_RECORD_OID
+            || oid == mz_pgrepr::oid::TYPE_RECORD_ARRAY_OID
+        {
+            sql_bail!(
+                "column {} has type {} which is not supported by Postgres sinks",
+                name.quoted(),
+                scx.humanize_sql_scalar_type(&typ.scalar_type, false)
+            );
+        }
+    }
+
+    // Rows are matched on the key with `=`, which never matches NULLs.
+    if let Some((key_desc, _)) = &key_desc_and_indices {
+        for (name, typ) in key_desc.iter() {
+            if typ.nullable {
+                sql_bail!(
+                    "column {} referenced in KEY is nullable, which is not supported by \
+                     Postgres sinks",
+                    name.quoted()
+                );
+            }
+        }
+    }
+
+    Ok(StorageSinkConnection::Postgres(PostgresSinkConnection {
+        connection_id,
+        connection: connection_id,
+        schema,
+        table,
+        relation_key_indices,
+        key_desc_and_indices,
+        value_desc,
+    }))
+}
+
+generate_extracted_config!(
+    HttpSinkConfigOption,
+    (Url, String),
+    (
+        Headers,
+        BTreeMap<String, StringOrSecret>,
+        Default(BTreeMap::new())
+    ),
+    (BatchSize, u64, Default(1000_u64))
+);
+
+fn http_sink_builder(
+    scx: &StatementContext,
+    options: Vec<HttpSinkConfigOption<Aug>>,
+    format: Option<FormatSpecifier<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+    commit_interval: Option<Duration>,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_HTTP_SINK)?;
+
+    if commit_interval.is_some() {
+        sql_bail!("COMMIT INTERVAL option is not supported with HTTP sinks");
+    }
+
+    // Requests are always JSON documents, so the only format we accept is
+    // plain JSON, which is also what we use when no format is specified.
+    let is_plain_json = |format: &Format<Aug>| {
+        matches!(
+            format,
+            Format::Json {
+                array: false,
+                csr_connection: None,
+            }
+        )
+    };
+    match &format {
+        None => (),
+        Some(FormatSpecifier::Bare(format)) if is_plain_json(format) => (),
+        Some(FormatSpecifier::KeyValue { key, value })
+            if is_plain_json(key) && is_plain_json(value) => {}
+        Some(_) => sql_bail!("HTTP sinks only support FORMAT JSON"),
+    }
+
+    let HttpSinkConfigOptionExtracted {
+        url,
+        headers,
+        batch_size,
+        seen: _,
+    }: HttpSinkConfigOptionExtracted = options.try_into()?;
+
+    let Some(url) = url else {
+        sql_bail!("HTTP sink must specify URL");
+    };
+    let url: reqwest::Url = url
+        .parse()
+        .map_err(|e| sql_err!("parsing HTTP sink URL: {e}"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        sql_bail!("HTTP sink URL must use the http or https scheme");
+    }
+    if url.host_str().is_none() {
+        sql_bail!("HTTP sink URL must specify a host");
+    }
+
+    for (name, value) in &headers {
+        let Ok(name) = reqwest::header::HeaderName::from_bytes(name.as_bytes()) else {
+            sql_bail!("invalid HTTP header name: {}", name.quoted());
+        };
+        // The sink sets these itself, and the idempotency key is what allows
+        // the endpoint to deduplicate requests that are retried.
+        if name == reqwest::header::CONTENT_TYPE || name.as_str() == "idempotency-key" {
+            sql_bail!(
+                "HTTP header {} cannot be overridden",
+                name.as_str().quoted()
+            );
+        }
+        // Secret values are validated when the sink starts.
+        if let StringOrSecret::String(value) = value {
+            if reqwest::header::HeaderValue::from_str(value).is_err() {
+                sql_bail!("invalid value for HTTP header {}", name.as_str().quoted());
+            }
+        }
+    }
+
+    if batch_size == 0 {
+        sql_bail!("BATCH SIZE must be greater than 0");
+    }
+
+    Ok(StorageSinkConnection::Http(HttpSinkConnection {
+        url,
+        headers,
+        relation_key_indices,
+        key_desc_and_indices,
+        value_desc,
+        batch_size,
     }))
 }
 
@@ -3953,74 +4446,59 @@ fn kafka_sink_builder(
         Ok((csr_connection, extracted_options))
     };
 
-    let map_format = |format: Format<Aug>, desc: &RelationDesc, is_key: bool| match format {
-        Format::Json { array: false } => Ok::<_, PlanError>(KafkaSinkFormatType::Json),
-        Format::Bytes if desc.arity() == 1 => {
-            let col_type = &desc.typ().column_types[0].scalar_type;
-            if !mz_pgrepr::Value::can_encode_binary(col_type) {
-                bail_unsupported!(format!(
-                    "BYTES format with non-encodable type: {:?}",
-                    col_type
-                ));
+    // Helper method to parse schema registry connection options for non-Avro
+    // format specifiers that register a schema with the registry. Only the
+    // compatibility levels apply; the rest shape the generated Avro schema.
+    // Returns the registry connection and the compatibility level for the
+    // side being planned.
+    let gen_csr_compatibility_level =
+        |connection: CsrConnection<Aug>, format_name: &str, is_key: bool| {
+            let CsrConnection {
+                connection,
+                options,
+            } = connection;
+            let item = scx.get_item_by_resolved_name(&connection)?;
+            let csr_connection = match item.connection()? {
+                Connection::Csr(_) => item.id(),
+                _ => {
+                    sql_bail!(
+                        "{} is not a schema registry connection",
+                        scx.catalog
+                            .resolve_full_name(item.name())
+                            .to_string()
+                            .quoted()
+                    )
+                }
+            };
+
+            let options: CsrConfigOptionExtracted = options.try_into()?;
+            if options.avro_key_fullname.is_some()
+                || options.avro_value_fullname.is_some()
+                || options.null_defaults
+                || !options.key_doc_options.is_empty()
+                || !options.value_doc_options.is_empty()
+            {
+                sql_bail!(
+                    "AVRO KEY FULLNAME, AVRO VALUE FULLNAME, NULL DEFAULTS, and DOC ON \
+                     options are not supported with FORMAT {}",
+                    format_name
+                );
             }
 
-            Ok(KafkaSinkFormatType::Bytes)
-        }
-        Format::Text if desc.arity() == 1 => Ok(KafkaSinkFormatType::Text),
-        Format::Bytes | Format::Text => {
-            bail_unsupported!("BYTES or TEXT format with multiple columns")
-        }
-        Format::Json { array: true } => bail_unsupported!("JSON ARRAY format in sinks"),
-        Format::Avro(AvroSchema::Csr { csr_connection }) => {
-            let (csr_connection, options) = gen_avro_schema_options(csr_connection)?;
-            let schema = if is_key {
-                AvroSchemaGenerator::new(
-                    desc.clone(),
-                    false,
-                    options.key_doc_options,
-                    options.avro_key_fullname.as_deref().unwrap_or("row"),
-                    options.null_defaults,
-                    Some(sink_from),
-                    false,
-                )?
-                .schema()
-                .to_string()
+            let compatibility_level = if is_key {
+                options.key_compatibility_level
             } else {
-                AvroSchemaGenerator::new(
-                    desc.clone(),
-                    matches!(envelope, SinkEnvelope::Debezium),
-                    options.value_doc_options,
-                    options.avro_value_fullname.as_deref().unwrap_or("envelope"),
-                    options.null_defaults,
-                    Some(sink_from),
-                    true,
-                )?
-                .schema()
-                .to_string()
+                options.value_compatibility_level
             };
-            Ok(KafkaSinkFormatType::Avro {
-                schema,
-                compatibility_level: if is_key {
-                    options.key_compatibility_level
-                } else {
-                    options.value_compatibility_level
-                },
-                // Confluent always derives the subject from the topic.
-                schema_name: None,
-                wire_format: WireFormat::Confluent {
-                    registry: Some(csr_connection),
-                },
-            })
-        }
-        Format::Avro(AvroSchema::Glue {
-            connection,
-            with_options,
-            seed,
-        }) => {
-            if seed.is_some() {
-                sql_bail!("SEED option does not make sense with sinks");
-            }
+            Ok::<_, PlanError>((csr_connection, compatibility_level))
+        };
 
+    // Helper method to parse AWS Glue Schema Registry connection options for
+    // format specifiers that use Glue for either key or value encoding.
+    // Returns the registry connection, and the compatibility level and schema
+    // name for the side being planned.
+    let gen_glue_schema_options =
+        |connection: ResolvedItemName, with_options: Vec<GlueAvroOption<Aug>>, is_key: bool| {
             let extracted: GlueAvroOptionExtracted = with_options.try_into()?;
             let GlueAvroOptionExtracted {
                 schema_name,
@@ -4093,6 +4571,109 @@ fn kafka_sink_builder(
             } else {
                 value_schema_name
             };
+            Ok::<_, PlanError>((glue_connection, compatibility_level, schema_name))
+        };
+
+    // Helper method to generate the `.proto` schema for format specifiers that
+    // use Protobuf for either key or value encoding. Returns the schema and
+    // the name of its top-level message.
+    let gen_protobuf_schema = |desc: &RelationDesc, is_key: bool| {
+        let (debezium, message_name) = if is_key {
+            (false, "Key")
+        } else {
+            (matches!(envelope, SinkEnvelope::Debezium), "Value")
+        };
+        let schema = ProtobufSchemaGenerator::new(desc.clone(), debezium, message_name)?.schema();
+        Ok::<_, PlanError>((schema, message_name.to_owned()))
+    };
+
+    let map_format = |format: Format<Aug>, desc: &RelationDesc, is_key: bool| match format {
+        Format::Json {
+            array: false,
+            csr_connection: None,
+        } => Ok::<_, PlanError>(KafkaSinkFormatType::Json),
+        Format::Json {
+            array: false,
+            csr_connection: Some(connection),
+        } => {
+            let (csr_connection, compatibility_level) =
+                gen_csr_compatibility_level(connection, "JSON", is_key)?;
+            let debezium = !is_key && matches!(envelope, SinkEnvelope::Debezium);
+            let schema = build_json_schema(desc.clone(), debezium);
+            Ok(KafkaSinkFormatType::JsonSchema {
+                schema: schema.to_string(),
+                compatibility_level,
+                csr_connection,
+            })
+        }
+        Format::Bytes if desc.arity() == 1 => {
+            let col_type = &desc.typ().column_types[0].scalar_type;
+            if !mz_pgrepr::Value::can_encode_binary(col_type) {
+                bail_unsupported!(format!(
+                    "BYTES format with non-encodable type: {:?}",
+                    col_type
+                ));
+            }
+
+            Ok(KafkaSinkFormatType::Bytes)
+        }
+        Format::Text if desc.arity() == 1 => Ok(KafkaSinkFormatType::Text),
+        Format::Bytes | Format::Text => {
+            bail_unsupported!("BYTES or TEXT format with multiple columns")
+        }
+        Format::Json { array: true, .. } => bail_unsupported!("JSON ARRAY format in sinks"),
+        Format::Avro(AvroSchema::Csr { csr_connection }) => {
+            let (csr_connection, options) = gen_avro_schema_options(csr_connection)?;
+            let schema = if is_key {
+                AvroSchemaGenerator::new(
+                    desc.clone(),
+                    false,
+                    options.key_doc_options,
+                    options.avro_key_fullname.as_deref().unwrap_or("row"),
+                    options.null_defaults,
+                    Some(sink_from),
+                    false,
+                )?
+                .schema()
+                .to_string()
+            } else {
+                AvroSchemaGenerator::new(
+                    desc.clone(),
+                    matches!(envelope, SinkEnvelope::Debezium),
+                    options.value_doc_options,
+                    options.avro_value_fullname.as_deref().unwrap_or("envelope"),
+                    options.null_defaults,
+                    Some(sink_from),
+                    true,
+                )?
+                .schema()
+                .to_string()
+            };
+            Ok(KafkaSinkFormatType::Avro {
+                schema,
+                compatibility_level: if is_key {
+                    options.key_compatibility_level
+                } else {
+                    options.value_compatibility_level
+                },
+                // Confluent always derives the subject from the topic.
+                schema_name: None,
+                wire_format: WireFormat::Confluent {
+                    registry: Some(csr_connection),
+                },
+            })
+        }
+        Format::Avro(AvroSchema::Glue {
+            connection,
+            with_options,
+            seed,
+        }) => {
+            if seed.is_some() {
+                sql_bail!("SEED option does not make sense with sinks");
+            }
+
+            let (glue_connection, compatibility_level, schema_name) =
+                gen_glue_schema_options(connection, with_options, is_key)?;
 
             // Unlike the CSR clause, the Glue clause carries no fullname or doc
             // options, so the schema is generated with defaults.
@@ -4130,6 +4711,43 @@ fn kafka_sink_builder(
                 },
             })
         }
+        Format::Protobuf(ProtobufSchema::Csr {
+            csr_connection: CsrConnectionProtobuf { connection, seed },
+        }) => {
+            if seed.is_some() {
+                sql_bail!("SEED option does not make sense with sinks");
+            }
+            let (csr_connection, compatibility_level) =
+                gen_csr_compatibility_level(connection, "PROTOBUF", is_key)?;
+            let (schema, message_name) = gen_protobuf_schema(desc, is_key)?;
+            Ok(KafkaSinkFormatType::Protobuf {
+                schema,
+                message_name,
+                compatibility_level,
+                // Confluent always derives the subject from the topic.
+                schema_name: None,
+                wire_format: WireFormat::Confluent {
+                    registry: Some(csr_connection),
+                },
+            })
+        }
+        Format::Protobuf(ProtobufSchema::Glue {
+            connection,
+            with_options,
+        }) => {
+            let (glue_connection, compatibility_level, schema_name) =
+                gen_glue_schema_options(connection, with_options, is_key)?;
+            let (schema, message_name) = gen_protobuf_schema(desc, is_key)?;
+            Ok(KafkaSinkFormatType::Protobuf {
+                schema,
+                message_name,
+                compatibility_level,
+                schema_name,
+                wire_format: WireFormat::Glue {
+                    registry: Some(glue_connection),
+                },
+            })
+        }
         format => bail_unsupported!(format!("sink format {:?}", format)),
     };
 
@@ -27,7 +27,7 @@ use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::bytes::ByteSize;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
 use mz_repr::optimize::OptimizerFeatureOverrides;
-use mz_repr::{CatalogItemId, Datum, RelationDesc, Row, SqlRelationType, SqlScalarType};
+use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, Row, SqlRelationType, SqlScalarType};
 use mz_sql_parser::ast::{
     CteBlock, ExplainAnalyzeClusterStatement, ExplainAnalyzeComputationProperties,
     ExplainAnalyzeComputationProperty, ExplainAnalyzeObjectStatement, ExplainAnalyzeProperty,
@@ -59,8 +59,9 @@ use crate::plan::scope::Scope;
 use crate::plan::statement::show::ShowSelect;
 use crate::plan::statement::{StatementContext, StatementDesc, ddl};
 use crate::plan::{
-    self, CopyFromFilter, CopyToPlan, CreateSinkPlan, ExplainPushdownPlan, ExplainSinkSchemaPlan,
-    ExplainTimestampPlan, HirRelationExpr, side_effecting_func, transform_ast,
+    self, CopyFromFilter, CopyToPlan, CreateSinkPlan, ExplainAnalyzeSelectPlan,
+    ExplainPushdownPlan, ExplainSinkSchemaPlan, ExplainTimestampPlan, HirRelationExpr,
+    side_effecting_func, transform_ast,
 };
 use crate::plan::{
     CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MutationKind, Params, Plan, PlanError,
@@ -356,7 +357,7 @@ pub fn describe_explain_pushdown(
 }
 
 pub fn describe_explain_analyze_object(
-    _scx: &StatementContext,
+    scx: &StatementContext,
     statement: ExplainAnalyzeObjectStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
     if statement.as_sql {
@@ -366,7 +367,19 @@ pub fn describe_explain_analyze_object(
         return Ok(StatementDesc::new(Some(relation_desc)));
     }
 
-    match statement.properties {
+    let relation_desc = explain_analyze_object_desc(statement.properties);
+    Ok(
+        StatementDesc::new(Some(relation_desc)).with_params(match statement.explainee {
+            Explainee::Select(select, _) => describe_select(scx, *select)?.param_types,
+            _ => vec![],
+        }),
+    )
+}
+
+/// The shape of the rows `EXPLAIN ANALYZE ... FOR <explainee>` reports for
+/// `properties`.
+fn explain_analyze_object_desc(properties: ExplainAnalyzeProperty) -> RelationDesc {
+    match properties {
         ExplainAnalyzeProperty::Computation(ExplainAnalyzeComputationProperties {
             properties,
             skew,
@@ -423,22 +436,35 @@ pub fn describe_explain_analyze_object(
                         relation_desc = relation_desc
                             .with_column("total_elapsed", SqlScalarType::Interval.nullable(true));
                     }
+                    ExplainAnalyzeComputationProperty::Updates => {
+                        let numeric = SqlScalarType::Numeric { max_scale: None }.nullable(true);
+                        relation_desc = relation_desc
+                            .with_column("records_in", numeric.clone())
+                            .with_column("records_out", numeric);
+                    }
+                    ExplainAnalyzeComputationProperty::Hydration => {
+                        relation_desc = relation_desc
+                            .with_column("hydrated", SqlScalarType::Bool.nullable(true))
+                            .with_column("hydration_time", SqlScalarType::Interval.nullable(true));
+                    }
+                    ExplainAnalyzeComputationProperty::Records => {
+                        let numeric = SqlScalarType::Numeric { max_scale: None }.nullable(true);
+                        relation_desc = relation_desc
+                            .with_column("arrangement_records", numeric.clone())
+                            .with_column("arrangement_batches", numeric);
+                    }
                 }
             }
 
-            let relation_desc = relation_desc.finish();
-            Ok(StatementDesc::new(Some(relation_desc)))
-        }
-        ExplainAnalyzeProperty::Hints => {
-            let relation_desc = RelationDesc::builder()
-                .with_column("operator", SqlScalarType::String.nullable(true))
-                .with_column("levels", SqlScalarType::Int64.nullable(true))
-                .with_column("to_cut", SqlScalarType::Int64.nullable(true))
-                .with_column("hint", SqlScalarType::Float64.nullable(true))
-                .with_column("savings", SqlScalarType::String.nullable(true))
-                .finish();
-            Ok(StatementDesc::new(Some(relation_desc)))
+            relation_desc.finish()
         }
+        ExplainAnalyzeProperty::Hints => RelationDesc::builder()
+            .with_column("operator", SqlScalarType::String.nullable(true))
+            .with_column("levels", SqlScalarType::Int64.nullable(true))
+            .with_column("to_cut", SqlScalarType::Int64.nullable(true))
+            .with_column("hint", SqlScalarType::Float64.nullable(true))
+            .with_column("savings", SqlScalarType::String.nullable(true))
+            .finish(),
     }
 }
 
@@ -506,6 +532,11 @@ pub fn describe_explain_analyze_cluster(
                 relation_desc = relation_desc
                     .with_column("total_elapsed", SqlScalarType::Interval.nullable(true));
             }
+            ExplainAnalyzeComputationProperty::Updates
+            | ExplainAnalyzeComputationProperty::Hydration
+            | ExplainAnalyzeComputationProperty::Records => {
+                sql_bail!("EXPLAIN ANALYZE CLUSTER does not support {property}")
+            }
         }
     }
 
@@ -885,6 +916,16 @@ pub fn plan_explain_analyze_object(
     statement: ExplainAnalyzeObjectStatement<Aug>,
     params: &Params,
 ) -> Result<Plan, PlanError> {
+    if let Explainee::Select(select, _) = statement.explainee {
+        return plan_explain_analyze_select(
+            scx,
+            statement.properties,
+            *select,
+            statement.as_sql,
+            params,
+        );
+    }
+
     let explainee_name = statement
         .explainee
         .name()
@@ -910,6 +951,76 @@ pub fn plan_explain_analyze_object(
         _ => return Err(sql_err!("EXPLAIN ANALYZE queries for this explainee type",)),
     };
 
+    let query = explain_analyze_object_query(
+        statement.properties,
+        AnalyzedDataflow::Named(&explainee_name),
+    );
+
+    if statement.as_sql {
+        let rows = vec![Row::pack_slice(&[Datum::String(
+            &mz_sql_pretty::pretty_str_simple(&query, 80).map_err(|e| {
+                PlanError::Unstructured(format!("internal error parsing our own SQL: {e}"))
+            })?,
+        )])];
+        let typ = SqlRelationType::new(vec![SqlScalarType::String.nullable(false)]);
+
+        Ok(Plan::Select(SelectPlan::immediate(rows, typ)))
+    } else {
+        let (show_select, resolved_ids) = ShowSelect::new_from_bare_query(scx, query)?;
+        scx.record_sql_impl_ids(&resolved_ids);
+        show_select.plan()
+    }
+}
+
+/// Plans `EXPLAIN ANALYZE ... FOR <query>`.
+///
+/// Unlike indexes and materialized views, the query has no dataflow to inspect
+/// until it runs, so the introspection query is generated by the coordinator
+/// once it knows the objects built by the dataflow it installed, using
+/// [`explain_analyze_dataflow_query`].
+fn plan_explain_analyze_select(
+    scx: &StatementContext,
+    properties: ExplainAnalyzeProperty,
+    select: SelectStatement<Aug>,
+    as_sql: bool,
+    params: &Params,
+) -> Result<Plan, PlanError> {
+    if as_sql {
+        sql_bail!("EXPLAIN ANALYZE ... AS SQL is not supported for queries");
+    }
+
+    let desc = explain_analyze_object_desc(properties.clone());
+    let (select, _) = plan_select_inner(scx, select, params, None)?;
+    Ok(Plan::ExplainAnalyzeSelect(ExplainAnalyzeSelectPlan {
+        select,
+        properties,
+        desc,
+    }))
+}
+
+/// The dataflows whose introspection data an `EXPLAIN ANALYZE` query reports.
+#[derive(Clone, Copy)]
+enum AnalyzedDataflow<'a> {
+    /// The dataflows of the index or materialized view with this full name.
+    Named(&'a str),
+    /// The transient dataflow building the objects with these IDs.
+    Transient(&'a [GlobalId]),
+}
+
+/// Generates the SQL query that reports `properties` for the LIR operators of
+/// the transient dataflow building the objects `ids`, for
+/// `EXPLAIN ANALYZE ... FOR <query>`.
+pub fn explain_analyze_dataflow_query(
+    properties: ExplainAnalyzeProperty,
+    ids: &[GlobalId],
+) -> String {
+    explain_analyze_object_query(properties, AnalyzedDataflow::Transient(ids))
+}
+
+fn explain_analyze_object_query(
+    properties: ExplainAnalyzeProperty,
+    dataflow: AnalyzedDataflow,
+) -> String {
     // generate SQL query
 
     /* WITH {CTEs}
@@ -917,22 +1028,29 @@ pub fn plan_explain_analyze_object(
              {columns}
         FROM      mz_introspection.mz_lir_mapping mlm
              JOIN {from} USING (lir_id)
-             JOIN mz_introspection.mz_mappable_objects mo
+             JOIN mz_introspection.mz_mappable_objects mo     (if named)
                ON (mlm.global_id = mo.global_id)
-       WHERE     mo.name = {escaped explainee_name}
+       WHERE     mo.name = {escaped explainee_name}       (if named)
+                 mlm.global_id IN ({escaped global_ids})  (if transient)
              AND {predicates}
        ORDER BY lir_id DESC
     */
-    let mut ctes = Vec::with_capacity(4); // max 2 per ExplainAnalyzeComputationProperty
+    let mut ctes = Vec::with_capacity(8); // max 2 per ExplainAnalyzeComputationProperty
     let mut columns = vec!["REPEAT(' ', nesting * 2) || operator AS operator"];
     let mut from = vec!["mz_introspection.mz_lir_mapping mlm"];
-    let mut predicates = vec![format!(
-        "mo.name = {}",
-        escaped_string_literal(&explainee_name)
-    )];
+    let mut predicates = vec![match dataflow {
+        AnalyzedDataflow::Named(name) => format!("mo.name = {}", escaped_string_literal(name)),
+        AnalyzedDataflow::Transient(ids) => format!(
+            "mlm.global_id IN ({})",
+            separated(
+                ", ",
+                ids.iter().map(|id| escaped_string_literal(&id.to_string()))
+            )
+        ),
+    }];
     let mut order_by = vec!["mlm.lir_id DESC"];
 
-    match statement.properties {
+    match properties {
         ExplainAnalyzeProperty::Computation(ExplainAnalyzeComputationProperties {
             properties,
             skew,
@@ -1060,6 +1178,99 @@ GROUP BY mlm.global_id, mlm.lir_id, mse.worker_id"#,
                             "sc.total_ns / 1000 * '1 microsecond'::INTERVAL AS total_elapsed",
                         );
                     }
+                    ExplainAnalyzeComputationProperty::Updates => {
+                        // Records entering an LIR operator on channels from
+                        // outside its operator range, and leaving it on
+                        // channels to outside of it. Each channel is counted
+                        // once per sending worker.
+                        ctes.push((
+                            "channel_updates",
+                            r#"
+  SELECT mdco.from_operator_id AS from_operator_id,
+         mdco.to_operator_id AS to_operator_id,
+         SUM(mmc.sent) AS sent,
+         SUM(mmc.received) AS received
+    FROM      mz_introspection.mz_dataflow_channel_operators_per_worker mdco
+         JOIN mz_introspection.mz_message_counts_per_worker mmc
+           ON (mmc.channel_id = mdco.id AND mmc.from_worker_id = mdco.worker_id)
+GROUP BY mdco.from_operator_id, mdco.to_operator_id"#,
+                        ));
+                        ctes.push((
+                            "summary_updates",
+                            r#"
+  SELECT global_id, lir_id, SUM(records_in) AS records_in, SUM(records_out) AS records_out
+    FROM (SELECT mlm.global_id AS global_id,
+                 mlm.lir_id AS lir_id,
+                 cu.received AS records_in,
+                 NULL :: numeric AS records_out
+            FROM            mz_introspection.mz_lir_mapping mlm
+                 CROSS JOIN generate_series((mlm.operator_id_start) :: int8, (mlm.operator_id_end - 1) :: int8) AS valid_id
+                       JOIN channel_updates cu
+                         ON (cu.to_operator_id = valid_id)
+           WHERE cu.from_operator_id NOT BETWEEN mlm.operator_id_start AND mlm.operator_id_end - 1
+          UNION ALL
+          SELECT mlm.global_id AS global_id,
+                 mlm.lir_id AS lir_id,
+                 NULL :: numeric AS records_in,
+                 cu.sent AS records_out
+            FROM            mz_introspection.mz_lir_mapping mlm
+                 CROSS JOIN generate_series((mlm.operator_id_start) :: int8, (mlm.operator_id_end - 1) :: int8) AS valid_id
+                       JOIN channel_updates cu
+                         ON (cu.from_operator_id = valid_id)
+           WHERE cu.to_operator_id NOT BETWEEN mlm.operator_id_start AND mlm.operator_id_end - 1)
+GROUP BY global_id, lir_id"#,
+                        ));
+                        from.push("LEFT JOIN summary_updates su USING (global_id, lir_id)");
+                        columns.extend([
+                            "su.records_in AS records_in",
+                            "su.records_out AS records_out",
+                        ]);
+                    }
+                    ExplainAnalyzeComputationProperty::Hydration => {
+                        // An LIR operator is hydrated once it is hydrated on
+                        // every worker, for every export of its dataflow.
+                        ctes.push((
+                            "summary_hydration",
+                            r#"
+  SELECT mlm.global_id AS global_id,
+         mlm.lir_id AS lir_id,
+         bool_and(mcohs.hydrated) AS hydrated,
+         CASE WHEN bool_and(mcohs.hydrated) THEN MAX(mcohs.time_ns) :: numeric ELSE NULL END AS hydration_ns
+    FROM      mz_introspection.mz_lir_mapping mlm
+         JOIN mz_introspection.mz_dataflow_global_ids mdgi
+           ON (mdgi.global_id = mlm.global_id)
+         JOIN mz_introspection.mz_compute_exports mce
+           ON (mce.dataflow_id = mdgi.id)
+         JOIN mz_introspection.mz_compute_operator_hydration_statuses_per_worker mcohs
+           ON (mcohs.export_id = mce.export_id AND mcohs.lir_id = mlm.lir_id)
+GROUP BY mlm.global_id, mlm.lir_id"#,
+                        ));
+                        from.push("LEFT JOIN summary_hydration sh USING (global_id, lir_id)");
+                        columns.extend([
+                            "sh.hydrated AS hydrated",
+                            "sh.hydration_ns / 1000 * '1 microsecond'::INTERVAL AS hydration_time",
+                        ]);
+                    }
+                    ExplainAnalyzeComputationProperty::Records => {
+                        ctes.push((
+                            "summary_records",
+                            r#"
+  SELECT mlm.global_id AS global_id,
+         mlm.lir_id AS lir_id,
+         SUM(mas.records) AS arrangement_records,
+         SUM(mas.batches) AS arrangement_batches
+    FROM            mz_introspection.mz_lir_mapping mlm
+         CROSS JOIN generate_series((mlm.operator_id_start) :: int8, (mlm.operator_id_end - 1) :: int8) AS valid_id
+               JOIN mz_introspection.mz_arrangement_sizes_per_worker mas
+                 ON (mas.operator_id = valid_id)
+GROUP BY mlm.global_id, mlm.lir_id"#,
+                        ));
+                        from.push("LEFT JOIN summary_records sr USING (global_id, lir_id)");
+                        columns.extend([
+                            "sr.arrangement_records AS arrangement_records",
+                            "sr.arrangement_batches AS arrangement_batches",
+                        ]);
+                    }
                 }
             }
         }
@@ -1076,7 +1287,9 @@ GROUP BY mlm.global_id, mlm.lir_id, mse.worker_id"#,
         }
     }
 
-    from.push("JOIN mz_introspection.mz_mappable_objects mo ON (mlm.global_id = mo.global_id)");
+    if let AnalyzedDataflow::Named(_) = dataflow {
+        from.push("JOIN mz_introspection.mz_mappable_objects mo ON (mlm.global_id = mo.global_id)");
+    }
 
     let ctes = if !ctes.is_empty() {
         format!(
@@ -1094,28 +1307,13 @@ GROUP BY mlm.global_id, mlm.lir_id, mse.worker_id"#,
     let from = separated(" ", from);
     let predicates = separated(" AND ", predicates);
     let order_by = separated(", ", order_by);
-    let query = format!(
+    format!(
         r#"{ctes}
 SELECT {columns}
 FROM {from}
 WHERE {predicates}
 ORDER BY {order_by}"#
-    );
-
-    if statement.as_sql {
-        let rows = vec![Row::pack_slice(&[Datum::String(
-            &mz_sql_pretty::pretty_str_simple(&query, 80).map_err(|e| {
-                PlanError::Unstructured(format!("internal error parsing our own SQL: {e}"))
-            })?,
-        )])];
-        let typ = SqlRelationType::new(vec![SqlScalarType::String.nullable(false)]);
-
-        Ok(Plan::Select(SelectPlan::immediate(rows, typ)))
-    } else {
-        let (show_select, resolved_ids) = ShowSelect::new_from_bare_query(scx, query)?;
-        scx.record_sql_impl_ids(&resolved_ids);
-        show_select.plan()
-    }
+    )
 }
 
 pub fn plan_explain_analyze_cluster(
@@ -1162,6 +1360,11 @@ pub fn plan_explain_analyze_cluster(
         }
 
         match property {
+            ExplainAnalyzeComputationProperty::Updates
+            | ExplainAnalyzeComputationProperty::Hydration
+            | ExplainAnalyzeComputationProperty::Records => {
+                sql_bail!("EXPLAIN ANALYZE CLUSTER does not support {property}")
+            }
             ExplainAnalyzeComputationProperty::Memory => {
                 if skew {
                     let mut set_worker_id = false;
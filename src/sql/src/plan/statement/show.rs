@@ -21,9 +21,10 @@ use mz_repr::{CatalogItemId, Datum, RelationDesc, Row, SqlScalarType};
 use mz_sql_parser::ast::display::{AstDisplay, FormatMode};
 use mz_sql_parser::ast::{
     CreateSinkOptionName, CreateSubsourceOptionName, ExternalReferenceExport, ExternalReferences,
-    ObjectType, ShowCreateClusterStatement, ShowCreateConnectionStatement,
-    ShowCreateMaterializedViewStatement, ShowCreateTypeStatement, ShowObjectType,
-    SqlServerConfigOptionName, SystemObjectType, UnresolvedItemName, WithOptionValue,
+    IcebergSourceConfigOptionName, ObjectType, ShowCreateClusterStatement,
+    ShowCreateConnectionStatement, ShowCreateMaterializedViewStatement, ShowCreateTypeStatement,
+    ShowObjectType, SqlServerConfigOptionName, SystemObjectType, UnresolvedItemName,
+    WithOptionValue,
 };
 use mz_sql_pretty::PrettyConfig;
 use query::QueryContext;
@@ -1096,6 +1097,7 @@ fn humanize_sql_for_show_create(
                 TableFromSourceOptionName::Details => false,
                 TableFromSourceOptionName::PartitionBy => true,
                 TableFromSourceOptionName::RetainHistory => true,
+                TableFromSourceOptionName::RetractOnTruncate => true,
             });
             // The `Defined` column list and constraints are populated during
             // purification (from the upstream schema), and `CREATE TABLE ... FROM
@@ -1265,6 +1267,10 @@ fn humanize_sql_for_show_create(
                     curr_references.clear();
                     stmt.external_references = Some(ExternalReferences::All);
                 }
+                CreateSourceConnection::Iceberg { options, .. } => {
+                    // Drop details, which does not roundtrip.
+                    options.retain(|o| o.name != IcebergSourceConfigOptionName::Details);
+                }
                 CreateSourceConnection::Kafka { .. }
                 | CreateSourceConnection::LoadGenerator { .. } => {}
             }
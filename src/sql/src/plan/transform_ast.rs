@@ -134,6 +134,7 @@ impl<'a> FuncRewriter<'a> {
             filter,
             over,
             distinct,
+            within_group: false,
         })
     }
 
@@ -428,6 +429,7 @@ impl<'a> FuncRewriter<'a> {
             filter,
             distinct,
             over,
+            within_group: false,
         } = func
         {
             let pg_catalog_id = self
@@ -738,6 +740,7 @@ impl<'a> Desugarer<'a> {
                                 filter: None,
                                 over: None,
                                 distinct: false,
+                                within_group: false,
                             },
                             alias: Some(TableAlias {
                                 name: ident!("_"),
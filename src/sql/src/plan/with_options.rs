@@ -17,8 +17,9 @@ use mz_repr::bytes::ByteSize;
 use mz_repr::{CatalogItemId, RelationVersionSelector, strconv};
 use mz_sql_parser::ast::{
     ClusterAlterOptionValue, ClusterAutoScalingStrategyOptionValue, ClusterScheduleOptionValue,
-    ConnectionDefaultAwsPrivatelink, Expr, Ident, KafkaBroker, KafkaMatchingBrokerRule,
-    NetworkPolicyRuleDefinition, RefreshOptionValue, ReplicaDefinition,
+    ConnectionDefaultAwsPrivatelink, Expr, IcebergPartitionField, IcebergSortField, Ident,
+    KafkaBroker, KafkaMatchingBrokerRule, NetworkPolicyRuleDefinition, RefreshOptionValue,
+    ReplicaDefinition,
 };
 use mz_storage_types::connections::IcebergCatalogType;
 use mz_storage_types::connections::string_or_secret::StringOrSecret;
@@ -712,7 +713,9 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
             | WithOptionValue::Refresh(_)
             | WithOptionValue::ClusterScheduleOptionValue(_)
             | WithOptionValue::ClusterAutoScalingStrategyOptionValue(_)
-            | WithOptionValue::NetworkPolicyRules(_) => sql_bail!(
+            | WithOptionValue::NetworkPolicyRules(_)
+            | WithOptionValue::IcebergPartitionBy(_)
+            | WithOptionValue::IcebergSortBy(_) => sql_bail!(
                 "incompatible value types: cannot convert {} to {}",
                 match v {
                     // The first few are unreachable because they are handled at the top of the outer match.
@@ -736,6 +739,8 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
                     WithOptionValue::ClusterAutoScalingStrategyOptionValue(_) =>
                         "cluster auto scaling strategy",
                     WithOptionValue::NetworkPolicyRules(_) => "network policy rules",
+                    WithOptionValue::IcebergPartitionBy(_) => "iceberg partition fields",
+                    WithOptionValue::IcebergSortBy(_) => "iceberg sort fields",
                 },
                 V::name()
             ),
@@ -1088,3 +1093,49 @@ impl ImpliedValue for Vec<NetworkPolicyRuleDefinition<Aug>> {
         sql_bail!("must provide a set of network policy rules")
     }
 }
+
+impl TryFromValue<WithOptionValue<Aug>> for Vec<IcebergPartitionField> {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::IcebergPartitionBy(fields) => Ok(fields),
+            _ => sql_bail!("cannot use value as iceberg partition fields"),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<WithOptionValue<Aug>> {
+        Some(WithOptionValue::IcebergPartitionBy(self))
+    }
+
+    fn name() -> String {
+        "iceberg partition fields".to_string()
+    }
+}
+
+impl ImpliedValue for Vec<IcebergPartitionField> {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a set of partition fields")
+    }
+}
+
+impl TryFromValue<WithOptionValue<Aug>> for Vec<IcebergSortField> {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::IcebergSortBy(fields) => Ok(fields),
+            _ => sql_bail!("cannot use value as iceberg sort fields"),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<WithOptionValue<Aug>> {
+        Some(WithOptionValue::IcebergSortBy(self))
+    }
+
+    fn name() -> String {
+        "iceberg sort fields".to_string()
+    }
+}
+
+impl ImpliedValue for Vec<IcebergSortField> {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a set of sort fields")
+    }
+}
@@ -40,9 +40,10 @@ use mz_sql_parser::ast::{
     AlterSourceAction, AlterSourceAddSubsourceOptionName, AlterSourceStatement, AvroDocOn,
     ColumnName, CreateMaterializedViewStatement, CreateSinkConnection, CreateSinkOptionName,
     CreateSinkStatement, CreateSourceOptionName, CreateSubsourceOption, CreateSubsourceOptionName,
-    CreateTableFromSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
-    CsrSeedAvro, CsrSeedProtobuf, CsrSeedProtobufSchema, DeferredItemName, DocOnIdentifier,
-    DocOnSchema, Expr, Function, FunctionArgs, GlueAvroOption, GlueAvroSeed, Ident,
+    CreateTableFromSourceStatement, CreateWebhookSourceStatement, CsrConfigOption,
+    CsrConfigOptionName, CsrConnection, CsrSeedAvro, CsrSeedProtobuf, CsrSeedProtobufSchema,
+    DeferredItemName, DocOnIdentifier, DocOnSchema, Expr, Function, FunctionArgs, GlueAvroOption,
+    GlueAvroSeed, IcebergSourceConfigOption, IcebergSourceConfigOptionName, Ident,
     KafkaSourceConfigOption, KafkaSourceConfigOptionName, LoadGenerator, LoadGeneratorOption,
     LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
     MySqlConfigOptionName, PgConfigOption, PgConfigOptionName, RawItemName,
@@ -56,6 +57,7 @@ use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::connections::Connection;
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::errors::ContextCreationError;
+use mz_storage_types::sources::iceberg::{IcebergSourceDetails, IcebergSourceDetailsError};
 use mz_storage_types::sources::load_generator::LoadGeneratorOutput;
 use mz_storage_types::sources::mysql::MySqlSourceDetails;
 use mz_storage_types::sources::postgres::PostgresSourcePublicationDetails;
@@ -75,6 +77,7 @@ use crate::ast::{
     ExternalReferences, Format, FormatSpecifier, ProtobufSchema, Value, WithOptionValue,
 };
 use crate::catalog::{CatalogItemType, SessionCatalog};
+use crate::iceberg::IcebergSourceConfigOptionExtracted;
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
     Aug, FullItemName, PartialItemName, ResolvedColumnReference, ResolvedDataType, ResolvedIds,
@@ -83,7 +86,9 @@ use crate::names::{
 use crate::plan::error::PlanError;
 use crate::plan::statement::ddl::load_generator_ast_to_generator;
 use crate::plan::{SourceReferences, StatementContext};
-use crate::pure::error::{IcebergSinkPurificationError, SqlServerSourcePurificationError};
+use crate::pure::error::{
+    IcebergSinkPurificationError, IcebergSourcePurificationError, SqlServerSourcePurificationError,
+};
 use crate::pure::mysql::{ensure_binlog_full_metadata, is_binlog_full_metadata};
 use crate::{kafka_util, normalize};
 
@@ -237,6 +242,7 @@ pub enum PurifiedStatement {
     PurifiedCreateTableFromSource {
         stmt: CreateTableFromSourceStatement<Aug>,
     },
+    PurifiedCreateWebhookSource(CreateWebhookSourceStatement<Aug>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -267,6 +273,7 @@ pub enum PurifiedExportDetails {
         initial_lsn: mz_sql_server_util::cdc::Lsn,
     },
     Kafka {},
+    Iceberg {},
     LoadGenerator {
         table: Option<RelationDesc>,
         output: LoadGeneratorOutput,
@@ -311,6 +318,13 @@ pub async fn purify_statement(
             purify_create_table_from_source(catalog, stmt, storage_configuration).await,
             None,
         ),
+        Statement::CreateWebhookSource(stmt) => {
+            let cluster_id = stmt.in_cluster.as_ref().map(|cluster| cluster.id.clone());
+            (
+                purify_create_webhook_source(catalog, stmt, storage_configuration).await,
+                cluster_id,
+            )
+        }
         o => (
             Err(internal_err!(
                 "unexpected statement type in purification: {:?}",
@@ -630,6 +644,70 @@ async fn purify_create_sink(
                 .await
                 .map_err(|e| IcebergSinkPurificationError::CatalogError(Arc::new(e)))?;
         }
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            ..
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            // Check this before connecting to the upstream database, rather than
+            // leaving it to planning.
+            scx.require_feature_flag(&crate::session::vars::ENABLE_POSTGRES_SINK)?;
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::Postgres(connection) => {
+                    connection.clone().into_inline_connection(scx.catalog)
+                }
+                _ => sql_bail!(
+                    "{} is not a postgres connection",
+                    scx.catalog.resolve_full_name(connection_item.name())
+                ),
+            };
+            let crate::plan::statement::PostgresSinkConfigOptionExtracted { schema, table, .. } =
+                options.clone().try_into()?;
+            let Some(table) = table else {
+                sql_bail!("POSTGRES sink must specify TABLE");
+            };
+
+            let config = connection
+                .config(
+                    &storage_configuration.connection_context.secrets_reader,
+                    storage_configuration,
+                    InTask::No,
+                )
+                .await?;
+            let client = config
+                .connect(
+                    "postgres_sink_purification",
+                    &storage_configuration.connection_context.ssh_tunnel_manager,
+                )
+                .await?;
+
+            // The sink checks the table's columns itself when it starts, but a
+            // missing table is by far the most common mistake, so report it
+            // while the user is still around to see it.
+            let exists = mz_postgres_util::query_opt(
+                &*client,
+                mz_postgres_util::sql!(
+                    "SELECT 1 FROM information_schema.tables \
+                     WHERE table_schema = $1 AND table_name = $2"
+                ),
+                &[&schema, &table],
+            )
+            .await?;
+            if exists.is_none() {
+                sql_bail!(
+                    "table {} does not exist in the upstream database",
+                    format!("{schema}.{table}").quoted()
+                );
+            }
+        }
+        CreateSinkConnection::Http { .. } => {
+            // We purposefully don't probe the endpoint: any request we could
+            // send might have side effects on the receiving end, and the
+            // endpoint only needs to be reachable once the sink starts
+            // sending updates.
+        }
     }
 
     let mut csr_connection_ids = BTreeSet::new();
@@ -638,8 +716,12 @@ async fn purify_create_sink(
         | Format::Avro(AvroSchema::Glue { .. })
         | Format::Bytes
         | Format::Csv { .. }
-        | Format::Json { .. }
+        | Format::Json {
+            csr_connection: None,
+            ..
+        }
         | Format::Protobuf(ProtobufSchema::InlineSchema { .. })
+        | Format::Protobuf(ProtobufSchema::Glue { .. })
         | Format::Regex(..)
         | Format::Text => (),
         Format::Avro(AvroSchema::Csr {
@@ -647,7 +729,11 @@ async fn purify_create_sink(
         })
         | Format::Protobuf(ProtobufSchema::Csr {
             csr_connection: CsrConnectionProtobuf { connection, .. },
-        }) => {
+        })
+        | Format::Json {
+            csr_connection: Some(connection),
+            ..
+        } => {
             csr_connection_ids.insert(*connection.connection.item_id());
         }
     });
@@ -763,6 +849,9 @@ async fn purify_create_source(
         CreateSourceConnection::MySql { .. } => {
             &mz_storage_types::sources::mysql::MYSQL_PROGRESS_DESC
         }
+        CreateSourceConnection::Iceberg { .. } => {
+            &mz_storage_types::sources::iceberg::ICEBERG_PROGRESS_DESC
+        }
         CreateSourceConnection::LoadGenerator { .. } => {
             &mz_storage_types::sources::load_generator::LOAD_GEN_PROGRESS_DESC
         }
@@ -1115,8 +1204,7 @@ async fn purify_create_source(
             // Retrieve the current @gtid_executed value of the server to mark as the effective
             // initial snapshot point such that we can ensure consistency if the initial source
             // snapshot is broken up over multiple points in time.
-            let initial_gtid_set =
-                mz_mysql_util::query_sys_var(&mut conn, "global.gtid_executed").await?;
+            let initial_gtid_set = mz_mysql_util::query_gtid_executed(&mut conn).await?;
 
             let binlog_full_metadata = is_binlog_full_metadata(&mut conn).await?;
 
@@ -1171,6 +1259,81 @@ async fn purify_create_source(
                     Some(WithOptionValue::Sequence(normalized_exclude_columns));
             }
         }
+        CreateSourceConnection::Iceberg {
+            connection,
+            options,
+        } => {
+            if let Some(external_references) = external_references {
+                Err(IcebergSourcePurificationError::ReferencedSubsources(
+                    external_references.clone(),
+                ))?;
+            }
+
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::IcebergCatalog(connection) => {
+                    connection.clone().into_inline_connection(&catalog)
+                }
+                _ => Err(IcebergSourcePurificationError::NotIcebergConnection(
+                    scx.catalog.resolve_full_name(connection_item.name()),
+                ))?,
+            };
+            let IcebergSourceConfigOptionExtracted {
+                details,
+                namespace,
+                table,
+                seen: _,
+            } = options.clone().try_into()?;
+
+            if details.is_some() {
+                Err(IcebergSourcePurificationError::UserSpecifiedDetails)?;
+            }
+            let namespace =
+                namespace.ok_or(IcebergSourcePurificationError::ConnectionMissingNamespace)?;
+            let table = table.ok_or(IcebergSourcePurificationError::ConnectionMissingTable)?;
+
+            let table_name = format!("{namespace}.{table}");
+            let details =
+                IcebergSourceDetails::fetch(&connection, storage_configuration, &namespace, &table)
+                    .await
+                    .map_err(|e| match e {
+                        IcebergSourceDetailsError::Catalog(e) => {
+                            IcebergSourcePurificationError::CatalogError(
+                                table_name.clone(),
+                                Arc::new(e),
+                            )
+                        }
+                        IcebergSourceDetailsError::UnsupportedFormatVersion(version) => {
+                            IcebergSourcePurificationError::UnsupportedFormatVersion {
+                                table: table_name.clone(),
+                                version,
+                            }
+                        }
+                        IcebergSourceDetailsError::UnsupportedTypes(columns) => {
+                            IcebergSourcePurificationError::UnsupportedTypes(
+                                table_name.clone(),
+                                columns,
+                            )
+                        }
+                    })?;
+
+            let reference_client = SourceReferenceClient::Iceberg {
+                namespace: &namespace,
+                table: &table,
+                desc: &details.desc,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            options.retain(|IcebergSourceConfigOption { name, .. }| {
+                name != &IcebergSourceConfigOptionName::Details
+            });
+            options.push(IcebergSourceConfigOption {
+                name: IcebergSourceConfigOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            });
+        }
         CreateSourceConnection::LoadGenerator { generator, options } => {
             let load_generator =
                 load_generator_ast_to_generator(&scx, generator, options, include_metadata)?;
@@ -1543,8 +1706,7 @@ async fn purify_alter_source_add_subsources(
 
             // Retrieve the current @gtid_executed value of the server to mark as the effective
             // initial snapshot point for these subsources.
-            let initial_gtid_set =
-                mz_mysql_util::query_sys_var(&mut conn, "global.gtid_executed").await?;
+            let initial_gtid_set = mz_mysql_util::query_gtid_executed(&mut conn).await?;
 
             let binlog_full_metadata = is_binlog_full_metadata(&mut conn).await?;
 
@@ -1749,6 +1911,14 @@ async fn purify_alter_source_refresh_references(
             };
             reference_client.get_source_references().await?
         }
+        GenericSourceConnection::Iceberg(iceberg_conn) => {
+            let reference_client = SourceReferenceClient::Iceberg {
+                namespace: &iceberg_conn.namespace,
+                table: &iceberg_conn.table,
+                desc: &iceberg_conn.details.desc,
+            };
+            reference_client.get_source_references().await?
+        }
     };
     Ok(PurifiedStatement::PurifiedAlterSourceRefreshReferences {
         source_name: resolved_source_name,
@@ -1910,8 +2080,7 @@ async fn purify_create_table_from_source(
 
             // Retrieve the current @gtid_executed value of the server to mark as the effective
             // initial snapshot point for this table.
-            let initial_gtid_set =
-                mz_mysql_util::query_sys_var(&mut conn, "global.gtid_executed").await?;
+            let initial_gtid_set = mz_mysql_util::query_gtid_executed(&mut conn).await?;
 
             let reference_client = SourceReferenceClient::MySql {
                 conn: &mut conn,
@@ -2025,6 +2194,23 @@ async fn purify_create_table_from_source(
                 details: PurifiedExportDetails::Kafka {},
             }
         }
+        GenericSourceConnection::Iceberg(iceberg_conn) => {
+            let reference_client = SourceReferenceClient::Iceberg {
+                namespace: &iceberg_conn.namespace,
+                table: &iceberg_conn.table,
+                desc: &iceberg_conn.details.desc,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+            let requested_exports = retrieved_source_references
+                .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
+            // There should be exactly one source_export returned
+            let export = requested_exports.into_element();
+
+            PurifiedSourceExport {
+                external_reference: export.external_reference,
+                details: PurifiedExportDetails::Iceberg {},
+            }
+        }
     };
 
     purify_source_format(
@@ -2250,6 +2436,18 @@ async fn purify_create_table_from_source(
                 )))),
             })
         }
+        PurifiedExportDetails::Iceberg {} => {
+            // NOTE: Iceberg tables take their schema from the details of the source, which were
+            // purified when the source was created. The RelationDesc will be determined during
+            // planning.
+            let details = SourceExportStatementDetails::Iceberg {};
+            with_options.push(TableFromSourceOption {
+                name: TableFromSourceOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
     };
 
     // TODO: We might as well use the retrieved available references to update the source
@@ -2258,9 +2456,43 @@ async fn purify_create_table_from_source(
     Ok(PurifiedStatement::PurifiedCreateTableFromSource { stmt })
 }
 
+/// Equivalent to `purify_create_source`, but for `CreateWebhookSourceStatement`.
+///
+/// Only `BODY FORMAT`s that use a schema registry require purification, which fetches the
+/// schemas registered under the name of the webhook source.
+async fn purify_create_webhook_source(
+    catalog: impl SessionCatalog,
+    mut stmt: CreateWebhookSourceStatement<Aug>,
+    storage_configuration: &StorageConfiguration,
+) -> Result<PurifiedStatement, PlanError> {
+    let name = stmt
+        .name
+        .0
+        .last()
+        .ok_or_else(|| sql_err!("webhook source must have a name"))?;
+    let options = SourceFormatOptions::Webhook {
+        name: name.as_str().to_string(),
+    };
+    purify_source_format_single(
+        &catalog,
+        &mut stmt.body_format,
+        &options,
+        &None,
+        storage_configuration,
+    )
+    .await?;
+    Ok(PurifiedStatement::PurifiedCreateWebhookSource(stmt))
+}
+
 enum SourceFormatOptions {
     Default,
-    Kafka { topic: String },
+    Kafka {
+        topic: String,
+    },
+    /// Webhook sources look up their schemas under the name of the source, in place of a topic.
+    Webhook {
+        name: String,
+    },
 }
 
 async fn purify_source_format(
@@ -2341,7 +2573,18 @@ async fn purify_source_format_single(
                 .await?;
             }
             ProtobufSchema::InlineSchema { .. } => {}
+            ProtobufSchema::Glue { .. } => {
+                sql_bail!(
+                    "FORMAT PROTOBUF USING AWS GLUE SCHEMA REGISTRY is only supported for sinks"
+                )
+            }
         },
+        Format::Json {
+            csr_connection: Some(_),
+            ..
+        } => {
+            sql_bail!("FORMAT JSON USING CONFLUENT SCHEMA REGISTRY is only supported for sinks")
+        }
         Format::Bytes
         | Format::Regex(_)
         | Format::Json { .. }
@@ -2440,6 +2683,12 @@ pub fn generate_subsource_statements(
             }
             vec![]
         }
+        PurifiedExportDetails::Iceberg { .. } => {
+            if !subsources.is_empty() {
+                bail_internal!("Iceberg sources do not produce data-bearing subsources");
+            }
+            vec![]
+        }
     };
     Ok(statements)
 }
@@ -2451,8 +2700,12 @@ async fn purify_csr_connection_proto(
     envelope: &Option<SourceEnvelope>,
     storage_configuration: &StorageConfiguration,
 ) -> Result<(), PlanError> {
-    let SourceFormatOptions::Kafka { topic } = options else {
-        sql_bail!("Confluent Schema Registry is only supported with Kafka sources")
+    let topic = match options {
+        SourceFormatOptions::Kafka { topic } => topic,
+        SourceFormatOptions::Webhook { name } => name,
+        SourceFormatOptions::Default => {
+            sql_bail!("Confluent Schema Registry is only supported with Kafka sources")
+        }
     };
 
     let CsrConnectionProtobuf {
@@ -2500,8 +2753,12 @@ async fn purify_csr_connection_avro(
     envelope: &Option<SourceEnvelope>,
     storage_configuration: &StorageConfiguration,
 ) -> Result<(), PlanError> {
-    let SourceFormatOptions::Kafka { topic } = options else {
-        sql_bail!("Confluent Schema Registry is only supported with Kafka sources")
+    let topic = match options {
+        SourceFormatOptions::Kafka { topic } => topic,
+        SourceFormatOptions::Webhook { name } => name,
+        SourceFormatOptions::Default => {
+            sql_bail!("Confluent Schema Registry is only supported with Kafka sources")
+        }
     };
 
     let CsrConnectionAvro {
@@ -2843,6 +3100,7 @@ pub fn purify_create_materialized_view_options(
                 filter: None,
                 over: None,
                 distinct: false,
+                within_group: false,
             }),
         )
     };
@@ -157,6 +157,54 @@ impl KafkaSourcePurificationError {
     }
 }
 
+/// Logical errors detectable during purification for an ICEBERG SOURCE.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum IcebergSourcePurificationError {
+    #[error("{} is only valid for multi-output sources", .0.to_ast_string_simple())]
+    ReferencedSubsources(ExternalReferences),
+    #[error("ICEBERG CONNECTION without NAMESPACE")]
+    ConnectionMissingNamespace,
+    #[error("ICEBERG CONNECTION without TABLE")]
+    ConnectionMissingTable,
+    #[error("{0} is not an ICEBERG CATALOG CONNECTION")]
+    NotIcebergConnection(FullItemName),
+    #[error("CREATE SOURCE specifies DETAILS option")]
+    UserSpecifiedDetails,
+    #[error("failed to load table {0}")]
+    CatalogError(String, Arc<anyhow::Error>),
+    #[error("table {table} uses unsupported format version {version}")]
+    UnsupportedFormatVersion { table: String, version: String },
+    #[error("table {0} contains columns of unsupported types")]
+    UnsupportedTypes(String, Vec<(String, String)>),
+    #[error("Referenced iceberg source uses a different table '{0}' than specified: '{1}'")]
+    WrongIcebergTable(String, UnresolvedItemName),
+}
+
+impl IcebergSourcePurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::CatalogError(_, e) => Some(e.to_string_with_causes()),
+            Self::UnsupportedTypes(_, columns) => Some(format!(
+                "the following columns have unsupported types: {}",
+                itertools::join(
+                    columns.iter().map(|(name, ty)| format!("{name} ({ty})")),
+                    ", "
+                )
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::UnsupportedFormatVersion { .. } => {
+                Some("Upgrade the table to format version 2 or later.".into())
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Logical errors detectable during purification for a LOAD GENERATOR SOURCE.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum LoadGeneratorSourcePurificationError {
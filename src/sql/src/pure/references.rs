@@ -47,6 +47,11 @@ pub(super) enum SourceReferenceClient<'a> {
     Kafka {
         topic: &'a str,
     },
+    Iceberg {
+        namespace: &'a str,
+        table: &'a str,
+        desc: &'a RelationDesc,
+    },
     LoadGenerator {
         generator: &'a LoadGenerator,
     },
@@ -66,6 +71,11 @@ pub(super) enum ReferenceMetadata {
         capture_instance: Arc<str>,
     },
     Kafka(String),
+    Iceberg {
+        namespace: String,
+        table: String,
+        desc: RelationDesc,
+    },
     LoadGenerator {
         name: String,
         desc: Option<RelationDesc>,
@@ -81,6 +91,7 @@ impl ReferenceMetadata {
             ReferenceMetadata::MySql(table) => Some(&table.schema_name),
             ReferenceMetadata::SqlServer { table, .. } => Some(table.schema_name.as_ref()),
             ReferenceMetadata::Kafka(_) => None,
+            ReferenceMetadata::Iceberg { namespace, .. } => Some(namespace),
             ReferenceMetadata::LoadGenerator { namespace, .. } => Some(namespace),
         }
     }
@@ -91,6 +102,7 @@ impl ReferenceMetadata {
             ReferenceMetadata::MySql(table) => &table.name,
             ReferenceMetadata::SqlServer { table, .. } => table.name.as_ref(),
             ReferenceMetadata::Kafka(topic) => topic,
+            ReferenceMetadata::Iceberg { table, .. } => table,
             ReferenceMetadata::LoadGenerator { name, .. } => name,
         }
     }
@@ -167,6 +179,12 @@ impl ReferenceMetadata {
             ReferenceMetadata::Kafka(topic) => {
                 Ok(UnresolvedItemName::qualified(&[Ident::new(topic)?]))
             }
+            ReferenceMetadata::Iceberg {
+                namespace, table, ..
+            } => Ok(UnresolvedItemName::qualified(&[
+                Ident::new(namespace)?,
+                Ident::new(table)?,
+            ])),
             ReferenceMetadata::LoadGenerator {
                 name, namespace, ..
             } => {
@@ -192,7 +210,7 @@ pub(super) struct RetrievedSourceReferences {
 /// The name of the fake database used to fit references into the 3-layer catalog
 /// model of the [`SourceReferenceResolver`] for source types whose
 /// [`ReferenceMetadata::external_reference`] stores no database component
-/// (MySQL and Kafka). Because those references are never fully qualified with a
+/// (MySQL, Kafka, and Iceberg). Because those references are never fully qualified with a
 /// database, the resolver's database name is never matched against and this
 /// placeholder is never stored in the catalog.
 ///
@@ -308,6 +326,17 @@ impl<'a> SourceReferenceClient<'a> {
             SourceReferenceClient::Kafka { topic } => {
                 vec![ReferenceMetadata::Kafka(topic.to_string())]
             }
+            SourceReferenceClient::Iceberg {
+                namespace,
+                table,
+                desc,
+            } => {
+                vec![ReferenceMetadata::Iceberg {
+                    namespace: namespace.to_string(),
+                    table: table.to_string(),
+                    desc: desc.clone(),
+                }]
+            }
             SourceReferenceClient::LoadGenerator { generator } => {
                 let mut references = generator
                     .views()
@@ -350,8 +379,8 @@ impl<'a> SourceReferenceClient<'a> {
         // fully-qualified reference we store (and print in `SHOW CREATE TABLE`)
         // won't resolve when fed back in. Postgres and SQL Server store the real
         // upstream database; load generators store the synthetic
-        // `mz_load_generators` database. MySQL and Kafka store no database
-        // component, so the resolver's database is never matched against and the
+        // `mz_load_generators` database. MySQL, Kafka, and Iceberg store no
+        // database component, so the resolver's database is never matched against and the
         // fake name is fine.
         let resolver = match self {
             SourceReferenceClient::Postgres { database, .. } => {
@@ -363,7 +392,9 @@ impl<'a> SourceReferenceClient<'a> {
             SourceReferenceClient::LoadGenerator { .. } => {
                 SourceReferenceResolver::new(LOAD_GENERATOR_DATABASE_NAME, &reference_names)
             }
-            SourceReferenceClient::MySql { .. } | SourceReferenceClient::Kafka { .. } => {
+            SourceReferenceClient::MySql { .. }
+            | SourceReferenceClient::Kafka { .. }
+            | SourceReferenceClient::Iceberg { .. } => {
                 SourceReferenceResolver::new(DATABASE_FAKE_NAME, &reference_names)
             }
         }?;
@@ -414,6 +445,15 @@ impl RetrievedSourceReferences {
                         namespace: None,
                         columns: vec![],
                     },
+                    ReferenceMetadata::Iceberg {
+                        namespace,
+                        table,
+                        desc,
+                    } => SourceReference {
+                        name: table,
+                        namespace: Some(namespace),
+                        columns: desc.iter_names().map(|n| n.to_string()).collect(),
+                    },
                     ReferenceMetadata::LoadGenerator {
                         name,
                         desc,
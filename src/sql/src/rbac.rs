@@ -890,6 +890,18 @@ fn generate_rbac_requirements(
             when: _,
             finishing: _,
             copy_to: _,
+        })
+        | Plan::ExplainAnalyzeSelect(plan::ExplainAnalyzeSelectPlan {
+            select:
+                plan::SelectPlan {
+                    source,
+                    select: _,
+                    when: _,
+                    finishing: _,
+                    copy_to: _,
+                },
+            properties: _,
+            desc: _,
         }) => {
             let items = source
                 .depends_on()
@@ -2235,6 +2235,36 @@ feature_flags!(
         // already-created metric sink unparseable and take the whole catalog down with it.
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_iceberg_source,
+        desc: "CREATE SOURCE ... FROM ICEBERG",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_http_sink,
+        desc: "CREATE SINK ... INTO HTTP",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_postgres_sink,
+        desc: "CREATE SINK ... INTO POSTGRES",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_postgres_source_retract_on_truncate,
+        desc: "RETRACT ON TRUNCATE option for PostgreSQL source tables",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_webhook_body_decoding,
+        desc: "AVRO, PROTOBUF, and JSON with declared columns as the BODY FORMAT of webhook sources",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_unlimited_retain_history,
         desc: "Disable limits on RETAIN HISTORY (below 1s default, and 0 disables compaction).",
@@ -1404,6 +1404,7 @@ impl<'a> RunnerInner<'a> {
             helm_chart_version: None,
             license_key: ValidatedLicenseKey::for_tests(),
             external_login_password_mz_system: None,
+            ldap_search_bind_password: None,
             force_builtin_schema_migration: None,
         };
         // We need to run the server on its own Tokio runtime, which in turn
@@ -305,8 +305,8 @@ pub fn glue_compatibility_from_csr(level: mz_ccsr::CompatibilityLevel) -> GlueCo
     }
 }
 
-/// Register `schema` for a sink in an AWS Glue Schema Registry, returning the
-/// schema-version UUID to frame records with.
+/// Register `schema`, written in `data_format`, for a sink in an AWS Glue
+/// Schema Registry, returning the schema-version UUID to frame records with.
 ///
 /// Reuses an already-registered definition so a sink restart does not create a
 /// duplicate version. On first publish the schema is created with
@@ -320,6 +320,7 @@ pub async fn publish_glue_schema(
     registry_name: String,
     schema_name: String,
     schema: String,
+    data_format: DataFormat,
     compatibility: Option<GlueCompatibility>,
 ) -> Result<Uuid, anyhow::Error> {
     async move {
@@ -376,7 +377,7 @@ pub async fn publish_glue_schema(
                     .create_schema(
                         &registry_name,
                         &schema_name,
-                        DataFormat::Avro,
+                        data_format,
                         compatibility,
                         &schema,
                     )
@@ -522,6 +523,7 @@ mod tests {
             "registry".to_string(),
             "schema".to_string(),
             "{}".to_string(),
+            DataFormat::Avro,
             None,
         )
         .await
@@ -1999,9 +1999,9 @@ impl StorageCollections for StorageCollectionsImpl {
                         Ingestion(ingestion) => {
                             use GenericSourceConnection::*;
                             match ingestion.desc.connection {
-                                // Kafka, Postgres, MySql, and SQL Server sources all
-                                // follow wall clock.
-                                Kafka(_) | Postgres(_) | MySql(_) | SqlServer(_) => {
+                                // Kafka, Postgres, MySql, SQL Server, and Iceberg
+                                // sources all follow wall clock.
+                                Kafka(_) | Postgres(_) | MySql(_) | SqlServer(_) | Iceberg(_) => {
                                     Some(TimeDependence::default())
                                 }
                                 // Load generators not further specified.
@@ -2572,7 +2572,8 @@ impl StorageController for Controller {
                     GenericSourceConnection::Kafka(_)
                     | GenericSourceConnection::Postgres(_)
                     | GenericSourceConnection::MySql(_)
-                    | GenericSourceConnection::SqlServer(_) => {
+                    | GenericSourceConnection::SqlServer(_)
+                    | GenericSourceConnection::Iceberg(_) => {
                         (connection.clone(), *remap_collection_id)
                     }
 
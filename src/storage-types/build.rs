@@ -18,10 +18,12 @@ fn main() {
         .extern_path(".mz_mysql_util", "::mz_mysql_util")
         .extern_path(".mz_sql_server_util", "::mz_sql_server_util")
         .extern_path(".mz_repr.row", "::mz_repr")
+        .extern_path(".mz_repr.relation_and_scalar", "::mz_repr")
         .compile_protos(
             &[
                 "storage-types/src/errors.proto",
                 "storage-types/src/sources.proto",
+                "storage-types/src/sources/iceberg.proto",
                 "storage-types/src/sources/kafka.proto",
                 "storage-types/src/sources/mysql.proto",
                 "storage-types/src/sources/postgres.proto",
@@ -197,6 +197,23 @@ pub const KAFKA_SINK_BATCH_NUM_MESSAGES: Config<usize> = Config::new(
     "Sets batch.num.messages in librdkafka for Kafka sink producers.",
 );
 
+// HTTP
+
+/// The timeout for a single request made by an HTTP sink.
+pub const HTTP_SINK_REQUEST_TIMEOUT: Config<Duration> = Config::new(
+    "http_sink_request_timeout",
+    Duration::from_secs(30),
+    "The timeout for a single request made by an HTTP sink.",
+);
+
+/// How long an HTTP sink retries a failing request before reporting an error
+/// and restarting.
+pub const HTTP_SINK_RETRY_MAX_DURATION: Config<Duration> = Config::new(
+    "http_sink_retry_max_duration",
+    Duration::from_secs(300),
+    "How long an HTTP sink retries a failing request before reporting an error and restarting.",
+);
+
 // MySQL
 
 /// Replication heartbeat interval requested from the MySQL server.
@@ -269,6 +286,26 @@ pub static PG_SOURCE_VALIDATE_TIMELINE: Config<bool> = Config::new(
     "Whether to treat a timeline switch as a definite error",
 );
 
+/// Whether the PostgreSQL source asks the upstream to stream large in-progress transactions
+/// (protocol version 2 and later) instead of decoding them in full before sending them. Streamed
+/// transactions are staged in the source and only emitted once they commit.
+pub static PG_SOURCE_STREAM_IN_PROGRESS_TRANSACTIONS: Config<bool> = Config::new(
+    "pg_source_stream_in_progress_transactions",
+    true,
+    "Whether to request streaming of in-progress transactions from PostgreSQL 14 and later",
+);
+
+/// The maximum number of bytes of in-progress transactions that the PostgreSQL source stages in
+/// memory while they are being streamed by the upstream. Exceeding it is a transient error; to
+/// ingest larger transactions either raise the limit or turn off
+/// [`PG_SOURCE_STREAM_IN_PROGRESS_TRANSACTIONS`] so that the upstream spills them to its own disk.
+pub static PG_SOURCE_STREAMED_TRANSACTIONS_MAX_BYTES: Config<usize> = Config::new(
+    "pg_source_streamed_transactions_max_bytes",
+    1 << 30,
+    "The maximum number of bytes of streamed in-progress transactions a PostgreSQL source stages \
+        in memory.",
+);
+
 /// Controls behavior of the SQL Server source when the upstream DB restore history changes. The
 /// default behavior is to emit a definite error, forcing source recreation.  In cases of Always
 /// On Availability Group (AOAG), the upstream DB may guarantee continuity without loss of data.
@@ -441,6 +478,8 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&CLUSTER_SHUTDOWN_GRACE_PERIOD)
         .add(&DELAY_SOURCES_PAST_REHYDRATION)
         .add(&ENFORCE_EXTERNAL_ADDRESSES)
+        .add(&HTTP_SINK_REQUEST_TIMEOUT)
+        .add(&HTTP_SINK_RETRY_MAX_DURATION)
         .add(&KAFKA_BUFFERED_EVENT_RESIZE_THRESHOLD_ELEMENTS)
         .add(&KAFKA_CLIENT_ID_ENRICHMENT_RULES)
         .add(&KAFKA_DEFAULT_AWS_PRIVATELINK_ENDPOINT_IDENTIFICATION_ALGORITHM)
@@ -461,6 +500,8 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&ORE_OVERFLOWING_BEHAVIOR)
         .add(&PG_FETCH_SLOT_RESUME_LSN_INTERVAL)
         .add(&PG_SCHEMA_VALIDATION_INTERVAL)
+        .add(&PG_SOURCE_STREAM_IN_PROGRESS_TRANSACTIONS)
+        .add(&PG_SOURCE_STREAMED_TRANSACTIONS_MAX_BYTES)
         .add(&PG_SOURCE_VALIDATE_TIMELINE)
         .add(&REPLICA_METRICS_HISTORY_RETENTION_INTERVAL)
         .add(&SINK_ENSURE_TOPIC_CONFIG)
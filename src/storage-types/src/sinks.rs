@@ -10,6 +10,7 @@
 //! Types and traits related to reporting changing collections out of `dataflow`.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::time::Duration;
 
@@ -23,12 +24,14 @@ use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use timely::PartialOrder;
 use timely::progress::frontier::Antichain;
+use url::Url;
 
 use crate::AlterCompatible;
 use crate::connections::inline::{
     ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
     ReferencedConnection,
 };
+use crate::connections::string_or_secret::StringOrSecret;
 use crate::connections::{ConnectionContext, KafkaConnection, KafkaTopicOptions};
 use crate::controller::AlterError;
 use crate::wire_format::WireFormat;
@@ -126,10 +129,14 @@ impl<S: Debug + PartialEq, T: Debug + PartialEq + PartialOrder> AlterCompatible
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum SinkEnvelope {
-    /// Only used for Kafka.
+    /// Writes each update as a `before`/`after` pair. Used by Kafka and HTTP sinks.
     Debezium,
+    /// Mirrors the current contents of the sinked relation, keyed by the sink's key. Kafka sinks
+    /// emit a tombstone for each retracted key, and Iceberg sinks (`MODE UPSERT`) write equality
+    /// and position deletes.
     Upsert,
-    /// Only used for Iceberg.
+    /// Writes the changelog of the sinked relation as append-only rows, with the diff and
+    /// timestamp of each update in extra columns. Only used for Iceberg (`MODE APPEND`).
     Append,
 }
 
@@ -137,6 +144,8 @@ pub enum SinkEnvelope {
 pub enum StorageSinkConnection<C: ConnectionAccess = InlinedConnection> {
     Kafka(KafkaSinkConnection<C>),
     Iceberg(IcebergSinkConnection<C>),
+    Http(HttpSinkConnection),
+    Postgres(PostgresSinkConnection<C>),
 }
 
 impl<C: ConnectionAccess> StorageSinkConnection<C> {
@@ -159,6 +168,12 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
             (StorageSinkConnection::Iceberg(s), StorageSinkConnection::Iceberg(o)) => {
                 s.alter_compatible(id, o)?
             }
+            (StorageSinkConnection::Http(s), StorageSinkConnection::Http(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::Postgres(s), StorageSinkConnection::Postgres(o)) => {
+                s.alter_compatible(id, o)?
+            }
             _ => {
                 tracing::warn!(
                     "StorageSinkConnection incompatible:\nself:\n{:#?}\n\nother\n{:#?}",
@@ -180,6 +195,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnection, R>
         match self {
             Self::Kafka(conn) => StorageSinkConnection::Kafka(conn.into_inline_connection(r)),
             Self::Iceberg(conn) => StorageSinkConnection::Iceberg(conn.into_inline_connection(r)),
+            Self::Http(conn) => StorageSinkConnection::Http(conn),
+            Self::Postgres(conn) => StorageSinkConnection::Postgres(conn.into_inline_connection(r)),
         }
     }
 }
@@ -197,6 +214,8 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
                 catalog_connection_id: connection_id,
                 ..
             }) => Some(*connection_id),
+            Http(_) => None,
+            Postgres(PostgresSinkConnection { connection_id, .. }) => Some(*connection_id),
         }
     }
 
@@ -209,6 +228,8 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
         match self {
             Kafka(_) => "kafka",
             Iceberg(_) => "iceberg",
+            Http(_) => "http",
+            Postgres(_) => "postgres",
         }
     }
 }
@@ -474,7 +495,27 @@ pub enum KafkaSinkFormatType<C: ConnectionAccess = InlinedConnection> {
         /// require a registry
         wire_format: WireFormat<C>,
     },
+    Protobuf {
+        /// The `.proto` schema to register, derived from the sink's relation.
+        schema: String,
+        /// The name of the top-level message in `schema` that records are
+        /// encoded as.
+        message_name: String,
+        compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+        /// The registry schema name to publish under. See the `Avro` variant.
+        schema_name: Option<String>,
+        /// Wire-format dispatch and the registry to publish to. Sinks
+        /// require a registry.
+        wire_format: WireFormat<C>,
+    },
     Json,
+    /// JSON framed for and registered with a Confluent schema registry.
+    JsonSchema {
+        /// The JSON Schema to register, derived from the sink's relation.
+        schema: String,
+        compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+        csr_connection: C::Csr,
+    },
     Text,
     Bytes,
 }
@@ -483,7 +524,8 @@ impl<C: ConnectionAccess> KafkaSinkFormatType<C> {
     pub fn get_format_name(&self) -> &str {
         match self {
             Self::Avro { .. } => "avro",
-            Self::Json => "json",
+            Self::Protobuf { .. } => "protobuf",
+            Self::Json | Self::JsonSchema { .. } => "json",
             Self::Text => "text",
             Self::Bytes => "bytes",
         }
@@ -495,7 +537,7 @@ impl<C: ConnectionAccess> KafkaSinkFormat<C> {
     /// `parse_catalog_create_sql`. Change both or they drift.
     pub fn get_format_name<'a>(&'a self) -> Cow<'a, str> {
         // For legacy reasons, if the key-format is none or the key & value formats are
-        // both the same (either avro or json), we return the value format name,
+        // both the same (avro, protobuf, or json), we return the value format name,
         // otherwise we return a composite name.
         match &self.key_format {
             None => self.value_format.get_format_name().into(),
@@ -503,7 +545,13 @@ impl<C: ConnectionAccess> KafkaSinkFormat<C> {
                 (KafkaSinkFormatType::Avro { .. }, KafkaSinkFormatType::Avro { .. }) => {
                     "avro".into()
                 }
-                (KafkaSinkFormatType::Json, KafkaSinkFormatType::Json) => "json".into(),
+                (KafkaSinkFormatType::Protobuf { .. }, KafkaSinkFormatType::Protobuf { .. }) => {
+                    "protobuf".into()
+                }
+                (
+                    KafkaSinkFormatType::Json | KafkaSinkFormatType::JsonSchema { .. },
+                    KafkaSinkFormatType::Json | KafkaSinkFormatType::JsonSchema { .. },
+                ) => "json".into(),
                 (keyf, valuef) => format!(
                     "key-{}-value-{}",
                     keyf.get_format_name(),
@@ -547,6 +595,62 @@ impl<C: ConnectionAccess> KafkaSinkFormat<C> {
                     return Err(AlterError { id });
                 }
             }
+            (
+                KafkaSinkFormatType::Protobuf {
+                    schema,
+                    message_name,
+                    compatibility_level: _,
+                    schema_name,
+                    wire_format,
+                },
+                KafkaSinkFormatType::Protobuf {
+                    schema: other_schema,
+                    message_name: other_message_name,
+                    compatibility_level: _,
+                    schema_name: other_schema_name,
+                    wire_format: other_wire_format,
+                },
+            ) => {
+                if schema != other_schema
+                    || message_name != other_message_name
+                    || schema_name != other_schema_name
+                    || wire_format.alter_compatible(id, other_wire_format).is_err()
+                {
+                    tracing::warn!(
+                        "KafkaSinkFormat::Protobuf incompatible at value_format:\nself:\n{:#?}\n\nother\n{:#?}",
+                        self,
+                        other
+                    );
+
+                    return Err(AlterError { id });
+                }
+            }
+            (
+                KafkaSinkFormatType::JsonSchema {
+                    schema,
+                    compatibility_level: _,
+                    csr_connection,
+                },
+                KafkaSinkFormatType::JsonSchema {
+                    schema: other_schema,
+                    compatibility_level: _,
+                    csr_connection: other_csr_connection,
+                },
+            ) => {
+                if schema != other_schema
+                    || csr_connection
+                        .alter_compatible(id, other_csr_connection)
+                        .is_err()
+                {
+                    tracing::warn!(
+                        "KafkaSinkFormat::JsonSchema incompatible at value_format:\nself:\n{:#?}\n\nother\n{:#?}",
+                        self,
+                        other
+                    );
+
+                    return Err(AlterError { id });
+                }
+            }
             (s, o) => {
                 if s != o {
                     tracing::warn!(
@@ -587,6 +691,62 @@ impl<C: ConnectionAccess> KafkaSinkFormat<C> {
                     return Err(AlterError { id });
                 }
             }
+            (
+                Some(KafkaSinkFormatType::Protobuf {
+                    schema,
+                    message_name,
+                    compatibility_level: _,
+                    schema_name,
+                    wire_format,
+                }),
+                Some(KafkaSinkFormatType::Protobuf {
+                    schema: other_schema,
+                    message_name: other_message_name,
+                    compatibility_level: _,
+                    schema_name: other_schema_name,
+                    wire_format: other_wire_format,
+                }),
+            ) => {
+                if schema != other_schema
+                    || message_name != other_message_name
+                    || schema_name != other_schema_name
+                    || wire_format.alter_compatible(id, other_wire_format).is_err()
+                {
+                    tracing::warn!(
+                        "KafkaSinkFormat::Protobuf incompatible at key_format:\nself:\n{:#?}\n\nother\n{:#?}",
+                        self,
+                        other
+                    );
+
+                    return Err(AlterError { id });
+                }
+            }
+            (
+                Some(KafkaSinkFormatType::JsonSchema {
+                    schema,
+                    compatibility_level: _,
+                    csr_connection,
+                }),
+                Some(KafkaSinkFormatType::JsonSchema {
+                    schema: other_schema,
+                    compatibility_level: _,
+                    csr_connection: other_csr_connection,
+                }),
+            ) => {
+                if schema != other_schema
+                    || csr_connection
+                        .alter_compatible(id, other_csr_connection)
+                        .is_err()
+                {
+                    tracing::warn!(
+                        "KafkaSinkFormat::JsonSchema incompatible at key_format:\nself:\n{:#?}\n\nother\n{:#?}",
+                        self,
+                        other
+                    );
+
+                    return Err(AlterError { id });
+                }
+            }
             (s, o) => {
                 if s != o {
                     tracing::warn!(
@@ -630,7 +790,29 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkFormatType, R>
                 schema_name,
                 wire_format: wire_format.into_inline_connection(r),
             },
+            KafkaSinkFormatType::Protobuf {
+                schema,
+                message_name,
+                compatibility_level,
+                schema_name,
+                wire_format,
+            } => KafkaSinkFormatType::Protobuf {
+                schema,
+                message_name,
+                compatibility_level,
+                schema_name,
+                wire_format: wire_format.into_inline_connection(r),
+            },
             KafkaSinkFormatType::Json => KafkaSinkFormatType::Json,
+            KafkaSinkFormatType::JsonSchema {
+                schema,
+                compatibility_level,
+                csr_connection,
+            } => KafkaSinkFormatType::JsonSchema {
+                schema,
+                compatibility_level,
+                csr_connection: r.resolve_connection(csr_connection).unwrap_csr(),
+            },
             KafkaSinkFormatType::Text => KafkaSinkFormatType::Text,
             KafkaSinkFormatType::Bytes => KafkaSinkFormatType::Bytes,
         }
@@ -728,6 +910,42 @@ pub struct IcebergSinkConnection<C: ConnectionAccess = InlinedConnection> {
     pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
     pub namespace: String,
     pub table: String,
+    /// The partition spec of the table, from the sink's `PARTITION BY` option.
+    pub partition_by: Vec<IcebergSinkPartitionField>,
+    /// The sort order of the table, from the sink's `SORT BY` option.
+    pub sort_by: Vec<IcebergSinkSortField>,
+}
+
+/// A transform that derives an Iceberg partition value from a column.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "proptest"), derive(Arbitrary))]
+pub enum IcebergPartitionTransform {
+    Identity,
+    /// Hash the value into the given number of buckets.
+    Bucket(u32),
+    /// Truncate the value to the given width.
+    Truncate(u32),
+    Day,
+    Hour,
+}
+
+/// A field of the partition spec of an Iceberg sink.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "proptest"), derive(Arbitrary))]
+pub struct IcebergSinkPartitionField {
+    /// The index of the partitioned column in the sinked relation.
+    pub column: usize,
+    pub transform: IcebergPartitionTransform,
+}
+
+/// A field of the sort order of an Iceberg sink.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "proptest"), derive(Arbitrary))]
+pub struct IcebergSinkSortField {
+    /// The index of the sorted column in the sinked relation.
+    pub column: usize,
+    pub descending: bool,
+    pub nulls_first: bool,
 }
 
 impl<C: ConnectionAccess> IcebergSinkConnection<C> {
@@ -748,6 +966,8 @@ impl<C: ConnectionAccess> IcebergSinkConnection<C> {
             key_desc_and_indices,
             namespace,
             table,
+            partition_by,
+            sort_by,
         } = self;
 
         let compatibility_checks = [
@@ -790,6 +1010,8 @@ impl<C: ConnectionAccess> IcebergSinkConnection<C> {
             ),
             (namespace == &other.namespace, "namespace"),
             (table == &other.table, "table"),
+            (partition_by == &other.partition_by, "partition_by"),
+            (sort_by == &other.sort_by, "sort_by"),
         ];
         for (compatible, field) in compatibility_checks {
             if !compatible {
@@ -820,6 +1042,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<IcebergSinkConnection, R>
             key_desc_and_indices,
             namespace,
             table,
+            partition_by,
+            sort_by,
         } = self;
         IcebergSinkConnection {
             catalog_connection_id,
@@ -832,6 +1056,169 @@ impl<R: ConnectionResolver> IntoInlineConnection<IcebergSinkConnection, R>
             key_desc_and_indices,
             namespace,
             table,
+            partition_by,
+            sort_by,
+        }
+    }
+}
+
+/// A sink that keeps a table in a PostgreSQL database in sync with the sinked
+/// relation by applying its upserts and deletes.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: CatalogItemId,
+    pub connection: C::Pg,
+    /// The schema of the target table.
+    pub schema: String,
+    /// The name of the target table.
+    pub table: String,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink, which identifies rows in the
+    /// target table.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub value_desc: RelationDesc,
+}
+
+impl<C: ConnectionAccess> PostgresSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let PostgresSinkConnection {
+            connection_id,
+            connection,
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (schema == &other.schema, "schema"),
+            (table == &other.table, "table"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "PostgresSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<PostgresSinkConnection, R>
+    for PostgresSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> PostgresSinkConnection {
+        let PostgresSinkConnection {
+            connection_id,
+            connection,
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        } = self;
+        PostgresSinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_pg(),
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        }
+    }
+}
+
+/// A sink that `POST`s batches of updates, encoded as JSON, to an HTTP
+/// endpoint.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HttpSinkConnection {
+    /// The URL to which requests are sent.
+    pub url: Url,
+    /// Additional headers to send with each request.
+    pub headers: BTreeMap<String, StringOrSecret>,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub value_desc: RelationDesc,
+    /// The maximum number of updates to send in a single request.
+    pub batch_size: u64,
+}
+
+impl HttpSinkConnection {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
         }
+        let HttpSinkConnection {
+            url,
+            headers,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+            batch_size,
+        } = self;
+
+        let compatibility_checks = [
+            (url == &other.url, "url"),
+            (headers == &other.headers, "headers"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+            (batch_size == &other.batch_size, "batch_size"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "HttpSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
     }
 }
@@ -21,6 +21,7 @@ use arrow::array::{Array, ArrayRef, BinaryArray, BinaryBuilder, NullArray, Struc
 use arrow::datatypes::{Field, Fields};
 use bytes::{BufMut, Bytes};
 use columnation::Columnation;
+use iceberg::IcebergSourceExportDetails;
 use itertools::EitherOrBoth::Both;
 use itertools::Itertools;
 use kafka::KafkaSourceExportDetails;
@@ -64,6 +65,7 @@ use crate::sources::sql_server::SqlServerSourceExportDetails;
 pub mod casts;
 pub mod encoding;
 pub mod envelope;
+pub mod iceberg;
 pub mod kafka;
 pub mod load_generator;
 pub mod mysql;
@@ -71,6 +73,7 @@ pub mod postgres;
 pub mod sql_server;
 
 pub use crate::sources::envelope::SourceEnvelope;
+pub use crate::sources::iceberg::IcebergSourceConnection;
 pub use crate::sources::kafka::KafkaSourceConnection;
 pub use crate::sources::load_generator::LoadGeneratorSourceConnection;
 pub use crate::sources::mysql::{MySqlSourceConnection, MySqlSourceExportDetails};
@@ -622,6 +625,8 @@ impl<C: ConnectionAccess> SourceExportDataConfig<C> {
                     GenericSourceConnection::MySql(_) => false,
                     // SQL Server can produce retractions (deletes).
                     GenericSourceConnection::SqlServer(_) => false,
+                    // Iceberg can produce retractions (deletes and overwrites).
+                    GenericSourceConnection::Iceberg(_) => false,
                     // Whether or not a Loadgen source can produce retractions varies.
                     GenericSourceConnection::LoadGenerator(g) => g.load_generator.is_monotonic(),
                     // Kafka exports with `None` envelope are append-only.
@@ -696,6 +701,7 @@ pub enum GenericSourceConnection<C: ConnectionAccess = InlinedConnection> {
     Postgres(PostgresSourceConnection<C>),
     MySql(MySqlSourceConnection<C>),
     SqlServer(SqlServerSourceConnection<C>),
+    Iceberg(IcebergSourceConnection<C>),
     LoadGenerator(LoadGeneratorSourceConnection),
 }
 
@@ -723,6 +729,12 @@ impl<C: ConnectionAccess> From<SqlServerSourceConnection<C>> for GenericSourceCo
     }
 }
 
+impl<C: ConnectionAccess> From<IcebergSourceConnection<C>> for GenericSourceConnection<C> {
+    fn from(conn: IcebergSourceConnection<C>) -> Self {
+        Self::Iceberg(conn)
+    }
+}
+
 impl<C: ConnectionAccess> From<LoadGeneratorSourceConnection> for GenericSourceConnection<C> {
     fn from(conn: LoadGeneratorSourceConnection) -> Self {
         Self::LoadGenerator(conn)
@@ -746,6 +758,9 @@ impl<R: ConnectionResolver> IntoInlineConnection<GenericSourceConnection, R>
             GenericSourceConnection::SqlServer(sql_server) => {
                 GenericSourceConnection::SqlServer(sql_server.into_inline_connection(r))
             }
+            GenericSourceConnection::Iceberg(iceberg) => {
+                GenericSourceConnection::Iceberg(iceberg.into_inline_connection(r))
+            }
             GenericSourceConnection::LoadGenerator(lg) => {
                 GenericSourceConnection::LoadGenerator(lg)
             }
@@ -760,6 +775,7 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
             Self::Postgres(conn) => conn.name(),
             Self::MySql(conn) => conn.name(),
             Self::SqlServer(conn) => conn.name(),
+            Self::Iceberg(conn) => conn.name(),
             Self::LoadGenerator(conn) => conn.name(),
         }
     }
@@ -770,6 +786,7 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
             Self::Postgres(conn) => conn.external_reference(),
             Self::MySql(conn) => conn.external_reference(),
             Self::SqlServer(conn) => conn.external_reference(),
+            Self::Iceberg(conn) => conn.external_reference(),
             Self::LoadGenerator(conn) => conn.external_reference(),
         }
     }
@@ -780,6 +797,7 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
             Self::Postgres(conn) => conn.default_key_desc(),
             Self::MySql(conn) => conn.default_key_desc(),
             Self::SqlServer(conn) => conn.default_key_desc(),
+            Self::Iceberg(conn) => conn.default_key_desc(),
             Self::LoadGenerator(conn) => conn.default_key_desc(),
         }
     }
@@ -790,6 +808,7 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
             Self::Postgres(conn) => conn.default_value_desc(),
             Self::MySql(conn) => conn.default_value_desc(),
             Self::SqlServer(conn) => conn.default_value_desc(),
+            Self::Iceberg(conn) => conn.default_value_desc(),
             Self::LoadGenerator(conn) => conn.default_value_desc(),
         }
     }
@@ -800,6 +819,7 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
             Self::Postgres(conn) => conn.timestamp_desc(),
             Self::MySql(conn) => conn.timestamp_desc(),
             Self::SqlServer(conn) => conn.timestamp_desc(),
+            Self::Iceberg(conn) => conn.timestamp_desc(),
             Self::LoadGenerator(conn) => conn.timestamp_desc(),
         }
     }
@@ -810,6 +830,7 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
             Self::Postgres(conn) => conn.connection_id(),
             Self::MySql(conn) => conn.connection_id(),
             Self::SqlServer(conn) => conn.connection_id(),
+            Self::Iceberg(conn) => conn.connection_id(),
             Self::LoadGenerator(conn) => conn.connection_id(),
         }
     }
@@ -820,6 +841,7 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
             GenericSourceConnection::Postgres(conn) => conn.supports_read_only(),
             GenericSourceConnection::MySql(conn) => conn.supports_read_only(),
             GenericSourceConnection::SqlServer(conn) => conn.supports_read_only(),
+            GenericSourceConnection::Iceberg(conn) => conn.supports_read_only(),
             GenericSourceConnection::LoadGenerator(conn) => conn.supports_read_only(),
         }
     }
@@ -830,6 +852,7 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
             GenericSourceConnection::Postgres(conn) => conn.prefers_single_replica(),
             GenericSourceConnection::MySql(conn) => conn.prefers_single_replica(),
             GenericSourceConnection::SqlServer(conn) => conn.prefers_single_replica(),
+            GenericSourceConnection::Iceberg(conn) => conn.prefers_single_replica(),
             GenericSourceConnection::LoadGenerator(conn) => conn.prefers_single_replica(),
         }
     }
@@ -844,6 +867,7 @@ impl<C: ConnectionAccess> crate::AlterCompatible for GenericSourceConnection<C>
             (Self::Postgres(conn), Self::Postgres(other)) => conn.alter_compatible(id, other),
             (Self::MySql(conn), Self::MySql(other)) => conn.alter_compatible(id, other),
             (Self::SqlServer(conn), Self::SqlServer(other)) => conn.alter_compatible(id, other),
+            (Self::Iceberg(conn), Self::Iceberg(other)) => conn.alter_compatible(id, other),
             (Self::LoadGenerator(conn), Self::LoadGenerator(other)) => {
                 conn.alter_compatible(id, other)
             }
@@ -873,6 +897,7 @@ pub enum SourceExportDetails {
     Postgres(PostgresSourceExportDetails),
     MySql(MySqlSourceExportDetails),
     SqlServer(SqlServerSourceExportDetails),
+    Iceberg(IcebergSourceExportDetails),
     LoadGenerator(LoadGeneratorSourceExportDetails),
 }
 
@@ -887,6 +912,7 @@ impl crate::AlterCompatible for SourceExportDetails {
             (Self::Postgres(s), Self::Postgres(o)) => s.alter_compatible(id, o),
             (Self::MySql(s), Self::MySql(o)) => s.alter_compatible(id, o),
             (Self::SqlServer(s), Self::SqlServer(o)) => s.alter_compatible(id, o),
+            (Self::Iceberg(s), Self::Iceberg(o)) => s.alter_compatible(id, o),
             (Self::LoadGenerator(s), Self::LoadGenerator(o)) => s.alter_compatible(id, o),
             _ => Err(AlterError { id }),
         };
@@ -933,6 +959,7 @@ pub enum SourceExportStatementDetails {
         output: LoadGeneratorOutput,
     },
     Kafka {},
+    Iceberg {},
 }
 
 impl RustType<ProtoSourceExportStatementDetails> for SourceExportStatementDetails {
@@ -989,6 +1016,11 @@ impl RustType<ProtoSourceExportStatementDetails> for SourceExportStatementDetail
                     kafka::ProtoKafkaSourceExportStatementDetails {},
                 )),
             },
+            SourceExportStatementDetails::Iceberg {} => ProtoSourceExportStatementDetails {
+                kind: Some(proto_source_export_statement_details::Kind::Iceberg(
+                    iceberg::ProtoIcebergSourceExportStatementDetails {},
+                )),
+            },
         }
     }
 
@@ -1023,6 +1055,7 @@ impl RustType<ProtoSourceExportStatementDetails> for SourceExportStatementDetail
                     .into_rust_if_some("ProtoLoadGeneratorSourceExportStatementDetails::output")?,
             },
             Some(Kind::Kafka(_details)) => SourceExportStatementDetails::Kafka {},
+            Some(Kind::Iceberg(_details)) => SourceExportStatementDetails::Iceberg {},
             None => {
                 return Err(TryFromProtoError::missing_field(
                     "ProtoSourceExportStatementDetails::kind",
@@ -12,16 +12,19 @@
 use anyhow::Context;
 use itertools::Itertools;
 use mz_interchange::{avro, protobuf};
+use mz_ore::future::InTask;
 use mz_repr::{Datum, GlobalId, RelationDesc, Row, SqlColumnType, SqlScalarType};
 use serde::{Deserialize, Serialize};
 
 use crate::AlterCompatible;
+use crate::configuration::StorageConfiguration;
 use crate::connections::inline::{
     ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
     ReferencedConnection,
 };
 use crate::controller::AlterError;
-use crate::errors::DecodeErrorKind;
+use crate::dyncfgs::ENFORCE_EXTERNAL_ADDRESSES;
+use crate::errors::{CsrConnectError, DecodeErrorKind};
 use crate::wire_format::WireFormat;
 
 /// A description of how to interpret data from various sources
@@ -281,6 +284,55 @@ impl<R: ConnectionResolver> IntoInlineConnection<AvroEncoding, R>
     }
 }
 
+impl AvroEncoding {
+    /// Returns the provider of writer schemas for this encoding's wire format, connecting to the
+    /// schema registry the wire format references, if any.
+    pub async fn writer_schemas(
+        &self,
+        storage_configuration: &StorageConfiguration,
+        in_task: InTask,
+    ) -> Result<avro::WriterSchemaProvider, CsrConnectError> {
+        let writer_schemas = match &self.wire_format {
+            WireFormat::None => avro::WriterSchemaProvider::None,
+            WireFormat::Confluent { registry } => {
+                let client = match registry {
+                    None => None,
+                    Some(csr_connection) => Some(
+                        csr_connection
+                            .connect(storage_configuration, in_task)
+                            .await?,
+                    ),
+                };
+                avro::WriterSchemaProvider::confluent(client)
+            }
+            WireFormat::Glue { registry } => {
+                let client_with_registry = match registry {
+                    None => None,
+                    Some(glue_connection) => {
+                        let enforce_external_addresses =
+                            ENFORCE_EXTERNAL_ADDRESSES.get(storage_configuration.config_set());
+                        let sdk_config = glue_connection
+                            .aws_connection
+                            .connection
+                            .load_sdk_config(
+                                &storage_configuration.connection_context,
+                                glue_connection.aws_connection.connection_id,
+                                in_task,
+                                enforce_external_addresses,
+                            )
+                            .await?;
+                        let client =
+                            mz_aws_glue_schema_registry::ClientConfig::new(sdk_config).build();
+                        Some((client, glue_connection.registry_name.clone()))
+                    }
+                };
+                avro::WriterSchemaProvider::glue(client_with_registry)
+            }
+        };
+        Ok(writer_schemas)
+    }
+}
+
 impl<C: ConnectionAccess> AlterCompatible for AvroEncoding<C> {
     fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
         if self == other {
@@ -0,0 +1,529 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Types related to Iceberg sources.
+//!
+//! An Iceberg source ingests a single table from an Iceberg catalog. Its
+//! timestamps are the sequence numbers of the table's snapshots: the data of
+//! the snapshot with sequence number `n` is emitted at offset `n`, so the
+//! frontier of a source that has fully ingested that snapshot is `n + 1`.
+
+use std::sync::LazyLock;
+
+use iceberg::spec::{FormatVersion, PrimitiveType, Schema, Snapshot, Type};
+use iceberg::{Catalog, NamespaceIdent, TableIdent};
+use mz_ore::future::InTask;
+use mz_proto::{IntoRustIfSome, RustType, TryFromProtoError};
+use mz_repr::adt::numeric::NumericMaxScale;
+use mz_repr::{CatalogItemId, ColumnName, GlobalId, RelationDesc, SqlColumnType, SqlScalarType};
+use serde::{Deserialize, Serialize};
+use timely::progress::Antichain;
+
+use crate::AlterCompatible;
+use crate::configuration::StorageConfiguration;
+use crate::connections::IcebergCatalogConnection;
+use crate::connections::inline::{
+    ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
+    ReferencedConnection,
+};
+use crate::controller::AlterError;
+use crate::sources::{MzOffset, SourceConnection};
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/mz_storage_types.sources.iceberg.rs"
+));
+
+pub static ICEBERG_PROGRESS_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("sequence_number", SqlScalarType::UInt64.nullable(true))
+        .finish()
+});
+
+/// Details about how to create a Materialize source that reads a table from an
+/// Iceberg catalog.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IcebergSourceConnection<C: ConnectionAccess = InlinedConnection> {
+    /// The ID of the Iceberg catalog connection object this source is using.
+    pub connection_id: CatalogItemId,
+    /// Configuration for connecting to the Iceberg catalog.
+    pub connection: C::IcebergCatalog,
+    /// The namespace of the upstream table.
+    pub namespace: String,
+    /// The name of the upstream table.
+    pub table: String,
+    /// The shape of the upstream table, as determined during purification.
+    pub details: IcebergSourceDetails,
+}
+
+impl<C: ConnectionAccess> IcebergSourceConnection<C> {
+    /// Returns the catalog identifier of the upstream table.
+    pub fn table_ident(&self) -> TableIdent {
+        TableIdent::new(
+            NamespaceIdent::new(self.namespace.clone()),
+            self.table.clone(),
+        )
+    }
+}
+
+impl IcebergSourceConnection<InlinedConnection> {
+    pub async fn fetch_write_frontier(
+        self,
+        storage_configuration: &StorageConfiguration,
+    ) -> Result<Antichain<MzOffset>, anyhow::Error> {
+        let catalog = self
+            .connection
+            .connect(storage_configuration, InTask::No)
+            .await?;
+        let table = catalog.load_table(&self.table_ident()).await?;
+        let upper = match table.metadata().current_snapshot() {
+            Some(snapshot) => snapshot_upper(snapshot)?,
+            None => MzOffset::from(0),
+        };
+        Ok(Antichain::from_elem(upper))
+    }
+}
+
+/// Returns the offset immediately after `snapshot`, i.e. the frontier of a
+/// source that has ingested all data up to and including `snapshot`.
+pub fn snapshot_upper(snapshot: &Snapshot) -> Result<MzOffset, anyhow::Error> {
+    let sequence_number = u64::try_from(snapshot.sequence_number()).map_err(|_| {
+        anyhow::anyhow!(
+            "snapshot {} has invalid sequence number {}",
+            snapshot.snapshot_id(),
+            snapshot.sequence_number()
+        )
+    })?;
+    Ok(MzOffset::from(sequence_number + 1))
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<IcebergSourceConnection, R>
+    for IcebergSourceConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> IcebergSourceConnection {
+        let IcebergSourceConnection {
+            connection_id,
+            connection,
+            namespace,
+            table,
+            details,
+        } = self;
+
+        IcebergSourceConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_iceberg_catalog(),
+            namespace,
+            table,
+            details,
+        }
+    }
+}
+
+impl<C: ConnectionAccess> SourceConnection for IcebergSourceConnection<C> {
+    fn name(&self) -> &'static str {
+        "iceberg"
+    }
+
+    fn external_reference(&self) -> Option<&str> {
+        Some(self.table.as_str())
+    }
+
+    fn default_key_desc(&self) -> RelationDesc {
+        RelationDesc::empty()
+    }
+
+    fn default_value_desc(&self) -> RelationDesc {
+        self.details.desc.clone()
+    }
+
+    fn timestamp_desc(&self) -> RelationDesc {
+        ICEBERG_PROGRESS_DESC.clone()
+    }
+
+    fn connection_id(&self) -> Option<CatalogItemId> {
+        Some(self.connection_id)
+    }
+
+    fn supports_read_only(&self) -> bool {
+        true
+    }
+
+    fn prefers_single_replica(&self) -> bool {
+        true
+    }
+}
+
+impl<C: ConnectionAccess> AlterCompatible for IcebergSourceConnection<C> {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+
+        let IcebergSourceConnection {
+            connection_id,
+            // The catalog connection itself may be altered, e.g. to rotate
+            // credentials.
+            connection: _,
+            namespace,
+            table,
+            details,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (namespace == &other.namespace, "namespace"),
+            (table == &other.table, "table"),
+            (details == &other.details, "details"),
+        ];
+
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "IcebergSourceConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The shape of the upstream Iceberg table, recorded when the source is
+/// created.
+///
+/// The information in this struct is durably recorded by serializing it as an
+/// option in the `CREATE SOURCE` SQL statement, thus backward compatibility is
+/// important!
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IcebergSourceDetails {
+    /// The relation the source produces.
+    pub desc: RelationDesc,
+    /// The Iceberg field ID of each column in `desc`.
+    ///
+    /// Iceberg tracks columns by ID rather than by name, so these let the
+    /// source detect columns that were dropped, retyped, or renamed upstream.
+    pub field_ids: Vec<i32>,
+}
+
+impl IcebergSourceDetails {
+    /// Derives the details of a source reading a table with the given schema.
+    ///
+    /// On failure, returns the name and Iceberg type of every column whose type
+    /// Materialize cannot ingest.
+    pub fn from_schema(schema: &Schema) -> Result<Self, Vec<(String, String)>> {
+        let mut builder = RelationDesc::builder();
+        let mut field_ids = Vec::new();
+        let mut unsupported = Vec::new();
+        for field in schema.as_struct().fields() {
+            match iceberg_type_to_scalar_type(&field.field_type) {
+                Some(scalar_type) => {
+                    builder = builder
+                        .with_column(field.name.as_str(), scalar_type.nullable(!field.required));
+                    field_ids.push(field.id);
+                }
+                None => unsupported.push((field.name.clone(), field.field_type.to_string())),
+            }
+        }
+        if !unsupported.is_empty() {
+            return Err(unsupported);
+        }
+        Ok(IcebergSourceDetails {
+            desc: builder.finish(),
+            field_ids,
+        })
+    }
+
+    /// Checks that `schema` still contains every column of this source with
+    /// the type the source expects, returning a description of the first
+    /// incompatibility if not.
+    pub fn check_compatible(&self, schema: &Schema) -> Result<(), String> {
+        for ((name, column_type), field_id) in self.desc.iter().zip(self.field_ids.iter()) {
+            let Some(field) = schema.field_by_id(*field_id) else {
+                return Err(format!("column {name} was dropped"));
+            };
+            if field.name != name.as_str() {
+                return Err(format!("column {name} was renamed to {}", field.name));
+            }
+            let scalar_type = iceberg_type_to_scalar_type(&field.field_type);
+            if scalar_type.as_ref() != Some(&column_type.scalar_type) {
+                return Err(format!(
+                    "column {name} changed type to {}",
+                    field.field_type
+                ));
+            }
+            if !field.required && !column_type.nullable {
+                return Err(format!("column {name} became nullable"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Errors that can occur while fetching the [`IcebergSourceDetails`] of a table.
+#[derive(Debug, thiserror::Error)]
+pub enum IcebergSourceDetailsError {
+    #[error(transparent)]
+    Catalog(#[from] anyhow::Error),
+    #[error("unsupported format version {0}")]
+    UnsupportedFormatVersion(String),
+    #[error("table contains columns of unsupported types")]
+    UnsupportedTypes(Vec<(String, String)>),
+}
+
+impl IcebergSourceDetails {
+    /// Loads `namespace.table` from the catalog and derives the details of a
+    /// source reading it.
+    ///
+    /// Only tables of format version 2 or later are supported, as earlier
+    /// versions do not assign sequence numbers to snapshots.
+    pub async fn fetch(
+        connection: &IcebergCatalogConnection,
+        storage_configuration: &StorageConfiguration,
+        namespace: &str,
+        table: &str,
+    ) -> Result<Self, IcebergSourceDetailsError> {
+        let catalog = connection
+            .connect(storage_configuration, InTask::No)
+            .await?;
+        let ident = TableIdent::new(
+            NamespaceIdent::new(namespace.to_string()),
+            table.to_string(),
+        );
+        let table = catalog
+            .load_table(&ident)
+            .await
+            .map_err(anyhow::Error::from)?;
+        let metadata = table.metadata();
+        if matches!(metadata.format_version(), FormatVersion::V1) {
+            let version = format!("{:?}", metadata.format_version());
+            return Err(IcebergSourceDetailsError::UnsupportedFormatVersion(version));
+        }
+        Self::from_schema(metadata.current_schema())
+            .map_err(IcebergSourceDetailsError::UnsupportedTypes)
+    }
+}
+
+impl RustType<ProtoIcebergSourceDetails> for IcebergSourceDetails {
+    fn into_proto(&self) -> ProtoIcebergSourceDetails {
+        ProtoIcebergSourceDetails {
+            desc: Some(self.desc.into_proto()),
+            field_ids: self.field_ids.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoIcebergSourceDetails) -> Result<Self, TryFromProtoError> {
+        Ok(IcebergSourceDetails {
+            desc: proto
+                .desc
+                .into_rust_if_some("ProtoIcebergSourceDetails::desc")?,
+            field_ids: proto.field_ids,
+        })
+    }
+}
+
+/// Returns the Materialize type that Iceberg values of type `typ` are ingested
+/// as, or `None` if the type is not supported.
+fn iceberg_type_to_scalar_type(typ: &Type) -> Option<SqlScalarType> {
+    let scalar_type = match typ {
+        Type::Primitive(primitive) => match primitive {
+            PrimitiveType::Boolean => SqlScalarType::Bool,
+            PrimitiveType::Int => SqlScalarType::Int32,
+            PrimitiveType::Long => SqlScalarType::Int64,
+            PrimitiveType::Float => SqlScalarType::Float32,
+            PrimitiveType::Double => SqlScalarType::Float64,
+            PrimitiveType::Decimal { scale, .. } => SqlScalarType::Numeric {
+                max_scale: Some(NumericMaxScale::try_from(i64::from(*scale)).ok()?),
+            },
+            PrimitiveType::Date => SqlScalarType::Date,
+            PrimitiveType::Time => SqlScalarType::Time,
+            PrimitiveType::Timestamp | PrimitiveType::TimestampNs => {
+                SqlScalarType::Timestamp { precision: None }
+            }
+            PrimitiveType::Timestamptz | PrimitiveType::TimestamptzNs => {
+                SqlScalarType::TimestampTz { precision: None }
+            }
+            PrimitiveType::String => SqlScalarType::String,
+            PrimitiveType::Uuid => SqlScalarType::Uuid,
+            PrimitiveType::Fixed(_) | PrimitiveType::Binary => SqlScalarType::Bytes,
+        },
+        Type::List(list) => SqlScalarType::List {
+            element_type: Box::new(iceberg_type_to_scalar_type(&list.element_field.field_type)?),
+            custom_id: None,
+        },
+        // Materialize maps only support string keys.
+        Type::Map(map) => match &*map.key_field.field_type {
+            Type::Primitive(PrimitiveType::String) => SqlScalarType::Map {
+                value_type: Box::new(iceberg_type_to_scalar_type(&map.value_field.field_type)?),
+                custom_id: None,
+            },
+            _ => return None,
+        },
+        Type::Struct(fields) => {
+            let fields = fields
+                .fields()
+                .iter()
+                .map(|field| {
+                    let column_type = SqlColumnType {
+                        scalar_type: iceberg_type_to_scalar_type(&field.field_type)?,
+                        nullable: !field.required,
+                    };
+                    Some((ColumnName::from(field.name.as_str()), column_type))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            SqlScalarType::Record {
+                fields: fields.into(),
+                custom_id: None,
+            }
+        }
+    };
+    Some(scalar_type)
+}
+
+/// Specifies the details of an Iceberg source export.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IcebergSourceExportDetails {}
+
+impl AlterCompatible for IcebergSourceExportDetails {
+    fn alter_compatible(&self, _id: GlobalId, _other: &Self) -> Result<(), AlterError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use iceberg::spec::{ListType, MapType, NestedField};
+
+    use super::*;
+
+    fn schema(fields: Vec<NestedField>) -> Schema {
+        Schema::builder()
+            .with_fields(fields.into_iter().map(Arc::new))
+            .build()
+            .expect("valid schema")
+    }
+
+    #[mz_ore::test]
+    fn details_from_schema() {
+        let schema = schema(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)),
+            NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)),
+            NestedField::optional(
+                3,
+                "price",
+                Type::Primitive(PrimitiveType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                }),
+            ),
+            NestedField::optional(
+                4,
+                "tags",
+                Type::List(ListType::new(Arc::new(NestedField::list_element(
+                    5,
+                    Type::Primitive(PrimitiveType::String),
+                    false,
+                )))),
+            ),
+        ]);
+        let details = IcebergSourceDetails::from_schema(&schema).expect("supported schema");
+        assert_eq!(details.field_ids, vec![1, 2, 3, 4]);
+        let columns: Vec<_> = details
+            .desc
+            .iter()
+            .map(|(name, typ)| (name.as_str().to_string(), typ.clone()))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id".into(), SqlScalarType::Int64.nullable(false)),
+                ("name".into(), SqlScalarType::String.nullable(true)),
+                (
+                    "price".into(),
+                    SqlScalarType::Numeric {
+                        max_scale: Some(NumericMaxScale::try_from(2i64).unwrap()),
+                    }
+                    .nullable(true)
+                ),
+                (
+                    "tags".into(),
+                    SqlScalarType::List {
+                        element_type: Box::new(SqlScalarType::String),
+                        custom_id: None,
+                    }
+                    .nullable(true)
+                ),
+            ]
+        );
+
+        let proto = details.into_proto();
+        assert_eq!(IcebergSourceDetails::from_proto(proto).unwrap(), details);
+    }
+
+    #[mz_ore::test]
+    fn details_from_schema_unsupported() {
+        let schema = schema(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)),
+            NestedField::optional(
+                2,
+                "attrs",
+                Type::Map(MapType::new(
+                    Arc::new(NestedField::map_key_element(
+                        3,
+                        Type::Primitive(PrimitiveType::Int),
+                    )),
+                    Arc::new(NestedField::map_value_element(
+                        4,
+                        Type::Primitive(PrimitiveType::String),
+                        false,
+                    )),
+                )),
+            ),
+        ]);
+        let unsupported = IcebergSourceDetails::from_schema(&schema).unwrap_err();
+        assert_eq!(unsupported.len(), 1);
+        assert_eq!(unsupported[0].0, "attrs");
+    }
+
+    #[mz_ore::test]
+    fn details_check_compatible() {
+        let before = schema(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)),
+            NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)),
+        ]);
+        let details = IcebergSourceDetails::from_schema(&before).unwrap();
+        assert_eq!(details.check_compatible(&before), Ok(()));
+
+        // Adding columns is compatible; the source keeps reading the old ones.
+        let added = schema(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)),
+            NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)),
+            NestedField::optional(3, "extra", Type::Primitive(PrimitiveType::Int)),
+        ]);
+        assert_eq!(details.check_compatible(&added), Ok(()));
+
+        let dropped = schema(vec![NestedField::required(
+            1,
+            "id",
+            Type::Primitive(PrimitiveType::Long),
+        )]);
+        assert!(details.check_compatible(&dropped).is_err());
+
+        let retyped = schema(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)),
+            NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)),
+        ]);
+        assert!(details.check_compatible(&retyped).is_err());
+    }
+}
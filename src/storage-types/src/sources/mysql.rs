@@ -9,6 +9,7 @@
 
 //! Types related to mysql sources
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::io;
 use std::num::NonZeroU64;
@@ -93,8 +94,7 @@ impl MySqlSourceConnection {
             )
             .await?;
 
-        let current_gtid_set =
-            mz_mysql_util::query_sys_var(&mut conn, "global.gtid_executed").await?;
+        let current_gtid_set = mz_mysql_util::query_gtid_executed(&mut conn).await?;
 
         let current_upper = gtid_set_frontier(&current_gtid_set)?;
 
@@ -381,8 +381,16 @@ impl SourceTimestamp for GtidPartition {
 /// partitions that represent the missing UUIDs between the singular partitions, which are
 /// each set to GtidState::Absent.
 ///
+/// MariaDB GTID sets (e.g. from @@gtid_binlog_state) are also accepted, see
+/// [`mariadb_gtid_set_frontier`].
+///
 /// TODO(roshan): Add compatibility for MySQL 8.3 'Tagged' GTIDs
 pub fn gtid_set_frontier(gtid_set_str: &str) -> Result<Antichain<GtidPartition>, io::Error> {
+    // MariaDB GTIDs are formatted as `domain-server-sequence` and never contain a ':'.
+    if !gtid_set_str.trim().is_empty() && !gtid_set_str.contains(':') {
+        return mariadb_gtid_set_frontier(gtid_set_str);
+    }
+
     let mut partitions = Antichain::new();
     let mut gap_lower = Some(Uuid::nil());
     for mut gtid_str in gtid_set_str.split(',') {
@@ -487,6 +495,96 @@ pub fn gtid_set_frontier(gtid_set_str: &str) -> Result<Antichain<GtidPartition>,
     Ok(partitions)
 }
 
+/// Returns the UUID that stands in for the source-id of the MariaDB GTIDs that originated on
+/// `server_id` within the replication domain `domain_id`.
+///
+/// MariaDB GTIDs are tracked with the same partitioned timestamps as MySQL GTIDs, with a partition
+/// per domain and server id pair whose sequence numbers take the place of the transaction-ids.
+/// Sequence numbers are only guaranteed to be monotonic within a domain, but that holds for the
+/// GTIDs of each of its servers as well.
+pub fn mariadb_source_id(domain_id: u32, server_id: u32) -> Uuid {
+    Uuid::from_u128(u128::from(domain_id) << 64 | u128::from(server_id))
+}
+
+/// The inverse of [`mariadb_source_id`], returning the domain and server id a UUID stands in for.
+pub fn mariadb_domain_server_id(source_id: &Uuid) -> Option<(u32, u32)> {
+    let id = source_id.as_u128();
+    let domain_id = u32::try_from(id >> 64).ok()?;
+    let server_id = u32::try_from(id & u128::from(u64::MAX)).ok()?;
+    Some((domain_id, server_id))
+}
+
+/// Parses a MariaDB GTID set (e.g. from @@gtid_binlog_state) made of the last GTID of each domain
+/// and server id pair, formatted as `domain-server-sequence`.
+///
+/// Returns the frontier of all future GTIDs that are not contained in the provided GTID set, in
+/// the same form as [`gtid_set_frontier`].
+pub fn mariadb_gtid_set_frontier(
+    gtid_set_str: &str,
+) -> Result<Antichain<GtidPartition>, io::Error> {
+    let invalid = |gtid_str: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid mariadb gtid: {}", gtid_str),
+        )
+    };
+
+    let mut sources = BTreeMap::new();
+    for gtid_str in gtid_set_str.split(',') {
+        let gtid_str = gtid_str.trim();
+        if gtid_str.is_empty() {
+            continue;
+        }
+        let mut parts = gtid_str.split('-');
+        let (domain_id, server_id, seq_no) =
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(domain_id), Some(server_id), Some(seq_no), None) => (
+                    domain_id.parse().map_err(|_| invalid(gtid_str))?,
+                    server_id.parse().map_err(|_| invalid(gtid_str))?,
+                    seq_no.parse::<u64>().map_err(|_| invalid(gtid_str))?,
+                ),
+                _ => return Err(invalid(gtid_str)),
+            };
+        let source_id = mariadb_source_id(domain_id, server_id);
+        if sources.insert(source_id, seq_no).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("duplicate mariadb gtid source: {}", gtid_str),
+            ));
+        }
+    }
+
+    let mut partitions = Antichain::new();
+    let mut gap_lower = Some(Uuid::nil());
+    for (source_id, seq_no) in sources {
+        // Create a partition representing all the UUIDs in the gap before this one
+        if let (Some(lower), Some(upper)) = (gap_lower, source_id.backward_checked(1)) {
+            if lower <= upper {
+                partitions.insert(GtidPartition::new_range(lower, upper, GtidState::Absent));
+            }
+        }
+        gap_lower = source_id.forward_checked(1);
+        // Insert a partition representing the 'next' GTID that might be seen from this source
+        let next = seq_no
+            .checked_add(1)
+            .and_then(NonZeroU64::new)
+            .ok_or_else(|| invalid(&seq_no.to_string()))?;
+        partitions.insert(GtidPartition::new_singleton(
+            source_id,
+            GtidState::Active(next),
+        ));
+    }
+    if let Some(gap_lower) = gap_lower {
+        partitions.insert(GtidPartition::new_range(
+            gap_lower,
+            Uuid::max(),
+            GtidState::Absent,
+        ));
+    }
+
+    Ok(partitions)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -570,6 +668,45 @@ mod tests {
         assert_err!(result);
     }
 
+    #[mz_ore::test]
+    fn test_mariadb_gtid_set_frontier_valid() {
+        let gtid_set_str = "1-2-7, 0-2-10,0-1-3";
+        let result = gtid_set_frontier(gtid_set_str).unwrap();
+        assert_eq!(result.len(), 6);
+        let next = |seq_no| GtidState::Active(NonZeroU64::new(seq_no).unwrap());
+        assert_eq!(
+            result,
+            Antichain::from_iter(vec![
+                GtidPartition::new_range(Uuid::nil(), Uuid::nil(), GtidState::Absent),
+                GtidPartition::new_singleton(mariadb_source_id(0, 1), next(4)),
+                GtidPartition::new_singleton(mariadb_source_id(0, 2), next(11)),
+                GtidPartition::new_range(
+                    Uuid::from_u128(3),
+                    Uuid::from_u128((1 << 64) + 1),
+                    GtidState::Absent,
+                ),
+                GtidPartition::new_singleton(mariadb_source_id(1, 2), next(8)),
+                GtidPartition::new_range(
+                    Uuid::from_u128((1 << 64) + 3),
+                    Uuid::max(),
+                    GtidState::Absent,
+                ),
+            ]),
+        );
+        assert_eq!(
+            mariadb_domain_server_id(&mariadb_source_id(1, 2)),
+            Some((1, 2))
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_mariadb_gtid_set_frontier_invalid() {
+        assert_err!(gtid_set_frontier("0-1"));
+        assert_err!(gtid_set_frontier("0-1-2-3"));
+        assert_err!(gtid_set_frontier("0-1-x"));
+        assert_err!(gtid_set_frontier("0-1-2,0-1-5"));
+    }
+
     #[mz_ore::test]
     fn test_gtid_set_frontier_empty_string() {
         let gtid_set_str = "";
@@ -190,6 +190,10 @@ pub struct PostgresSourceExportDetails {
     /// their target types
     pub column_casts: Vec<(CastType, crate::sources::casts::StorageScalarExpr)>,
     pub table: PostgresTableDesc,
+    /// Whether an upstream `TRUNCATE` retracts the current contents of this export instead of
+    /// putting it into an errored state.
+    #[serde(default)]
+    pub retract_on_truncate: bool,
 }
 
 impl AlterCompatible for PostgresSourceExportDetails {
@@ -199,6 +203,7 @@ impl AlterCompatible for PostgresSourceExportDetails {
         let Self {
             column_casts: _,
             table: _,
+            retract_on_truncate: _,
         } = self;
         Ok(())
     }
@@ -21,16 +21,12 @@ use std::time::Duration;
 use differential_dataflow::capture::{Message, Progress};
 use differential_dataflow::{AsCollection, Hashable, VecCollection};
 use futures::StreamExt;
-use mz_interchange::avro::WriterSchemaProvider;
 use mz_ore::error::ErrorExt;
 use mz_ore::future::InTask;
 use mz_repr::{Datum, Diff, Row};
 use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::errors::{CsrConnectError, DecodeError, DecodeErrorKind};
-use mz_storage_types::sources::encoding::{
-    AvroEncoding, CsvDecoderState, DataEncoding, RegexEncoding,
-};
-use mz_storage_types::wire_format::WireFormat;
+use mz_storage_types::sources::encoding::{CsvDecoderState, DataEncoding, RegexEncoding};
 use mz_timely_util::builder_async::{
     Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
 };
@@ -351,52 +347,13 @@ async fn get_decoder(
     storage_configuration: &StorageConfiguration,
 ) -> Result<DataDecoder, CsrConnectError> {
     let decoder = match encoding {
-        DataEncoding::Avro(AvroEncoding {
-            schema,
-            reference_schemas,
-            wire_format,
-        }) => {
-            let writer_schemas = match wire_format {
-                WireFormat::None => WriterSchemaProvider::None,
-                WireFormat::Confluent { registry } => {
-                    let client = match registry {
-                        None => None,
-                        Some(csr_connection) => Some(
-                            csr_connection
-                                .connect(storage_configuration, InTask::Yes)
-                                .await?,
-                        ),
-                    };
-                    WriterSchemaProvider::confluent(client)
-                }
-                WireFormat::Glue { registry } => {
-                    let client_with_registry = match registry {
-                        None => None,
-                        Some(glue_connection) => {
-                            let enforce_external_addresses =
-                                mz_storage_types::dyncfgs::ENFORCE_EXTERNAL_ADDRESSES
-                                    .get(storage_configuration.config_set());
-                            let sdk_config = glue_connection
-                                .aws_connection
-                                .connection
-                                .load_sdk_config(
-                                    &storage_configuration.connection_context,
-                                    glue_connection.aws_connection.connection_id,
-                                    InTask::Yes,
-                                    enforce_external_addresses,
-                                )
-                                .await?;
-                            let client =
-                                mz_aws_glue_schema_registry::ClientConfig::new(sdk_config).build();
-                            Some((client, glue_connection.registry_name.clone()))
-                        }
-                    };
-                    WriterSchemaProvider::glue(client_with_registry)
-                }
-            };
+        DataEncoding::Avro(encoding) => {
+            let writer_schemas = encoding
+                .writer_schemas(storage_configuration, InTask::Yes)
+                .await?;
             let state = avro::AvroDecoderState::new(
-                &schema,
-                &reference_schemas,
+                &encoding.schema,
+                &encoding.reference_schemas,
                 writer_schemas,
                 debug_name.to_string(),
             )
@@ -65,6 +65,7 @@ pub enum StatusNamespace {
     Upsert,
     Decode,
     Iceberg,
+    Http,
     Internal,
 }
 
@@ -88,6 +89,7 @@ impl fmt::Display for StatusNamespace {
             Decode => write!(f, "decode"),
             Internal => write!(f, "internal"),
             Iceberg => write!(f, "iceberg"),
+            Http => write!(f, "http"),
         }
     }
 }
@@ -255,5 +255,7 @@ fn get_sink_render_for<'scope>(connection: &StorageSinkConnection) -> Box<dyn Si
     match connection {
         StorageSinkConnection::Kafka(connection) => Box::new(connection.clone()),
         StorageSinkConnection::Iceberg(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Postgres(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Http(connection) => Box::new(connection.clone()),
     }
 }
@@ -9,5 +9,8 @@
 
 //! Moving data to external systems
 
+mod http;
 mod iceberg;
 mod kafka;
+mod postgres;
+mod single_worker;
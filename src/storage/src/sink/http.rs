@@ -0,0 +1,383 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of an [`HttpSinkConnection`]. The dataflow consists
+//! of two operators in order to take advantage of all the available workers.
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ stream of arrangement batches (trace reader dropped)
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃    row      ┃ walks each batch's cursor and emits one
+//!        ┃   encoder   ┃ JSON-encoded update per DiffPair
+//!        ┗━━━━━━┯━━━━━━┛
+//!               │ encoded data
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃    http     ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━┯━━━━━━━━┯━┛
+//!  requests │        │ uppers
+//!      ╭────v──╮ ╭───v──────╮
+//!      │ HTTP  │ │ progress │  <- uppers are only recorded once every
+//!      │ endp. │ │  shard   │     request before them was acknowledged
+//!      ╰───────╯ ╰──────────╯
+//! ```
+//!
+//! # Requests
+//!
+//! Once the input frontier advances past a timestamp, all updates at that timestamp are sorted and
+//! split into chunks of at most `BATCH SIZE` updates. Each chunk is sent as the JSON body of a
+//! `POST` request:
+//!
+//! ```json
+//! {"timestamp": "1700000000000", "updates": [{"key": {"a": 1}, "value": {"a": 1, "b": 2}}]}
+//! ```
+//!
+//! Requests carry an `Idempotency-Key` header of the form `<sink id>-<timestamp>-<chunk>`. Because
+//! the chunks of a timestamp are deterministic, a request that is retried, or re-sent after the
+//! sink restarts, carries the same key and the same body as the original.
+//!
+//! # Progress
+//!
+//! Unlike Kafka, an HTTP endpoint has nowhere for us to store our progress, so we use the upper of
+//! the sink's progress shard in persist instead. The upper is only advanced past a timestamp once
+//! every request for it has been acknowledged with a `2xx` response, and on restart we resume
+//! sending from that upper. Updates are therefore delivered at least once, and exactly once to
+//! endpoints that deduplicate requests by their idempotency key.
+//!
+//! Updates are buffered in memory until their timestamp is complete. This includes the initial
+//! snapshot, which is only acknowledged once it has been sent in full. If the sink restarts before
+//! that happens, the snapshot may be re-sent at a different timestamp and thus with different
+//! idempotency keys.
+//!
+//! # Retries
+//!
+//! Failed requests are retried with exponential backoff for up to `http_sink_retry_max_duration`.
+//! Responses with a `4xx` status other than `408 Request Timeout` and `429 Too Many Requests` are
+//! not retried. Once retries are exhausted the sink reports a halting error and the dataflow is
+//! restarted.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::{Context, anyhow};
+use differential_dataflow::VecCollection;
+use mz_interchange::avro::DiffPair;
+use mz_interchange::encode::Encode;
+use mz_interchange::envelopes::dbz_format;
+use mz_interchange::json::JsonEncoder;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_ore::netio::{ensure_url_ip_global, resolve_address};
+use mz_ore::retry::{Retry, RetryResult};
+use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::dyncfgs::{
+    ENFORCE_EXTERNAL_ADDRESSES, HTTP_SINK_REQUEST_TIMEOUT, HTTP_SINK_RETRY_MAX_DURATION,
+};
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{HttpSinkConnection, SinkEnvelope, StorageSinkDesc};
+use mz_timely_util::builder_async::PressOnDropButton;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use reqwest::{StatusCode, Url};
+use timely::dataflow::StreamVec;
+use timely::progress::Antichain;
+use tracing::warn;
+
+use crate::healthcheck::{HealthStatusMessage, StatusNamespace};
+use crate::render::sinks::{SinkBatchStream, SinkRender};
+use crate::sink::single_worker::{self, ProgressHandle, SinkWriter};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The header that carries the idempotency key of each request.
+const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+
+impl<'scope> SinkRender<'scope> for HttpSinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        batches: SinkBatchStream<'scope>,
+        key_is_synthetic: bool,
+        _err_collection: VecCollection<'scope, Timestamp, DataflowError, Diff>,
+    ) -> (
+        StreamVec<'scope, Timestamp, HealthStatusMessage>,
+        Vec<PressOnDropButton>,
+    ) {
+        let envelope = sink.envelope;
+        let key_encoder = self
+            .key_desc_and_indices
+            .clone()
+            .map(|(desc, _indices)| JsonEncoder::new(desc, false, None));
+        // whether to apply the debezium envelope to the value encoding
+        let debezium = matches!(envelope, SinkEnvelope::Debezium);
+        let value_encoder = JsonEncoder::new(self.value_desc.clone(), debezium, None);
+        let mut row_buf = Row::default();
+        // Each update is an object with a `key` and a `value` field. The key is `null` unless
+        // the sink has a user-specified key. The value is `null` for deletions in the upsert
+        // envelope.
+        let encode = move |key: &Option<Row>, value: DiffPair<Row>| {
+            // Only emit the arrangement key when the user configured one; relation-key and
+            // synthetic-hash arrangements exist purely for grouping / worker distribution.
+            let key = match (&key_encoder, key) {
+                (Some(key_encoder), Some(key)) => Some(key_encoder.encode_unchecked(key.clone())),
+                _ => None,
+            };
+            let value = match envelope {
+                SinkEnvelope::Upsert => value.after,
+                SinkEnvelope::Debezium => {
+                    dbz_format(&mut row_buf.packer(), value);
+                    Some(row_buf.clone())
+                }
+                SinkEnvelope::Append => {
+                    unreachable!("Append envelope is not valid for HTTP sinks")
+                }
+            };
+            let value = value.map(|value| value_encoder.encode_unchecked(value));
+
+            let mut update = br#"{"key":"#.to_vec();
+            update.extend_from_slice(key.as_deref().unwrap_or(b"null"));
+            update.extend_from_slice(br#","value":"#);
+            update.extend_from_slice(value.as_deref().unwrap_or(b"null"));
+            update.push(b'}');
+            update
+        };
+
+        let connection = self.clone();
+        let storage_configuration = storage_state.storage_configuration.clone();
+        let connect = async move |statistics: SinkStatistics, write_handle: &mut ProgressHandle| {
+            let client =
+                HttpSinkClient::new(sink_id, &connection, &storage_configuration, statistics)
+                    .await?;
+            let resume_upper = write_handle.fetch_recent_upper().await.clone();
+            Ok::<_, anyhow::Error>((client, resume_upper))
+        };
+
+        single_worker::render_sink(
+            "http",
+            StatusNamespace::Http,
+            storage_state,
+            sink,
+            sink_id,
+            batches,
+            key_is_synthetic,
+            encode,
+            connect,
+        )
+    }
+}
+
+/// Delivers the updates of complete timestamps to the sink's endpoint.
+struct HttpSinkClient {
+    sink_id: GlobalId,
+    url: Url,
+    client: reqwest::Client,
+    /// The headers sent with every request, with any secrets already resolved.
+    headers: HeaderMap,
+    batch_size: usize,
+    retry_max_duration: Duration,
+    statistics: SinkStatistics,
+}
+
+impl HttpSinkClient {
+    async fn new(
+        sink_id: GlobalId,
+        connection: &HttpSinkConnection,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+    ) -> Result<Self, anyhow::Error> {
+        let config_set = storage_configuration.config_set();
+        let enforce_external_addresses = ENFORCE_EXTERNAL_ADDRESSES.get(config_set);
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(HTTP_SINK_REQUEST_TIMEOUT.get(config_set))
+            // Following redirects would let the endpoint send us to an address that was never
+            // checked against `ENFORCE_EXTERNAL_ADDRESSES`.
+            .redirect(reqwest::redirect::Policy::none());
+
+        // Ensure any host we connect to is resolved to an external address. IP literals are not
+        // resolved, so they are checked separately.
+        if enforce_external_addresses {
+            ensure_url_ip_global(&connection.url)?;
+        }
+        if let Some(host) = connection.url.domain() {
+            let resolved = resolve_address(host, enforce_external_addresses).await?;
+            builder = builder.resolve_to_addrs(
+                host,
+                &resolved
+                    .iter()
+                    .map(|addr| SocketAddr::new(*addr, 0))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        let client = builder.build().context("building HTTP client")?;
+
+        let secrets_reader = &storage_configuration.connection_context.secrets_reader;
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        for (name, value) in &connection.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .with_context(|| format!("invalid header name {name}"))?;
+            let value = value.get_string(InTask::No, secrets_reader).await?;
+            let mut value = HeaderValue::from_str(&value)
+                .with_context(|| format!("invalid value for header {name}"))?;
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+
+        Ok(Self {
+            sink_id,
+            url: connection.url.clone(),
+            client,
+            headers,
+            batch_size: usize::cast_from(connection.batch_size),
+            retry_max_duration: HTTP_SINK_RETRY_MAX_DURATION.get(config_set),
+            statistics,
+        })
+    }
+
+    /// Sends all `updates` at `time`, returning once every request was acknowledged.
+    async fn send(&self, time: Timestamp, updates: &[Vec<u8>]) -> Result<(), anyhow::Error> {
+        for (chunk, updates) in updates.chunks(self.batch_size).enumerate() {
+            let body = encode_request_body(time, updates);
+            let idempotency_key =
+                HeaderValue::from_str(&format!("{}-{time}-{chunk}", self.sink_id))
+                    .expect("valid header value");
+            let message_count = u64::cast_from(updates.len());
+            let byte_count = u64::cast_from(body.len());
+            self.statistics.inc_messages_staged_by(message_count);
+            self.statistics.inc_bytes_staged_by(byte_count);
+
+            Retry::default()
+                .clamp_backoff(Duration::from_secs(30))
+                .max_duration(self.retry_max_duration)
+                .retry_async(|state| {
+                    let request = self
+                        .client
+                        .post(self.url.clone())
+                        .headers(self.headers.clone())
+                        .header(IDEMPOTENCY_KEY, idempotency_key.clone())
+                        .body(body.clone());
+                    async move {
+                        let result = match request.send().await {
+                            Ok(response) if response.status().is_success() => {
+                                return RetryResult::Ok(());
+                            }
+                            Ok(response) => {
+                                let status = response.status();
+                                let err = anyhow!("HTTP sink endpoint responded with {status}");
+                                if status.is_client_error()
+                                    && status != StatusCode::REQUEST_TIMEOUT
+                                    && status != StatusCode::TOO_MANY_REQUESTS
+                                {
+                                    return RetryResult::FatalErr(err);
+                                }
+                                err
+                            }
+                            // The URL may embed credentials, so we never include it in errors.
+                            Err(err) => anyhow::Error::new(err.without_url())
+                                .context("sending request to HTTP sink endpoint"),
+                        };
+                        warn!(
+                            "http-{}: request {chunk} for {time} failed (attempt {}): {}",
+                            self.sink_id,
+                            state.i,
+                            result.display_with_causes()
+                        );
+                        RetryResult::RetryableErr(result)
+                    }
+                })
+                .await?;
+
+            self.statistics.inc_messages_committed_by(message_count);
+            self.statistics.inc_bytes_committed_by(byte_count);
+        }
+        Ok(())
+    }
+}
+
+impl SinkWriter for HttpSinkClient {
+    type Update = Vec<u8>;
+
+    async fn write(
+        &mut self,
+        updates: Vec<(Timestamp, Vec<Vec<u8>>)>,
+        _upper: &Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        for (time, mut updates) in updates {
+            // Sorting makes the chunks of a timestamp, and therefore the requests that share an
+            // idempotency key, identical across restarts.
+            updates.sort_unstable();
+            self.send(time, &updates).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Encodes the body of a request that delivers `updates`, which must all be encoded JSON
+/// objects, at `time`.
+fn encode_request_body(time: Timestamp, updates: &[Vec<u8>]) -> Vec<u8> {
+    // Timestamps are encoded as strings since they don't fit into a JSON number.
+    let mut body = format!(r#"{{"timestamp":"{time}","updates":["#).into_bytes();
+    for (i, update) in updates.iter().enumerate() {
+        if i > 0 {
+            body.push(b',');
+        }
+        body.extend_from_slice(update);
+    }
+    body.extend_from_slice(b"]}");
+    body
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[mz_ore::test]
+    fn request_body() {
+        let updates = vec![
+            br#"{"key":null,"value":{"a":1}}"#.to_vec(),
+            br#"{"key":null,"value":null}"#.to_vec(),
+        ];
+        let body = encode_request_body(Timestamp::from(42), &updates);
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "timestamp": "42",
+                "updates": [
+                    {"key": null, "value": {"a": 1}},
+                    {"key": null, "value": null},
+                ],
+            })
+        );
+
+        let body = encode_request_body(Timestamp::from(42), &[]);
+        assert_eq!(body, br#"{"timestamp":"42","updates":[]}"#);
+    }
+}
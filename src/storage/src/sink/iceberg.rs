@@ -72,6 +72,9 @@
 //! rows are stashed until it does. This allows batches to be minted ahead of data arrival.
 //! The operator uses an Iceberg `DeltaWriter` to write Parquet data files
 //! (and position delete files if necessary) to object storage.
+//! If the table is partitioned, rows are routed to one writer per partition
+//! of the table's default partition spec, so that every data file belongs to
+//! exactly one partition.
 //! It outputs metadata about the written files along with their batch descriptions
 //! for the commit operator to consume.
 //!
@@ -83,15 +86,23 @@
 //! `mz-frontier` property to track progress.
 
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
 use std::convert::Infallible;
 use std::future::Future;
 use std::time::Instant;
 use std::{cell::RefCell, rc::Rc, sync::Arc};
 
 use anyhow::{Context, anyhow};
-use arrow::array::{ArrayRef, Int32Array, Int64Array, RecordBatch};
-use arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
+use arrow::array::{Array, ArrayRef, AsArray, Int32Array, Int64Array, RecordBatch, UInt32Array};
+use arrow::compute::{
+    SortColumn, SortOptions, concat_batches, lexsort_to_indices, take_record_batch,
+};
+use arrow::datatypes::{
+    DataType, Date32Type, Field, Int16Type, Int32Type, Int64Type, Schema as ArrowSchema, TimeUnit,
+    TimestampMicrosecondType,
+};
+use arrow::row::{RowConverter, SortField as RowSortField};
 use differential_dataflow::trace::BatchReader;
 use differential_dataflow::trace::implementations::ord_neu::OrdValBatch;
 use differential_dataflow::trace::implementations::{BatchContainer, Layout};
@@ -100,12 +111,14 @@ use futures::StreamExt;
 use iceberg::ErrorKind;
 use iceberg::arrow::{arrow_schema_to_schema, schema_to_arrow_schema};
 use iceberg::spec::{
-    DataFile, FormatVersion, Snapshot, StructType, read_data_files_from_avro,
-    write_data_files_to_avro,
+    DataFile, FormatVersion, Literal, NullOrder, PartitionKey, PartitionSpecRef, PrimitiveType,
+    Snapshot, SortDirection, SortField, SortOrder, Struct, StructType, Transform, Type,
+    UnboundPartitionSpec, read_data_files_from_avro, write_data_files_to_avro,
 };
 use iceberg::spec::{Schema, SchemaRef};
 use iceberg::table::Table;
 use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::transform::{BoxedTransformFunction, create_transform_function};
 use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
 use iceberg::writer::base_writer::equality_delete_writer::{
     EqualityDeleteFileWriterBuilder, EqualityDeleteWriterConfig,
@@ -133,14 +146,15 @@ use mz_ore::retry::{Retry, RetryResult};
 use mz_persist_client::Diagnostics;
 use mz_persist_client::write::WriteHandle;
 use mz_persist_types::codec_impls::UnitSchema;
-use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
 use mz_row_spine::ArcBatch;
 use mz_storage_types::StorageDiff;
 use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::errors::DataflowError;
 use mz_storage_types::sinks::{
-    IcebergSinkConnection, SinkEnvelope, StorageSinkDesc, iceberg_type_overrides,
+    IcebergPartitionTransform, IcebergSinkConnection, SinkEnvelope, StorageSinkDesc,
+    iceberg_type_overrides,
 };
 use mz_storage_types::sources::SourceData;
 use mz_timely_util::antichain::AntichainExt;
@@ -209,9 +223,27 @@ trait EnvelopeHandler: Send {
     /// `is_snapshot` is true for the initial "snapshot" batch (lower == as_of), which
     /// contains all pre-existing data and can be very large. Implementations may use
     /// this to disable memory-intensive optimisations like seen-rows deduplication.
-    async fn create_writer(&self, is_snapshot: bool) -> anyhow::Result<Box<dyn IcebergWriter>>;
+    ///
+    /// `partition_key` is the partition of the table that all rows written to the
+    /// writer belong to, or `None` if the table is unpartitioned.
+    async fn create_writer(
+        &self,
+        is_snapshot: bool,
+        partition_key: Option<PartitionKey>,
+    ) -> anyhow::Result<Box<dyn IcebergWriter>>;
 
     fn row_to_batch(&self, diff_pair: DiffPair<Row>, ts: Timestamp) -> anyhow::Result<RecordBatch>;
+
+    /// Put the buffered rows of one partition of a batch, as produced by
+    /// [`EnvelopeHandler::row_to_batch`], in the order to write them when the table has
+    /// a sort order.
+    fn order_rows(
+        &self,
+        batch: RecordBatch,
+        sort_by: &[(String, SortOptions)],
+    ) -> anyhow::Result<RecordBatch> {
+        sort_batch(&batch, sort_by)
+    }
 }
 
 struct UpsertEnvelopeHandler {
@@ -228,6 +260,8 @@ struct UpsertEnvelopeHandler {
     /// [`DeltaWriter`](iceberg::writer::combined_writer::delta_writer::DeltaWriter)
     /// uses to distinguish inserts (+1) from deletes (-1).
     schema_with_op: Arc<ArrowSchema>,
+    /// Indices of the key columns in the batches produced by `row_to_batch`.
+    key_indices: Vec<usize>,
 }
 
 impl EnvelopeHandler for UpsertEnvelopeHandler {
@@ -271,10 +305,15 @@ impl EnvelopeHandler for UpsertEnvelopeHandler {
             eq_schema,
             eq_config,
             schema_with_op,
+            key_indices: equality_indices.clone(),
         })
     }
 
-    async fn create_writer(&self, is_snapshot: bool) -> anyhow::Result<Box<dyn IcebergWriter>> {
+    async fn create_writer(
+        &self,
+        is_snapshot: bool,
+        partition_key: Option<PartitionKey>,
+    ) -> anyhow::Result<Box<dyn IcebergWriter>> {
         let data_parquet_writer = ParquetWriterBuilder::new(
             self.ctx.writer_properties.clone(),
             Arc::clone(&self.ctx.current_schema),
@@ -346,7 +385,7 @@ impl EnvelopeHandler for UpsertEnvelopeHandler {
 
         Ok(Box::new(
             builder
-                .build(None)
+                .build(partition_key)
                 .await
                 .context("Failed to create DeltaWriter")?,
         ))
@@ -391,6 +430,75 @@ impl EnvelopeHandler for UpsertEnvelopeHandler {
         RecordBatch::try_new(Arc::clone(&self.schema_with_op), columns)
             .context("Failed to create batch with op column")
     }
+
+    /// The `DeltaWriter` pairs each delete with the latest insert of the same key that it
+    /// has seen, so the operations on a key must not be reordered. The rows are reduced to
+    /// their net effect instead, and the deletes are written before the sorted inserts.
+    fn order_rows(
+        &self,
+        batch: RecordBatch,
+        sort_by: &[(String, SortOptions)],
+    ) -> anyhow::Result<RecordBatch> {
+        let (deletes, inserts) = net_upsert_rows(&batch, &self.key_indices)?;
+        let inserts = sort_batch(&inserts, sort_by)?;
+        concat_batches(&batch.schema(), [&deletes, &inserts]).context("Failed to order rows")
+    }
+}
+
+/// Reduce the rows of an upsert batch with an `__op` column to the deletes and inserts
+/// that make up their net effect, keyed by the columns at `key_indices`.
+///
+/// Within one batch only the first delete and the last insert of each key matter: the
+/// delete removes the row of a prior snapshot and the insert is the final state of the
+/// key. Everything in between is inserted and deleted again within the batch.
+fn net_upsert_rows(
+    batch: &RecordBatch,
+    key_indices: &[usize],
+) -> anyhow::Result<(RecordBatch, RecordBatch)> {
+    let converter = RowConverter::new(
+        key_indices
+            .iter()
+            .map(|i| RowSortField::new(batch.schema().field(*i).data_type().clone()))
+            .collect(),
+    )
+    .context("Failed to create key converter")?;
+    let key_columns: Vec<_> = key_indices
+        .iter()
+        .map(|i| Arc::clone(batch.column(*i)))
+        .collect();
+    let keys = converter
+        .convert_columns(&key_columns)
+        .context("Failed to convert key columns")?;
+    let ops = batch
+        .column(batch.num_columns() - 1)
+        .as_primitive::<Int32Type>();
+
+    // The first and last row of each key.
+    let mut ends: HashMap<_, (usize, usize)> = HashMap::new();
+    for row in 0..batch.num_rows() {
+        ends.entry(keys.row(row))
+            .and_modify(|(_, last)| *last = row)
+            .or_insert((row, row));
+    }
+    let mut deletes = Vec::new();
+    let mut inserts = Vec::new();
+    for (first, last) in ends.into_values() {
+        if ops.value(first) < 0 {
+            deletes.push(u32::try_from(first).expect("batch fits in u32"));
+        }
+        if ops.value(last) > 0 {
+            inserts.push(u32::try_from(last).expect("batch fits in u32"));
+        }
+    }
+    // Keep the rows in arrival order, so that the output doesn't depend on hashing.
+    deletes.sort_unstable();
+    inserts.sort_unstable();
+
+    let deletes = take_record_batch(batch, &UInt32Array::from(deletes))
+        .context("Failed to select deletes")?;
+    let inserts = take_record_batch(batch, &UInt32Array::from(inserts))
+        .context("Failed to select inserts")?;
+    Ok((deletes, inserts))
 }
 
 struct AppendEnvelopeHandler {
@@ -418,7 +526,11 @@ impl EnvelopeHandler for AppendEnvelopeHandler {
         })
     }
 
-    async fn create_writer(&self, _is_snapshot: bool) -> anyhow::Result<Box<dyn IcebergWriter>> {
+    async fn create_writer(
+        &self,
+        _is_snapshot: bool,
+        partition_key: Option<PartitionKey>,
+    ) -> anyhow::Result<Box<dyn IcebergWriter>> {
         let data_parquet_writer = ParquetWriterBuilder::new(
             self.ctx.writer_properties.clone(),
             Arc::clone(&self.ctx.current_schema),
@@ -434,7 +546,7 @@ impl EnvelopeHandler for AppendEnvelopeHandler {
         );
         Ok(Box::new(
             DataFileWriterBuilder::new(data_rolling_writer)
-                .build(None)
+                .build(partition_key)
                 .await
                 .context("Failed to create DataFileWriter")?,
         ))
@@ -480,6 +592,327 @@ impl EnvelopeHandler for AppendEnvelopeHandler {
     }
 }
 
+/// Splits record batches by the partition of the table's default partition spec that
+/// each row belongs to, and orders the rows of each partition by the table's sort order.
+struct PartitionRouter {
+    spec: PartitionSpecRef,
+    schema: SchemaRef,
+    /// The name of the source column, the transform, and the result type of each
+    /// partition field.
+    fields: Vec<(String, BoxedTransformFunction, PrimitiveType)>,
+    /// The name of the column and the direction of each sort field, or empty if rows
+    /// are written in the order they arrive.
+    sort_by: Vec<(String, SortOptions)>,
+}
+
+impl PartitionRouter {
+    fn new(
+        spec: PartitionSpecRef,
+        schema: SchemaRef,
+        sort_order: Option<&SortOrder>,
+    ) -> anyhow::Result<Self> {
+        let fields = spec
+            .fields()
+            .iter()
+            .map(|field| {
+                let source = schema.field_by_id(field.source_id).ok_or_else(|| {
+                    anyhow!(
+                        "partition field {} references unknown column id {}",
+                        field.name,
+                        field.source_id
+                    )
+                })?;
+                let transform = create_transform_function(&field.transform)
+                    .with_context(|| format!("Unsupported transform for {}", field.name))?;
+                let result_type = match field
+                    .transform
+                    .result_type(&source.field_type)
+                    .with_context(|| format!("Unsupported transform for {}", field.name))?
+                {
+                    Type::Primitive(typ) => typ,
+                    typ => anyhow::bail!(
+                        "partition field {} has non-primitive type {typ}",
+                        field.name
+                    ),
+                };
+                Ok((source.name.clone(), transform, result_type))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        let sort_by = sort_order
+            .map(|order| order.fields.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|field| {
+                let source = schema.field_by_id(field.source_id).ok_or_else(|| {
+                    anyhow!(
+                        "sort field references unknown column id {}",
+                        field.source_id
+                    )
+                })?;
+                if field.transform != Transform::Identity {
+                    anyhow::bail!(
+                        "unsupported transform {} for sort field {}",
+                        field.transform,
+                        source.name
+                    );
+                }
+                let options = SortOptions {
+                    descending: field.direction == SortDirection::Descending,
+                    nulls_first: field.null_order == NullOrder::First,
+                };
+                Ok((source.name.clone(), options))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            spec,
+            schema,
+            fields,
+            sort_by,
+        })
+    }
+
+    /// Split `batch` into one batch per partition, keyed by the partition values.
+    fn split(&self, batch: RecordBatch) -> anyhow::Result<Vec<(Struct, RecordBatch)>> {
+        if self.fields.is_empty() {
+            return Ok(vec![(Struct::empty(), batch)]);
+        }
+
+        let values = self
+            .fields
+            .iter()
+            .map(|(name, transform, _)| {
+                let column = batch
+                    .column_by_name(name)
+                    .ok_or_else(|| anyhow!("partition source column {name} missing from batch"))?;
+                transform
+                    .transform(Arc::clone(column))
+                    .with_context(|| format!("Failed to compute partition values of {name}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut partitions: Vec<(Struct, Vec<u32>)> = Vec::new();
+        let mut partition_indexes: HashMap<Struct, usize> = HashMap::new();
+        for row in 0..batch.num_rows() {
+            let key = values
+                .iter()
+                .zip_eq(&self.fields)
+                .map(|(array, (_, _, typ))| array_value_to_literal(array, row, typ))
+                .collect::<anyhow::Result<Struct>>()?;
+            let idx = *partition_indexes.entry(key.clone()).or_insert_with(|| {
+                partitions.push((key, Vec::new()));
+                partitions.len() - 1
+            });
+            partitions[idx]
+                .1
+                .push(u32::try_from(row).expect("batch fits in u32"));
+        }
+
+        if partitions.len() == 1 {
+            let (key, _) = partitions.pop().expect("one partition");
+            return Ok(vec![(key, batch)]);
+        }
+        partitions
+            .into_iter()
+            .map(|(key, rows)| {
+                let rows = take_record_batch(&batch, &UInt32Array::from(rows))
+                    .context("Failed to split batch by partition")?;
+                Ok((key, rows))
+            })
+            .collect()
+    }
+
+    /// The partition key to hand to writers of the partition with the given values.
+    fn partition_key(&self, values: Struct) -> Option<PartitionKey> {
+        (!self.fields.is_empty()).then(|| {
+            PartitionKey::new(self.spec.as_ref().clone(), Arc::clone(&self.schema), values)
+        })
+    }
+}
+
+/// Convert the partition value at `row` of `array`, the output of a partition transform
+/// whose Iceberg result type is `typ`, into an Iceberg literal. Only the types the planner
+/// allows as partition sources (and the types of their transforms) are supported.
+///
+/// Arrow has no distinct type for some Iceberg results, e.g. the `day` transform produces
+/// the days since the epoch as `Int32`, so `typ` decides the literal for those.
+fn array_value_to_literal(
+    array: &ArrayRef,
+    row: usize,
+    typ: &PrimitiveType,
+) -> anyhow::Result<Option<Literal>> {
+    if array.is_null(row) {
+        return Ok(None);
+    }
+    let literal = match array.data_type() {
+        DataType::Boolean => Literal::bool(array.as_boolean().value(row)),
+        DataType::Int16 => Literal::int(i32::from(array.as_primitive::<Int16Type>().value(row))),
+        DataType::Int32 => {
+            let value = array.as_primitive::<Int32Type>().value(row);
+            match typ {
+                PrimitiveType::Date => Literal::date(value),
+                _ => Literal::int(value),
+            }
+        }
+        DataType::Int64 => Literal::long(array.as_primitive::<Int64Type>().value(row)),
+        DataType::Utf8 => Literal::string(array.as_string::<i32>().value(row)),
+        DataType::LargeUtf8 => Literal::string(array.as_string::<i64>().value(row)),
+        DataType::Date32 => Literal::date(array.as_primitive::<Date32Type>().value(row)),
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => {
+            let micros = array.as_primitive::<TimestampMicrosecondType>().value(row);
+            if tz.is_some() {
+                Literal::timestamptz(micros)
+            } else {
+                Literal::timestamp(micros)
+            }
+        }
+        typ => anyhow::bail!("unsupported partition value type {typ}"),
+    };
+    Ok(Some(literal))
+}
+
+/// Sort the rows of `batch` by the named columns.
+fn sort_batch(
+    batch: &RecordBatch,
+    sort_by: &[(String, SortOptions)],
+) -> anyhow::Result<RecordBatch> {
+    let columns = sort_by
+        .iter()
+        .map(|(name, options)| {
+            let values = batch
+                .column_by_name(name)
+                .ok_or_else(|| anyhow!("sort column {name} missing from batch"))?;
+            Ok(SortColumn {
+                values: Arc::clone(values),
+                options: Some(*options),
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let indices = lexsort_to_indices(&columns, None).context("Failed to sort batch")?;
+    take_record_batch(batch, &indices).context("Failed to sort batch")
+}
+
+/// The rows of one partition of a batch, held until the batch closes so that they can
+/// be written in the table's sort order.
+#[derive(Default)]
+struct SortBuffer {
+    /// Rows that have been concatenated into larger batches.
+    chunks: Vec<RecordBatch>,
+    /// Rows as they arrived, usually one per batch.
+    pending: Vec<RecordBatch>,
+}
+
+impl SortBuffer {
+    /// The number of pending batches to concatenate at once. Rows arrive one at a time,
+    /// and holding each of them in its own batch would waste memory.
+    const CHUNK_BATCHES: usize = 1024;
+
+    fn push(&mut self, batch: RecordBatch) -> anyhow::Result<()> {
+        self.pending.push(batch);
+        if self.pending.len() >= Self::CHUNK_BATCHES {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    fn compact(&mut self) -> anyhow::Result<()> {
+        if let Some(first) = self.pending.first() {
+            let chunk = concat_batches(&first.schema(), &self.pending)
+                .context("Failed to buffer rows for sorting")?;
+            self.chunks.push(chunk);
+            self.pending.clear();
+        }
+        Ok(())
+    }
+
+    /// All buffered rows in a single batch, or `None` if there are none.
+    fn finish(mut self) -> anyhow::Result<Option<RecordBatch>> {
+        self.compact()?;
+        let Some(first) = self.chunks.first() else {
+            return Ok(None);
+        };
+        let batch = concat_batches(&first.schema(), &self.chunks)
+            .context("Failed to buffer rows for sorting")?;
+        Ok(Some(batch))
+    }
+}
+
+/// The writers of one batch, one per partition of the table that the batch has
+/// rows for. Writers are created when the first row of their partition arrives.
+///
+/// If the table has a sort order, rows can only be put in order once all of them are
+/// known, so the rows of each partition are buffered in memory and written when the
+/// batch closes. Each data file then holds a sorted run of the rows.
+struct PartitionedWriter {
+    is_snapshot: bool,
+    writers: HashMap<Struct, Box<dyn IcebergWriter>>,
+    buffers: HashMap<Struct, SortBuffer>,
+}
+
+impl PartitionedWriter {
+    fn new(is_snapshot: bool) -> Self {
+        Self {
+            is_snapshot,
+            writers: HashMap::new(),
+            buffers: HashMap::new(),
+        }
+    }
+
+    async fn write<H: EnvelopeHandler>(
+        &mut self,
+        handler: &H,
+        router: &PartitionRouter,
+        batch: RecordBatch,
+    ) -> anyhow::Result<()> {
+        for (values, batch) in router.split(batch)? {
+            if router.sort_by.is_empty() {
+                self.write_partition(handler, router, values, batch).await?;
+            } else {
+                self.buffers.entry(values).or_default().push(batch)?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn write_partition<H: EnvelopeHandler>(
+        &mut self,
+        handler: &H,
+        router: &PartitionRouter,
+        values: Struct,
+        batch: RecordBatch,
+    ) -> anyhow::Result<()> {
+        let writer = match self.writers.entry(values) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let partition_key = router.partition_key(entry.key().clone());
+                let writer = handler
+                    .create_writer(self.is_snapshot, partition_key)
+                    .await?;
+                entry.insert(writer)
+            }
+        };
+        writer.write(batch).await?;
+        Ok(())
+    }
+
+    async fn close<H: EnvelopeHandler>(
+        &mut self,
+        handler: &H,
+        router: &PartitionRouter,
+    ) -> anyhow::Result<Vec<DataFile>> {
+        for (values, buffer) in std::mem::take(&mut self.buffers) {
+            if let Some(batch) = buffer.finish()? {
+                let batch = handler.order_rows(batch, &router.sort_by)?;
+                self.write_partition(handler, router, values, batch).await?;
+            }
+        }
+        let mut data_files = Vec::new();
+        for (_, mut writer) in self.writers.drain() {
+            data_files.extend(writer.close().await?);
+        }
+        Ok(data_files)
+    }
+}
+
 /// Add Parquet field IDs to an Arrow schema. Iceberg requires field IDs in the
 /// Parquet metadata for schema evolution tracking. Field IDs are assigned
 /// recursively to all nested fields (structs, lists, maps) using a depth-first,
@@ -912,12 +1345,141 @@ async fn try_commit_batch(
     }
 }
 
+/// Build the partition spec and sort order declared by the `PARTITION BY` and `SORT BY`
+/// options of `connection`, with fields resolved against the table schema by column name.
+fn declared_table_layout(
+    connection: &IcebergSinkConnection,
+    desc: &RelationDesc,
+    schema: &Schema,
+) -> anyhow::Result<(UnboundPartitionSpec, SortOrder)> {
+    let field_id = |column: usize| -> anyhow::Result<(i32, String)> {
+        let name = desc.get_name(column).to_string();
+        let field = schema
+            .field_by_name(&name)
+            .ok_or_else(|| anyhow!("column {name} is missing from the Iceberg schema"))?;
+        Ok((field.id, name))
+    };
+
+    let mut partition_spec = UnboundPartitionSpec::builder();
+    for field in &connection.partition_by {
+        let (source_id, name) = field_id(field.column)?;
+        // Name partition fields the way other Iceberg engines do, so that the
+        // partition directories look familiar to downstream users.
+        let (target_name, transform) = match field.transform {
+            IcebergPartitionTransform::Identity => (name, Transform::Identity),
+            IcebergPartitionTransform::Bucket(n) => {
+                (format!("{name}_bucket"), Transform::Bucket(n))
+            }
+            IcebergPartitionTransform::Truncate(w) => {
+                (format!("{name}_trunc"), Transform::Truncate(w))
+            }
+            IcebergPartitionTransform::Day => (format!("{name}_day"), Transform::Day),
+            IcebergPartitionTransform::Hour => (format!("{name}_hour"), Transform::Hour),
+        };
+        partition_spec = partition_spec
+            .add_partition_field(source_id, target_name, transform)
+            .context("Failed to build Iceberg partition spec")?;
+    }
+
+    let mut sort_order = SortOrder::builder();
+    if !connection.sort_by.is_empty() {
+        sort_order.with_order_id(1);
+    }
+    for field in &connection.sort_by {
+        let (source_id, _) = field_id(field.column)?;
+        sort_order.with_sort_field(
+            SortField::builder()
+                .source_id(source_id)
+                .transform(Transform::Identity)
+                .direction(if field.descending {
+                    SortDirection::Descending
+                } else {
+                    SortDirection::Ascending
+                })
+                .null_order(if field.nulls_first {
+                    NullOrder::First
+                } else {
+                    NullOrder::Last
+                })
+                .build(),
+        );
+    }
+    let sort_order = sort_order
+        .build(schema)
+        .context("Failed to build Iceberg sort order")?;
+
+    Ok((partition_spec.build(), sort_order))
+}
+
+/// Check that an existing Iceberg table has the partition spec and sort order declared
+/// on the sink. The sink can only route rows to partitions of the table's default spec,
+/// so a table created by some other engine must agree with the declared spec. The sort
+/// order is only checked when one is declared.
+fn validate_table_layout(
+    table: &Table,
+    partition_spec: &UnboundPartitionSpec,
+    sort_order: &SortOrder,
+) -> anyhow::Result<()> {
+    let metadata = table.metadata();
+    let table_name = table.identifier().name();
+
+    let existing_spec: Vec<_> = metadata
+        .default_partition_spec()
+        .fields()
+        .iter()
+        .map(|field| (field.source_id, field.transform))
+        .collect();
+    let declared_spec: Vec<_> = partition_spec
+        .fields()
+        .iter()
+        .map(|field| (field.source_id, field.transform))
+        .collect();
+    if existing_spec != declared_spec {
+        anyhow::bail!(
+            "Iceberg table '{}' has partition spec {:?}, but the sink declares {:?}",
+            table_name,
+            existing_spec,
+            declared_spec,
+        );
+    }
+
+    if !sort_order.fields.is_empty() {
+        let sort_key = |field: &SortField| {
+            (
+                field.source_id,
+                field.transform,
+                field.direction,
+                field.null_order,
+            )
+        };
+        let existing_order: Vec<_> = metadata
+            .default_sort_order()
+            .fields
+            .iter()
+            .map(sort_key)
+            .collect();
+        let declared_order: Vec<_> = sort_order.fields.iter().map(sort_key).collect();
+        if existing_order != declared_order {
+            anyhow::bail!(
+                "Iceberg table '{}' has sort order {:?}, but the sink declares {:?}",
+                table_name,
+                existing_order,
+                declared_order,
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// Load an existing Iceberg table or create it if it doesn't exist.
 async fn load_or_create_table(
     catalog: &dyn Catalog,
     namespace: String,
     table_name: String,
     schema: &Schema,
+    partition_spec: UnboundPartitionSpec,
+    sort_order: SortOrder,
 ) -> anyhow::Result<iceberg::table::Table> {
     let namespace_ident = NamespaceIdent::new(namespace.clone());
     let table_ident = TableIdent::new(namespace_ident.clone(), table_name.clone());
@@ -925,8 +1487,9 @@ async fn load_or_create_table(
     // Try to load the table first
     match catalog.load_table(&table_ident).await {
         Ok(table) => {
-            // Table exists, return it
+            // Table exists, make sure it is laid out the way the sink expects.
             // TODO: Add proper schema evolution/validation to ensure compatibility
+            validate_table_layout(&table, &partition_spec, &sort_order)?;
             Ok(table)
         }
         Err(err) => {
@@ -941,9 +1504,8 @@ async fn load_or_create_table(
                 let table_creation = TableCreation::builder()
                     .name(table_name.clone())
                     .schema(schema.clone())
-                    // Use unpartitioned spec by default
-                    // TODO: Consider making partition spec configurable
-                    // .partition_spec(UnboundPartitionSpec::builder().build())
+                    .partition_spec(partition_spec)
+                    .sort_order(sort_order)
                     .build();
 
                 catalog
@@ -1120,6 +1682,7 @@ fn mint_batch_descriptions<'scope>(
     let mut input = builder.new_input_for(input, Pipeline, &batch_desc_output);
 
     let as_of = sink.as_of.clone();
+    let from_desc = sink.from_desc.clone();
     let commit_interval = sink
         .commit_interval
         .expect("the planner should have enforced this")
@@ -1146,11 +1709,15 @@ fn mint_batch_descriptions<'scope>(
                     )
                 })?;
 
+            let (partition_spec, sort_order) =
+                declared_table_layout(&connection, &from_desc, initial_schema.as_ref())?;
             let table = load_or_create_table(
                 catalog.as_ref(),
                 connection.namespace.clone(),
                 connection.table.clone(),
                 initial_schema.as_ref(),
+                partition_spec,
+                sort_order,
             )
             .await?;
             debug!(
@@ -1378,12 +1945,15 @@ fn mint_batch_descriptions<'scope>(
 struct SerializableDataFile {
     pub data_file: DataFile,
     pub schema: Schema,
+    pub partition_type: StructType,
+    pub partition_spec_id: i32,
 }
 
 /// A wrapper around Iceberg's DataFile that implements Serialize and Deserialize.
 /// This is slightly complicated by the fact that Iceberg's DataFile doesn't implement
 /// these traits directly, so we serialize to/from Avro bytes (which Iceberg supports natively).
-/// The avro ser(de) also requires the Iceberg schema to be provided, so we include that as well.
+/// The avro ser(de) also requires the Iceberg schema and the partition type and spec of the
+/// file to be provided, so we include those as well.
 /// It is distinctly possible that this is overkill, but it avoids re-implementing
 /// Iceberg's serialization logic here.
 /// If at some point this becomes a serious overhead, we can revisit this decision.
@@ -1392,6 +1962,9 @@ struct AvroDataFile {
     pub data_file: Vec<u8>,
     /// Schema serialized as JSON bytes to avoid bincode issues with HashMap
     pub schema: Vec<u8>,
+    /// Partition type serialized as JSON bytes, like the schema.
+    pub partition_type: Vec<u8>,
+    pub partition_spec_id: i32,
 }
 
 impl From<SerializableDataFile> for AvroDataFile {
@@ -1400,12 +1973,19 @@ impl From<SerializableDataFile> for AvroDataFile {
         write_data_files_to_avro(
             &mut data_file,
             [value.data_file],
-            &StructType::new(vec![]),
+            &value.partition_type,
             FormatVersion::V2,
         )
         .expect("serialization into buffer");
         let schema = serde_json::to_vec(&value.schema).expect("schema serialization");
-        AvroDataFile { data_file, schema }
+        let partition_type =
+            serde_json::to_vec(&value.partition_type).expect("partition type serialization");
+        AvroDataFile {
+            data_file,
+            schema,
+            partition_type,
+            partition_spec_id: value.partition_spec_id,
+        }
     }
 }
 
@@ -1415,18 +1995,25 @@ impl TryFrom<AvroDataFile> for SerializableDataFile {
     fn try_from(value: AvroDataFile) -> Result<Self, Self::Error> {
         let schema: Schema = serde_json::from_slice(&value.schema)
             .map_err(|e| format!("Failed to deserialize schema: {}", e))?;
+        let partition_type: StructType = serde_json::from_slice(&value.partition_type)
+            .map_err(|e| format!("Failed to deserialize partition type: {}", e))?;
         let data_files = read_data_files_from_avro(
             &mut &*value.data_file,
             &schema,
-            0,
-            &StructType::new(vec![]),
+            value.partition_spec_id,
+            &partition_type,
             FormatVersion::V2,
         )
         .map_err_to_string_with_causes()?;
         let Some(data_file) = data_files.into_iter().next() else {
             return Err("No DataFile found in Avro data".into());
         };
-        Ok(SerializableDataFile { data_file, schema })
+        Ok(SerializableDataFile {
+            data_file,
+            schema,
+            partition_type,
+            partition_spec_id: value.partition_spec_id,
+        })
     }
 }
 
@@ -1441,12 +2028,16 @@ impl BoundedDataFile {
     pub fn new(
         file: DataFile,
         schema: Schema,
+        partition_type: StructType,
+        partition_spec_id: i32,
         batch_desc: (Antichain<Timestamp>, Antichain<Timestamp>),
     ) -> Self {
         Self {
             data_file: SerializableDataFile {
                 data_file: file,
                 schema,
+                partition_type,
+                partition_spec_id,
             },
             batch_desc,
         }
@@ -1537,6 +2128,19 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
 
                 let table_metadata = table.metadata().clone();
                 let current_schema = Arc::clone(table_metadata.current_schema());
+                let partition_spec = Arc::clone(table_metadata.default_partition_spec());
+                let partition_type = partition_spec
+                    .partition_type(current_schema.as_ref())
+                    .context("Failed to compute Iceberg partition type")?;
+                // The table's sort order was checked against the declared one when the
+                // table was loaded, so only apply it if the sink declares one.
+                let sort_order = (!connection.sort_by.is_empty())
+                    .then(|| table_metadata.default_sort_order().as_ref());
+                let router = PartitionRouter::new(
+                    Arc::clone(&partition_spec),
+                    Arc::clone(&current_schema),
+                    sort_order,
+                )?;
 
                 // Merge Materialize extension metadata into the Iceberg schema.
                 // We need extension metadata for ArrowBuilder to work correctly (it uses
@@ -1593,10 +2197,10 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
 
                 // Track batches currently being written. When a row arrives, we check if it belongs
                 // to an in-flight batch. When frontiers advance to a batch's upper, we close the
-                // writer and emit its data files downstream.
+                // writers and emit their data files downstream.
                 let mut in_flight_batches: VecDeque<(
                     (Antichain<Timestamp>, Antichain<Timestamp>),
-                    Box<dyn IcebergWriter>,
+                    PartitionedWriter,
                 )> = VecDeque::new();
 
                 // The bounds of the most recently received batch description and input batch.
@@ -1648,7 +2252,7 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
                                         upper.pretty(),
                                         is_snapshot
                                     );
-                                    let batch_writer = handler.create_writer(is_snapshot).await?;
+                                    let batch_writer = PartitionedWriter::new(is_snapshot);
                                     in_flight_batches.push_back((batch_desc.clone(), batch_writer));
                                 }
                             }
@@ -1705,7 +2309,7 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
                     // How to write rows from a(n arrangement) batch into a(n Iceberg) batch.
                     let write_rows = async |rows: &OrdValBatch<_>,
                                             (lower, upper): BatchDescription,
-                                            batch_writer: &mut Box<dyn IcebergWriter>|
+                                            batch_writer: &mut PartitionedWriter|
                            -> Result<(), anyhow::Error> {
                         for_each_diff_pair_async(
                             rows,
@@ -1722,7 +2326,7 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
                                 staged_messages_since_flush +=
                                     u64::cast_from(record_batch.num_rows());
                                 batch_writer
-                                    .write(record_batch)
+                                    .write(&handler, &router, record_batch)
                                     .await
                                     .context("failed to write recordbatch")?;
                                 if staged_messages_since_flush >= 10_000 {
@@ -1744,10 +2348,10 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
 
                     // How to seal the data files for an Iceberg commit.
                     let close_batch = async |batch_desc: BatchDescription,
-                                             batch_writer: &mut Box<dyn IcebergWriter>|
+                                             batch_writer: &mut PartitionedWriter|
                            -> Result<(), anyhow::Error> {
                         let close_started_at = Instant::now();
-                        let data_files = batch_writer.close().await;
+                        let data_files = batch_writer.close(&handler, &router).await;
                         metrics
                             .writer_close_duration_seconds
                             .observe(close_started_at.elapsed().as_secs_f64());
@@ -1773,6 +2377,8 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
                             let file = BoundedDataFile::new(
                                 data_file,
                                 current_schema.as_ref().clone(),
+                                partition_type.clone(),
+                                partition_spec.spec_id(),
                                 batch_desc.clone(),
                             );
                             output.give(&capset[0], file);
@@ -1911,7 +2517,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use iceberg::spec::{PrimitiveType, Type};
+    use arrow::array::{StringArray, TimestampMicrosecondArray};
+    use iceberg::spec::{NestedField, PartitionSpec};
     use mz_repr::SqlScalarType;
     use mz_storage_types::sinks::ICEBERG_UINT64_DECIMAL_PRECISION;
 
@@ -2351,6 +2958,130 @@ mod tests {
             assert!(outputs.is_empty());
         }
     }
+
+    #[mz_ore::test]
+    fn day_partition_values_are_dates() {
+        let schema = Arc::new(
+            Schema::builder()
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+                    NestedField::optional(2, "ts", Type::Primitive(PrimitiveType::Timestamp))
+                        .into(),
+                ])
+                .build()
+                .expect("valid schema"),
+        );
+        let spec = PartitionSpec::builder(Arc::clone(&schema))
+            .add_partition_field("ts", "ts_day", Transform::Day)
+            .expect("valid partition field")
+            .build()
+            .expect("valid partition spec");
+        let router = PartitionRouter::new(Arc::new(spec), Arc::clone(&schema), None)
+            .expect("supported partition spec");
+
+        // 2024-01-01 00:00:00 UTC, which is day 19723 since the epoch.
+        let midnight = 1_704_067_200_000_000;
+        let batch = RecordBatch::try_new(
+            Arc::new(ArrowSchema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("ts", DataType::Timestamp(TimeUnit::Microsecond, None), true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2, 3])),
+                Arc::new(TimestampMicrosecondArray::from(vec![
+                    Some(midnight),
+                    None,
+                    Some(midnight + 1),
+                ])),
+            ],
+        )
+        .expect("valid batch");
+
+        let partitions: Vec<_> = router
+            .split(batch)
+            .expect("split succeeds")
+            .into_iter()
+            .map(|(key, rows)| (key, rows.num_rows()))
+            .collect();
+        assert_eq!(
+            partitions,
+            vec![
+                (Struct::from_iter([Some(Literal::date(19723))]), 2),
+                (Struct::from_iter([None]), 1),
+            ]
+        );
+
+        // The partition values must have the type that the spec declares, or the
+        // data files can't be committed.
+        let partition_type = router
+            .spec
+            .partition_type(&schema)
+            .expect("valid partition type");
+        assert_eq!(
+            *partition_type.fields()[0].field_type,
+            Type::Primitive(PrimitiveType::Date)
+        );
+    }
+
+    #[mz_ore::test]
+    fn upsert_rows_are_reduced_before_sorting() {
+        let schema = Arc::new(ArrowSchema::new(vec![
+            Field::new("k", DataType::Utf8, false),
+            Field::new("v", DataType::Int64, true),
+            Field::new("__op", DataType::Int32, false),
+        ]));
+        // `a` is inserted and updated twice, `b` is deleted, `c` is updated, and `d` is
+        // inserted and deleted again.
+        let rows = [
+            ("a", 3, 1),
+            ("b", 1, -1),
+            ("a", 3, -1),
+            ("a", 5, 1),
+            ("c", 2, -1),
+            ("d", 4, 1),
+            ("a", 5, -1),
+            ("a", 1, 1),
+            ("c", 7, 1),
+            ("d", 4, -1),
+        ];
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from_iter_values(rows.iter().map(|r| r.0))),
+                Arc::new(Int64Array::from_iter_values(rows.iter().map(|r| r.1))),
+                Arc::new(Int32Array::from_iter_values(rows.iter().map(|r| r.2))),
+            ],
+        )
+        .expect("valid batch");
+
+        let values = |batch: &RecordBatch| -> Vec<(String, i64)> {
+            let keys = batch.column(0).as_string::<i32>();
+            let values = batch.column(1).as_primitive::<Int64Type>();
+            (0..batch.num_rows())
+                .map(|i| (keys.value(i).to_string(), values.value(i)))
+                .collect()
+        };
+
+        let (deletes, inserts) = net_upsert_rows(&batch, &[0]).expect("reduce succeeds");
+        assert_eq!(
+            values(&deletes),
+            vec![("b".to_string(), 1), ("c".to_string(), 2)]
+        );
+        assert_eq!(
+            values(&inserts),
+            vec![("a".to_string(), 1), ("c".to_string(), 7)]
+        );
+
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let sorted = sort_batch(&inserts, &[("v".to_string(), descending)]).expect("sorted");
+        assert_eq!(
+            values(&sorted),
+            vec![("c".to_string(), 7), ("a".to_string(), 1)]
+        );
+    }
 }
 
 /// Commit completed batches to Iceberg as snapshots.
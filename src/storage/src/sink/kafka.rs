@@ -94,6 +94,7 @@ use mz_interchange::avro::{AvroEncoder, AvroSchemaId};
 use mz_interchange::encode::Encode;
 use mz_interchange::envelopes::{dbz_format, for_each_diff_pair};
 use mz_interchange::json::JsonEncoder;
+use mz_interchange::protobuf::{ProtobufEncoder, ProtobufSchemaGenerator, ProtobufSchemaId};
 use mz_interchange::text_binary::{BinaryEncoder, TextEncoder};
 use mz_kafka_util::admin::EnsureTopicConfig;
 use mz_kafka_util::client::{
@@ -113,6 +114,7 @@ use mz_repr::{Datum, DatumVec, Diff, GlobalId, RelationDesc, Row, RowArena, Time
 use mz_storage_client::sink::progress_key::ProgressKey;
 use mz_storage_types::StorageDiff;
 use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::connections::CsrConnection;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::dyncfgs::{
     KAFKA_BUFFERED_EVENT_RESIZE_THRESHOLD_ELEMENTS, KAFKA_SINK_BATCH_NUM_MESSAGES,
@@ -146,6 +148,7 @@ use timely::progress::{Antichain, Timestamp as _};
 use tokio::sync::watch;
 use tokio::time::{self, MissedTickBehavior};
 use tracing::{debug, error, info, warn};
+use uuid::Uuid;
 
 impl<'scope> SinkRender<'scope> for KafkaSinkConnection {
     fn get_key_indices(&self) -> Option<&[usize]> {
@@ -1398,8 +1401,14 @@ async fn fetch_partition_count_loop<F>(
     }
 }
 
-/// Register an Avro `schema` with the sink's schema registry and build an
-/// [`AvroEncoder`] framing records for that registry.
+/// The id a schema registry assigned to a schema published by
+/// [`publish_schema`].
+enum PublishedSchemaId {
+    Confluent(i32),
+    Glue(Uuid),
+}
+
+/// Register `schema` with the sink's schema registry.
 ///
 /// `subject` is the registry name for the schema, either `{topic}-key` or
 /// `{topic}-value`. It is the Confluent subject or, for Glue, the schema name.
@@ -1407,16 +1416,15 @@ async fn fetch_partition_count_loop<F>(
 /// each time the sink is rendered. The `WireFormat` must carry a registry:
 /// sinks are never built without one (see the sink planner), so a missing
 /// registry is unreachable.
-async fn build_avro_encoder(
-    desc: RelationDesc,
-    debezium: bool,
+async fn publish_schema(
     schema: String,
+    schema_type: mz_ccsr::SchemaType,
     compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
     wire_format: WireFormat,
     subject: String,
     storage_configuration: &StorageConfiguration,
-) -> Result<AvroEncoder, anyhow::Error> {
-    let schema_id = match wire_format {
+) -> Result<PublishedSchemaId, anyhow::Error> {
+    match wire_format {
         WireFormat::Confluent {
             registry: Some(csr),
         } => {
@@ -1424,13 +1432,13 @@ async fn build_avro_encoder(
             let schema_id = mz_storage_client::sink::publish_kafka_schema(
                 ccsr,
                 subject,
-                schema.clone(),
-                mz_ccsr::SchemaType::Avro,
+                schema,
+                schema_type,
                 compatibility_level,
             )
             .await
             .context("error publishing kafka schemas for sink")?;
-            AvroSchemaId::Confluent(schema_id)
+            Ok(PublishedSchemaId::Confluent(schema_id))
         }
         WireFormat::Glue {
             registry: Some(glue),
@@ -1450,6 +1458,11 @@ async fn build_avro_encoder(
             let client = mz_aws_glue_schema_registry::ClientConfig::new(sdk_config).build();
             let compatibility =
                 compatibility_level.map(mz_storage_client::sink::glue_compatibility_from_csr);
+            let data_format = match schema_type {
+                mz_ccsr::SchemaType::Avro => mz_aws_glue_schema_registry::DataFormat::Avro,
+                mz_ccsr::SchemaType::Protobuf => mz_aws_glue_schema_registry::DataFormat::Protobuf,
+                mz_ccsr::SchemaType::Json => mz_aws_glue_schema_registry::DataFormat::Json,
+            };
             // `subject` is used verbatim as the Glue schema name. When it is
             // topic-derived (`{topic}-key` or `{topic}-value`) no mangling is
             // needed: Kafka topic chars `[a-zA-Z0-9._-]` are a subset of Glue's
@@ -1469,21 +1482,114 @@ async fn build_avro_encoder(
                 client,
                 glue.registry_name,
                 subject,
-                schema.clone(),
+                schema,
+                data_format,
                 compatibility,
             )
             .await
             .context("error publishing glue schemas for sink")?;
-            AvroSchemaId::Glue(schema_version_id)
+            Ok(PublishedSchemaId::Glue(schema_version_id))
         }
         other => unreachable!(
-            "sink Avro wire_format must carry a registry, got {:?}",
-            other
+            "sink {:?} wire_format must carry a registry, got {:?}",
+            schema_type, other
         ),
+    }
+}
+
+/// Register an Avro `schema` with the sink's schema registry and build an
+/// [`AvroEncoder`] framing records for that registry.
+///
+/// See [`publish_schema`] for how `subject` and `wire_format` are used.
+async fn build_avro_encoder(
+    desc: RelationDesc,
+    debezium: bool,
+    schema: String,
+    compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+    wire_format: WireFormat,
+    subject: String,
+    storage_configuration: &StorageConfiguration,
+) -> Result<AvroEncoder, anyhow::Error> {
+    let schema_id = match publish_schema(
+        schema.clone(),
+        mz_ccsr::SchemaType::Avro,
+        compatibility_level,
+        wire_format,
+        subject,
+        storage_configuration,
+    )
+    .await?
+    {
+        PublishedSchemaId::Confluent(id) => AvroSchemaId::Confluent(id),
+        PublishedSchemaId::Glue(id) => AvroSchemaId::Glue(id),
     };
     Ok(AvroEncoder::new(desc, debezium, &schema, schema_id))
 }
 
+/// Register a Protobuf `schema` with the sink's schema registry and build a
+/// [`ProtobufEncoder`] framing messages for that registry.
+///
+/// The encoder's message descriptor is regenerated from `desc`, which yields
+/// the same schema the planner derived. See [`publish_schema`] for how
+/// `subject` and `wire_format` are used.
+async fn build_protobuf_encoder(
+    desc: RelationDesc,
+    debezium: bool,
+    schema: String,
+    message_name: String,
+    compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+    wire_format: WireFormat,
+    subject: String,
+    storage_configuration: &StorageConfiguration,
+) -> Result<ProtobufEncoder, anyhow::Error> {
+    let generator = ProtobufSchemaGenerator::new(desc, debezium, &message_name)?;
+    let schema_id = match publish_schema(
+        schema,
+        mz_ccsr::SchemaType::Protobuf,
+        compatibility_level,
+        wire_format,
+        subject,
+        storage_configuration,
+    )
+    .await?
+    {
+        PublishedSchemaId::Confluent(id) => ProtobufSchemaId::Confluent(id),
+        PublishedSchemaId::Glue(id) => ProtobufSchemaId::Glue(id),
+    };
+    Ok(ProtobufEncoder::new(generator, schema_id))
+}
+
+/// Register a JSON `schema` with the sink's Confluent schema registry and build
+/// a [`JsonEncoder`] framing documents for that registry.
+///
+/// See [`publish_schema`] for how `subject` is used.
+async fn build_json_encoder(
+    desc: RelationDesc,
+    debezium: bool,
+    schema: String,
+    compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+    csr_connection: CsrConnection,
+    subject: String,
+    storage_configuration: &StorageConfiguration,
+) -> Result<JsonEncoder, anyhow::Error> {
+    let schema_id = match publish_schema(
+        schema,
+        mz_ccsr::SchemaType::Json,
+        compatibility_level,
+        WireFormat::Confluent {
+            registry: Some(csr_connection),
+        },
+        subject,
+        storage_configuration,
+    )
+    .await?
+    {
+        PublishedSchemaId::Confluent(id) => id,
+        PublishedSchemaId::Glue(_) => unreachable!("published to a Confluent registry"),
+    };
+    Ok(JsonEncoder::new(desc, debezium, Some(schema_id)))
+}
+
 /// Walks each arrangement batch and emits encoded Kafka messages, one per
 /// `DiffPair` observed at each `(key, timestamp)`.
 ///
@@ -1526,7 +1632,24 @@ fn encode_collection<'scope>(
                         Some(Box::new(TextEncoder::new(desc, false)))
                     }
                     (Some(desc), Some(KafkaSinkFormatType::Json)) => {
-                        Some(Box::new(JsonEncoder::new(desc, false)))
+                        Some(Box::new(JsonEncoder::new(desc, false, None)))
+                    }
+                    (Some(desc), Some(KafkaSinkFormatType::JsonSchema {
+                        schema,
+                        compatibility_level,
+                        csr_connection,
+                    })) => {
+                        let encoder = build_json_encoder(
+                            desc,
+                            false,
+                            schema,
+                            compatibility_level,
+                            csr_connection,
+                            format!("{}-key", connection.topic),
+                            &storage_configuration,
+                        )
+                        .await?;
+                        Some(Box::new(encoder))
                     }
                     (Some(desc), Some(KafkaSinkFormatType::Avro {
                         schema,
@@ -1548,6 +1671,28 @@ fn encode_collection<'scope>(
                         .await?;
                         Some(Box::new(encoder))
                     }
+                    (Some(desc), Some(KafkaSinkFormatType::Protobuf {
+                        schema,
+                        message_name,
+                        compatibility_level,
+                        schema_name,
+                        wire_format,
+                    })) => {
+                        let subject = schema_name
+                            .unwrap_or_else(|| format!("{}-key", connection.topic));
+                        let encoder = build_protobuf_encoder(
+                            desc,
+                            false,
+                            schema,
+                            message_name,
+                            compatibility_level,
+                            wire_format,
+                            subject,
+                            &storage_configuration,
+                        )
+                        .await?;
+                        Some(Box::new(encoder))
+                    }
                     (None, None) => None,
                     (desc, format) => {
                         return Err(anyhow!(
@@ -1564,7 +1709,26 @@ fn encode_collection<'scope>(
             let value_encoder: Box<dyn Encode> = match connection.format.value_format {
                 KafkaSinkFormatType::Bytes => Box::new(BinaryEncoder::new(value_desc, debezium)),
                 KafkaSinkFormatType::Text => Box::new(TextEncoder::new(value_desc, debezium)),
-                KafkaSinkFormatType::Json => Box::new(JsonEncoder::new(value_desc, debezium)),
+                KafkaSinkFormatType::Json => {
+                    Box::new(JsonEncoder::new(value_desc, debezium, None))
+                }
+                KafkaSinkFormatType::JsonSchema {
+                    schema,
+                    compatibility_level,
+                    csr_connection,
+                } => {
+                    let encoder = build_json_encoder(
+                        value_desc,
+                        debezium,
+                        schema,
+                        compatibility_level,
+                        csr_connection,
+                        format!("{}-value", connection.topic),
+                        &storage_configuration,
+                    )
+                    .await?;
+                    Box::new(encoder)
+                }
                 KafkaSinkFormatType::Avro {
                     schema,
                     compatibility_level,
@@ -1585,6 +1749,28 @@ fn encode_collection<'scope>(
                     .await?;
                     Box::new(encoder)
                 }
+                KafkaSinkFormatType::Protobuf {
+                    schema,
+                    message_name,
+                    compatibility_level,
+                    schema_name,
+                    wire_format,
+                } => {
+                    let subject = schema_name
+                        .unwrap_or_else(|| format!("{}-value", connection.topic));
+                    let encoder = build_protobuf_encoder(
+                        value_desc,
+                        debezium,
+                        schema,
+                        message_name,
+                        compatibility_level,
+                        wire_format,
+                        subject,
+                        &storage_configuration,
+                    )
+                    .await?;
+                    Box::new(encoder)
+                }
             };
 
             // !IMPORTANT!
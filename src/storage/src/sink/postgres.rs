@@ -0,0 +1,557 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`PostgresSinkConnection`]. The dataflow consists
+//! of two operators in order to take advantage of all the available workers.
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ stream of arrangement batches (trace reader dropped)
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃    row      ┃ walks each batch's cursor and emits the
+//!        ┃   encoder   ┃ text-encoded key and new row per DiffPair
+//!        ┗━━━━━━┯━━━━━━┛
+//!               │ encoded data
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃  postgres   ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━┯━━━━━━━━┯━┛
+//!   writes  │        │ uppers
+//!  ╭────────v──╮ ╭───v──────╮
+//!  │  target   │ │ progress │  <- uppers are only recorded once the
+//!  │   table   │ │  shard   │     upstream transaction committed
+//!  ╰───────────╯ ╰──────────╯
+//! ```
+//!
+//! # Writes
+//!
+//! Once the input frontier advances, the updates at all timestamps before it are folded into the
+//! latest row for each key. Keys whose latest row was retracted are deleted from the target table,
+//! and all other rows are inserted with `INSERT ... ON CONFLICT (<key>) DO UPDATE`, which requires
+//! the target table to have a unique index on exactly the sink's key columns.
+//!
+//! Values are sent as text and cast to the type of the target column upstream, so the target
+//! table may use any type that accepts the text representation of the sinked column.
+//!
+//! # Progress
+//!
+//! Each sink records the frontier it has written up to in a side table,
+//! `<schema>._mz_sink_progress`, which is created if it doesn't exist. The frontier is updated in
+//! the same transaction as the writes to the target table, so on restart the sink resumes exactly
+//! where the last committed transaction left off.
+//!
+//! To make sure that only one instance of a sink ever writes to the target table, every instance
+//! bumps the `epoch` of the sink's progress row when it starts, and each of its transactions
+//! checks that the epoch is still its own while holding a lock on the row. An instance that finds
+//! a newer epoch has been fenced out and reports an error instead of writing.
+//!
+//! Only once a transaction has committed do we advance the upper of the sink's progress shard in
+//! persist, which the controller uses to track the sink's progress.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Context, bail};
+use bytes::BytesMut;
+use differential_dataflow::VecCollection;
+use mz_interchange::avro::DiffPair;
+use mz_ore::cast::CastFrom;
+use mz_ore::future::InTask;
+use mz_pgrepr::TextEncodeSettings;
+use mz_postgres_util::{Client, Sql, execute, query, query_one, query_opt, simple_query, sql};
+use mz_repr::{Diff, GlobalId, Row, SqlRelationType, Timestamp};
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{PostgresSinkConnection, SinkEnvelope, StorageSinkDesc};
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::PressOnDropButton;
+use timely::dataflow::StreamVec;
+use timely::progress::Antichain;
+use tokio_postgres::types::ToSql;
+use tracing::debug;
+
+use crate::healthcheck::{HealthStatusMessage, StatusNamespace};
+use crate::render::sinks::{SinkBatchStream, SinkRender};
+use crate::sink::single_worker::{self, ProgressHandle, SinkWriter};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The name of the table, in the target table's schema, that records the progress of each sink.
+const PROGRESS_TABLE: &str = "_mz_sink_progress";
+
+/// The maximum number of parameters of a single statement, which PostgreSQL limits to `u16::MAX`.
+const MAX_PARAMS: usize = 65535;
+
+/// A text-encoded update: the columns of the key, and the columns of the new row, or `None` if
+/// the row was deleted.
+type EncodedUpdate = (Vec<Option<String>>, Option<Vec<Option<String>>>);
+
+impl<'scope> SinkRender<'scope> for PostgresSinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        batches: SinkBatchStream<'scope>,
+        key_is_synthetic: bool,
+        _err_collection: VecCollection<'scope, Timestamp, DataflowError, Diff>,
+    ) -> (
+        StreamVec<'scope, Timestamp, HealthStatusMessage>,
+        Vec<PressOnDropButton>,
+    ) {
+        assert_eq!(
+            sink.envelope,
+            SinkEnvelope::Upsert,
+            "Postgres sinks only support the upsert envelope"
+        );
+        let (key_desc, _indices) = self
+            .key_desc_and_indices
+            .as_ref()
+            .expect("Postgres sinks have a key");
+        let key_type = key_desc.typ().clone();
+        let value_type = self.value_desc.typ().clone();
+        let mut buf = BytesMut::new();
+        let encode = move |key: &Option<Row>, value: DiffPair<Row>| {
+            let key = key.as_ref().expect("Postgres sinks have a key");
+            let key = encode_row(key, &key_type, &mut buf);
+            let value = value
+                .after
+                .map(|value| encode_row(&value, &value_type, &mut buf));
+            (key, value)
+        };
+
+        let connection = self.clone();
+        let storage_configuration = storage_state.storage_configuration.clone();
+        let connect = async move |statistics: SinkStatistics,
+                                  _write_handle: &mut ProgressHandle| {
+            PostgresSinkClient::new(sink_id, &connection, &storage_configuration, statistics).await
+        };
+
+        single_worker::render_sink(
+            "postgres",
+            StatusNamespace::Postgres,
+            storage_state,
+            sink,
+            sink_id,
+            batches,
+            key_is_synthetic,
+            encode,
+            connect,
+        )
+    }
+}
+
+/// Applies the updates of complete timestamps to the target table.
+struct PostgresSinkClient {
+    sink_id: GlobalId,
+    client: Client,
+    /// The epoch of this instance of the sink, see the module docs.
+    epoch: i64,
+    /// The fully qualified name of the target table.
+    table: Sql,
+    /// The fully qualified name of the progress table.
+    progress_table: Sql,
+    /// The quoted names of all columns of the sink, in the order of the encoded rows.
+    columns: Vec<Sql>,
+    /// The types of the target table's columns, in the same order as `columns`.
+    column_types: Vec<Sql>,
+    /// The positions of the key columns in `columns`, in the order of the encoded keys.
+    key_positions: Vec<usize>,
+    statistics: SinkStatistics,
+}
+
+impl PostgresSinkClient {
+    /// Connects to the upstream database, validates the target table, and takes over the sink's
+    /// progress row. Returns the client along with the frontier that the sink should resume from.
+    async fn new(
+        sink_id: GlobalId,
+        connection: &PostgresSinkConnection,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+    ) -> Result<(Self, Antichain<Timestamp>), anyhow::Error> {
+        let config = connection
+            .connection
+            .config(
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                InTask::Yes,
+            )
+            .await?;
+        let client = config
+            .connect(
+                &format!("postgres-{sink_id}-sink"),
+                &storage_configuration.connection_context.ssh_tunnel_manager,
+            )
+            .await?;
+
+        let schema = Sql::ident(&connection.schema);
+        let table = sql!("{}.{}", schema.clone(), Sql::ident(&connection.table));
+        let progress_table = sql!("{}.{}", schema, Sql::ident(PROGRESS_TABLE));
+        // The name of the target table as `to_regclass` expects it.
+        let regclass = table.as_str().to_string();
+
+        // Find the types of the target columns, which we cast the text-encoded values to.
+        let upstream_columns: BTreeMap<String, String> = query(
+            &*client,
+            sql!(
+                "SELECT a.attname::text, format_type(a.atttypid, a.atttypmod)
+                FROM pg_catalog.pg_attribute AS a
+                WHERE a.attrelid = to_regclass($1) AND a.attnum > 0 AND NOT a.attisdropped"
+            ),
+            &[&regclass],
+        )
+        .await?
+        .into_iter()
+        .map(|row| (row.get(0), row.get(1)))
+        .collect();
+        if upstream_columns.is_empty() {
+            bail!("table {regclass} does not exist");
+        }
+
+        let mut columns = vec![];
+        let mut column_types = vec![];
+        for name in connection.value_desc.iter_names() {
+            let Some(typ) = upstream_columns.get(name.as_str()) else {
+                bail!(
+                    "table {regclass} has no column {}",
+                    Sql::ident(name.as_str())
+                );
+            };
+            columns.push(Sql::ident(name.as_str()));
+            // Produced by `format_type` upstream, which quotes any identifiers as needed.
+            column_types.push(Sql::raw_unchecked(typ.clone()));
+        }
+
+        let (key_desc, _) = connection
+            .key_desc_and_indices
+            .as_ref()
+            .expect("Postgres sinks have a key");
+        let key_names: BTreeSet<String> = key_desc
+            .iter_names()
+            .map(|name| name.as_str().to_string())
+            .collect();
+        let key_positions = key_desc
+            .iter_names()
+            .map(|key_name| {
+                connection
+                    .value_desc
+                    .iter_names()
+                    .position(|name| name == key_name)
+                    .expect("key columns are columns of the value")
+            })
+            .collect();
+
+        // `ON CONFLICT` needs a unique index on exactly the key columns.
+        let unique_indexes: Vec<Vec<String>> = query(
+            &*client,
+            sql!(
+                "SELECT array_agg(a.attname::text)
+                FROM pg_catalog.pg_index AS i
+                JOIN pg_catalog.pg_attribute AS a
+                    ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey)
+                WHERE
+                    i.indrelid = to_regclass($1)
+                    AND i.indisunique
+                    AND i.indpred IS NULL
+                    AND i.indexprs IS NULL
+                GROUP BY i.indexrelid"
+            ),
+            &[&regclass],
+        )
+        .await?
+        .into_iter()
+        .map(|row| row.get(0))
+        .collect();
+        if !unique_indexes
+            .into_iter()
+            .any(|index| index.into_iter().collect::<BTreeSet<_>>() == key_names)
+        {
+            bail!(
+                "table {regclass} has no unique index on exactly the key columns ({})",
+                Sql::join(key_names.iter().map(|name| Sql::ident(name)), ", ")
+            );
+        }
+
+        execute(
+            &*client,
+            sql!(
+                "CREATE TABLE IF NOT EXISTS {} (
+                    sink_id text PRIMARY KEY,
+                    epoch bigint NOT NULL,
+                    frontier numeric
+                )",
+                progress_table.clone()
+            ),
+            &[],
+        )
+        .await?;
+
+        // Take over the progress row, which fences out any previous instance of the sink.
+        let sink_id_str = sink_id.to_string();
+        let row = query_one(
+            &*client,
+            sql!(
+                "INSERT INTO {} AS p (sink_id, epoch, frontier) VALUES ($1, 1, 0)
+                ON CONFLICT (sink_id) DO UPDATE SET epoch = p.epoch + 1
+                RETURNING epoch, frontier::text",
+                progress_table.clone()
+            ),
+            &[&sink_id_str],
+        )
+        .await?;
+        let epoch: i64 = row.get(0);
+        let frontier: Option<String> = row.get(1);
+        let resume_upper = match frontier {
+            // The empty frontier is recorded as `NULL`.
+            None => Antichain::new(),
+            Some(frontier) => {
+                let frontier: u64 = frontier
+                    .parse()
+                    .with_context(|| format!("invalid frontier {frontier} in progress table"))?;
+                Antichain::from_elem(Timestamp::from(frontier))
+            }
+        };
+
+        let client = Self {
+            sink_id,
+            client,
+            epoch,
+            table,
+            progress_table,
+            columns,
+            column_types,
+            key_positions,
+            statistics,
+        };
+        Ok((client, resume_upper))
+    }
+
+    /// Applies the latest row of each key in `rows`, or deletes the key if it has none, and
+    /// records `frontier` as the sink's progress, all in one transaction.
+    async fn commit(
+        &self,
+        rows: BTreeMap<Vec<Option<String>>, Option<Vec<Option<String>>>>,
+        frontier: &Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        let sink_id = self.sink_id.to_string();
+        let (deletes, upserts): (Vec<_>, Vec<_>) =
+            rows.into_iter().partition(|(_key, row)| row.is_none());
+        let deletes: Vec<_> = deletes.into_iter().map(|(key, _row)| key).collect();
+        let upserts: Vec<_> = upserts.into_iter().filter_map(|(_key, row)| row).collect();
+
+        let message_count = u64::cast_from(deletes.len() + upserts.len());
+        let byte_count = deletes
+            .iter()
+            .chain(upserts.iter())
+            .flatten()
+            .flatten()
+            .map(|value| u64::cast_from(value.len()))
+            .sum();
+        self.statistics.inc_messages_staged_by(message_count);
+        self.statistics.inc_bytes_staged_by(byte_count);
+
+        simple_query(&self.client, sql!("BEGIN")).await?;
+
+        let epoch: Option<i64> = query_opt(
+            &*self.client,
+            sql!(
+                "SELECT epoch FROM {} WHERE sink_id = $1 FOR UPDATE",
+                self.progress_table.clone()
+            ),
+            &[&sink_id],
+        )
+        .await?
+        .map(|row| row.get(0));
+        if epoch != Some(self.epoch) {
+            simple_query(&self.client, sql!("ROLLBACK")).await?;
+            bail!(
+                "postgres-{}: fenced out by another instance of the sink (epoch {}, found {})",
+                self.sink_id,
+                self.epoch,
+                epoch.map_or_else(|| "no progress row".to_string(), |e| e.to_string()),
+            );
+        }
+
+        let key_columns: Vec<_> = self
+            .key_positions
+            .iter()
+            .map(|&i| self.columns[i].clone())
+            .collect();
+        let key_types: Vec<_> = self
+            .key_positions
+            .iter()
+            .map(|&i| self.column_types[i].clone())
+            .collect();
+
+        for keys in deletes.chunks(MAX_PARAMS / key_columns.len()) {
+            let (tuples, params) = encode_tuples(keys, &key_types);
+            execute(
+                &*self.client,
+                sql!(
+                    "DELETE FROM {} WHERE ({}) IN ({})",
+                    self.table.clone(),
+                    Sql::join(key_columns.iter().cloned(), ", "),
+                    tuples
+                ),
+                &params,
+            )
+            .await?;
+        }
+
+        let non_key_columns: Vec<_> = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.key_positions.contains(i))
+            .map(|(_, column)| column.clone())
+            .collect();
+        let on_conflict = if non_key_columns.is_empty() {
+            sql!("DO NOTHING")
+        } else {
+            sql!(
+                "DO UPDATE SET {}",
+                Sql::join(
+                    non_key_columns.into_iter().map(|column| sql!(
+                        "{} = EXCLUDED.{}",
+                        column.clone(),
+                        column
+                    )),
+                    ", "
+                )
+            )
+        };
+        for rows in upserts.chunks(MAX_PARAMS / self.columns.len()) {
+            let (tuples, params) = encode_tuples(rows, &self.column_types);
+            execute(
+                &*self.client,
+                sql!(
+                    "INSERT INTO {} ({}) VALUES {} ON CONFLICT ({}) {}",
+                    self.table.clone(),
+                    Sql::join(self.columns.iter().cloned(), ", "),
+                    tuples,
+                    Sql::join(key_columns.iter().cloned(), ", "),
+                    on_conflict.clone()
+                ),
+                &params,
+            )
+            .await?;
+        }
+
+        // The empty frontier is recorded as `NULL`.
+        let frontier = frontier.as_option().map(|t| t.to_string());
+        execute(
+            &*self.client,
+            sql!(
+                "UPDATE {} SET frontier = $2::text::numeric WHERE sink_id = $1",
+                self.progress_table.clone()
+            ),
+            &[&sink_id, &frontier],
+        )
+        .await?;
+
+        simple_query(&self.client, sql!("COMMIT")).await?;
+
+        self.statistics.inc_messages_committed_by(message_count);
+        self.statistics.inc_bytes_committed_by(byte_count);
+        Ok(())
+    }
+}
+
+impl SinkWriter for PostgresSinkClient {
+    type Update = EncodedUpdate;
+
+    async fn write(
+        &mut self,
+        updates: Vec<(Timestamp, Vec<EncodedUpdate>)>,
+        upper: &Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        // Fold the complete timestamps into the latest row of each key.
+        let mut rows = BTreeMap::new();
+        for (_time, updates) in updates {
+            for (key, row) in updates {
+                rows.insert(key, row);
+            }
+        }
+        debug!(
+            "postgres-{}: applying {} rows up to {}",
+            self.sink_id,
+            rows.len(),
+            upper.pretty()
+        );
+        self.commit(rows, upper).await
+    }
+}
+
+/// Returns the SQL for a list of row constructors that hold `rows`, e.g. `($1::text::integer),
+/// ($2::text::integer)`, along with the parameters they refer to. Each value is passed as text and
+/// cast to the matching type in `types`.
+fn encode_tuples<'a>(
+    rows: &'a [Vec<Option<String>>],
+    types: &[Sql],
+) -> (Sql, Vec<&'a (dyn ToSql + Sync)>) {
+    let mut params: Vec<&(dyn ToSql + Sync)> = vec![];
+    let mut tuples = vec![];
+    for row in rows {
+        let mut values = vec![];
+        for (value, typ) in row.iter().zip(types) {
+            params.push(value);
+            values.push(sql!("{}::text::{}", Sql::param(params.len()), typ.clone()));
+        }
+        tuples.push(sql!("({})", Sql::join(values, ", ")));
+    }
+    (Sql::join(tuples, ", "), params)
+}
+
+/// Text-encodes the datums of `row`, which has type `typ`, the way PostgreSQL would.
+fn encode_row(row: &Row, typ: &SqlRelationType, buf: &mut BytesMut) -> Vec<Option<String>> {
+    mz_pgrepr::values_from_row(row, typ)
+        .into_iter()
+        .map(|value| {
+            value.map(|value| {
+                value.encode_text(buf, TextEncodeSettings::STABLE);
+                String::from_utf8(buf.split().to_vec()).expect("text encoding is valid UTF-8")
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[mz_ore::test]
+    fn tuples() {
+        let rows = vec![
+            vec![Some("1".to_string()), None],
+            vec![Some("2".to_string()), Some("b".to_string())],
+        ];
+        let types = vec![Sql::new("integer"), Sql::new("text")];
+        let (tuples, params) = encode_tuples(&rows, &types);
+        assert_eq!(
+            tuples.as_str(),
+            "($1::text::integer, $2::text::text), ($3::text::integer, $4::text::text)"
+        );
+        assert_eq!(params.len(), 4);
+    }
+}
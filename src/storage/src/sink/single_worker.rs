@@ -0,0 +1,386 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the dataflow of sinks that deliver their updates to the external system from a
+//! single worker. The dataflow consists of two operators in order to take advantage of all the
+//! available workers.
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ stream of arrangement batches (trace reader dropped)
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃    row      ┃ walks each batch's cursor and emits one
+//!        ┃   encoder   ┃ encoded update per DiffPair
+//!        ┗━━━━━━┯━━━━━━┛
+//!               │ encoded data
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃    sink     ┃ (single worker)
+//!        ┗━━┯━━━━━━━━┯━┛
+//!   writes  │        │ uppers
+//!  ╭────────v──╮ ╭───v──────╮
+//!  │ external  │ │ progress │  <- uppers are only recorded once the
+//!  │  system   │ │  shard   │     writes before them completed
+//!  ╰───────────╯ ╰──────────╯
+//! ```
+//!
+//! The encoder is specific to each sink, and so is the [`SinkWriter`] that delivers the updates
+//! of complete timestamps. Everything else, including resuming from the sink's progress and
+//! recording it in the sink's progress shard, is shared.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use differential_dataflow::{AsCollection, Hashable, VecCollection};
+use futures::StreamExt;
+use mz_interchange::avro::DiffPair;
+use mz_interchange::envelopes::for_each_diff_pair;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_persist_client::Diagnostics;
+use mz_persist_client::write::WriteHandle;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::sinks::StorageSinkDesc;
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::StreamVec;
+use timely::dataflow::channels::pact::{Exchange, Pipeline};
+use timely::dataflow::operators::Concatenate;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::progress::{Antichain, Timestamp as _};
+use timely::{ExchangeData, PartialOrder};
+use tracing::{debug, error, info};
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::{PkViolationWarner, SinkBatchStream};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The persist write handle of a sink's progress shard.
+pub(super) type ProgressHandle = WriteHandle<SourceData, (), Timestamp, StorageDiff>;
+
+/// Delivers the updates of a sink to the external system.
+pub(super) trait SinkWriter: 'static {
+    /// The encoded form of a single update.
+    type Update: ExchangeData;
+
+    /// Delivers the `updates` of all timestamps before `upper`, grouped by timestamp in
+    /// timestamp order. Once this returns, none of the updates may need to be delivered again,
+    /// since the sink's progress shard is advanced to `upper` next.
+    async fn write(
+        &mut self,
+        updates: Vec<(Timestamp, Vec<Self::Update>)>,
+        upper: &Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error>;
+}
+
+/// Renders the dataflow of a sink that delivers its updates from a single worker.
+///
+/// `encode` turns the key and `DiffPair` of each `(key, timestamp)` into an update, and `connect`
+/// creates the [`SinkWriter`] along with the frontier to resume delivering from.
+pub(super) fn render_sink<'scope, W, E, C>(
+    name: &str,
+    namespace: StatusNamespace,
+    storage_state: &mut StorageState,
+    sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+    sink_id: GlobalId,
+    batches: SinkBatchStream<'scope>,
+    key_is_synthetic: bool,
+    encode: E,
+    connect: C,
+) -> (
+    StreamVec<'scope, Timestamp, HealthStatusMessage>,
+    Vec<PressOnDropButton>,
+)
+where
+    W: SinkWriter,
+    E: FnMut(&Option<Row>, DiffPair<Row>) -> W::Update + 'static,
+    C: AsyncFnOnce(
+            SinkStatistics,
+            &mut ProgressHandle,
+        ) -> Result<(W, Antichain<Timestamp>), anyhow::Error>
+        + 'static,
+{
+    let scope = batches.scope();
+
+    let write_handle = {
+        let persist = Arc::clone(&storage_state.persist_clients);
+        let shard_meta = sink.to_storage_metadata.clone();
+        async move {
+            let client = persist.open(shard_meta.persist_location).await?;
+            let handle = client
+                .open_writer(
+                    shard_meta.data_shard,
+                    Arc::new(shard_meta.relation_desc),
+                    Arc::new(UnitSchema),
+                    Diagnostics::from_purpose("sink handle"),
+                )
+                .await?;
+            Ok(handle)
+        }
+    };
+
+    let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+    storage_state
+        .sink_write_frontiers
+        .insert(sink_id, Rc::clone(&write_frontier));
+
+    let (encoded, encode_token) = encode_collection(
+        format!("{name}-{sink_id}-encode"),
+        batches,
+        sink_id,
+        sink.from,
+        key_is_synthetic,
+        encode,
+    );
+
+    let statistics = storage_state
+        .aggregated_statistics
+        .get_sink(&sink_id)
+        .expect("statistics initialized")
+        .clone();
+
+    let (sink_status, sink_token) = sink_collection(
+        format!("{name}-{sink_id}-sink"),
+        namespace,
+        encoded,
+        sink_id,
+        sink,
+        statistics,
+        write_handle,
+        write_frontier,
+        connect,
+    );
+
+    let running_status = Some(HealthStatusMessage {
+        id: None,
+        update: HealthStatusUpdate::Running,
+        namespace,
+    })
+    .to_stream(scope);
+
+    let status = scope.concatenate([running_status, sink_status]);
+
+    (status, vec![encode_token, sink_token])
+}
+
+/// Delivers the encoded updates of complete timestamps with the sink's [`SinkWriter`], and
+/// records the sink's progress in its progress shard.
+fn sink_collection<'scope, W, C>(
+    name: String,
+    namespace: StatusNamespace,
+    input: VecCollection<'scope, Timestamp, W::Update, Diff>,
+    sink_id: GlobalId,
+    sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+    statistics: SinkStatistics,
+    write_handle: impl Future<Output = anyhow::Result<ProgressHandle>> + 'static,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    connect: C,
+) -> (
+    StreamVec<'scope, Timestamp, HealthStatusMessage>,
+    PressOnDropButton,
+)
+where
+    W: SinkWriter,
+    C: AsyncFnOnce(
+            SinkStatistics,
+            &mut ProgressHandle,
+        ) -> Result<(W, Antichain<Timestamp>), anyhow::Error>
+        + 'static,
+{
+    let scope = input.scope();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to write all the data to the external system.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(input.inner, Exchange::new(move |_| hashed_id));
+
+    let as_of = sink.as_of.clone();
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            let mut write_handle = write_handle.await?;
+
+            let (mut writer, resume_upper) = connect(statistics, &mut write_handle).await?;
+
+            // The input has overcompacted if
+            let overcompacted =
+                // ..we have made some progress in the past
+                *resume_upper != [Timestamp::minimum()] &&
+                // ..but the since frontier is now beyond that
+                !PartialOrder::less_equal(&as_of, &resume_upper);
+            if overcompacted {
+                let err = format!(
+                    "{name}: input compacted past resume upper: as_of {}, resume_upper: {}",
+                    as_of.pretty(),
+                    resume_upper.pretty()
+                );
+                // This would normally be an assertion but because it can happen after a
+                // Materialize backup/restore we log an error so that it appears on Sentry but
+                // leaves the rest of the objects in the cluster unaffected.
+                error!("{err}");
+                return Err(anyhow!("{err}"));
+            }
+
+            info!(
+                "{name}: as_of: {}, resume upper: {}",
+                as_of.pretty(),
+                resume_upper.pretty()
+            );
+
+            if resume_upper.is_empty() {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            // Updates whose timestamps are not yet complete, by timestamp.
+            let mut pending: BTreeMap<Timestamp, Vec<W::Update>> = BTreeMap::new();
+            while let Some(event) = input.next().await {
+                match event {
+                    Event::Data(_cap, batch) => {
+                        for (update, time, _diff) in batch {
+                            // Updates not beyond the resume upper have already been delivered.
+                            if resume_upper.less_equal(&time) {
+                                pending.entry(time).or_default().push(update);
+                            }
+                        }
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier, for
+                        // the same reason as the Kafka sink: recording progress up to the as_of
+                        // before the snapshot was delivered would skip the snapshot if the sink
+                        // is restarted with an earlier as_of.
+                        if !as_of.iter().all(|t| !progress.less_equal(t)) {
+                            continue;
+                        }
+                        if PartialOrder::less_equal(&progress, &*write_frontier.borrow()) {
+                            continue;
+                        }
+
+                        let mut complete = Vec::new();
+                        while let Some(entry) = pending.first_entry() {
+                            if progress.less_equal(entry.key()) {
+                                break;
+                            }
+                            complete.push(entry.remove_entry());
+                        }
+
+                        debug!(
+                            "{name}: writing {} timestamps up to {}",
+                            complete.len(),
+                            progress.pretty()
+                        );
+                        writer.write(complete, &progress).await?;
+
+                        let mut expect_upper = write_handle.shared_upper();
+                        loop {
+                            if PartialOrder::less_equal(&progress, &expect_upper) {
+                                // The frontier has already been advanced as far as necessary.
+                                break;
+                            }
+                            const EMPTY: &[((SourceData, ()), Timestamp, StorageDiff)] = &[];
+                            match write_handle
+                                .compare_and_append(EMPTY, expect_upper, progress.clone())
+                                .await
+                                .expect("valid usage")
+                            {
+                                Ok(()) => break,
+                                Err(mismatch) => {
+                                    expect_upper = mismatch.current;
+                                }
+                            }
+                        }
+                        write_frontier.borrow_mut().clone_from(&progress);
+                        if progress.is_empty() {
+                            break;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(move |error: Rc<anyhow::Error>| HealthStatusMessage {
+        id: None,
+        update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+        namespace,
+    });
+
+    (statuses, button.press_on_drop())
+}
+
+/// Walks each arrangement batch and emits one update per `DiffPair` observed at each
+/// `(key, timestamp)`, as encoded by `encode`.
+fn encode_collection<'scope, U, E>(
+    name: String,
+    batches: SinkBatchStream<'scope>,
+    sink_id: GlobalId,
+    from_id: GlobalId,
+    key_is_synthetic: bool,
+    mut encode: E,
+) -> (VecCollection<'scope, Timestamp, U, Diff>, PressOnDropButton)
+where
+    U: ExchangeData,
+    E: FnMut(&Option<Row>, DiffPair<Row>) -> U + 'static,
+{
+    let mut builder = AsyncOperatorBuilder::new(name, batches.scope());
+
+    let (output, stream) = builder.new_output::<CapacityContainerBuilder<Vec<_>>>();
+    let mut input = builder.new_input_for(batches, Pipeline, &output);
+
+    let button = builder.build(move |_caps| async move {
+        let mut pk_warner = (!key_is_synthetic).then(|| PkViolationWarner::new(sink_id, from_id));
+
+        while let Some(event) = input.next().await {
+            if let Event::Data(cap, mut batches) = event {
+                for batch in batches.drain(..) {
+                    for_each_diff_pair(&batch, |key, time, value| {
+                        if let Some(warner) = pk_warner.as_mut() {
+                            warner.observe(key, time);
+                        }
+                        output.give(&cap, (encode(key, value), time, Diff::ONE));
+                    });
+                    // Flush after each batch so the final `(key, time)` group of the walk is
+                    // resolved immediately.
+                    if let Some(warner) = pk_warner.as_mut() {
+                        warner.flush();
+                    }
+                }
+            }
+        }
+    });
+
+    (stream.as_collection(), button.press_on_drop())
+}
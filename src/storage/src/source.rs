@@ -33,6 +33,7 @@ mod source_reader_pipeline;
 pub mod types;
 
 pub mod generator;
+mod iceberg;
 mod kafka;
 mod mysql;
 mod postgres;
@@ -0,0 +1,596 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the ingestion dataflow of an [`IcebergSourceConnection`].
+//!
+//! An Iceberg table is a sequence of immutable snapshots, each of which is
+//! identified by a monotonically increasing sequence number. The source uses
+//! that sequence number as its native timestamp ([`MzOffset`]): all changes
+//! introduced by the snapshot with sequence number `n` are emitted at offset
+//! `n`, and having ingested that snapshot the source's upper is `n + 1`.
+//!
+//! The operator works as follows:
+//!
+//! * On a fresh start, the current snapshot of the table is read in full and
+//!   emitted at its sequence number.
+//! * Afterwards, the table metadata is refreshed at `timestamp_interval` and
+//!   every snapshot that was committed since the last ingested one is
+//!   translated into differential updates. This is done by planning a scan of
+//!   both the previous and the new snapshot and comparing the resulting file
+//!   scan tasks: every task that only exists in the previous snapshot is read
+//!   and retracted, and every task that only exists in the new snapshot is
+//!   read and inserted. A task is identified by its data file together with
+//!   the set of delete files that apply to it, which means that appends,
+//!   overwrites, and both position and equality deletes are all handled
+//!   uniformly, at the cost of re-reading data files that gained deletes.
+//! * `replace` snapshots (e.g. compactions) do not change the logical contents
+//!   of the table and are skipped without emitting any updates.
+//!
+//! If the snapshot the source needs to resume from has been expired, or if the
+//! table's schema changes incompatibly, the source reports a definite error.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use arrow::array::StructArray;
+use differential_dataflow::AsCollection;
+use futures::{StreamExt, TryStreamExt};
+use iceberg::Catalog;
+use iceberg::scan::FileScanTask;
+use iceberg::spec::{Operation, SnapshotRef};
+use iceberg::table::Table;
+use itertools::Itertools;
+use mz_arrow_util::reader::ArrowReader;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row};
+use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
+use mz_storage_types::sources::iceberg::snapshot_upper;
+use mz_storage_types::sources::{
+    IcebergSourceConnection, MzOffset, SourceExportDetails, SourceTimestamp,
+};
+use mz_timely_util::builder_async::{
+    AsyncOutputHandle, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use mz_timely_util::containers::stack::FueledBuilder;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::core::Partition;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::dataflow::operators::{CapabilitySet, Concat};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::source::types::{
+    FuelSize, Probe, SignaledFuture, SourceMessage, SourceRender, StackedCollection,
+};
+use crate::source::{RawSourceCreationConfig, probe};
+
+/// Used as a partition ID to determine the worker that is responsible for
+/// reading data from the Iceberg table.
+static ICEBERG_READER: &str = "reader";
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DefiniteError {
+    #[error("snapshot {0} of table {1} is no longer available")]
+    SnapshotExpired(i64, String),
+    #[error("table {0} no longer contains snapshot {1} in its history")]
+    HistoryRewritten(String, i64),
+    #[error("incompatible schema change for table {0}: {1}")]
+    IncompatibleSchemaChange(String, String),
+    #[error("failed to decode row: {0}")]
+    Decoding(String),
+}
+
+impl From<DefiniteError> for DataflowError {
+    fn from(val: DefiniteError) -> Self {
+        let msg = val.to_string().into();
+        DataflowError::SourceError(Box::new(SourceError {
+            error: SourceErrorDetails::Other(msg),
+        }))
+    }
+}
+
+impl SourceRender for IcebergSourceConnection {
+    type Time = MzOffset;
+
+    const STATUS_NAMESPACE: StatusNamespace = StatusNamespace::Iceberg;
+
+    fn render<'scope>(
+        self,
+        scope: Scope<'scope, MzOffset>,
+        config: &RawSourceCreationConfig,
+        resume_uppers: impl futures::Stream<Item = Antichain<MzOffset>> + 'static,
+        _start_signal: impl Future<Output = ()> + 'static,
+    ) -> (
+        BTreeMap<
+            GlobalId,
+            StackedCollection<'scope, MzOffset, Result<SourceMessage, DataflowError>>,
+        >,
+        StreamVec<'scope, MzOffset, HealthStatusMessage>,
+        StreamVec<'scope, MzOffset, Probe<MzOffset>>,
+        Vec<PressOnDropButton>,
+    ) {
+        // All exports of an Iceberg source receive the full contents of the table.
+        let mut output_indexes = Vec::new();
+        for (idx, (_id, export)) in config.source_exports.iter().enumerate() {
+            match &export.details {
+                SourceExportDetails::Iceberg(_) => output_indexes.push(u64::cast_from(idx)),
+                // This is an export that doesn't need any data output to it.
+                SourceExportDetails::None => continue,
+                other => unreachable!("unexpected source export details: {other:?}"),
+            }
+        }
+
+        let op_name = format!("IcebergReader({})", config.id);
+        let mut builder = AsyncOperatorBuilder::new(op_name, scope.clone());
+
+        let (data_output, data_stream) = builder.new_output::<FueledBuilder<_>>();
+        let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+
+        let config = config.clone();
+        let (button, transient_errors) = builder.build_fallible::<anyhow::Error, _>(move |caps| {
+            let busy_signal = Arc::clone(&config.busy_signal);
+            Box::pin(SignaledFuture::new(busy_signal, async move {
+                let [data_cap_set, probe_cap_set]: &mut [_; 2] = caps.try_into().unwrap();
+
+                if !config.responsible_for(ICEBERG_READER) {
+                    // Emit 0 to mark this worker as having started up correctly.
+                    for stat in config.statistics.values() {
+                        stat.set_offset_known(0);
+                        stat.set_offset_committed(0);
+                    }
+                    return Ok(());
+                }
+
+                let resume_upper = Antichain::from_iter(
+                    config
+                        .source_resume_uppers
+                        .values()
+                        .flat_map(|f| f.iter().map(MzOffset::decode_row)),
+                );
+                let Some(resume_offset) = resume_upper.into_option() else {
+                    return Ok(());
+                };
+                data_cap_set.downgrade(Antichain::from_elem(resume_offset));
+
+                let catalog = self.connection.connect(&config.config, InTask::Yes).await?;
+                let table_ident = self.table_ident();
+                let table_name = format!("{}.{}", self.namespace, self.table);
+                let columns: Vec<String> = self
+                    .details
+                    .desc
+                    .iter_names()
+                    .map(|name| name.to_string())
+                    .collect();
+
+                let reader = IcebergReader {
+                    table_name: table_name.clone(),
+                    desc: self.details.desc.clone(),
+                    columns,
+                    outputs: output_indexes,
+                    data_output,
+                };
+
+                let table = catalog.load_table(&table_ident).await?;
+
+                // The snapshot whose contents are fully reflected in the output, along with the
+                // file scan tasks that make it up.
+                let (mut cursor, mut cursor_tasks) = if resume_offset == MzOffset::minimum() {
+                    match table.metadata().current_snapshot() {
+                        Some(snapshot) => {
+                            if let Err(err) = self
+                                .details
+                                .check_compatible(table.metadata().current_schema())
+                            {
+                                let err = DefiniteError::IncompatibleSchemaChange(
+                                    table_name.clone(),
+                                    err,
+                                );
+                                reader.return_definite_error(err, data_cap_set).await;
+                                return Ok(());
+                            }
+                            let tasks = reader.plan_tasks(&table, snapshot).await?;
+                            let snapshot_records: u64 = tasks
+                                .values()
+                                .map(|task| task.record_count.unwrap_or(0))
+                                .sum();
+                            for stat in config.statistics.values() {
+                                stat.set_snapshot_records_known(snapshot_records);
+                                stat.set_snapshot_records_staged(0);
+                            }
+
+                            let time = snapshot_time(snapshot)?;
+                            let staged = reader
+                                .read_tasks(&table, tasks.values(), time, Diff::ONE, data_cap_set)
+                                .await?;
+                            if let Err(err) = staged {
+                                reader.return_definite_error(err, data_cap_set).await;
+                                return Ok(());
+                            }
+                            for stat in config.statistics.values() {
+                                stat.set_snapshot_records_staged(snapshot_records);
+                            }
+                            data_cap_set.downgrade(Antichain::from_elem(snapshot_upper(snapshot)?));
+                            (Some(Arc::clone(snapshot)), tasks)
+                        }
+                        None => (None, BTreeMap::new()),
+                    }
+                } else {
+                    match find_resume_snapshot(&table, resume_offset)? {
+                        Ok(Some(snapshot)) => {
+                            let tasks = reader.plan_tasks(&table, &snapshot).await?;
+                            (Some(snapshot), tasks)
+                        }
+                        Ok(None) => (None, BTreeMap::new()),
+                        Err(err) => {
+                            let err = DefiniteError::SnapshotExpired(err, table_name.clone());
+                            reader.return_definite_error(err, data_cap_set).await;
+                            return Ok(());
+                        }
+                    }
+                };
+
+                let timestamp_interval = config.timestamp_interval;
+                let mut probe_ticker =
+                    probe::Ticker::new(move || timestamp_interval, config.now_fn.clone());
+                let mut resume_uppers = std::pin::pin!(resume_uppers);
+
+                loop {
+                    let table = catalog.load_table(&table_ident).await?;
+                    let metadata = table.metadata();
+
+                    let new_snapshots = match snapshots_since(&table, &table_name, cursor.as_ref())
+                    {
+                        Ok(snapshots) => snapshots,
+                        Err(err) => {
+                            reader.return_definite_error(err, data_cap_set).await;
+                            return Ok(());
+                        }
+                    };
+                    if !new_snapshots.is_empty() {
+                        if let Err(err) = self.details.check_compatible(metadata.current_schema()) {
+                            let err =
+                                DefiniteError::IncompatibleSchemaChange(table_name.clone(), err);
+                            reader.return_definite_error(err, data_cap_set).await;
+                            return Ok(());
+                        }
+                    }
+
+                    for snapshot in new_snapshots {
+                        let upper = snapshot_upper(&snapshot)?;
+                        if snapshot.summary().operation == Operation::Replace {
+                            // Replacing files does not change the contents of the table, so we
+                            // only need to track the new set of files.
+                            cursor_tasks = reader.plan_tasks(&table, &snapshot).await?;
+                        } else {
+                            let time = snapshot_time(&snapshot)?;
+                            let tasks = reader.plan_tasks(&table, &snapshot).await?;
+                            let retractions = cursor_tasks
+                                .iter()
+                                .filter(|(key, _)| !tasks.contains_key(*key))
+                                .map(|(_, task)| task);
+                            let retracted = reader
+                                .read_tasks(
+                                    &table,
+                                    retractions,
+                                    time,
+                                    Diff::MINUS_ONE,
+                                    data_cap_set,
+                                )
+                                .await?;
+                            let insertions = tasks
+                                .iter()
+                                .filter(|(key, _)| !cursor_tasks.contains_key(*key))
+                                .map(|(_, task)| task);
+                            let inserted = match retracted {
+                                Ok(()) => {
+                                    reader
+                                        .read_tasks(
+                                            &table,
+                                            insertions,
+                                            time,
+                                            Diff::ONE,
+                                            data_cap_set,
+                                        )
+                                        .await?
+                                }
+                                Err(err) => Err(err),
+                            };
+                            if let Err(err) = inserted {
+                                reader.return_definite_error(err, data_cap_set).await;
+                                return Ok(());
+                            }
+                            cursor_tasks = tasks;
+                        }
+                        data_cap_set.downgrade(Antichain::from_elem(upper));
+                        cursor = Some(snapshot);
+                    }
+
+                    let upstream_upper = match metadata.current_snapshot() {
+                        Some(snapshot) => snapshot_upper(snapshot)?,
+                        None => MzOffset::minimum(),
+                    };
+                    for stat in config.statistics.values() {
+                        stat.set_offset_known(upstream_upper.offset);
+                    }
+
+                    loop {
+                        tokio::select! {
+                            probe_ts = probe_ticker.tick() => {
+                                let probe = Probe {
+                                    probe_ts,
+                                    upstream_frontier: Antichain::from_elem(upstream_upper),
+                                };
+                                probe_output.give(&probe_cap_set[0], probe);
+                                break;
+                            }
+                            Some(committed_upper) = resume_uppers.next() => {
+                                if let Some(committed) = committed_upper.as_option() {
+                                    for stat in config.statistics.values() {
+                                        stat.set_offset_committed(committed.offset);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }))
+        });
+
+        let partition_count = u64::cast_from(config.source_exports.len());
+        let data_streams: Vec<_> = data_stream.partition::<CapacityContainerBuilder<_>, _, _>(
+            partition_count,
+            |((output, data), time, diff): (
+                (u64, Result<SourceMessage, DataflowError>),
+                MzOffset,
+                Diff,
+            )| { (output, (data, time, diff)) },
+        );
+        let mut data_collections = BTreeMap::new();
+        for (id, data_stream) in config.source_exports.keys().zip_eq(data_streams) {
+            data_collections.insert(*id, data_stream.as_collection());
+        }
+
+        let export_ids = config.source_exports.keys().copied();
+        let health_init = export_ids
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|id| HealthStatusMessage {
+                id,
+                namespace: Self::STATUS_NAMESPACE,
+                update: HealthStatusUpdate::Running,
+            })
+            .collect::<Vec<_>>()
+            .to_stream(scope);
+
+        let health_errs = transient_errors.map(move |err: Rc<anyhow::Error>| {
+            // This update will cause the dataflow to restart
+            let err_string = err.display_with_causes().to_string();
+            HealthStatusMessage {
+                id: None,
+                namespace: Self::STATUS_NAMESPACE,
+                update: HealthStatusUpdate::halting(err_string, None),
+            }
+        });
+        let health = health_init.concat(health_errs);
+
+        (
+            data_collections,
+            health,
+            probe_stream,
+            vec![button.press_on_drop()],
+        )
+    }
+}
+
+/// Identifies a [`FileScanTask`] across snapshots: a data file read under a
+/// specific set of delete files.
+type TaskKey = (String, u64, BTreeSet<String>);
+
+type StackedAsyncOutputHandle<T, D> =
+    AsyncOutputHandle<T, FueledBuilder<CapacityContainerBuilder<Vec<(D, T, Diff)>>>>;
+
+/// Reads file scan tasks of an Iceberg table into the source's outputs.
+struct IcebergReader {
+    table_name: String,
+    desc: RelationDesc,
+    columns: Vec<String>,
+    outputs: Vec<u64>,
+    data_output: StackedAsyncOutputHandle<MzOffset, (u64, Result<SourceMessage, DataflowError>)>,
+}
+
+impl IcebergReader {
+    /// Plans a scan of `snapshot`, keyed by [`TaskKey`].
+    async fn plan_tasks(
+        &self,
+        table: &Table,
+        snapshot: &SnapshotRef,
+    ) -> Result<BTreeMap<TaskKey, FileScanTask>, anyhow::Error> {
+        let scan = table
+            .scan()
+            .snapshot_id(snapshot.snapshot_id())
+            .select(self.columns.iter().cloned())
+            .build()?;
+        let tasks: Vec<FileScanTask> = scan.plan_files().await?.try_collect().await?;
+        let mut planned = BTreeMap::new();
+        for task in tasks {
+            let deletes = task
+                .deletes
+                .iter()
+                .map(|delete| delete.file_path.clone())
+                .collect();
+            let key = (task.data_file_path.clone(), task.start, deletes);
+            planned.insert(key, task);
+        }
+        Ok(planned)
+    }
+
+    /// Reads the rows produced by `tasks` and emits them to all outputs at
+    /// `time` with `diff`.
+    ///
+    /// The outer error is transient, the inner error is definite.
+    async fn read_tasks<'t>(
+        &self,
+        table: &Table,
+        tasks: impl Iterator<Item = &'t FileScanTask>,
+        time: MzOffset,
+        diff: Diff,
+        data_cap_set: &CapabilitySet<MzOffset>,
+    ) -> Result<Result<(), DefiniteError>, anyhow::Error> {
+        let tasks: Vec<_> = tasks.cloned().map(Ok).collect();
+        if tasks.is_empty() {
+            return Ok(Ok(()));
+        }
+
+        let mut batches = table
+            .reader_builder()
+            .build()
+            .read(Box::pin(futures::stream::iter(tasks)))?;
+
+        let cap = data_cap_set.delayed(&time);
+        let mut row = Row::default();
+        while let Some(batch) = batches.try_next().await? {
+            let num_rows = batch.num_rows();
+            let reader = match ArrowReader::new(&self.desc, StructArray::from(batch)) {
+                Ok(reader) => reader,
+                Err(err) => {
+                    let err = err.display_with_causes().to_string();
+                    return Ok(Err(DefiniteError::Decoding(err)));
+                }
+            };
+            for idx in 0..num_rows {
+                if let Err(err) = reader.read(idx, &mut row) {
+                    let err = err.display_with_causes().to_string();
+                    return Ok(Err(DefiniteError::Decoding(err)));
+                }
+                let message = SourceMessage {
+                    key: Row::default(),
+                    value: row.clone(),
+                    metadata: Row::default(),
+                };
+                for &output in &self.outputs {
+                    let update = ((output, Ok(message.clone())), time, diff);
+                    let size = update.fuel_size();
+                    self.data_output.give_fueled(&cap, update, size).await;
+                }
+            }
+        }
+        Ok(Ok(()))
+    }
+
+    /// Emits `err` to all outputs and reports it for the entire source.
+    async fn return_definite_error(
+        &self,
+        err: DefiniteError,
+        data_cap_set: &mut CapabilitySet<MzOffset>,
+    ) {
+        tracing::warn!(table = %self.table_name, "iceberg source encountered definite error: {err}");
+        for &output in &self.outputs {
+            let update = (
+                (output, Err(err.clone().into())),
+                *data_cap_set[0].time(),
+                Diff::ONE,
+            );
+            let size = update.fuel_size();
+            self.data_output
+                .give_fueled(&data_cap_set[0], update, size)
+                .await;
+        }
+        // An errored source never makes progress again.
+        data_cap_set.downgrade(Antichain::new());
+    }
+}
+
+/// The offset at which the changes introduced by `snapshot` are emitted.
+fn snapshot_time(snapshot: &SnapshotRef) -> Result<MzOffset, anyhow::Error> {
+    let upper = snapshot_upper(snapshot)?;
+    Ok(MzOffset::from(upper.offset - 1))
+}
+
+/// Finds the most recent snapshot in the current history of `table` whose
+/// changes are reflected by `resume_offset`, i.e. the snapshot ingestion
+/// should continue from.
+///
+/// Returns `Ok(None)` if the table was empty at `resume_offset`, and
+/// `Err(snapshot_id)` if the history between that snapshot and the current
+/// snapshot has been expired.
+fn find_resume_snapshot(
+    table: &Table,
+    resume_offset: MzOffset,
+) -> Result<Result<Option<SnapshotRef>, i64>, anyhow::Error> {
+    let metadata = table.metadata();
+    let mut next = metadata.current_snapshot().cloned();
+    while let Some(snapshot) = next {
+        if snapshot_upper(&snapshot)? <= resume_offset {
+            return Ok(Ok(Some(snapshot)));
+        }
+        next = match snapshot.parent_snapshot_id() {
+            Some(parent_id) => match metadata.snapshot_by_id(parent_id) {
+                Some(parent) => Some(Arc::clone(parent)),
+                None => return Ok(Err(parent_id)),
+            },
+            None => None,
+        };
+    }
+    Ok(Ok(None))
+}
+
+/// Returns the snapshots committed to `table` after `cursor`, oldest first.
+fn snapshots_since(
+    table: &Table,
+    table_name: &str,
+    cursor: Option<&SnapshotRef>,
+) -> Result<Vec<SnapshotRef>, DefiniteError> {
+    let metadata = table.metadata();
+    let table_name = table_name.to_string();
+    let cursor_id = cursor.map(|snapshot| snapshot.snapshot_id());
+
+    let mut snapshots = Vec::new();
+    let mut next = metadata.current_snapshot().cloned();
+    while let Some(snapshot) = next {
+        if Some(snapshot.snapshot_id()) == cursor_id {
+            snapshots.reverse();
+            return Ok(snapshots);
+        }
+        if let Some(cursor) = cursor {
+            // We walked past the cursor without finding it, so the table's history was
+            // rewritten (e.g. by rolling back to an earlier snapshot).
+            if snapshot.sequence_number() < cursor.sequence_number() {
+                return Err(DefiniteError::HistoryRewritten(
+                    table_name,
+                    cursor.snapshot_id(),
+                ));
+            }
+        }
+        next = match snapshot.parent_snapshot_id() {
+            Some(parent_id) => match metadata.snapshot_by_id(parent_id) {
+                Some(parent) => Some(Arc::clone(parent)),
+                None => return Err(DefiniteError::SnapshotExpired(parent_id, table_name)),
+            },
+            None => None,
+        };
+        snapshots.push(snapshot);
+    }
+
+    match cursor {
+        // We reached the root of the table's history without encountering the cursor.
+        Some(cursor) => Err(DefiniteError::HistoryRewritten(
+            table_name,
+            cursor.snapshot_id(),
+        )),
+        None => {
+            snapshots.reverse();
+            Ok(snapshots)
+        }
+    }
+}
@@ -23,6 +23,11 @@
 //! This allows us to easily advance the frontier each time we see a new GTID on the replication
 //! stream.
 //!
+//! MariaDB GTIDs are made of a replication domain id, the id of the originating server and a
+//! sequence number instead. They are tracked with the same partitioned timestamps by standing in
+//! a UUID for each domain and server id pair, see
+//! [`mz_storage_types::sources::mysql::mariadb_source_id`].
+//!
 //! # Resumption
 //!
 //! When the dataflow is resumed, the MySQL replication stream is started from the GTID frontier
@@ -63,13 +68,16 @@ use tracing::trace;
 use uuid::Uuid;
 
 use mz_mysql_util::{
-    ER_SOURCE_FATAL_ERROR_READING_BINLOG_CODE, MySqlConn, MySqlError, query_sys_var,
+    ER_SOURCE_FATAL_ERROR_READING_BINLOG_CODE, MySqlConn, MySqlError, is_mariadb,
+    query_gtid_executed, query_gtid_purged,
 };
 use mz_ore::cast::CastFrom;
 use mz_repr::GlobalId;
 use mz_storage_types::errors::DataflowError;
 use mz_storage_types::sources::MySqlSourceConnection;
-use mz_storage_types::sources::mysql::{GtidPartition, GtidState, gtid_set_frontier};
+use mz_storage_types::sources::mysql::{
+    GtidPartition, GtidState, gtid_set_frontier, mariadb_domain_server_id,
+};
 use mz_timely_util::builder_async::{
     Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
 };
@@ -162,7 +170,7 @@ pub(crate) fn render<'scope>(
             // Get the set of GTIDs that have been purged from the binlogs. The assumption is that this
             // represents the frontier of possible GTIDs that exist in the binlog, that we can start
             // replicating from.
-            let binlog_purged_set = query_sys_var(&mut conn, "global.gtid_purged").await?;
+            let binlog_purged_set = query_gtid_purged(&mut conn).await?;
             let binlog_frontier = match gtid_set_frontier(&binlog_purged_set) {
                 Ok(frontier) => frontier,
                 Err(err) => {
@@ -227,7 +235,7 @@ pub(crate) fn render<'scope>(
                     trace!(%id, "timely-{worker_id} replication reader found no outputs \
                                  to replicate, using latest gtid_executed as resume_upper");
                     let executed_gtid_set =
-                        query_sys_var(&mut conn, "global.gtid_executed").await?;
+                        query_gtid_executed(&mut conn).await?;
 
                     gtid_set_frontier(&executed_gtid_set)?
                 }
@@ -329,9 +337,48 @@ pub(crate) fn render<'scope>(
             while let Some(event) = repl_context.stream.next().await {
                 use mysql_async::binlog::events::*;
                 let event = event?;
-                let event_data = event.read_data()?;
+                let mariadb_gtid = events::mariadb_gtid(&event)?;
+                let event_data = match mariadb_gtid {
+                    Some(_) => None,
+                    None => event.read_data()?,
+                };
                 metrics.total.inc();
 
+                // We receive a GtidEvent that tells us the GTID of the incoming RowsEvents (and
+                // other events). MariaDB servers send their own kind of GTID event instead.
+                let gtid = match &event_data {
+                    Some(EventData::GtidEvent(event)) => Some((
+                        Uuid::from_bytes(event.sid()),
+                        NonZeroU64::new(event.gno()).unwrap(),
+                    )),
+                    _ => mariadb_gtid,
+                };
+                if let Some((source_id, tx_id)) = gtid {
+                    // We are potentially about to ingest a big transaction that we don't want
+                    // to store in memory. For this reason we are immediately downgrading our
+                    // progress frontier to one that includes the upcoming transaction. This
+                    // will cause a remap binding to be minted right away and so the data of
+                    // the transaction will not accumulate in the reclock operator.
+                    let next_tx_id = tx_id.checked_add(1).unwrap();
+                    let next_gtid =
+                        GtidPartition::new_singleton(source_id, GtidState::Active(next_tx_id));
+
+                    if let Err(err) = progress_partitions.advance_frontier(next_gtid) {
+                        return Ok(return_definite_error(
+                            err,
+                            &output_indexes,
+                            &data_output,
+                            data_cap_set,
+                            &definite_error_handle,
+                            definite_error_cap_set,
+                        )
+                        .await);
+                    }
+                    // Store the information of the active transaction for the subsequent events
+                    active_tx = Some((source_id, tx_id));
+                    continue;
+                }
+
                 match event_data {
                     Some(EventData::XidEvent(_)) => {
                         // We've received a transaction commit event, which means that we've seen
@@ -357,34 +404,6 @@ pub(crate) fn render<'scope>(
                         let new_upper = data_partitions.frontier();
                         repl_context.downgrade_data_cap_set("xid_event", new_upper);
                     }
-                    // We receive a GtidEvent that tells us the GTID of the incoming RowsEvents (and other events)
-                    Some(EventData::GtidEvent(event)) => {
-                        let source_id = Uuid::from_bytes(event.sid());
-                        let tx_id = NonZeroU64::new(event.gno()).unwrap();
-
-                        // We are potentially about to ingest a big transaction that we don't want
-                        // to store in memory. For this reason we are immediately downgrading our
-                        // progress frontier to one that includes the upcoming transaction. This
-                        // will cause a remap binding to be minted right away and so the data of
-                        // the transaction will not accumulate in the reclock operator.
-                        let next_tx_id = tx_id.checked_add(1).unwrap();
-                        let next_gtid =
-                            GtidPartition::new_singleton(source_id, GtidState::Active(next_tx_id));
-
-                        if let Err(err) = progress_partitions.advance_frontier(next_gtid) {
-                            return Ok(return_definite_error(
-                                err,
-                                &output_indexes,
-                                &data_output,
-                                data_cap_set,
-                                &definite_error_handle,
-                                definite_error_cap_set,
-                            )
-                            .await);
-                        }
-                        // Store the information of the active transaction for the subsequent events
-                        active_tx = Some((source_id, tx_id));
-                    }
                     Some(EventData::RowsEvent(data)) => {
                         let (source_id, tx_id) = active_tx
                             .clone()
@@ -549,19 +568,35 @@ async fn raw_stream(
          and server_id: {server_id:?}"
     );
 
+    // MariaDB doesn't support requesting a stream from a GTID set. Instead the position to start
+    // from is set up front on the connection as the last GTID seen for each replication domain,
+    // after which the server is asked for a stream from the (empty) default binlog position.
+    let mariadb = is_mariadb(&mut conn).await?;
+    if mariadb {
+        let connect_state = mariadb_connect_state(resume_upper);
+        trace!("requesting mariadb replication stream with connect state: {connect_state:?}");
+        conn.exec_drop("SET @slave_connect_state = ?", (connect_state,))
+            .await?;
+        #[allow(clippy::disallowed_methods)] // static SQL string
+        conn.query_drop("SET @slave_gtid_strict_mode = 1").await?;
+        // Announce that we understand GTIDs so that the server sends us GTID events.
+        #[allow(clippy::disallowed_methods)] // static SQL string
+        conn.query_drop("SET @mariadb_slave_capability = 4").await?;
+    }
+
     // We need to transform the connection into a BinlogStream (which takes the `Conn` by value),
     // but to avoid dropping any active SSH tunnel used by the connection we need to preserve the
     // tunnel handle and return it
     let (inner_conn, conn_tunnel_handle) = conn.take();
 
-    let repl_stream = match inner_conn
-        .get_binlog_stream(
-            BinlogStreamRequest::new(server_id)
-                .with_gtid()
-                .with_gtid_set(seen_gtids),
-        )
-        .await
-    {
+    let request = if mariadb {
+        BinlogStreamRequest::new(server_id)
+    } else {
+        BinlogStreamRequest::new(server_id)
+            .with_gtid()
+            .with_gtid_set(seen_gtids)
+    };
+    let repl_stream = match inner_conn.get_binlog_stream(request).await {
         Ok(stream) => stream,
         Err(mysql_async::Error::Server(ref server_err))
             if server_err.code == ER_SOURCE_FATAL_ERROR_READING_BINLOG_CODE =>
@@ -575,3 +610,47 @@ async fn raw_stream(
 
     Ok(Ok((repl_stream, conn_tunnel_handle)))
 }
+
+/// Returns the MariaDB GTID position to resume a replication stream from `resume_upper` at,
+/// formatted as the comma-separated list of the last GTID seen in each replication domain.
+fn mariadb_connect_state(resume_upper: &Antichain<GtidPartition>) -> String {
+    let mut positions = BTreeMap::new();
+    for partition in resume_upper.iter() {
+        let GtidState::Active(next_seq_no) = partition.timestamp() else {
+            continue;
+        };
+        let source_id = partition
+            .interval()
+            .singleton()
+            .expect("Non-absent paritions will be singletons");
+        let Some((domain_id, server_id)) = mariadb_domain_server_id(source_id) else {
+            continue;
+        };
+        // Sequence numbers increase monotonically within a domain regardless of the server that
+        // originated them, so the last GTID of a domain is the one with the highest of them.
+        let seq_no = next_seq_no.get() - 1;
+        let position = positions.entry(domain_id).or_insert((server_id, seq_no));
+        if position.1 < seq_no {
+            *position = (server_id, seq_no);
+        }
+    }
+    positions
+        .into_iter()
+        .filter(|(_, (_, seq_no))| *seq_no > 0)
+        .map(|(domain_id, (server_id, seq_no))| format!("{domain_id}-{server_id}-{seq_no}"))
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_mariadb_connect_state() {
+        let frontier = gtid_set_frontier("0-1-10,0-2-12,1-1-5,2-3-7").unwrap();
+        assert_eq!(mariadb_connect_state(&frontier), "0-2-12,1-1-5,2-3-7");
+
+        let frontier = gtid_set_frontier("").unwrap();
+        assert_eq!(mariadb_connect_state(&frontier), "");
+    }
+}
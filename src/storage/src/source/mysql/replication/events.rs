@@ -12,17 +12,19 @@ use sqlparser::ast::{ObjectName, ObjectNamePart, ObjectType, Statement};
 use sqlparser::dialect::MySqlDialect;
 use sqlparser::parser::Parser;
 use std::collections::BTreeMap;
+use std::num::NonZeroU64;
 
 use maplit::btreemap;
 use mysql_async::binlog::events::OptionalMetaExtractor;
-use mysql_common::binlog::events::{QueryEvent, RowsEventData};
+use mysql_common::binlog::events::{Event, QueryEvent, RowsEventData};
 use mz_mysql_util::{MySqlError, pack_mysql_row};
 use mz_ore::iter::IteratorExt;
 use mz_repr::{Diff, Row};
 use mz_storage_types::errors::DataflowError;
-use mz_storage_types::sources::mysql::GtidPartition;
+use mz_storage_types::sources::mysql::{GtidPartition, mariadb_source_id};
 use timely::progress::Timestamp;
 use tracing::trace;
+use uuid::Uuid;
 
 use crate::source::mysql::SourceOutputInfo;
 use crate::source::types::{FuelSize, SourceMessage};
@@ -87,6 +89,34 @@ fn table_ident_from_object_name(
     )
 }
 
+/// The type code of the GTID events that MariaDB logs at the start of every event group.
+const MARIADB_GTID_EVENT: u8 = 162;
+
+/// Returns the GTID of a MariaDB GTID event, as the source-id that stands in for its domain
+/// and server id and its sequence number, or `None` if this is not a MariaDB GTID event.
+///
+/// MariaDB logs these events in place of the MySQL GtidEvents, which the binlog parser doesn't
+/// know how to decode.
+pub(super) fn mariadb_gtid(event: &Event) -> Result<Option<(Uuid, NonZeroU64)>, TransientError> {
+    if event.header().event_type().0 != MARIADB_GTID_EVENT {
+        return Ok(None);
+    }
+    // The event starts with the 8 byte sequence number followed by the 4 byte domain id.
+    let data = event.data();
+    let (Some(seq_no), Some(domain_id)) = (data.get(0..8), data.get(8..12)) else {
+        return Err(TransientError::Generic(anyhow::anyhow!(
+            "truncated MariaDB GTID event"
+        )));
+    };
+    let seq_no = u64::from_le_bytes(seq_no.try_into().expect("known to be 8 bytes"));
+    let domain_id = u32::from_le_bytes(domain_id.try_into().expect("known to be 4 bytes"));
+    let seq_no = NonZeroU64::new(seq_no).ok_or_else(|| {
+        TransientError::Generic(anyhow::anyhow!("invalid MariaDB GTID sequence number 0"))
+    })?;
+    let source_id = mariadb_source_id(domain_id, event.header().server_id());
+    Ok(Some((source_id, seq_no)))
+}
+
 /// Handles QueryEvents from the MySQL replication stream. Since we only use
 /// row-based replication, we only expect to see QueryEvents for DDL changes.
 ///
@@ -117,7 +117,7 @@ use itertools::Itertools;
 use mysql_async::prelude::Queryable;
 use mysql_async::{IsolationLevel, Row as MySqlRow, Transaction, TxOpts, Value};
 use mz_mysql_util::{
-    MySqlConn, MySqlError, QualifiedTableRef, pack_mysql_row, query_sys_var, quote_identifier,
+    MySqlConn, MySqlError, QualifiedTableRef, pack_mysql_row, query_gtid_executed, quote_identifier,
 };
 use mz_ore::cast::CastFrom;
 use mz_ore::future::InTask;
@@ -313,6 +313,7 @@ async fn sample_pk_bounds(
     task_name: &str,
     tables: &BTreeMap<MySqlTableName, Vec<SourceOutputInfo>>,
     metrics: &MySqlSnapshotMetrics,
+    is_mariadb: bool,
 ) -> Result<
     (
         BTreeMap<MySqlTableName, Option<PkBoundaries>>,
@@ -365,12 +366,7 @@ async fn sample_pk_bounds(
                             .connect(task_name, ssh_tunnel_manager)
                             .await?;
                         if let Some(timeout) = max_execution_time {
-                            #[allow(clippy::disallowed_methods)]
-                            conn.query_drop(format!(
-                                "SET @@session.max_execution_time = {}",
-                                timeout.as_millis()
-                            ))
-                            .await?;
+                            set_max_execution_time(&mut *conn, timeout, is_mariadb).await?;
                         }
                         conn
                     }
@@ -470,6 +466,7 @@ async fn lock_and_prepare_snapshot(
         set_wait_timeout(&mut *lock_conn, timeout).await?;
     }
 
+    let is_mariadb = mz_mysql_util::is_mariadb(&mut *lock_conn).await?;
     let errored_outputs = verify_output_schemas(&mut *lock_conn, tables).await?;
     let errored: BTreeSet<usize> = errored_outputs.iter().map(|(idx, _)| *idx).collect();
     let sample_tables: BTreeMap<MySqlTableName, Vec<SourceOutputInfo>> = tables
@@ -492,6 +489,7 @@ async fn lock_and_prepare_snapshot(
         task_name,
         &sample_tables,
         metrics,
+        is_mariadb,
     )
     .await?;
 
@@ -821,6 +819,7 @@ pub(crate) fn render<'scope>(
                     Err(err) => Err(err)?,
                     Ok(()) => (),
                 };
+                let is_mariadb = mz_mysql_util::is_mariadb(&mut conn).await?;
 
                 if let Some(timeout) = config
                     .config
@@ -969,13 +968,7 @@ pub(crate) fn render<'scope>(
                     .mysql_source_timeouts
                     .snapshot_max_execution_time
                 {
-                    // Interpolating an integer millis value; not parameterizable in MySQL `SET`.
-                    #[allow(clippy::disallowed_methods)]
-                    tx.query_drop(format!(
-                        "SET @@session.max_execution_time = {}",
-                        timeout.as_millis()
-                    ))
-                    .await?;
+                    set_max_execution_time(&mut tx, timeout, is_mariadb).await?;
                 }
 
                 // Signal readiness by dropping the snapshot capability, then the leader
@@ -1171,6 +1164,30 @@ where
     ))
     .await
 }
+
+/// Sets the session limit on the execution time of each statement. MariaDB calls this setting
+/// `max_statement_time` and expects it in seconds rather than milliseconds.
+async fn set_max_execution_time<Q>(
+    conn: &mut Q,
+    timeout: Duration,
+    is_mariadb: bool,
+) -> Result<(), mysql_async::Error>
+where
+    Q: Queryable,
+{
+    let statement = if is_mariadb {
+        format!(
+            "SET @@session.max_statement_time = {}",
+            timeout.as_secs_f64()
+        )
+    } else {
+        format!("SET @@session.max_execution_time = {}", timeout.as_millis())
+    };
+    // Interpolating a `Duration` number; not parameterizable in MySQL `SET`.
+    #[allow(clippy::disallowed_methods)]
+    conn.query_drop(statement).await
+}
+
 async fn lock_tables_and_read_gtid_set(
     lock_conn: &mut MySqlConn,
     lock_clauses: &str,
@@ -1196,7 +1213,7 @@ async fn lock_tables_and_read_gtid_set(
             .await?;
     }
 
-    let snapshot_gtid_set = query_sys_var(lock_conn, "global.gtid_executed").await?;
+    let snapshot_gtid_set = query_gtid_executed(lock_conn).await?;
     Ok(snapshot_gtid_set)
 }
 
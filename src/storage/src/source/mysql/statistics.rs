@@ -16,7 +16,7 @@ use timely::dataflow::operators::vec::Map;
 use timely::dataflow::{Scope, StreamVec};
 use timely::progress::Antichain;
 
-use mz_mysql_util::query_sys_var;
+use mz_mysql_util::query_gtid_executed;
 use mz_ore::future::InTask;
 use mz_storage_types::sources::MySqlSourceConnection;
 use mz_storage_types::sources::mysql::{GtidPartition, GtidState, gtid_set_frontier};
@@ -91,7 +91,7 @@ pub(crate) fn render<'scope>(
                     let probe_ts = probe_ticker.tick().await;
 
                     let gtid_executed =
-                        query_sys_var(&mut stats_conn, "global.gtid_executed").await?;
+                        query_gtid_executed(&mut stats_conn).await?;
                     // We don't translate this into a definite error like in snapshotting, but we
                     // will restart the source.
                     let upstream_frontier =
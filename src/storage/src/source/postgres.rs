@@ -154,6 +154,7 @@ impl SourceRender for PostgresSourceConnection {
             };
             let desc = details.table.clone();
             let casts = details.column_casts.clone();
+            let retract_on_truncate = details.retract_on_truncate;
             let resume_upper = Antichain::from_iter(
                 config
                     .source_resume_uppers
@@ -168,6 +169,7 @@ impl SourceRender for PostgresSourceConnection {
                 casts,
                 resume_upper,
                 export_id: id.clone(),
+                retract_on_truncate,
             };
             table_info
                 .entry(output.desc.oid)
@@ -279,6 +281,9 @@ struct SourceOutputInfo {
     casts: Vec<(CastType, StorageScalarExpr)>,
     resume_upper: Antichain<MzOffset>,
     export_id: GlobalId,
+    /// Whether an upstream `TRUNCATE` retracts the contents of this output instead of producing a
+    /// [`DefiniteError::TableTruncated`] error.
+    retract_on_truncate: bool,
 }
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -315,6 +320,16 @@ pub enum TransientError {
     InvalidTransaction,
     #[error("BEGIN within existing BEGIN stream")]
     NestedTransaction,
+    #[error("unexpected message in streamed transaction")]
+    InvalidStreamedTransaction,
+    #[error(
+        "streamed in-progress transactions exceed {max_bytes} bytes; raise \
+        pg_source_streamed_transactions_max_bytes or disable \
+        pg_source_stream_in_progress_transactions"
+    )]
+    StreamedTransactionsTooLarge { max_bytes: usize },
+    #[error("malformed logical replication message")]
+    MalformedReplicationMessage,
     #[error("recoverable errors should crash the process during snapshots")]
     SyntheticError,
     #[error("sql client error")]
@@ -68,6 +68,32 @@
 //!
 //! [1]: https://www.postgresql.org/docs/15/protocol-replication.html#PROTOCOL-REPLICATION-START-REPLICATION
 //! [2]: https://www.postgresql.org/message-id/CAFPTHDZS9O9WG02EfayBd6oONzK%2BqfUxS6AbVLJ7W%2BKECza2gg%40mail.gmail.com
+//!
+//! Servers running PostgreSQL 14 or later stream large transactions to us before they commit. The
+//! chunks of such transactions are staged by the `streaming` module and only handed to the logic
+//! above once the transaction commits, as a regular transaction at its commit LSN. Since a
+//! transaction's commit record comes after all the changes it has streamed, the keepalive
+//! reasoning above continues to hold.
+//!
+//! # Truncation
+//!
+//! By default an upstream `TRUNCATE` puts the affected outputs into an errored state because the
+//! replication stream does not tell us which rows were removed. Outputs created with the `RETRACT
+//! ON TRUNCATE` option instead retract their contents as of the truncation LSN and keep ingesting.
+//!
+//! The contents of an output right before a truncation at `commit_lsn` are made of two parts. The
+//! first one is the data already committed to the persist shard of the output. We read it back
+//! along with the bindings of the remap shard at the same time, which tells us the exact LSN
+//! frontier `persisted_upper` that the shard contents correspond to. The second one is the updates
+//! that this operator has emitted at LSNs not less than `persisted_upper` but which have not made
+//! it to the shard yet. We keep these around in memory for every retracting output and discard
+//! them as the committed upper of the source advances past them. Since the committed upper can
+//! never be beyond `persisted_upper`, the in-flight updates always cover the gap between the shard
+//! contents and `commit_lsn`.
+//!
+//! Updates at LSN 0 are produced by the snapshot operator and never pass through this operator, so
+//! we wait until they are committed before reading back the contents. For the same reason a
+//! truncation that is observed while there are pending rewinds falls back to producing an error.
 
 use std::collections::BTreeMap;
 use std::convert::Infallible;
@@ -79,25 +105,35 @@ use std::sync::LazyLock;
 use std::time::Instant;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use anyhow::Context;
 use differential_dataflow::AsCollection;
 use futures::{FutureExt, Stream as AsyncStream, StreamExt, TryStreamExt};
 use mz_dyncfg::ConfigSet;
 use mz_ore::cast::CastFrom;
 use mz_ore::future::InTask;
+use mz_persist_client::Diagnostics;
+use mz_persist_client::read::ListenEvent;
+use mz_persist_types::codec_impls::UnitSchema;
 use mz_postgres_util::PostgresError;
+use mz_postgres_util::schemas::get_pg_major_version;
 use mz_postgres_util::{Client, Sql, execute, query_opt, simple_query_opt, sql};
-use mz_repr::{Datum, DatumVec, Diff, Row};
+use mz_repr::{Datum, DatumVec, Diff, GlobalId, Row, Timestamp};
+use mz_storage_types::StorageDiff;
 use mz_storage_types::dyncfgs::PG_SCHEMA_VALIDATION_INTERVAL;
 use mz_storage_types::dyncfgs::PG_SOURCE_VALIDATE_TIMELINE;
+use mz_storage_types::dyncfgs::{
+    PG_SOURCE_STREAM_IN_PROGRESS_TRANSACTIONS, PG_SOURCE_STREAMED_TRANSACTIONS_MAX_BYTES,
+};
 use mz_storage_types::errors::DataflowError;
-use mz_storage_types::sources::{MzOffset, PostgresSourceConnection};
+use mz_storage_types::sources::{MzOffset, PostgresSourceConnection, SourceData, SourceTimestamp};
 use mz_timely_util::builder_async::{
     AsyncOutputHandle, Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder,
     PressOnDropButton,
 };
-use postgres_replication::LogicalReplicationStream;
+use postgres_replication::ReplicationStream;
 use postgres_replication::protocol::{LogicalReplicationMessage, ReplicationMessage, TupleData};
 use serde::{Deserialize, Serialize};
+use timely::PartialOrder;
 use timely::container::CapacityContainerBuilder;
 use timely::dataflow::channels::pact::{Exchange, Pipeline};
 use timely::dataflow::operators::Capability;
@@ -106,6 +142,7 @@ use timely::dataflow::operators::Operator;
 use timely::dataflow::operators::core::Map;
 use timely::dataflow::{Scope, StreamVec};
 use timely::progress::Antichain;
+use timely::progress::frontier::MutableAntichain;
 use tokio::sync::{mpsc, watch};
 use tokio_postgres::error::SqlState;
 use tokio_postgres::types::PgLsn;
@@ -118,12 +155,33 @@ use crate::source::postgres::{DefiniteError, ReplicationError, SourceOutputInfo,
 use crate::source::probe;
 use crate::source::types::{FuelSize, Probe, SignaledFuture, SourceMessage, StackedCollection};
 
+mod streaming;
+
 /// A logical replication message from the server.
 type LogicalReplMsg = ReplicationMessage<LogicalReplicationMessage>;
 
 /// A decoded row from a transaction with source information.
 type DecodedRow = (u32, usize, Result<Row, DefiniteError>, Diff);
 
+/// An event extracted from a transaction.
+enum TransactionEvent {
+    /// A decoded row for one of the outputs.
+    Row(DecodedRow),
+    /// An output that retracts its contents on `TRUNCATE` was truncated.
+    Truncate(u32, usize),
+}
+
+/// The state kept for an output that retracts its contents when its upstream table is truncated.
+/// See the module documentation for more information.
+struct TruncateState {
+    /// The source export of this output.
+    export_id: GlobalId,
+    /// The updates emitted for this output that might not have been committed to persist yet.
+    in_flight: Vec<(Row, MzOffset, Diff)>,
+    /// The LSN of the last truncation of this output observed by this operator, if any.
+    last_truncate: Option<MzOffset>,
+}
+
 /// Postgres epoch is 2000-01-01T00:00:00Z
 static PG_EPOCH: LazyLock<SystemTime> =
     LazyLock::new(|| UNIX_EPOCH + Duration::from_secs(946_684_800));
@@ -160,6 +218,7 @@ pub(crate) fn render<'scope>(
 
     let slot_reader = u64::cast_from(config.responsible_worker("slot"));
     let (data_output, data_stream) = builder.new_output();
+    let (truncate_output, truncate_stream) = builder.new_output();
     let (definite_error_handle, definite_errors) =
         builder.new_output::<CapacityContainerBuilder<_>>();
     let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
@@ -179,8 +238,12 @@ pub(crate) fn render<'scope>(
         let busy_signal = Arc::clone(&config.busy_signal);
         Box::pin(SignaledFuture::new(busy_signal, async move {
             let (id, worker_id) = (config.id, config.worker_id);
-            let [data_cap_set, definite_error_cap_set, probe_cap]: &mut [_; 3] =
-                caps.try_into().unwrap();
+            let [
+                data_cap_set,
+                truncate_cap_set,
+                definite_error_cap_set,
+                probe_cap,
+            ]: &mut [_; 4] = caps.try_into().unwrap();
 
             if !config.responsible_for("slot") {
                 // Emit 0, to mark this worker as having started up correctly.
@@ -376,7 +439,27 @@ pub(crate) fn render<'scope>(
             }
             trace!(%id, "timely-{worker_id} pending rewinds {rewinds:?}");
 
+            // Keep track of the committed upper so that we can discard in-flight updates of
+            // outputs that retract their contents on truncation.
+            let (committed_upper_tx, committed_upper) =
+                watch::channel(Antichain::from_elem(MzOffset::from(0)));
+            let committed_uppers = committed_uppers.inspect(move |upper| {
+                committed_upper_tx.send_replace(upper.clone());
+            });
             let mut committed_uppers = pin!(committed_uppers);
+            let mut truncate_states: BTreeMap<usize, TruncateState> = table_info
+                .values()
+                .flatten()
+                .filter(|(_, info)| info.retract_on_truncate)
+                .map(|(output_index, info)| {
+                    let state = TruncateState {
+                        export_id: info.export_id,
+                        in_flight: vec![],
+                        last_truncate: None,
+                    };
+                    (*output_index, state)
+                })
+                .collect();
 
             let stream_result = raw_stream(
                 &config,
@@ -457,6 +540,7 @@ pub(crate) fn render<'scope>(
                                 &mut table_info,
                                 &metrics,
                                 &connection.publication,
+                                rewinds.is_empty(),
                             ));
 
                             trace!(
@@ -469,8 +553,51 @@ pub(crate) fn render<'scope>(
                                 "new_upper={data_upper} tx_lsn={commit_lsn}",
                             );
                             data_upper = commit_lsn + 1;
-                            while let Some((oid, output_index, event, diff)) = tx.try_next().await?
-                            {
+                            while let Some(event) = tx.try_next().await? {
+                                let (oid, output_index, event, diff) = match event {
+                                    TransactionEvent::Row(row) => row,
+                                    TransactionEvent::Truncate(oid, output_index) => {
+                                        // The retractions are emitted at `commit_lsn` so we let
+                                        // the frontier advance up to it, which allows the updates
+                                        // before the truncation to be committed.
+                                        data_cap_set.downgrade([&commit_lsn]);
+                                        truncate_cap_set.downgrade([&commit_lsn]);
+                                        let state = truncate_states
+                                            .get_mut(&output_index)
+                                            .expect("retracting outputs have a truncate state");
+                                        let (persisted, in_flight) =
+                                            truncated_contents(&config, state).await?;
+                                        state.last_truncate = Some(commit_lsn);
+                                        let mut persisted = pin!(persisted);
+                                        while let Some((row, diff)) = persisted.next().await {
+                                            let message = SourceMessage {
+                                                key: Row::default(),
+                                                value: row,
+                                                metadata: Row::default(),
+                                            };
+                                            let update =
+                                                ((output_index, Ok(message)), commit_lsn, -diff);
+                                            let size = update.fuel_size();
+                                            truncate_output
+                                                .give_fueled(&truncate_cap_set[0], update, size)
+                                                .await;
+                                        }
+                                        for (row, diff) in in_flight {
+                                            let update =
+                                                ((oid, output_index, Ok(row)), commit_lsn, -diff);
+                                            let size = update.fuel_size();
+                                            data_output
+                                                .give_fueled(&data_cap_set[0], update, size)
+                                                .await;
+                                        }
+                                        continue;
+                                    }
+                                };
+                                if let (Ok(row), Some(state)) =
+                                    (&event, truncate_states.get_mut(&output_index))
+                                {
+                                    state.in_flight.push((row.clone(), commit_lsn, diff));
+                                }
                                 let event = event.map_err(Into::into);
                                 let data = (oid, output_index, event);
                                 if let Some(req) = rewinds.get(&output_index) {
@@ -595,6 +722,13 @@ pub(crate) fn render<'scope>(
                     // since we must be able to produce data at offset 0.
                     if rewinds.is_empty() {
                         data_cap_set.downgrade([&data_upper]);
+                        truncate_cap_set.downgrade([&data_upper]);
+                    }
+                    let committed = committed_upper.borrow();
+                    for state in truncate_states.values_mut() {
+                        state
+                            .in_flight
+                            .retain(|(_, lsn, _)| committed.less_equal(lsn));
                     }
                 }
             }
@@ -639,6 +773,7 @@ pub(crate) fn render<'scope>(
                 });
             }
         })
+        .concat(truncate_stream)
         .as_collection();
 
     let errors = definite_errors.concat(transient_errors.map(ReplicationError::from));
@@ -746,15 +881,35 @@ async fn raw_stream<'a>(
     // 'Delay' ensures we always tick at least 'feedback_interval'.
     feedback_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
+    // Servers that support it are asked to stream large transactions while they are still in
+    // progress, which keeps them from spilling to disk upstream. See the `streaming` module for
+    // how these are reassembled.
+    let streaming = if PG_SOURCE_STREAM_IN_PROGRESS_TRANSACTIONS.get(config.config.config_set()) {
+        let major_version = get_pg_major_version(&*metadata_client).await?;
+        streaming::streaming_options(major_version)
+    } else {
+        None
+    };
+
     // Postgres will return all transactions that commit *at or after* after the provided LSN,
     // following the timely upper semantics.
     let lsn = PgLsn::from(resume_lsn.offset);
-    let query = sql!(
-        "START_REPLICATION SLOT {} LOGICAL {} (\"proto_version\" '1', \"publication_names\" {})",
-        Sql::ident(slot),
-        Sql::raw_unchecked(lsn.to_string()),
-        Sql::literal(publication)
-    );
+    let query = match streaming {
+        Some((proto_version, mode)) => sql!(
+            "START_REPLICATION SLOT {} LOGICAL {} (\"proto_version\" {}, \"publication_names\" {}, \"streaming\" {})",
+            Sql::ident(slot),
+            Sql::raw_unchecked(lsn.to_string()),
+            Sql::literal(proto_version),
+            Sql::literal(publication),
+            Sql::literal(mode)
+        ),
+        None => sql!(
+            "START_REPLICATION SLOT {} LOGICAL {} (\"proto_version\" '1', \"publication_names\" {})",
+            Sql::ident(slot),
+            Sql::raw_unchecked(lsn.to_string()),
+            Sql::literal(publication)
+        ),
+    };
     let copy_stream = match replication_client.copy_both_simple(query.as_str()).await {
         Ok(copy_stream) => copy_stream,
         Err(err) if err.code() == Some(&SqlState::OBJECT_NOT_IN_PREREQUISITE_STATE) => {
@@ -813,7 +968,10 @@ async fn raw_stream<'a>(
         let mut uppers = pin!(uppers);
         let mut last_committed_upper = resume_lsn;
 
-        let mut stream = pin!(LogicalReplicationStream::new(copy_stream));
+        let mut stream = pin!(ReplicationStream::new(copy_stream));
+        let mut streamed_transactions = streaming::StreamedTransactions::new(
+            PG_SOURCE_STREAMED_TRANSACTIONS_MAX_BYTES.get(config.config.config_set()),
+        );
 
         if !(resume_lsn == MzOffset::from(0) || min_resume_lsn <= resume_lsn) {
             let err = TransientError::OvercompactedReplicationSlot {
@@ -828,7 +986,12 @@ async fn raw_stream<'a>(
             tokio::select! {
                 Some(next_message) = stream.next() => match next_message {
                     Ok(ReplicationMessage::XLogData(data)) => {
-                        yield ReplicationMessage::XLogData(data);
+                        for data in streamed_transactions.handle(data)? {
+                            let data = data
+                                .map_data(|buf| LogicalReplicationMessage::parse(&buf))
+                                .context("parsing logical replication message")?;
+                            yield ReplicationMessage::XLogData(data);
+                        }
                         Ok(())
                     }
                     Ok(ReplicationMessage::PrimaryKeepAlive(keepalive)) => {
@@ -893,7 +1056,8 @@ fn extract_transaction<'a>(
     table_info: &'a mut BTreeMap<u32, BTreeMap<usize, SourceOutputInfo>>,
     metrics: &'a PgSourceMetrics,
     publication: &'a str,
-) -> impl AsyncStream<Item = Result<DecodedRow, TransientError>> + 'a {
+    retract_truncates: bool,
+) -> impl AsyncStream<Item = Result<TransactionEvent, TransientError>> + 'a {
     use LogicalReplicationMessage::*;
     let mut row = Row::default();
     async_stream::try_stream!({
@@ -927,7 +1091,7 @@ fn extract_transaction<'a>(
                         };
                         let datums = projection.iter().map(|idx| &tuple_data[*idx]);
                         let row = unpack_tuple(datums, &mut row);
-                        yield (rel, *output, row, Diff::ONE);
+                        yield TransactionEvent::Row((rel, *output, row, Diff::ONE));
                     }
                 }
                 Update(body) => match body.old_tuple() {
@@ -953,19 +1117,19 @@ fn extract_transaction<'a>(
                             let old_row = unpack_tuple(old_tuple, &mut row);
                             let new_row = unpack_tuple(new_tuple, &mut row);
 
-                            yield (rel, *output, old_row, Diff::MINUS_ONE);
-                            yield (rel, *output, new_row, Diff::ONE);
+                            yield TransactionEvent::Row((rel, *output, old_row, Diff::MINUS_ONE));
+                            yield TransactionEvent::Row((rel, *output, new_row, Diff::ONE));
                         }
                     }
                     None => {
                         let rel = body.rel_id();
                         for (output, _) in table_info.get(&rel).into_iter().flatten() {
-                            yield (
+                            yield TransactionEvent::Row((
                                 rel,
                                 *output,
                                 Err(DefiniteError::DefaultReplicaIdentity),
                                 Diff::ONE,
-                            );
+                            ));
                         }
                     }
                 },
@@ -979,18 +1143,18 @@ fn extract_transaction<'a>(
                             };
                             let datums = projection.iter().map(|idx| &old_tuple.tuple_data()[*idx]);
                             let row = unpack_tuple(datums, &mut row);
-                            yield (rel, *output, row, Diff::MINUS_ONE);
+                            yield TransactionEvent::Row((rel, *output, row, Diff::MINUS_ONE));
                         }
                     }
                     None => {
                         let rel = body.rel_id();
                         for (output, _) in table_info.get(&rel).into_iter().flatten() {
-                            yield (
+                            yield TransactionEvent::Row((
                                 rel,
                                 *output,
                                 Err(DefiniteError::DefaultReplicaIdentity),
                                 Diff::ONE,
-                            );
+                            ));
                         }
                     }
                 },
@@ -1043,20 +1207,25 @@ fn extract_transaction<'a>(
                             info.projection = Some(projection);
                         }
                         for schema_error in schema_errors {
-                            yield schema_error;
+                            yield TransactionEvent::Row(schema_error);
                         }
                     }
                 }
                 Truncate(body) => {
                     for &rel_id in body.rel_ids() {
                         if let Some(outputs) = table_info.get_mut(&rel_id) {
-                            for (output, _) in std::mem::take(outputs) {
-                                yield (
-                                    rel_id,
-                                    output,
-                                    Err(DefiniteError::TableTruncated),
-                                    Diff::ONE,
-                                );
+                            for (output, info) in std::mem::take(outputs) {
+                                if retract_truncates && info.retract_on_truncate {
+                                    outputs.insert(output, info);
+                                    yield TransactionEvent::Truncate(rel_id, output);
+                                } else {
+                                    yield TransactionEvent::Row((
+                                        rel_id,
+                                        output,
+                                        Err(DefiniteError::TableTruncated),
+                                        Diff::ONE,
+                                    ));
+                                }
                             }
                         }
                     }
@@ -1080,6 +1249,147 @@ fn extract_transaction<'a>(
     })
 }
 
+/// Computes the contents of a retracting output right before its truncation. See the module
+/// documentation for more information.
+///
+/// The contents are returned in two parts. The rows read back from the persist shard of the output,
+/// which have already been cast and are streamed from the shard, and the in-flight updates emitted
+/// by the replication reader, which have not.
+async fn truncated_contents(
+    config: &RawSourceCreationConfig,
+    state: &mut TruncateState,
+) -> Result<(impl AsyncStream<Item = (Row, Diff)>, Vec<(Row, Diff)>), TransientError> {
+    // The updates of the initial snapshot are not part of the in-flight updates so we must wait
+    // for them to be committed, unless the output has been truncated since.
+    let wait_for_snapshot = state.last_truncate.is_none();
+    let (persisted_upper, persisted) =
+        fetch_persisted_contents(config, state.export_id, wait_for_snapshot).await?;
+    let in_flight = std::mem::take(&mut state.in_flight);
+    match state.last_truncate {
+        // The previous truncation has not been committed yet so the contents are exactly the
+        // updates emitted since then.
+        Some(lsn) if persisted_upper.less_equal(&lsn) => {
+            let in_flight = in_flight.into_iter().map(|(row, _, diff)| (row, diff));
+            Ok((futures::stream::empty().left_stream(), in_flight.collect()))
+        }
+        _ => {
+            let in_flight = in_flight
+                .into_iter()
+                .filter(|(_, lsn, _)| persisted_upper.less_equal(lsn))
+                .map(|(row, _, diff)| (row, diff));
+            Ok((persisted.right_stream(), in_flight.collect()))
+        }
+    }
+}
+
+/// Reads back the contents of a source export from its persist shard.
+///
+/// Along with the contents it returns the LSN frontier that they correspond to, which is
+/// determined by following the bindings of the remap shard until they cover the upper that the
+/// data shard had when we started, and then reading the data shard as of the same time. If
+/// `wait_for_snapshot` is set, we also wait for the bindings to cover the initial snapshot.
+///
+/// The contents are streamed from the data shard rather than fetched into memory, since they can
+/// be as large as the upstream table.
+async fn fetch_persisted_contents(
+    config: &RawSourceCreationConfig,
+    export_id: GlobalId,
+    wait_for_snapshot: bool,
+) -> Result<(Antichain<MzOffset>, impl AsyncStream<Item = (Row, Diff)>), TransientError> {
+    let data_metadata = &config.source_exports[&export_id].storage_metadata;
+    let remap_metadata = &config.remap_metadata;
+    let diagnostics = |shard_name: GlobalId| Diagnostics {
+        shard_name: shard_name.to_string(),
+        handle_purpose: format!("truncate retractions for {}", config.id),
+    };
+
+    let data_client = config
+        .persist_clients
+        .open(data_metadata.persist_location.clone())
+        .await
+        .context("error creating persist client")?;
+    let remap_client = config
+        .persist_clients
+        .open(remap_metadata.persist_location.clone())
+        .await
+        .context("error creating persist client")?;
+    let mut data_read = data_client
+        .open_leased_reader::<SourceData, (), Timestamp, StorageDiff>(
+            data_metadata.data_shard,
+            Arc::new(data_metadata.relation_desc.clone()),
+            Arc::new(UnitSchema),
+            diagnostics(export_id),
+            false,
+        )
+        .await
+        .expect("invalid usage");
+    let remap_read = remap_client
+        .open_leased_reader::<SourceData, (), Timestamp, StorageDiff>(
+            remap_metadata.data_shard,
+            Arc::new(remap_metadata.relation_desc.clone()),
+            Arc::new(UnitSchema),
+            diagnostics(config.remap_collection_id),
+            false,
+        )
+        .await
+        .expect("invalid usage");
+
+    // Registering the reader fetched the latest state of the data shard, so its upper includes
+    // every update that the committed upper of the source can have advanced past.
+    let data_upper = data_read.shared_upper();
+    let remap_as_of = remap_read.since().clone();
+    let mut remap_subscribe = remap_read
+        .subscribe(remap_as_of)
+        .await
+        .expect("reader holds back the since of the shard");
+
+    let mut bindings = MutableAntichain::new();
+    let mut remap_upper = Antichain::from_elem(Timestamp::MIN);
+    loop {
+        for event in remap_subscribe.fetch_next().await {
+            match event {
+                ListenEvent::Updates(updates) => {
+                    bindings.update_iter(updates.into_iter().map(|((binding, ()), _, diff)| {
+                        let binding = binding.0.expect("invalid binding");
+                        (MzOffset::decode_row(&binding), diff)
+                    }));
+                }
+                ListenEvent::Progress(upper) => remap_upper = upper,
+            }
+        }
+        if !PartialOrder::less_equal(&data_upper, &remap_upper) {
+            continue;
+        }
+        let Some(as_of) = remap_upper.as_option().and_then(|upper| upper.step_back()) else {
+            continue;
+        };
+        let persisted_upper = bindings.frontier().to_owned();
+        if wait_for_snapshot && persisted_upper.less_equal(&MzOffset::from(0)) {
+            continue;
+        }
+        // The since of the data shard can advance concurrently, in which case we retry with the
+        // next bindings.
+        let Ok(contents) = data_read
+            .snapshot_and_stream(Antichain::from_elem(as_of))
+            .await
+        else {
+            continue;
+        };
+        let contents = async_stream::stream! {
+            // The reader must outlive the stream, which reads the parts leased by it.
+            let _data_read = data_read;
+            for await ((data, ()), _, diff) in contents {
+                // Errors are not part of the contents of the upstream table so they are left
+                // alone.
+                if let Ok(row) = data.0 {
+                    yield (row, Diff::from(diff));
+                }
+            }
+        };
+        return Ok((persisted_upper, contents));
+    }
+}
+
 /// Unpacks an iterator of TupleData into a list of nullable bytes or an error if this can't be
 /// done.
 #[inline]
@@ -0,0 +1,439 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Staging of transactions that the upstream streams to us while they are still in progress.
+//!
+//! Starting with protocol version 2, and when the `streaming` option is requested, PostgreSQL
+//! stops buffering a transaction whose decoded changes exceed `logical_decoding_work_mem` and
+//! instead sends them in chunks as they are decoded, long before the transaction commits or
+//! aborts. Each chunk is delimited by a STREAM START and a STREAM STOP message and every change
+//! within it carries the id of the (sub)transaction that produced it. The fate of the transaction
+//! is announced later by a STREAM COMMIT or a STREAM ABORT message. Chunks of different
+//! transactions can be interleaved with each other and with regular transactions.
+//!
+//! The replication operator expects every transaction to arrive as a contiguous BEGIN..COMMIT
+//! block, so [`StreamedTransactions`] keeps the chunks of in-progress transactions aside, drops
+//! them when the (sub)transaction aborts, and replays them as a regular transaction when the
+//! transaction commits. Messages that are not part of a stream are passed through untouched.
+//!
+//! The staged chunks are kept in memory until their transaction commits or aborts, so a single
+//! huge transaction costs as much memory as its decoded changes. To keep this bounded, staging
+//! more than `pg_source_streamed_transactions_max_bytes` across all in-progress transactions is
+//! a transient error. Operators can then either raise the limit or stop requesting streaming,
+//! in which case the upstream spills large transactions to its own disk instead.
+//!
+//! See <https://www.postgresql.org/docs/current/protocol-logicalrep-message-formats.html>.
+
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use postgres_replication::protocol::{ReplicationMessage, XLogDataBody};
+
+use crate::source::postgres::TransientError;
+
+const BEGIN: u8 = b'B';
+const COMMIT: u8 = b'C';
+const STREAM_START: u8 = b'S';
+const STREAM_STOP: u8 = b'E';
+const STREAM_COMMIT: u8 = b'c';
+const STREAM_ABORT: u8 = b'A';
+
+/// The messages that are prefixed with the id of the producing (sub)transaction when they are
+/// sent as part of a stream.
+const STREAMABLE: [u8; 7] = [b'R', b'Y', b'I', b'U', b'D', b'T', b'M'];
+
+/// Returns the protocol version and the `streaming` mode to request from an upstream server with
+/// the given major version, or `None` if the server cannot stream in-progress transactions.
+pub(super) fn streaming_options(major_version: u32) -> Option<(&'static str, &'static str)> {
+    match major_version {
+        // Version 4 adds the abort LSN to STREAM ABORT messages, which we ignore.
+        16.. => Some(("4", "parallel")),
+        14.. => Some(("2", "on")),
+        _ => None,
+    }
+}
+
+/// The transactions that are currently being streamed by the upstream.
+#[derive(Debug)]
+pub(super) struct StreamedTransactions {
+    /// The top-level transaction whose chunk is currently being received, if any.
+    current: Option<u32>,
+    /// The changes staged for each in-progress top-level transaction, in the order they were
+    /// sent, along with the id of the subtransaction that produced them.
+    staged: BTreeMap<u32, Vec<(u32, XLogDataBody<Bytes>)>>,
+    /// The total size of the payloads in `staged`.
+    staged_bytes: usize,
+    /// The maximum value of `staged_bytes` before staging fails.
+    max_bytes: usize,
+}
+
+impl StreamedTransactions {
+    /// Creates an empty set of streamed transactions that stages at most `max_bytes` of changes.
+    pub(super) fn new(max_bytes: usize) -> Self {
+        StreamedTransactions {
+            current: None,
+            staged: BTreeMap::new(),
+            staged_bytes: 0,
+            max_bytes,
+        }
+    }
+
+    /// Processes the next message of the replication stream and returns the messages that are
+    /// ready to be decoded, in order.
+    pub(super) fn handle(
+        &mut self,
+        body: XLogDataBody<Bytes>,
+    ) -> Result<Vec<XLogDataBody<Bytes>>, TransientError> {
+        let payload = body.data();
+        let Some(&tag) = payload.first() else {
+            return Ok(vec![body]);
+        };
+        match (tag, self.current) {
+            (STREAM_START, None) => {
+                let xid = u32::from_be_bytes(field(payload, 1)?);
+                let [first_segment] = field(payload, 5)?;
+                // The upstream re-streams a transaction from the beginning after a reconnection.
+                if first_segment == 1 {
+                    self.discard(xid, 0);
+                }
+                self.current = Some(xid);
+                Ok(vec![])
+            }
+            (STREAM_STOP, Some(_)) => {
+                self.current = None;
+                Ok(vec![])
+            }
+            (tag, Some(xid)) if STREAMABLE.contains(&tag) => {
+                let subxid = u32::from_be_bytes(field(payload, 1)?);
+                // Strip the transaction id so that the message can be decoded as a regular one.
+                let Ok(body) = body.map_data(|data| {
+                    let mut stripped = BytesMut::with_capacity(data.len() - 4);
+                    stripped.put_u8(tag);
+                    stripped.put_slice(&data[5..]);
+                    Ok::<_, Infallible>(stripped.freeze())
+                });
+                self.staged_bytes += body.data().len();
+                if self.staged_bytes > self.max_bytes {
+                    return Err(TransientError::StreamedTransactionsTooLarge {
+                        max_bytes: self.max_bytes,
+                    });
+                }
+                self.staged.entry(xid).or_default().push((subxid, body));
+                Ok(vec![])
+            }
+            (STREAM_COMMIT, None) => {
+                let xid: [u8; 4] = field(payload, 1)?;
+                let flags: [u8; 1] = field(payload, 5)?;
+                let commit_lsn: [u8; 8] = field(payload, 6)?;
+                let end_lsn: [u8; 8] = field(payload, 14)?;
+                let timestamp: [u8; 8] = field(payload, 22)?;
+
+                let mut begin = BytesMut::with_capacity(21);
+                begin.put_u8(BEGIN);
+                begin.put_slice(&commit_lsn);
+                begin.put_slice(&timestamp);
+                begin.put_slice(&xid);
+
+                let mut commit = BytesMut::with_capacity(26);
+                commit.put_u8(COMMIT);
+                commit.put_slice(&flags);
+                commit.put_slice(&commit_lsn);
+                commit.put_slice(&end_lsn);
+                commit.put_slice(&timestamp);
+
+                let staged = self
+                    .staged
+                    .remove(&u32::from_be_bytes(xid))
+                    .unwrap_or_default();
+                self.staged_bytes -= staged
+                    .iter()
+                    .map(|(_, body)| body.data().len())
+                    .sum::<usize>();
+                let mut ready = Vec::with_capacity(staged.len() + 2);
+                ready.push(xlog_data(&body, &begin)?);
+                ready.extend(staged.into_iter().map(|(_, body)| body));
+                ready.push(xlog_data(&body, &commit)?);
+                Ok(ready)
+            }
+            (STREAM_ABORT, None) => {
+                let xid = u32::from_be_bytes(field(payload, 1)?);
+                let subxid = u32::from_be_bytes(field(payload, 5)?);
+                if xid == subxid {
+                    self.discard(xid, 0);
+                    self.staged.remove(&xid);
+                } else if let Some(staged) = self.staged.get(&xid) {
+                    // A transaction cannot make changes while one of its subtransactions is
+                    // open, so everything from the first change of the aborted subtransaction
+                    // onwards belongs to it or to its children. This mirrors what the apply
+                    // worker of PostgreSQL does.
+                    if let Some(idx) = staged.iter().position(|(id, _)| *id == subxid) {
+                        self.discard(xid, idx);
+                    }
+                }
+                Ok(vec![])
+            }
+            (STREAM_START | STREAM_COMMIT | STREAM_ABORT, Some(_)) | (STREAM_STOP, None) => {
+                Err(TransientError::InvalidStreamedTransaction)
+            }
+            (_, None) => Ok(vec![body]),
+            (_, Some(_)) => Err(TransientError::InvalidStreamedTransaction),
+        }
+    }
+
+    /// Drops the changes staged for `xid` from position `from` onwards.
+    fn discard(&mut self, xid: u32, from: usize) {
+        if let Some(staged) = self.staged.get_mut(&xid) {
+            for (_, body) in staged.drain(from..) {
+                self.staged_bytes -= body.data().len();
+            }
+        }
+    }
+}
+
+/// Reads the `N` bytes of `payload` at `offset`.
+fn field<const N: usize>(payload: &[u8], offset: usize) -> Result<[u8; N], TransientError> {
+    payload
+        .get(offset..offset + N)
+        .and_then(|field| field.try_into().ok())
+        .ok_or(TransientError::MalformedReplicationMessage)
+}
+
+/// Creates an XLogData message with the given `payload` and the WAL positions of `template`.
+fn xlog_data(
+    template: &XLogDataBody<Bytes>,
+    payload: &[u8],
+) -> Result<XLogDataBody<Bytes>, TransientError> {
+    let mut buf = BytesMut::with_capacity(25 + payload.len());
+    buf.put_u8(b'w');
+    buf.put_u64(template.wal_start());
+    buf.put_u64(template.wal_end());
+    buf.put_i64(template.timestamp());
+    buf.put_slice(payload);
+    match ReplicationMessage::<Bytes>::parse(&buf.freeze()) {
+        Ok(ReplicationMessage::XLogData(body)) => Ok(body),
+        _ => Err(TransientError::MalformedReplicationMessage),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(payload: &[u8]) -> XLogDataBody<Bytes> {
+        let mut buf = BytesMut::new();
+        buf.put_u8(b'w');
+        buf.put_u64(0);
+        buf.put_u64(0);
+        buf.put_i64(0);
+        buf.put_slice(payload);
+        match ReplicationMessage::<Bytes>::parse(&buf.freeze()).unwrap() {
+            ReplicationMessage::XLogData(body) => body,
+            _ => unreachable!(),
+        }
+    }
+
+    fn stream_start(xid: u32, first_segment: bool) -> XLogDataBody<Bytes> {
+        let mut buf = vec![STREAM_START];
+        buf.extend(xid.to_be_bytes());
+        buf.push(u8::from(first_segment));
+        message(&buf)
+    }
+
+    fn stream_stop() -> XLogDataBody<Bytes> {
+        message(&[STREAM_STOP])
+    }
+
+    fn insert(xid: u32, value: u8) -> XLogDataBody<Bytes> {
+        let mut buf = vec![b'I'];
+        buf.extend(xid.to_be_bytes());
+        buf.push(value);
+        message(&buf)
+    }
+
+    fn stream_commit(xid: u32, commit_lsn: u64) -> XLogDataBody<Bytes> {
+        let mut buf = vec![STREAM_COMMIT];
+        buf.extend(xid.to_be_bytes());
+        buf.push(0);
+        buf.extend(commit_lsn.to_be_bytes());
+        buf.extend((commit_lsn + 1).to_be_bytes());
+        buf.extend(42i64.to_be_bytes());
+        message(&buf)
+    }
+
+    fn stream_abort(xid: u32, subxid: u32) -> XLogDataBody<Bytes> {
+        let mut buf = vec![STREAM_ABORT];
+        buf.extend(xid.to_be_bytes());
+        buf.extend(subxid.to_be_bytes());
+        message(&buf)
+    }
+
+    fn feed(
+        streams: &mut StreamedTransactions,
+        messages: impl IntoIterator<Item = XLogDataBody<Bytes>>,
+    ) -> Vec<Vec<u8>> {
+        messages
+            .into_iter()
+            .flat_map(|body| streams.handle(body).unwrap())
+            .map(|body| body.into_data().to_vec())
+            .collect()
+    }
+
+    /// The inserted values of a replayed transaction, with its BEGIN and COMMIT messages.
+    fn replayed(xid: u32, commit_lsn: u64, values: &[u8]) -> Vec<Vec<u8>> {
+        let mut begin = vec![BEGIN];
+        begin.extend(commit_lsn.to_be_bytes());
+        begin.extend(42i64.to_be_bytes());
+        begin.extend(xid.to_be_bytes());
+        let mut commit = vec![COMMIT, 0];
+        commit.extend(commit_lsn.to_be_bytes());
+        commit.extend((commit_lsn + 1).to_be_bytes());
+        commit.extend(42i64.to_be_bytes());
+
+        let mut messages = vec![begin];
+        messages.extend(values.iter().map(|value| vec![b'I', *value]));
+        messages.push(commit);
+        messages
+    }
+
+    #[mz_ore::test]
+    fn regular_messages_pass_through() {
+        let mut streams = StreamedTransactions::new(usize::MAX);
+        let messages = vec![vec![BEGIN, 1, 2], vec![b'I', 3], vec![COMMIT, 4]];
+        let output = feed(&mut streams, messages.iter().map(|m| message(m)));
+        assert_eq!(output, messages);
+    }
+
+    #[mz_ore::test]
+    fn interleaved_streams_are_replayed_on_commit() {
+        let mut streams = StreamedTransactions::new(usize::MAX);
+        let output = feed(
+            &mut streams,
+            [
+                stream_start(1, true),
+                insert(1, 10),
+                insert(1, 11),
+                stream_stop(),
+                stream_start(2, true),
+                insert(2, 20),
+                stream_stop(),
+                stream_start(1, false),
+                insert(1, 12),
+                stream_stop(),
+            ],
+        );
+        assert!(output.is_empty());
+
+        let output = feed(&mut streams, [stream_commit(2, 100)]);
+        assert_eq!(output, replayed(2, 100, &[20]));
+        let output = feed(&mut streams, [stream_commit(1, 200)]);
+        assert_eq!(output, replayed(1, 200, &[10, 11, 12]));
+        assert!(streams.staged.is_empty());
+    }
+
+    #[mz_ore::test]
+    fn aborted_streams_are_dropped() {
+        let mut streams = StreamedTransactions::new(usize::MAX);
+        let output = feed(
+            &mut streams,
+            [
+                stream_start(1, true),
+                insert(1, 10),
+                stream_stop(),
+                stream_abort(1, 1),
+                stream_commit(1, 100),
+            ],
+        );
+        assert_eq!(output, replayed(1, 100, &[]));
+        assert!(streams.staged.is_empty());
+    }
+
+    #[mz_ore::test]
+    fn aborted_subtransactions_are_dropped() {
+        let mut streams = StreamedTransactions::new(usize::MAX);
+        let output = feed(
+            &mut streams,
+            [
+                stream_start(1, true),
+                insert(1, 10),
+                insert(2, 20),
+                insert(3, 30),
+                stream_stop(),
+                stream_abort(1, 2),
+                stream_start(1, false),
+                insert(1, 11),
+                insert(4, 40),
+                stream_stop(),
+                stream_commit(1, 100),
+            ],
+        );
+        assert_eq!(output, replayed(1, 100, &[10, 11, 40]));
+    }
+
+    #[mz_ore::test]
+    fn restreamed_transactions_start_over() {
+        let mut streams = StreamedTransactions::new(usize::MAX);
+        let output = feed(
+            &mut streams,
+            [
+                stream_start(1, true),
+                insert(1, 10),
+                stream_stop(),
+                stream_start(1, true),
+                insert(1, 10),
+                stream_stop(),
+                stream_commit(1, 100),
+            ],
+        );
+        assert_eq!(output, replayed(1, 100, &[10]));
+    }
+
+    #[mz_ore::test]
+    fn staged_bytes_are_bounded() {
+        let mut streams = StreamedTransactions::new(4);
+        feed(
+            &mut streams,
+            [stream_start(1, true), insert(1, 10), insert(1, 11)],
+        );
+        assert_eq!(streams.staged_bytes, 4);
+        assert!(streams.handle(insert(1, 12)).is_err());
+
+        let mut streams = StreamedTransactions::new(4);
+        let output = feed(
+            &mut streams,
+            [
+                stream_start(1, true),
+                insert(1, 10),
+                insert(2, 20),
+                stream_stop(),
+                stream_abort(1, 2),
+                stream_commit(1, 100),
+                stream_start(3, true),
+                insert(3, 30),
+                insert(3, 31),
+                stream_stop(),
+            ],
+        );
+        assert_eq!(output, replayed(1, 100, &[10]));
+        assert_eq!(streams.staged_bytes, 4);
+    }
+
+    #[mz_ore::test]
+    fn stream_events_are_validated() {
+        let mut streams = StreamedTransactions::new(usize::MAX);
+        assert!(streams.handle(stream_stop()).is_err());
+
+        let mut streams = StreamedTransactions::new(usize::MAX);
+        streams.handle(stream_start(1, true)).unwrap();
+        assert!(streams.handle(message(&[BEGIN])).is_err());
+
+        let mut streams = StreamedTransactions::new(usize::MAX);
+        assert!(streams.handle(message(&[STREAM_COMMIT, 0, 0])).is_err());
+    }
+}
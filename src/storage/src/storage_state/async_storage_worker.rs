@@ -29,7 +29,7 @@ use mz_storage_types::StorageDiff;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::sinks::StorageSinkDesc;
 use mz_storage_types::sources::{
-    GenericSourceConnection, IngestionDescription, KafkaSourceConnection,
+    GenericSourceConnection, IcebergSourceConnection, IngestionDescription, KafkaSourceConnection,
     LoadGeneratorSourceConnection, MySqlSourceConnection, PostgresSourceConnection,
     SourceConnection, SourceData, SourceEnvelope, SourceTimestamp, SqlServerSourceConnection,
 };
@@ -380,6 +380,17 @@ impl<T: Timestamp + TimestampManipulation + Lattice + Codec64 + Display + Sync>
                                 .await;
                                 to_vec_row(uppers)
                             }
+                            GenericSourceConnection::Iceberg(_) => {
+                                let uppers = reclock_resume_uppers::<IcebergSourceConnection, _>(
+                                    &id,
+                                    &persist_clients,
+                                    &ingestion_description,
+                                    as_of.clone(),
+                                    &resume_uppers,
+                                )
+                                .await;
+                                to_vec_row(uppers)
+                            }
                             GenericSourceConnection::LoadGenerator(_) => {
                                 let uppers =
                                     reclock_resume_uppers::<LoadGeneratorSourceConnection, _>(
@@ -10,9 +10,10 @@
 //! Unified configuration for timestamp oracles.
 //!
 //! This module provides a [`TimestampOracleConfig`] enum that can hold
-//! configuration for either a Postgres-backed or FoundationDB-backed
+//! configuration for a Postgres-backed, FoundationDB-backed, or SQLite-backed
 //! timestamp oracle, allowing the choice of backend to be made at startup time.
 
+use std::path::Path;
 use std::sync::Arc;
 
 use mz_ore::metrics::MetricsRegistry;
@@ -27,6 +28,7 @@ use crate::metrics::Metrics;
 use crate::postgres_oracle::{
     PostgresTimestampOracle, PostgresTimestampOracleConfig, TimestampOracleParameters,
 };
+use crate::sqlite_oracle::{SqliteTimestampOracle, SqliteTimestampOracleConfig};
 
 /// Unified configuration for timestamp oracles.
 ///
@@ -39,6 +41,8 @@ pub enum TimestampOracleConfig {
     /// Use a FoundationDB-backed timestamp oracle.
     #[cfg(feature = "foundationdb")]
     Fdb(FdbTimestampOracleConfig),
+    /// Use a timestamp oracle backed by a local SQLite database file.
+    Sqlite(SqliteTimestampOracleConfig),
 }
 
 impl TimestampOracleConfig {
@@ -47,6 +51,7 @@ impl TimestampOracleConfig {
     /// The backend is determined by the URL scheme:
     /// - `postgres://` or `postgresql://` -> Postgres-backed oracle
     /// - `foundationdb://` -> FoundationDB-backed oracle
+    /// - `sqlite:///abs/path` -> SQLite-backed oracle, stored in the file at the URL's path
     ///
     /// Returns an error if the URL scheme is not recognized.
     pub fn from_url(
@@ -62,10 +67,21 @@ impl TimestampOracleConfig {
             "foundationdb" => {
                 anyhow::bail!("FoundationDB timestamp oracle is not supported on this platform")
             }
+            "sqlite" => {
+                // `sqlite://oracle.db` names `oracle.db` as the host and leaves the path
+                // empty, and SQLite opens an empty path as a private temporary database.
+                if url.host_str().is_some_and(|host| !host.is_empty()) || url.path().is_empty() {
+                    anyhow::bail!(
+                        "sqlite timestamp oracle URL must be sqlite:///abs/path: {}",
+                        url
+                    );
+                }
+                Ok(Self::new_sqlite(Path::new(url.path()), metrics_registry))
+            }
             _ => {
                 anyhow::bail!(
                     "unsupported timestamp oracle URL scheme: '{}'. \
-                     Supported schemes: postgres, postgresql, foundationdb",
+                     Supported schemes: postgres, postgresql, foundationdb, sqlite",
                     scheme
                 )
             }
@@ -83,12 +99,18 @@ impl TimestampOracleConfig {
         TimestampOracleConfig::Fdb(FdbTimestampOracleConfig::new(url, metrics_registry))
     }
 
+    /// Create a new SQLite-backed timestamp oracle configuration.
+    pub fn new_sqlite(path: &Path, metrics_registry: &MetricsRegistry) -> Self {
+        TimestampOracleConfig::Sqlite(SqliteTimestampOracleConfig::new(path, metrics_registry))
+    }
+
     /// Returns the metrics for this configuration.
     pub fn metrics(&self) -> Arc<Metrics> {
         match self {
             TimestampOracleConfig::Postgres(config) => Arc::clone(config.metrics()),
             #[cfg(feature = "foundationdb")]
             TimestampOracleConfig::Fdb(config) => Arc::clone(config.metrics()),
+            TimestampOracleConfig::Sqlite(config) => Arc::clone(config.metrics()),
         }
     }
 
@@ -124,6 +146,10 @@ impl TimestampOracleConfig {
                 .expect("failed to open FdbTimestampOracle");
                 Arc::new(fdb_oracle)
             }
+            TimestampOracleConfig::Sqlite(config) => Arc::new(
+                SqliteTimestampOracle::open(config.clone(), timeline, initially, now_fn, read_only)
+                    .await,
+            ),
         }
     }
 
@@ -139,6 +165,9 @@ impl TimestampOracleConfig {
             TimestampOracleConfig::Fdb(config) => {
                 FdbTimestampOracle::<NowFn>::get_all_timelines(config.clone()).await
             }
+            TimestampOracleConfig::Sqlite(config) => {
+                SqliteTimestampOracle::<NowFn>::get_all_timelines(config.clone()).await
+            }
         }
     }
 
@@ -147,9 +176,32 @@ impl TimestampOracleConfig {
     /// This is a no-op for non-Postgres backends.
     pub fn apply_parameters(&self, params: TimestampOracleParameters) {
         // Only the Postgres oracle supports parameters for now.
-        #[allow(irrefutable_let_patterns)]
         if let TimestampOracleConfig::Postgres(pg_config) = self {
             params.apply(pg_config)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn sqlite_url() {
+        let from_url = |url: &str| {
+            TimestampOracleConfig::from_url(
+                &url.parse().expect("valid url"),
+                &MetricsRegistry::new(),
+            )
+        };
+
+        assert!(matches!(
+            from_url("sqlite:///var/lib/mz/oracle.db"),
+            Ok(TimestampOracleConfig::Sqlite(_))
+        ));
+        // A relative path parses as the host, with an empty path.
+        assert!(from_url("sqlite://oracle.db").is_err());
+        assert!(from_url("sqlite://localhost/oracle.db").is_err());
+        assert!(from_url("sqlite:").is_err());
+    }
+}
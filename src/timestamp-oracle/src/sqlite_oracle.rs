@@ -0,0 +1,474 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A timestamp oracle backed by an embedded SQLite database file, for
+//! single-node deployments that don't want to run an external database just
+//! to hand out timestamps.
+//!
+//! Every operation runs in a single `BEGIN IMMEDIATE` transaction, which
+//! takes the database's write lock up front and thereby linearizes all oracle
+//! operations against each other, including those of other processes that
+//! have the same file open.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use mz_ore::instrument;
+use mz_ore::metrics::MetricsRegistry;
+use mz_repr::Timestamp;
+use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params};
+use tracing::{debug, info};
+
+use crate::WriteTimestamp;
+use crate::metrics::Metrics;
+use crate::postgres_oracle::retry_fallible;
+use crate::{GenericNowFn, TimestampOracle};
+
+// SQLite integers are signed 64-bit, which can't hold all `u64` timestamps, so
+// the timestamps are stored as their decimal string representation and all
+// comparisons happen in Rust, inside the transaction.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS timestamp_oracle (
+    timeline TEXT NOT NULL,
+    read_ts TEXT NOT NULL,
+    write_ts TEXT NOT NULL,
+    PRIMARY KEY(timeline)
+) WITHOUT ROWID
+";
+
+/// How long a connection waits on a lock held by another connection before
+/// giving up with `SQLITE_BUSY`. The operation is then retried.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Configuration for a SQLite-backed implementation of [`TimestampOracle`].
+#[derive(Clone, Debug)]
+pub struct SqliteTimestampOracleConfig {
+    path: PathBuf,
+    metrics: Arc<Metrics>,
+}
+
+impl SqliteTimestampOracleConfig {
+    /// Returns a new instance of [`SqliteTimestampOracleConfig`] that stores
+    /// timestamps in the database file at `path`.
+    pub fn new(path: &Path, metrics_registry: &MetricsRegistry) -> Self {
+        SqliteTimestampOracleConfig {
+            path: path.to_path_buf(),
+            metrics: Arc::new(Metrics::new(metrics_registry)),
+        }
+    }
+
+    /// Returns the metrics associated with this config.
+    pub(crate) fn metrics(&self) -> &Arc<Metrics> {
+        &self.metrics
+    }
+
+    fn connect(&self) -> Result<Connection, anyhow::Error> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(&self.path)?;
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+        // FULL synchronous mode fsyncs on every commit, so a timestamp that
+        // we've handed out is never forgotten after a crash.
+        conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA synchronous = FULL;")?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(conn)
+    }
+}
+
+/// A [`TimestampOracle`] backed by SQLite.
+#[derive(Debug)]
+pub struct SqliteTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp>,
+{
+    timeline: String,
+    next: N,
+    conn: Arc<Mutex<Connection>>,
+    metrics: Arc<Metrics>,
+    /// A read-only timestamp oracle is NOT allowed to do operations that change
+    /// the backing SQLite state.
+    read_only: bool,
+}
+
+/// The timestamps stored for a timeline.
+#[derive(Debug)]
+struct TimelineRow {
+    read_ts: Timestamp,
+    write_ts: Timestamp,
+}
+
+fn ts_to_sql(ts: Timestamp) -> String {
+    u64::from(ts).to_string()
+}
+
+fn ts_from_sql(ts: String) -> Result<Timestamp, anyhow::Error> {
+    let ts: u64 = ts
+        .parse()
+        .map_err(|e| anyhow::anyhow!("invalid timestamp {:?}: {}", ts, e))?;
+    Ok(Timestamp::from(ts))
+}
+
+fn get_row(conn: &Connection, timeline: &str) -> Result<TimelineRow, anyhow::Error> {
+    let q = "SELECT read_ts, write_ts FROM timestamp_oracle WHERE timeline = ?1";
+    let (read_ts, write_ts) = conn
+        .prepare_cached(q)?
+        .query_row(params![timeline], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("missing timestamp_oracle row for {}", timeline))?;
+    Ok(TimelineRow {
+        read_ts: ts_from_sql(read_ts)?,
+        write_ts: ts_from_sql(write_ts)?,
+    })
+}
+
+fn put_row(conn: &Connection, timeline: &str, row: &TimelineRow) -> Result<(), anyhow::Error> {
+    let q = "UPDATE timestamp_oracle SET read_ts = ?2, write_ts = ?3 WHERE timeline = ?1";
+    conn.prepare_cached(q)?.execute(params![
+        timeline,
+        ts_to_sql(row.read_ts),
+        ts_to_sql(row.write_ts)
+    ])?;
+    Ok(())
+}
+
+impl<N> SqliteTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    /// Open a SQLite [`TimestampOracle`] instance with `config`, for the
+    /// timeline named `timeline`. `next` generates new timestamps when invoked.
+    /// Timestamps that are returned are made durable and will never retract.
+    pub async fn open(
+        config: SqliteTimestampOracleConfig,
+        timeline: String,
+        initially: Timestamp,
+        next: N,
+        read_only: bool,
+    ) -> Self {
+        info!(config = ?config, "opening SqliteTimestampOracle");
+
+        let fallible = || async {
+            let config = config.clone();
+            let timeline = timeline.clone();
+            let conn = mz_ore::task::spawn_blocking(
+                || "tsoracle::sqlite::open",
+                move || -> Result<Connection, anyhow::Error> {
+                    let conn = config.connect()?;
+                    // Create a row for our timeline, if it doesn't exist, so
+                    // that all other operations can assume it's present.
+                    let initially = ts_to_sql(initially);
+                    conn.execute(
+                        "INSERT INTO timestamp_oracle (timeline, read_ts, write_ts)
+                            VALUES (?1, ?2, ?3)
+                            ON CONFLICT (timeline) DO NOTHING",
+                        params![timeline, initially, initially],
+                    )?;
+                    Ok(conn)
+                },
+            )
+            .await?;
+            Result::<_, anyhow::Error>::Ok(conn)
+        };
+
+        let conn = retry_fallible(&config.metrics.retries.open, fallible).await;
+
+        let oracle = SqliteTimestampOracle {
+            timeline,
+            next,
+            conn: Arc::new(Mutex::new(conn)),
+            metrics: Arc::clone(&config.metrics),
+            read_only,
+        };
+
+        // Forward timestamps to what we're given from outside. Remember, the
+        // above insert only creates the row at the initial timestamp if it
+        // didn't exist before.
+        if !read_only {
+            TimestampOracle::apply_write(&oracle, initially).await;
+        }
+
+        oracle
+    }
+
+    /// Returns a `Vec` of all known timelines along with their current greatest
+    /// timestamp (max of read_ts and write_ts).
+    pub async fn get_all_timelines(
+        config: SqliteTimestampOracleConfig,
+    ) -> Result<Vec<(String, Timestamp)>, anyhow::Error> {
+        let fallible = || async {
+            let config = config.clone();
+            mz_ore::task::spawn_blocking(
+                || "tsoracle::sqlite::get_all_timelines",
+                move || {
+                    let conn = config.connect()?;
+                    let mut statement =
+                        conn.prepare("SELECT timeline, read_ts, write_ts FROM timestamp_oracle")?;
+                    let rows = statement.query_map([], |row| {
+                        Ok((
+                            row.get::<_, String>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, String>(2)?,
+                        ))
+                    })?;
+                    let mut result = Vec::new();
+                    for row in rows {
+                        let (timeline, read_ts, write_ts) = row?;
+                        let ts = std::cmp::max(ts_from_sql(read_ts)?, ts_from_sql(write_ts)?);
+                        result.push((timeline, ts));
+                    }
+                    Result::<_, anyhow::Error>::Ok(result)
+                },
+            )
+            .await
+        };
+
+        let metrics = &config.metrics.retries.get_all_timelines;
+
+        let result = retry_fallible(metrics, fallible).await;
+
+        Ok(result)
+    }
+
+    /// Runs `f` against our timeline's row inside a write transaction,
+    /// persisting the row if `f` returns `true` for "modified".
+    async fn update<R, F>(&self, name: &'static str, f: F) -> Result<R, anyhow::Error>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut TimelineRow) -> (R, bool) + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        let timeline = self.timeline.clone();
+        mz_ore::task::spawn_blocking(
+            || name,
+            move || {
+                let mut conn = conn.lock().expect("lock poisoned");
+                let txn = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+                let mut row = get_row(&txn, &timeline)?;
+                let (ret, modified) = f(&mut row);
+                if modified {
+                    put_row(&txn, &timeline, &row)?;
+                }
+                txn.commit()?;
+                Ok(ret)
+            },
+        )
+        .await
+    }
+
+    #[mz_ore::instrument(name = "oracle::write_ts")]
+    async fn fallible_write_ts(&self) -> Result<WriteTimestamp<Timestamp>, anyhow::Error> {
+        if self.read_only {
+            panic!("attempting write_ts in read-only mode");
+        }
+
+        let proposed_next_ts = self.next.now();
+
+        let write_ts = self
+            .update("tsoracle::sqlite::write_ts", move |row| {
+                row.write_ts = std::cmp::max(row.write_ts.step_forward(), proposed_next_ts);
+                (row.write_ts, true)
+            })
+            .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            proposed_next_ts = ?proposed_next_ts,
+            "returning from write_ts()");
+
+        let advance_to = write_ts.step_forward();
+
+        Ok(WriteTimestamp {
+            timestamp: write_ts,
+            advance_to,
+        })
+    }
+
+    #[mz_ore::instrument(name = "oracle::peek_write_ts")]
+    async fn fallible_peek_write_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let write_ts = self
+            .update("tsoracle::sqlite::peek_write_ts", |row| {
+                (row.write_ts, false)
+            })
+            .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            "returning from peek_write_ts()");
+
+        Ok(write_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::read_ts")]
+    async fn fallible_read_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let read_ts = self
+            .update("tsoracle::sqlite::read_ts", |row| (row.read_ts, false))
+            .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            read_ts = ?read_ts,
+            "returning from read_ts()");
+
+        Ok(read_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::apply_write")]
+    async fn fallible_apply_write(&self, write_ts: Timestamp) -> Result<(), anyhow::Error> {
+        if self.read_only {
+            panic!("attempting apply_write in read-only mode");
+        }
+
+        self.update("tsoracle::sqlite::apply_write", move |row| {
+            let modified = write_ts > row.write_ts || write_ts > row.read_ts;
+            row.write_ts = std::cmp::max(row.write_ts, write_ts);
+            row.read_ts = std::cmp::max(row.read_ts, write_ts);
+            ((), modified)
+        })
+        .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            "returning from apply_write()");
+
+        Ok(())
+    }
+}
+
+// A wrapper around the `fallible_` methods that adds operation metrics and
+// retries.
+#[async_trait]
+impl<N> TimestampOracle<Timestamp> for SqliteTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    #[instrument]
+    async fn write_ts(&self) -> WriteTimestamp<Timestamp> {
+        let metrics = &self.metrics.retries.write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .write_ts
+                .run_op(|| self.fallible_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn peek_write_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.peek_write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .peek_write_ts
+                .run_op(|| self.fallible_peek_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn read_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.read_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .read_ts
+                .run_op(|| self.fallible_read_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn apply_write(&self, write_ts: Timestamp) {
+        let metrics = &self.metrics.retries.apply_write;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .apply_write
+                .run_op(|| self.fallible_apply_write(write_ts))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::now::NowFn;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `sqlite3_open_v2` on OS `linux`
+    async fn test_sqlite_timestamp_oracle() -> Result<(), anyhow::Error> {
+        let temp_dir = tempfile::tempdir()?;
+        let config = SqliteTimestampOracleConfig::new(
+            &temp_dir.path().join("tsoracle.db"),
+            &MetricsRegistry::new(),
+        );
+
+        crate::tests::timestamp_oracle_impl_test(|timeline, now_fn, initial_ts| {
+            let oracle = SqliteTimestampOracle::open(
+                config.clone(),
+                timeline,
+                initial_ts,
+                now_fn,
+                false, /* read-only */
+            );
+
+            async {
+                let arced_oracle: Arc<dyn TimestampOracle<Timestamp> + Send + Sync> =
+                    Arc::new(oracle.await);
+
+                arced_oracle
+            }
+        })
+        .await?;
+
+        // Timestamps survive reopening the oracle.
+        let timeline = "reopen".to_string();
+        let oracle = SqliteTimestampOracle::open(
+            config.clone(),
+            timeline.clone(),
+            Timestamp::MIN,
+            NowFn::from(|| 0u64),
+            false, /* read-only */
+        )
+        .await;
+        oracle.apply_write(5u64.into()).await;
+        assert_eq!(oracle.write_ts().await.timestamp, Timestamp::from(6u64));
+        drop(oracle);
+
+        let oracle = SqliteTimestampOracle::open(
+            config.clone(),
+            timeline.clone(),
+            Timestamp::MIN,
+            NowFn::from(|| 0u64),
+            true, /* read-only */
+        )
+        .await;
+        assert_eq!(oracle.read_ts().await, Timestamp::from(5u64));
+        assert_eq!(oracle.peek_write_ts().await, Timestamp::from(6u64));
+
+        let timelines = SqliteTimestampOracle::<NowFn>::get_all_timelines(config).await?;
+        assert!(timelines.contains(&(timeline, Timestamp::from(6u64))));
+
+        Ok(())
+    }
+}
@@ -81,7 +81,10 @@ impl ReduceElision {
                 if input_keys.iter().any(|keys| {
                     keys.iter()
                         .all(|k| group_key.iter().any(|gk| gk.as_column() == Some(*k)))
-                }) {
+                }) && aggregates
+                    .iter()
+                    .all(|a| a.can_compute_on_unique(input_type))
+                {
                     let map_scalars = aggregates
                         .iter()
                         .map(|a| a.on_unique(input_type))
@@ -0,0 +1,114 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+A mock HTTP endpoint for HTTP sinks (see mzcompose.py).
+
+Every request to `POST /hook` is recorded along with its idempotency key, the
+headers the sink was configured with, and the status it was answered with.
+Requests are answered with `200 OK`, unless statuses were queued with
+`POST /respond`, in which case each request is answered with the next queued
+status instead.
+
+The test drives the mock with:
+
+* `GET /requests`: the recorded requests, in the order they were received.
+* `DELETE /requests`: forget all recorded requests and queued statuses.
+* `POST /respond`: queue statuses, e.g. `{"statuses": [503, 429]}`. A status of
+  `null` is answered as `503` forever, until the statuses are reset.
+"""
+
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import Any
+
+PORT = 8080
+
+# The headers the test configures on its sinks, and expects to be forwarded.
+RECORDED_HEADERS = ["Authorization", "Content-Type", "X-Source"]
+
+_lock = threading.Lock()
+_requests: list[dict[str, Any]] = []
+_statuses: list[int | None] = []
+
+
+def next_status() -> int:
+    with _lock:
+        if not _statuses:
+            return 200
+        if _statuses[0] is None:
+            return 503
+        return _statuses.pop(0)
+
+
+class Handler(BaseHTTPRequestHandler):
+    protocol_version = "HTTP/1.1"
+
+    def log_message(self, format: str, *args: Any) -> None:
+        print(format % args, flush=True)
+
+    def read_body(self) -> bytes:
+        length = int(self.headers.get("Content-Length", 0))
+        return self.rfile.read(length)
+
+    def reply(self, status: int, body: Any = None) -> None:
+        payload = json.dumps(body).encode() if body is not None else b""
+        self.send_response(status)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(payload)))
+        self.end_headers()
+        self.wfile.write(payload)
+
+    def do_GET(self) -> None:
+        if self.path == "/health":
+            self.reply(200)
+        elif self.path == "/requests":
+            with _lock:
+                self.reply(200, _requests)
+        else:
+            self.reply(404)
+
+    def do_DELETE(self) -> None:
+        if self.path == "/requests":
+            with _lock:
+                _requests.clear()
+                _statuses.clear()
+            self.reply(200)
+        else:
+            self.reply(404)
+
+    def do_POST(self) -> None:
+        body = self.read_body()
+        if self.path == "/respond":
+            with _lock:
+                _statuses.extend(json.loads(body)["statuses"])
+            self.reply(200)
+        elif self.path == "/hook":
+            status = next_status()
+            with _lock:
+                _requests.append(
+                    {
+                        "idempotency_key": self.headers.get("Idempotency-Key"),
+                        "headers": {
+                            name: self.headers.get(name)
+                            for name in RECORDED_HEADERS
+                            if name in self.headers
+                        },
+                        "body": json.loads(body),
+                        "status": status,
+                    }
+                )
+            self.reply(status)
+        else:
+            self.reply(404)
+
+
+if __name__ == "__main__":
+    ThreadingHTTPServer(("0.0.0.0", PORT), Handler).serve_forever()
@@ -0,0 +1,350 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+End-to-end tests of HTTP sinks against a mock endpoint (mock_http.py).
+
+The mock records every request it receives, so the tests can assert the exact
+bodies and idempotency keys the sink sends, and can make the endpoint fail to
+exercise the sink's retries.
+"""
+
+import json
+import time
+import urllib.request
+from collections.abc import Callable
+from typing import Any, TypeVar
+
+from materialize.mzcompose.composition import Composition
+from materialize.mzcompose.service import Service as DockerService
+from materialize.mzcompose.services.materialized import Materialized
+
+T = TypeVar("T")
+
+MOCK_HOST = "http-sink-mock"
+MOCK_PORT = 8080
+HOOK_URL = f"http://{MOCK_HOST}:{MOCK_PORT}/hook"
+
+SERVICES = [
+    DockerService(
+        name=MOCK_HOST,
+        config={
+            "image": "python:3.12-slim",
+            "volumes": ["./mock_http.py:/app/mock_http.py"],
+            "command": ["python3", "-u", "/app/mock_http.py"],
+            "ports": [MOCK_PORT],
+            "healthcheck": {
+                "test": [
+                    "CMD",
+                    "python3",
+                    "-c",
+                    "import urllib.request; urllib.request.urlopen('http://localhost:8080/health')",
+                ],
+                "interval": "1s",
+                "start_period": "30s",
+            },
+        },
+    ),
+    Materialized(
+        # The tests restart environmentd themselves.
+        sanity_restart=False,
+        additional_system_parameter_defaults={
+            "enable_http_sink": "true",
+            # The mock endpoint has a private address.
+            "storage_enforce_external_addresses": "false",
+            "storage_suspend_and_restart_delay": "1s",
+            "http_sink_retry_max_duration": "60s",
+            "log_filter": "mz_storage::sink::http=debug,info",
+        },
+    ),
+]
+
+
+def workflow_default(c: Composition) -> None:
+    def process(name: str) -> None:
+        if name == "default":
+            return
+
+        with c.test_case(name):
+            c.workflow(name)
+
+    c.test_parts(list(c.workflows.keys()), process)
+
+
+def _setup(c: Composition) -> None:
+    """Start fresh, with a table whose initial snapshot spans several requests."""
+    c.down(destroy_volumes=True)
+    c.up(MOCK_HOST, "materialized")
+    _mock(c, "DELETE", "/requests")
+
+    _sql(
+        c,
+        """
+        CREATE TABLE http_src (id int, name text);
+        INSERT INTO http_src VALUES (3, 'c'), (1, 'a'), (5, 'e'), (2, 'b'), (4, 'd');
+        CREATE SECRET http_token AS 'Bearer abc';
+        """,
+    )
+
+
+def _create_sink(c: Composition) -> str:
+    """Create the sink under test and return its ID."""
+    _sql(
+        c,
+        f"""
+        CREATE SINK http_sink
+        FROM http_src
+        INTO HTTP (
+            URL '{HOOK_URL}',
+            HEADERS MAP['Authorization' => SECRET http_token, 'X-Source' => 'materialize'],
+            BATCH SIZE 2
+        )
+        KEY (id) NOT ENFORCED
+        ENVELOPE UPSERT
+        """,
+    )
+    return c.sql_query(
+        "SELECT id FROM mz_sinks WHERE name = 'http_sink'", reuse_connection=False
+    )[0][0]
+
+
+def _sql(c: Composition, sql: str) -> None:
+    c.sql(sql, reuse_connection=False)
+
+
+def _mock(c: Composition, method: str, path: str, body: Any = None) -> Any:
+    request = urllib.request.Request(
+        f"http://localhost:{c.port(MOCK_HOST, MOCK_PORT)}{path}",
+        data=json.dumps(body).encode() if body is not None else None,
+        method=method,
+    )
+    with urllib.request.urlopen(request) as response:
+        payload = response.read()
+    return json.loads(payload) if payload else None
+
+
+def _requests(c: Composition) -> list[dict[str, Any]]:
+    """The requests the mock received, in the order it received them."""
+    return _mock(c, "GET", "/requests")
+
+
+def _respond_with(c: Composition, *statuses: int | None) -> None:
+    """Answer the next requests with `statuses`, see mock_http.py."""
+    _mock(c, "POST", "/respond", {"statuses": list(statuses)})
+
+
+def _wait_for(
+    description: str, check: Callable[[], T | None], timeout: float = 60
+) -> T:
+    deadline = time.monotonic() + timeout
+    while True:
+        result = check()
+        if result is not None:
+            return result
+        if time.monotonic() > deadline:
+            raise AssertionError(f"timed out waiting for {description}")
+        time.sleep(0.5)
+
+
+def _wait_for_requests(
+    c: Composition, description: str, predicate: Callable[[list[dict[str, Any]]], bool]
+) -> list[dict[str, Any]]:
+    def check() -> list[dict[str, Any]] | None:
+        requests = _requests(c)
+        return requests if predicate(requests) else None
+
+    return _wait_for(description, check)
+
+
+def _sink_status(c: Composition) -> tuple[str, str | None]:
+    return c.sql_query(
+        """
+        SELECT status, error
+        FROM mz_internal.mz_sink_statuses
+        WHERE name = 'http_sink'
+        """,
+        reuse_connection=False,
+    )[0]
+
+
+def _update(id: int, name: str | None) -> dict[str, Any]:
+    value = {"id": id, "name": name} if name is not None else None
+    return {"key": {"id": id}, "value": value}
+
+
+def _snapshot_requests(sink_id: str, requests: list[dict[str, Any]]) -> list[str]:
+    """Asserts that `requests` deliver the initial snapshot of `http_src`, and
+    returns their idempotency keys."""
+    assert len(requests) == 3, requests
+    timestamp = requests[0]["body"]["timestamp"]
+    # The updates of a timestamp are sorted, and split into chunks of at most
+    # `BATCH SIZE` updates.
+    assert [r["body"] for r in requests] == [
+        {"timestamp": timestamp, "updates": [_update(1, "a"), _update(2, "b")]},
+        {"timestamp": timestamp, "updates": [_update(3, "c"), _update(4, "d")]},
+        {"timestamp": timestamp, "updates": [_update(5, "e")]},
+    ], requests
+    keys = [r["idempotency_key"] for r in requests]
+    assert keys == [f"{sink_id}-{timestamp}-{chunk}" for chunk in range(3)], keys
+    return keys
+
+
+def workflow_delivery(c: Composition) -> None:
+    """Updates are sent in batches, with the configured headers and
+    deterministic idempotency keys."""
+    _setup(c)
+    sink_id = _create_sink(c)
+
+    requests = _wait_for_requests(c, "the snapshot", lambda r: len(r) >= 3)
+    _snapshot_requests(sink_id, requests)
+    for request in requests:
+        assert request["status"] == 200, request
+        assert request["headers"] == {
+            "Authorization": "Bearer abc",
+            "Content-Type": "application/json",
+            "X-Source": "materialize",
+        }, request
+
+    # Updates and deletions at later timestamps are sent as new requests.
+    _sql(c, "UPDATE http_src SET name = 'z' WHERE id = 1")
+    _sql(c, "DELETE FROM http_src WHERE id = 2")
+    requests = _wait_for_requests(c, "the updates", lambda r: len(r) >= 5)
+    assert len(requests) == 5, requests
+    update, delete = requests[3], requests[4]
+    assert update["body"]["updates"] == [_update(1, "z")], update
+    assert delete["body"]["updates"] == [_update(2, None)], delete
+    assert int(update["body"]["timestamp"]) < int(delete["body"]["timestamp"])
+    for request in (update, delete):
+        assert (
+            request["idempotency_key"]
+            == f"{sink_id}-{request['body']['timestamp']}-0"
+        ), request
+
+    assert _sink_status(c) == ("running", None)
+    messages_committed = _wait_for(
+        "the sink statistics",
+        lambda: c.sql_query(
+            """
+            SELECT messages_committed
+            FROM mz_internal.mz_sink_statistics
+            JOIN mz_sinks ON mz_sinks.id = mz_sink_statistics.id
+            WHERE name = 'http_sink' AND messages_committed >= 7
+            """,
+            reuse_connection=False,
+        ),
+    )
+    assert messages_committed == [(7,)], messages_committed
+
+
+def workflow_retries(c: Composition) -> None:
+    """Requests that fail with a 5xx, 408, or 429 status are retried with the
+    same idempotency key and body."""
+    _setup(c)
+    _respond_with(c, 503, 408, 429, 500)
+    sink_id = _create_sink(c)
+
+    requests = _wait_for_requests(c, "the retried snapshot", lambda r: len(r) >= 7)
+    assert [r["status"] for r in requests] == [503, 408, 429, 500, 200, 200, 200]
+    # All failed attempts were for the first request of the snapshot.
+    assert all(r == requests[0] | {"status": r["status"]} for r in requests[:5])
+    _snapshot_requests(sink_id, requests[4:])
+    assert _sink_status(c) == ("running", None)
+
+
+def workflow_fatal_error(c: Composition) -> None:
+    """Requests that fail with any other 4xx status are not retried. The sink
+    reports an error and restarts, and sends the request again once restarted."""
+    _setup(c)
+    sink_id = _create_sink(c)
+
+    requests = _wait_for_requests(c, "the snapshot", lambda r: len(r) >= 3)
+    snapshot_keys = _snapshot_requests(sink_id, requests)
+
+    _respond_with(c, 400)
+    _sql(c, "INSERT INTO http_src VALUES (6, 'f')")
+
+    def error() -> str | None:
+        errors = c.sql_query(
+            """
+            SELECT error
+            FROM mz_internal.mz_sink_status_history
+            JOIN mz_sinks ON mz_sink_status_history.sink_id = mz_sinks.id
+            WHERE name = 'http_sink' AND status = 'stalled'
+            """,
+            reuse_connection=False,
+        )
+        return errors[0][0] if errors else None
+
+    error = _wait_for("the sink to stall", error)
+    assert "HTTP sink endpoint responded with 400 Bad Request" in error, error
+
+    # The restarted sink sends the same request again, without re-sending the
+    # snapshot.
+    requests = _wait_for_requests(c, "the update", lambda r: len(r) >= 5)
+    assert len(requests) == 5, requests
+    rejected, resent = requests[3], requests[4]
+    assert rejected["status"] == 400, rejected
+    assert rejected["body"]["updates"] == [_update(6, "f")], rejected
+    assert resent == rejected | {"status": 200}, resent
+    assert resent["idempotency_key"] not in snapshot_keys, resent
+    _wait_for("the sink to recover", lambda: _sink_status(c)[0] == "running" or None)
+
+
+def workflow_restart(c: Composition) -> None:
+    """After environmentd restarts, the sink resumes from the progress recorded
+    in its progress shard: acknowledged updates are not sent again, and an
+    unacknowledged request is re-sent with the same idempotency key and body."""
+    _setup(c)
+    sink_id = _create_sink(c)
+
+    requests = _wait_for_requests(c, "the snapshot", lambda r: len(r) >= 3)
+    snapshot_keys = _snapshot_requests(sink_id, requests)
+
+    # Fail every request, so the update is never acknowledged before the
+    # restart.
+    _respond_with(c, None)
+    _sql(c, "INSERT INTO http_src VALUES (6, 'f')")
+    requests = _wait_for_requests(c, "the failing update", lambda r: len(r) >= 4)
+    unacknowledged = requests[3]
+    assert unacknowledged["status"] == 503, unacknowledged
+    assert unacknowledged["body"]["updates"] == [_update(6, "f")], unacknowledged
+
+    c.kill("materialized")
+    _mock(c, "DELETE", "/requests")
+    c.up("materialized")
+
+    requests = _wait_for_requests(
+        c, "the update to be re-sent", lambda r: any(x["status"] == 200 for x in r)
+    )
+    for request in requests:
+        assert request["idempotency_key"] not in snapshot_keys, request
+        assert request["idempotency_key"] == unacknowledged["idempotency_key"]
+        assert request["body"] == unacknowledged["body"], request
+
+    # Once acknowledged, the update is not sent again.
+    _sql(c, "INSERT INTO http_src VALUES (7, 'g')")
+
+    def sent_next(requests: list[dict[str, Any]]) -> bool:
+        return any(r["body"]["updates"] == [_update(7, "g")] for r in requests)
+
+    requests = _wait_for_requests(c, "the next update", sent_next)
+    resent = [r for r in requests if r["body"] == unacknowledged["body"]]
+    assert resent[-1]["status"] == 200, resent
+    assert requests[len(resent) :] == [
+        {
+            "idempotency_key": requests[-1]["idempotency_key"],
+            "headers": unacknowledged["headers"],
+            "body": {
+                "timestamp": requests[-1]["body"]["timestamp"],
+                "updates": [_update(7, "g")],
+            },
+            "status": 200,
+        }
+    ], requests
+    assert _sink_status(c) == ("running", None)
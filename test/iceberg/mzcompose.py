@@ -105,6 +105,40 @@ def workflow_mode_append(c: Composition) -> None:
     )
 
 
+def workflow_mode_upsert(c: Composition) -> None:
+    key = _setup(c)
+
+    c.run_testdrive_files(
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "mode-upsert.td",
+    )
+
+
+def workflow_partitioning(c: Composition) -> None:
+    key = _setup(c)
+
+    c.run_testdrive_files(
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "partitioning.td",
+    )
+
+
+def workflow_source(c: Composition) -> None:
+    """An Iceberg source reads the initial snapshot of a table through the REST
+    catalog, and then ingests every snapshot committed to it, including the
+    position and equality deletes of a MODE UPSERT sink."""
+    key = _setup(c)
+
+    c.run_testdrive_files(
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "source.td",
+        "source-upsert.td",
+    )
+
+
 def workflow_idle_gap(c: Composition) -> None:
     """A caught-up Iceberg sink whose input goes quiet and then receives a
     write must stay healthy. Differential's arrange leaves a gap in the emitted
@@ -0,0 +1,176 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+Tests the LDAP password authenticator against a local OpenLDAP server, in both
+simple-bind and search+bind modes, over pgwire and HTTP, including the
+group-to-role sync that LDAP shares with OIDC.
+"""
+
+import psycopg
+import requests
+
+from materialize import MZ_ROOT
+from materialize.mzcompose.composition import Composition
+from materialize.mzcompose.service import Service as DockerService
+from materialize.mzcompose.services.materialized import Materialized
+
+LDAP_HOST = "openldap"
+LDAP_ADMIN_DN = "cn=admin,dc=example,dc=com"
+LDAP_ADMIN_PASSWORD = "admin"
+PEOPLE_DN = "ou=people,dc=example,dc=com"
+
+SERVICES = [
+    DockerService(
+        name=LDAP_HOST,
+        config={
+            "image": "osixia/openldap:1.5.0",
+            # Load the custom bootstrap LDIF rather than mutating the image.
+            "command": ["--copy-service"],
+            "environment": [
+                "LDAP_ORGANISATION=Example",
+                "LDAP_DOMAIN=example.com",
+                f"LDAP_ADMIN_PASSWORD={LDAP_ADMIN_PASSWORD}",
+            ],
+            "volumes": [
+                "./bootstrap.ldif:/container/service/slapd/assets/config/bootstrap/ldif/custom/50-bootstrap.ldif",
+            ],
+            "ports": [389],
+            "healthcheck": {
+                "test": [
+                    "CMD",
+                    "ldapsearch",
+                    "-x",
+                    "-H",
+                    "ldap://localhost",
+                    "-D",
+                    LDAP_ADMIN_DN,
+                    "-w",
+                    LDAP_ADMIN_PASSWORD,
+                    "-b",
+                    PEOPLE_DN,
+                    "uid=alice",
+                ],
+                "interval": "1s",
+                "start_period": "30s",
+            },
+        },
+    ),
+    Materialized(
+        listeners_config_path=f"{MZ_ROOT}/test/ldap-auth/listener_config.json",
+        environment_extra=[
+            f"MZ_LDAP_SEARCH_BIND_PASSWORD={LDAP_ADMIN_PASSWORD}",
+        ],
+        additional_system_parameter_defaults={
+            "ldap_url": f"ldap://{LDAP_HOST}:389",
+        },
+    ),
+]
+
+
+def workflow_default(c: Composition) -> None:
+    c.up(LDAP_HOST, "materialized")
+
+    def system_sql(sql: str) -> None:
+        c.sql(sql, user="mz_system", port=6877)
+
+    def current_user(user: str, password: str) -> str:
+        return c.sql_query(
+            "SELECT current_user",
+            user=user,
+            password=password,
+            reuse_connection=False,
+        )[0][0]
+
+    def assert_login_fails(user: str, password: str, message: str) -> None:
+        try:
+            current_user(user, password)
+        except psycopg.OperationalError as e:
+            assert message in str(e), f"expected {message!r}, got: {e}"
+        else:
+            raise AssertionError(f"login as {user} unexpectedly succeeded")
+
+    def is_member(member: str, group: str) -> bool:
+        return c.sql_query(
+            f"SELECT pg_has_role('{member}', '{group}', 'MEMBER')",
+            user="mz_system",
+            port=6877,
+        )[0][0]
+
+    system_sql("CREATE ROLE alice LOGIN")
+    system_sql("CREATE ROLE bob LOGIN")
+    system_sql("CREATE ROLE analytics")
+
+    with c.test_case("missing_bind_mode"):
+        assert_login_fails("alice", "alice-password", "invalid LDAP")
+
+    system_sql(
+        f"ALTER SYSTEM SET ldap_bind_dn_template = 'uid={{username}},{PEOPLE_DN}'"
+    )
+
+    with c.test_case("simple_bind"):
+        assert current_user("alice", "alice-password") == "alice"
+        assert current_user("bob", "bob-password") == "bob"
+
+    with c.test_case("simple_bind_rejects_bad_credentials"):
+        assert_login_fails("alice", "bob-password", "invalid password")
+        assert_login_fails("mallory", "alice-password", "invalid password")
+
+    with c.test_case("ldap_user_without_role"):
+        # carol exists in the directory but not in Materialize, and LDAP does
+        # not auto-provision roles.
+        assert_login_fails("carol", "carol-password", "role does not exist")
+
+    with c.test_case("http_basic_auth"):
+        base = f"http://localhost:{c.port('materialized', 6876)}/api/sql"
+        r = requests.post(
+            base,
+            auth=("alice", "alice-password"),
+            json={"query": "SELECT current_user"},
+        )
+        assert r.status_code == 200, f"expected 200, got {r.status_code}: {r.text}"
+        assert r.json()["results"][0]["rows"][0][0] == "alice"
+
+        r = requests.post(
+            base,
+            auth=("alice", "wrong"),
+            json={"query": "SELECT current_user"},
+        )
+        assert r.status_code == 401, f"expected 401, got {r.status_code}: {r.text}"
+
+    with c.test_case("group_sync"):
+        system_sql("ALTER SYSTEM SET oidc_group_role_sync_enabled = true")
+        current_user("alice", "alice-password")
+        current_user("bob", "bob-password")
+        assert is_member("alice", "analytics")
+        assert not is_member("bob", "analytics")
+
+        # Disabling the group attribute turns sync off without revoking
+        # existing grants.
+        system_sql("ALTER SYSTEM SET ldap_group_attribute = ''")
+        current_user("alice", "alice-password")
+        assert is_member("alice", "analytics")
+        system_sql("ALTER SYSTEM RESET ldap_group_attribute")
+        system_sql("ALTER SYSTEM RESET oidc_group_role_sync_enabled")
+
+    with c.test_case("conflicting_bind_modes"):
+        system_sql(f"ALTER SYSTEM SET ldap_search_base_dn = '{PEOPLE_DN}'")
+        assert_login_fails("alice", "alice-password", "invalid LDAP")
+        system_sql("ALTER SYSTEM RESET ldap_bind_dn_template")
+
+    with c.test_case("search_bind"):
+        system_sql(f"ALTER SYSTEM SET ldap_search_bind_dn = '{LDAP_ADMIN_DN}'")
+        assert current_user("alice", "alice-password") == "alice"
+        assert_login_fails("alice", "bob-password", "invalid password")
+        assert_login_fails("mallory", "alice-password", "invalid password")
+
+    with c.test_case("search_bind_rejects_filter_injection"):
+        # Without escaping, `*` would match every entry under the base DN.
+        assert_login_fails("*", "alice-password", "invalid password")
+        assert_login_fails("alice)(uid=*", "alice-password", "invalid password")
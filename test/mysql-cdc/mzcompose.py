@@ -26,7 +26,7 @@ from materialize.mzcompose.composition import (
     WorkflowArgumentParser,
 )
 from materialize.mzcompose.services.materialized import Materialized
-from materialize.mzcompose.services.mysql import MySql
+from materialize.mzcompose.services.mysql import MariaDb, MySql
 from materialize.mzcompose.services.mz import Mz
 from materialize.mzcompose.services.test_certs import TestCerts
 from materialize.mzcompose.services.testdrive import Testdrive
@@ -176,6 +176,21 @@ def workflow_replica_connection(c: Composition, parser: WorkflowArgumentParser)
         )
 
 
+def workflow_mariadb(c: Composition, parser: WorkflowArgumentParser) -> None:
+    """
+    Validates that MySQL sources work against a MariaDB server, which uses its own
+    GTID format.
+    """
+
+    with c.override(MariaDb()):
+        c.up("materialized", "mysql")
+        c.run_testdrive_files(
+            f"--var=mysql-root-password={MySql.DEFAULT_ROOT_PASSWORD}",
+            f"--var=default-replica-size=scale={Materialized.Size.DEFAULT_SIZE},workers={Materialized.Size.DEFAULT_SIZE}",
+            "mariadb/10-mariadb.td",
+        )
+
+
 def workflow_schema_change_restart(
     c: Composition, parser: WorkflowArgumentParser
 ) -> None:
@@ -631,6 +631,113 @@ def workflow_large_scale(c: Composition, parser: WorkflowArgumentParser) -> None
     )
 
 
+def workflow_postgres_sink_restart(
+    c: Composition, parser: WorkflowArgumentParser
+) -> None:
+    """
+    Test that a Postgres sink that is interrupted mid-stream resumes from the
+    frontier in its `_mz_sink_progress` table: after environmentd restarts,
+    every row reaches the target table exactly once.
+    """
+
+    pg_version = get_targeted_pg_version(parser)
+    with c.override(create_postgres(pg_version=pg_version)):
+        c.up("materialized", "postgres")
+
+        pg_conn = psycopg.connect(
+            host="localhost",
+            user="postgres",
+            password="postgres",
+            port=c.default_port("postgres"),
+            autocommit=True,
+        )
+        # Every insert or update of a target row is recorded in `applied`, so
+        # a transaction that the sink applies twice shows up as a duplicate.
+        pg_conn.execute(dedent("""
+            CREATE SCHEMA sink_restart;
+            CREATE TABLE sink_restart.rows (id integer PRIMARY KEY, v text);
+            CREATE TABLE sink_restart.applied (id integer NOT NULL);
+            CREATE FUNCTION sink_restart.record_applied() RETURNS trigger AS $$
+            BEGIN
+                INSERT INTO sink_restart.applied VALUES (NEW.id);
+                RETURN NEW;
+            END;
+            $$ LANGUAGE plpgsql;
+            CREATE TRIGGER record_applied AFTER INSERT OR UPDATE ON sink_restart.rows
+                FOR EACH ROW EXECUTE FUNCTION sink_restart.record_applied();
+            """))
+
+        c.sql(
+            "ALTER SYSTEM SET enable_postgres_sink TO true",
+            port=6877,
+            user="mz_system",
+        )
+        c.sql(dedent("""
+            CREATE SECRET pgpass AS 'postgres';
+            CREATE CONNECTION pg TO POSTGRES (
+                HOST postgres,
+                DATABASE postgres,
+                USER postgres,
+                PASSWORD SECRET pgpass
+            );
+            CREATE TABLE restart_src (id int NOT NULL, v text);
+            CREATE SINK restart_sink
+                FROM restart_src
+                INTO POSTGRES CONNECTION pg (SCHEMA 'sink_restart', TABLE 'rows')
+                KEY (id) NOT ENFORCED
+                MODE UPSERT;
+            """))
+
+        batch_size = 100
+
+        def insert_batches(batches: range) -> None:
+            # Each batch is its own transaction, and so its own timestamp and
+            # its own transaction of the sink.
+            for batch in batches:
+                start, end = batch * batch_size + 1, (batch + 1) * batch_size
+                c.sql(
+                    f"INSERT INTO restart_src SELECT id, 'v' || id FROM generate_series({start}, {end}) AS id",
+                    reuse_connection=False,
+                )
+
+        def count(table: str) -> int:
+            return pg_conn.execute(f"SELECT count(*) FROM {table}").fetchone()[0]
+
+        def await_rows(n: int) -> None:
+            for _ in range(120):
+                if count("sink_restart.rows") >= n:
+                    return
+                time.sleep(1)
+            raise RuntimeError(f"sink did not write {n} rows")
+
+        insert_batches(range(0, 20))
+        # Kill environmentd as soon as the sink has written anything, which is
+        # usually before it has caught up with the inserts.
+        await_rows(1)
+        c.kill("materialized")
+        written = count("sink_restart.rows")
+        print(f"{written} rows written before the restart")
+
+        c.up("materialized")
+        insert_batches(range(20, 40))
+        await_rows(40 * batch_size)
+
+        assert count("sink_restart.rows") == 40 * batch_size
+        applied, distinct = pg_conn.execute(
+            "SELECT count(*), count(DISTINCT id) FROM sink_restart.applied"
+        ).fetchone()
+        assert (
+            applied == distinct == 40 * batch_size
+        ), f"{applied} applications of {distinct} distinct rows"
+
+        # The restarted sink fenced out the killed one by taking over the
+        # progress row.
+        epoch = pg_conn.execute(
+            "SELECT epoch FROM sink_restart._mz_sink_progress"
+        ).fetchone()[0]
+        assert epoch == 2, f"expected epoch 2, found {epoch}"
+
+
 def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
     def process(name: str) -> None:
         if name in ("default", "large-scale"):